@@ -0,0 +1,14 @@
+-- Add migration script here
+-- Create task collaborators table
+CREATE TYPE collaborator_role AS ENUM ('viewer', 'editor', 'owner');
+
+CREATE TABLE IF NOT EXISTS task_collaborators (
+    task_id UUID NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    role collaborator_role NOT NULL DEFAULT 'viewer',
+    invited_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (task_id, user_id)
+);
+
+CREATE INDEX idx_task_collaborators_user_id ON task_collaborators(user_id);
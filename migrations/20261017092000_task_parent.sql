@@ -0,0 +1,5 @@
+-- Add migration script here
+-- Add parent_id to tasks (subtasks)
+ALTER TABLE tasks ADD COLUMN parent_id UUID REFERENCES tasks(id) ON DELETE CASCADE;
+
+CREATE INDEX idx_tasks_parent_id ON tasks(parent_id);
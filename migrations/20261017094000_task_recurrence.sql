@@ -0,0 +1,8 @@
+-- Add migration script here
+-- Add recurrence rule and series link to tasks
+ALTER TABLE tasks
+    ADD COLUMN recurrence_rule TEXT,
+    ADD COLUMN series_id UUID REFERENCES tasks(id) ON DELETE SET NULL,
+    ADD COLUMN occurrence INTEGER NOT NULL DEFAULT 1;
+
+CREATE INDEX idx_tasks_series_id ON tasks(series_id);
@@ -0,0 +1,3 @@
+-- Add migration script here
+-- Index for streaming task history in (changed_at, id) order
+CREATE INDEX IF NOT EXISTS idx_task_history_changed_at_id ON task_history(changed_at, id);
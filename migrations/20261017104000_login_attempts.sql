@@ -0,0 +1,17 @@
+-- Add migration script here
+-- Create login_attempts table (brute-force protection and login audit log)
+CREATE TYPE login_outcome AS ENUM ('success', 'mfa_required', 'invalid_credentials', 'invalid_mfa_code', 'locked');
+
+CREATE TABLE IF NOT EXISTS login_attempts (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID REFERENCES users(id) ON DELETE SET NULL,
+    email VARCHAR(255) NOT NULL,
+    ip_address VARCHAR(45),
+    user_agent TEXT,
+    outcome login_outcome NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_login_attempts_email ON login_attempts(email, created_at);
+CREATE INDEX idx_login_attempts_ip ON login_attempts(ip_address, created_at);
+CREATE INDEX idx_login_attempts_user_id ON login_attempts(user_id);
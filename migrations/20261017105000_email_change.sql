@@ -0,0 +1,3 @@
+-- Add migration script here
+-- Add pending email change to verification tokens (the address switches only after confirmation)
+ALTER TABLE email_verification_tokens ADD COLUMN new_email VARCHAR(255);
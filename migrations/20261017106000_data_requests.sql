@@ -0,0 +1,24 @@
+-- Add migration script here
+-- Create data_requests table (background data export and account erasure jobs)
+CREATE TYPE data_request_kind AS ENUM ('export', 'erasure');
+CREATE TYPE data_request_status AS ENUM ('pending', 'processing', 'completed', 'failed', 'expired');
+
+CREATE TABLE IF NOT EXISTS data_requests (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    kind data_request_kind NOT NULL,
+    status data_request_status NOT NULL DEFAULT 'pending',
+    storage_key VARCHAR(255),
+    error TEXT,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    requested_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    started_at TIMESTAMP WITH TIME ZONE,
+    completed_at TIMESTAMP WITH TIME ZONE,
+    expires_at TIMESTAMP WITH TIME ZONE
+);
+
+CREATE INDEX idx_data_requests_user_id ON data_requests(user_id, kind, requested_at DESC);
+CREATE INDEX idx_data_requests_status ON data_requests(status, requested_at);
+
+-- Erased accounts are kept anonymized for aggregate statistics
+ALTER TABLE users ADD COLUMN erased_at TIMESTAMP WITH TIME ZONE;
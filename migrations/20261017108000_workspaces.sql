@@ -0,0 +1,53 @@
+-- Add migration script here
+-- Create workspaces (tenants) and memberships, and scope tasks to a workspace
+CREATE TYPE workspace_role AS ENUM ('member', 'admin', 'owner');
+
+CREATE TABLE IF NOT EXISTS workspaces (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    name VARCHAR(100) NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE IF NOT EXISTS workspace_members (
+    workspace_id UUID NOT NULL REFERENCES workspaces(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    role workspace_role NOT NULL DEFAULT 'member',
+    invited_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (workspace_id, user_id)
+);
+
+CREATE INDEX idx_workspace_members_user_id ON workspace_members(user_id, created_at);
+
+-- Every existing user gets a personal workspace (same id as the user) holding their tasks
+INSERT INTO workspaces (id, name, created_at, updated_at)
+SELECT id, 'Pessoal', created_at, created_at FROM users;
+
+INSERT INTO workspace_members (workspace_id, user_id, role, created_at)
+SELECT id, id, 'owner', created_at FROM users;
+
+ALTER TABLE tasks ADD COLUMN workspace_id UUID REFERENCES workspaces(id) ON DELETE CASCADE;
+UPDATE tasks SET workspace_id = user_id;
+ALTER TABLE tasks ALTER COLUMN workspace_id SET NOT NULL;
+
+CREATE INDEX idx_tasks_workspace_id ON tasks(workspace_id, created_at DESC);
+
+-- Active workspace of each session (carried in the access token)
+ALTER TABLE sessions ADD COLUMN workspace_id UUID REFERENCES workspaces(id) ON DELETE SET NULL;
+
+-- Tenant isolation: when app.workspace_id is set (transaction-local), only that
+-- workspace's tasks are visible or writable. Background jobs leave it unset.
+-- Not enforced for superusers or roles with BYPASSRLS.
+ALTER TABLE tasks ENABLE ROW LEVEL SECURITY;
+ALTER TABLE tasks FORCE ROW LEVEL SECURITY;
+
+CREATE POLICY tasks_workspace_isolation ON tasks
+    USING (
+        NULLIF(current_setting('app.workspace_id', true), '') IS NULL
+        OR workspace_id = NULLIF(current_setting('app.workspace_id', true), '')::uuid
+    )
+    WITH CHECK (
+        NULLIF(current_setting('app.workspace_id', true), '') IS NULL
+        OR workspace_id = NULLIF(current_setting('app.workspace_id', true), '')::uuid
+    );
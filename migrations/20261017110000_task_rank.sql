@@ -0,0 +1,17 @@
+-- Add migration script here
+-- Board position of each task: fractional index keys compared byte by byte,
+-- so a move only rewrites the moved task
+ALTER TABLE tasks ADD COLUMN rank TEXT COLLATE "C";
+
+-- Existing tasks keep their creation order inside each workspace
+UPDATE tasks
+SET rank = 'a0' || lpad(ranked.n::text, 10, '0') || '1'
+FROM (
+    SELECT id, row_number() OVER (PARTITION BY workspace_id ORDER BY created_at, id) AS n
+    FROM tasks
+) ranked
+WHERE tasks.id = ranked.id;
+
+ALTER TABLE tasks ALTER COLUMN rank SET NOT NULL;
+
+CREATE INDEX idx_tasks_board ON tasks(workspace_id, status, rank);
@@ -0,0 +1,41 @@
+-- Add migration script here
+-- Custom workflows: user-defined states mapped to a task_status category and
+-- the allowed transitions between them. Tasks without a state keep the
+-- built-in task_status transitions, so existing data needs no conversion.
+CREATE TABLE IF NOT EXISTS workflows (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    workspace_id UUID NOT NULL REFERENCES workspaces(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_workflows_workspace_id ON workflows(workspace_id, created_at);
+
+CREATE TABLE IF NOT EXISTS workflow_states (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    workflow_id UUID NOT NULL REFERENCES workflows(id) ON DELETE CASCADE,
+    name VARCHAR(50) NOT NULL,
+    category task_status NOT NULL,
+    position INTEGER NOT NULL,
+    UNIQUE (workflow_id, name)
+);
+
+CREATE INDEX idx_workflow_states_workflow_id ON workflow_states(workflow_id, position);
+
+CREATE TABLE IF NOT EXISTS workflow_transitions (
+    workflow_id UUID NOT NULL REFERENCES workflows(id) ON DELETE CASCADE,
+    from_state_id UUID NOT NULL REFERENCES workflow_states(id) ON DELETE CASCADE,
+    to_state_id UUID NOT NULL REFERENCES workflow_states(id) ON DELETE CASCADE,
+    PRIMARY KEY (from_state_id, to_state_id)
+);
+
+CREATE INDEX idx_workflow_transitions_workflow_id ON workflow_transitions(workflow_id);
+
+-- Projects opt into a workflow; deleting it sends the project back to the built-in one
+ALTER TABLE projects ADD COLUMN workflow_id UUID REFERENCES workflows(id) ON DELETE SET NULL;
+
+-- Current state of a task inside its project's workflow (NULL = built-in transitions)
+ALTER TABLE tasks ADD COLUMN state_id UUID REFERENCES workflow_states(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_tasks_state_id ON tasks(state_id);
@@ -0,0 +1,10 @@
+-- Add migration script here
+-- Anchor MONTHLY/YEARLY recurrences on the first occurrence of the series
+ALTER TABLE tasks ADD COLUMN series_start TIMESTAMPTZ;
+
+-- Existing series start at the due date of their first task (when it still exists)
+UPDATE tasks
+SET series_start = first.due_date
+FROM tasks first
+WHERE tasks.series_id = first.id
+  AND tasks.occurrence > 1;
@@ -0,0 +1,12 @@
+-- Add migration script here
+-- Store login attempt IPs as INET (values that do not parse become NULL)
+CREATE FUNCTION pg_temp.try_inet(value TEXT) RETURNS INET AS $$
+BEGIN
+    RETURN value::inet;
+EXCEPTION WHEN others THEN
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+ALTER TABLE login_attempts
+    ALTER COLUMN ip_address TYPE INET USING pg_temp.try_inet(ip_address);
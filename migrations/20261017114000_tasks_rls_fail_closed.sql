@@ -0,0 +1,12 @@
+-- Add migration script here
+-- Make tenant isolation fail closed: without app.workspace_id no task is visible;
+-- cross-tenant access (background jobs, statistics, data export/erasure) must opt in with app.bypass_rls
+DROP POLICY tasks_workspace_isolation ON tasks;
+
+CREATE POLICY tasks_workspace_isolation ON tasks
+    USING (workspace_id = NULLIF(current_setting('app.workspace_id', true), '')::uuid)
+    WITH CHECK (workspace_id = NULLIF(current_setting('app.workspace_id', true), '')::uuid);
+
+CREATE POLICY tasks_cross_tenant_access ON tasks
+    USING (current_setting('app.bypass_rls', true) = 'on')
+    WITH CHECK (current_setting('app.bypass_rls', true) = 'on');
@@ -0,0 +1,15 @@
+-- Add migration script here
+-- Each occurrence of a series is created once, even when completions race
+-- Duplicates created before this constraint are detached from the series (the oldest one stays)
+UPDATE tasks
+SET series_id = NULL
+WHERE id IN (
+    SELECT id FROM (
+        SELECT id, ROW_NUMBER() OVER (PARTITION BY series_id, occurrence ORDER BY created_at, id) AS position
+        FROM tasks
+        WHERE series_id IS NOT NULL
+    ) occurrences
+    WHERE position > 1
+);
+
+CREATE UNIQUE INDEX idx_tasks_series_occurrence ON tasks(series_id, occurrence);
@@ -0,0 +1,118 @@
+use crate::domain::entities::User;
+use crate::domain::repositories::SystemStatistics;
+use crate::domain::value_objects::{TaskPriority, TaskStatus, UserRole};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// DTO para filtros da listagem administrativa de usuários
+#[derive(Debug, Deserialize)]
+pub struct AdminUserFilterDto {
+    /// Trecho do email ou do nome
+    pub search: Option<String>,
+    pub role: Option<UserRole>,
+    pub disabled: Option<bool>,
+    pub page: Option<u32>,
+    pub page_size: Option<u32>,
+}
+
+/// DTO de resposta de usuário na visão administrativa
+#[derive(Debug, Serialize)]
+pub struct AdminUserResponseDto {
+    pub id: Uuid,
+    pub email: String,
+    pub name: String,
+    pub role: UserRole,
+    pub email_verified: bool,
+    pub disabled_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<User> for AdminUserResponseDto {
+    fn from(user: User) -> Self {
+        Self {
+            id: user.id,
+            email_verified: user.is_email_verified(),
+            email: user.email.as_str().to_string(),
+            name: user.name,
+            role: user.role,
+            disabled_at: user.disabled_at,
+            created_at: user.created_at,
+            updated_at: user.updated_at,
+        }
+    }
+}
+
+/// DTO de contagem de tarefas por status
+#[derive(Debug, Serialize)]
+pub struct StatusCountDto {
+    pub status: TaskStatus,
+    pub count: i64,
+}
+
+/// DTO de contagem de tarefas por prioridade
+#[derive(Debug, Serialize)]
+pub struct PriorityCountDto {
+    pub priority: TaskPriority,
+    pub count: i64,
+}
+
+/// DTO de estatísticas de usuários
+#[derive(Debug, Serialize)]
+pub struct UserStatisticsDto {
+    pub total: i64,
+    pub admins: i64,
+    pub disabled: i64,
+    pub erased: i64,
+}
+
+/// DTO de estatísticas de tarefas
+#[derive(Debug, Serialize)]
+pub struct TaskStatisticsDto {
+    pub total: i64,
+    pub overdue: i64,
+    /// Tarefas criadas na janela `recent_days`
+    pub recent: i64,
+    pub recent_days: i64,
+    pub by_status: Vec<StatusCountDto>,
+    pub by_priority: Vec<PriorityCountDto>,
+}
+
+/// DTO de resposta das estatísticas do sistema
+#[derive(Debug, Serialize)]
+pub struct SystemStatisticsDto {
+    pub users: UserStatisticsDto,
+    pub tasks: TaskStatisticsDto,
+    pub generated_at: DateTime<Utc>,
+}
+
+impl SystemStatisticsDto {
+    pub fn from_statistics(statistics: SystemStatistics, recent_days: i64) -> Self {
+        Self {
+            users: UserStatisticsDto {
+                total: statistics.total_users,
+                admins: statistics.admin_users,
+                disabled: statistics.disabled_users,
+                erased: statistics.erased_users,
+            },
+            tasks: TaskStatisticsDto {
+                total: statistics.total_tasks,
+                overdue: statistics.overdue_tasks,
+                recent: statistics.recent_tasks,
+                recent_days,
+                by_status: statistics
+                    .tasks_by_status
+                    .into_iter()
+                    .map(|(status, count)| StatusCountDto { status, count })
+                    .collect(),
+                by_priority: statistics
+                    .tasks_by_priority
+                    .into_iter()
+                    .map(|(priority, count)| PriorityCountDto { priority, count })
+                    .collect(),
+            },
+            generated_at: Utc::now(),
+        }
+    }
+}
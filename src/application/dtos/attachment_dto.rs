@@ -0,0 +1,40 @@
+use crate::domain::entities::Attachment;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+/// Arquivo recebido no upload (montado a partir do multipart)
+#[derive(Debug)]
+pub struct UploadAttachmentDto {
+    pub file_name: String,
+    pub content_type: Option<String>,
+    pub content: Vec<u8>,
+}
+
+/// DTO de resposta de anexo
+#[derive(Debug, Serialize)]
+pub struct AttachmentResponseDto {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub user_id: Uuid,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub sha256: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Attachment> for AttachmentResponseDto {
+    fn from(attachment: Attachment) -> Self {
+        Self {
+            id: attachment.id,
+            task_id: attachment.task_id,
+            user_id: attachment.user_id,
+            file_name: attachment.file_name,
+            content_type: attachment.content_type,
+            size_bytes: attachment.size_bytes,
+            sha256: attachment.sha256,
+            created_at: attachment.created_at,
+        }
+    }
+}
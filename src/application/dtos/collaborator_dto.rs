@@ -0,0 +1,26 @@
+use crate::domain::value_objects::CollaboratorRole;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+/// DTO para convidar um colaborador para uma tarefa
+#[derive(Debug, Deserialize, Validate)]
+pub struct InviteCollaboratorDto {
+    #[validate(email(message = "Email inválido"))]
+    pub email: String,
+
+    pub role: Option<CollaboratorRole>,
+}
+
+/// DTO de resposta de colaborador
+#[derive(Debug, Serialize)]
+pub struct CollaboratorResponseDto {
+    pub task_id: Uuid,
+    pub user_id: Uuid,
+    pub email: String,
+    pub name: String,
+    pub role: CollaboratorRole,
+    pub invited_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,53 @@
+use crate::domain::entities::Comment;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+/// DTO para criação de comentário
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateCommentDto {
+    #[validate(length(
+        min = 1,
+        max = 5000,
+        message = "Comentário deve ter entre 1 e 5000 caracteres"
+    ))]
+    pub body: String,
+}
+
+/// DTO para edição de comentário
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateCommentDto {
+    #[validate(length(
+        min = 1,
+        max = 5000,
+        message = "Comentário deve ter entre 1 e 5000 caracteres"
+    ))]
+    pub body: String,
+}
+
+/// DTO de resposta de comentário
+#[derive(Debug, Serialize)]
+pub struct CommentResponseDto {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub user_id: Uuid,
+    pub body: String,
+    pub edited: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Comment> for CommentResponseDto {
+    fn from(comment: Comment) -> Self {
+        Self {
+            edited: comment.is_edited(),
+            id: comment.id,
+            task_id: comment.task_id,
+            user_id: comment.user_id,
+            body: comment.body,
+            created_at: comment.created_at,
+            updated_at: comment.updated_at,
+        }
+    }
+}
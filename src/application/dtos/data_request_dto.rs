@@ -0,0 +1,54 @@
+use crate::domain::entities::DataRequest;
+use crate::domain::repositories::PersonalData;
+use crate::domain::value_objects::{DataRequestKind, DataRequestStatus};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+/// Caminho para download da exportação concluída
+pub const DATA_EXPORT_DOWNLOAD_PATH: &str = "/api/users/me/export/download";
+
+/// DTO de resposta de uma solicitação de exportação ou eliminação de dados
+#[derive(Debug, Serialize)]
+pub struct DataRequestResponseDto {
+    pub id: Uuid,
+    pub kind: DataRequestKind,
+    pub status: DataRequestStatus,
+    pub attempts: i32,
+    pub error: Option<String>,
+    pub requested_at: DateTime<Utc>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+    /// Fim da disponibilidade do arquivo (apenas exportações)
+    pub expires_at: Option<DateTime<Utc>>,
+    /// Link do arquivo (apenas exportações concluídas)
+    pub download_url: Option<String>,
+}
+
+impl From<DataRequest> for DataRequestResponseDto {
+    fn from(request: DataRequest) -> Self {
+        Self {
+            download_url: request
+                .is_downloadable()
+                .then(|| DATA_EXPORT_DOWNLOAD_PATH.to_string()),
+            id: request.id,
+            kind: request.kind,
+            status: request.status,
+            attempts: request.attempts,
+            error: request.error,
+            requested_at: request.requested_at,
+            started_at: request.started_at,
+            completed_at: request.completed_at,
+            expires_at: request.expires_at,
+        }
+    }
+}
+
+/// Arquivo de exportação dos dados pessoais (JSON)
+#[derive(Debug, Serialize)]
+pub struct PersonalDataExportDto {
+    pub request_id: Uuid,
+    pub generated_at: DateTime<Utc>,
+    #[serde(flatten)]
+    pub data: PersonalData,
+}
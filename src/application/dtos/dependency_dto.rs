@@ -0,0 +1,51 @@
+use crate::application::dtos::TaskResponseDto;
+use crate::domain::entities::TaskDependency;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// DTO para adicionar uma dependência ("bloqueada por")
+#[derive(Debug, Deserialize)]
+pub struct AddDependencyDto {
+    pub blocked_by_id: Uuid,
+}
+
+/// DTO de resposta de dependência
+#[derive(Debug, Serialize)]
+pub struct DependencyResponseDto {
+    pub task_id: Uuid,
+    pub blocked_by_id: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<TaskDependency> for DependencyResponseDto {
+    fn from(dependency: TaskDependency) -> Self {
+        Self {
+            task_id: dependency.task_id,
+            blocked_by_id: dependency.blocked_by_id,
+            created_at: dependency.created_at,
+        }
+    }
+}
+
+/// DTO de resposta com as dependências de uma tarefa
+#[derive(Debug, Serialize)]
+pub struct TaskDependenciesResponseDto {
+    pub task_id: Uuid,
+    /// Tarefas que bloqueiam esta tarefa
+    pub blocked_by: Vec<TaskResponseDto>,
+    /// Tarefas bloqueadas por esta tarefa
+    pub blocking: Vec<TaskResponseDto>,
+    /// Indica se todas as bloqueadoras estão finalizadas
+    pub ready: bool,
+}
+
+/// DTO de resposta da listagem "o que fazer a seguir"
+#[derive(Debug, Serialize)]
+pub struct NextTaskResponseDto {
+    #[serde(flatten)]
+    pub task: TaskResponseDto,
+    pub ready: bool,
+    /// Bloqueadoras ainda não finalizadas
+    pub blocked_by: Vec<Uuid>,
+}
@@ -0,0 +1,16 @@
+use serde::Deserialize;
+use validator::Validate;
+
+/// Query string do link de verificação de email
+#[derive(Debug, Deserialize, Validate)]
+pub struct VerifyEmailQueryDto {
+    #[validate(length(min = 1, message = "Token é obrigatório"))]
+    pub token: String,
+}
+
+/// DTO para reenviar o email de verificação
+#[derive(Debug, Deserialize, Validate)]
+pub struct ResendVerificationDto {
+    #[validate(email(message = "Email inválido"))]
+    pub email: String,
+}
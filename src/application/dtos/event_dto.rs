@@ -0,0 +1,31 @@
+use crate::application::dtos::TaskResponseDto;
+use crate::domain::events::{TaskEvent, TaskEventKind};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+/// Mensagem de evento de tarefa enviada aos clientes em tempo real
+#[derive(Debug, Serialize)]
+pub struct TaskEventMessageDto {
+    pub id: Uuid,
+    #[serde(rename = "type")]
+    pub kind: TaskEventKind,
+    pub task_id: Uuid,
+    pub actor_id: Uuid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task: Option<TaskResponseDto>,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl From<TaskEvent> for TaskEventMessageDto {
+    fn from(event: TaskEvent) -> Self {
+        Self {
+            id: event.id,
+            kind: event.kind,
+            task_id: event.task_id,
+            actor_id: event.actor_id,
+            task: event.task.map(Into::into),
+            occurred_at: event.occurred_at,
+        }
+    }
+}
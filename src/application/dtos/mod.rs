@@ -1,5 +1,35 @@
+pub mod admin_dto;
+pub mod attachment_dto;
+pub mod collaborator_dto;
+pub mod comment_dto;
+pub mod data_request_dto;
+pub mod dependency_dto;
+pub mod email_verification_dto;
+pub mod event_dto;
+pub mod password_reset_dto;
+pub mod project_dto;
+pub mod session_dto;
 pub mod task_dto;
+pub mod two_factor_dto;
 pub mod user_dto;
+pub mod webhook_dto;
+pub mod workflow_dto;
+pub mod workspace_dto;
 
+pub use admin_dto::*;
+pub use attachment_dto::*;
+pub use collaborator_dto::*;
+pub use comment_dto::*;
+pub use data_request_dto::*;
+pub use dependency_dto::*;
+pub use email_verification_dto::*;
+pub use event_dto::*;
+pub use password_reset_dto::*;
+pub use project_dto::*;
+pub use session_dto::*;
 pub use task_dto::*;
+pub use two_factor_dto::*;
 pub use user_dto::*;
+pub use webhook_dto::*;
+pub use workflow_dto::*;
+pub use workspace_dto::*;
@@ -0,0 +1,19 @@
+use serde::Deserialize;
+use validator::Validate;
+
+/// DTO para solicitar a redefinição de senha
+#[derive(Debug, Deserialize, Validate)]
+pub struct ForgotPasswordDto {
+    #[validate(email(message = "Email inválido"))]
+    pub email: String,
+}
+
+/// DTO para redefinir a senha com o token recebido por email
+#[derive(Debug, Deserialize, Validate)]
+pub struct ResetPasswordDto {
+    #[validate(length(min = 1, message = "Token é obrigatório"))]
+    pub token: String,
+
+    #[validate(length(min = 8, message = "Senha deve ter pelo menos 8 caracteres"))]
+    pub new_password: String,
+}
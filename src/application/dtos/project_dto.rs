@@ -0,0 +1,78 @@
+use crate::application::dtos::StatusCountDto;
+use crate::application::dtos::task_dto::nullable;
+use crate::domain::entities::Project;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+/// DTO para criação de projeto
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateProjectDto {
+    #[validate(length(min = 1, max = 100, message = "Nome deve ter entre 1 e 100 caracteres"))]
+    pub name: String,
+
+    /// Cor no formato `#rrggbb`
+    pub color: Option<String>,
+}
+
+/// DTO para atualização de projeto (campos omitidos não são alterados)
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateProjectDto {
+    #[validate(length(min = 1, max = 100, message = "Nome deve ter entre 1 e 100 caracteres"))]
+    pub name: Option<String>,
+
+    /// Cor no formato `#rrggbb` (string vazia remove a cor)
+    pub color: Option<String>,
+
+    #[validate(range(min = 0, message = "Posição não pode ser negativa"))]
+    pub position: Option<i32>,
+
+    pub archived: Option<bool>,
+
+    /// Workflow das tarefas do projeto (`null` volta aos status padrão)
+    #[serde(default, deserialize_with = "nullable")]
+    pub workflow_id: Option<Option<Uuid>>,
+}
+
+/// DTO para filtros de listagem de projetos
+#[derive(Debug, Deserialize)]
+pub struct ProjectFilterDto {
+    /// Inclui projetos arquivados
+    pub include_archived: Option<bool>,
+}
+
+/// DTO de resposta de projeto
+#[derive(Debug, Serialize)]
+pub struct ProjectResponseDto {
+    pub id: Uuid,
+    pub workspace_id: Uuid,
+    pub name: String,
+    pub color: Option<String>,
+    pub position: i32,
+    pub workflow_id: Option<Uuid>,
+    pub archived: bool,
+    pub archived_at: Option<DateTime<Utc>>,
+    /// Quantidade de tarefas do projeto por status
+    pub task_counts: Vec<StatusCountDto>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ProjectResponseDto {
+    pub fn new(project: Project, task_counts: Vec<StatusCountDto>) -> Self {
+        Self {
+            id: project.id,
+            workspace_id: project.workspace_id,
+            name: project.name,
+            color: project.color,
+            position: project.position,
+            workflow_id: project.workflow_id,
+            archived: project.archived_at.is_some(),
+            archived_at: project.archived_at,
+            task_counts,
+            created_at: project.created_at,
+            updated_at: project.updated_at,
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use crate::domain::entities::Session;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Informações do cliente que inicia ou renova uma sessão
+#[derive(Debug, Clone, Default)]
+pub struct ClientInfoDto {
+    pub user_agent: Option<String>,
+    /// IP do cliente, resolvido pelo extractor `ClientInfo` a partir dos proxies confiáveis
+    pub ip_address: Option<IpAddr>,
+}
+
+/// Par de tokens emitido no login e na renovação
+#[derive(Debug, Serialize)]
+pub struct TokenResponseDto {
+    /// Access token (JWT de curta duração)
+    pub token: String,
+    /// Refresh token opaco (uso único; cada renovação emite outro)
+    pub refresh_token: String,
+    pub token_type: String,
+    /// Validade do access token em segundos
+    pub expires_in: i64,
+}
+
+/// DTO com o refresh token (renovação e logout)
+#[derive(Debug, Deserialize, Validate)]
+pub struct RefreshTokenDto {
+    #[validate(length(min = 1, message = "Refresh token é obrigatório"))]
+    pub refresh_token: String,
+}
+
+/// DTO de resposta de sessão ativa
+#[derive(Debug, Serialize)]
+pub struct SessionResponseDto {
+    pub id: Uuid,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub last_used_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    /// Sessão do access token usado na requisição
+    pub current: bool,
+}
+
+impl SessionResponseDto {
+    pub fn from_session(session: Session, current_session_id: Option<Uuid>) -> Self {
+        Self {
+            current: current_session_id == Some(session.family_id),
+            id: session.family_id,
+            user_agent: session.user_agent,
+            ip_address: session.ip_address,
+            started_at: session.started_at,
+            last_used_at: session.last_used_at,
+            expires_at: session.expires_at,
+        }
+    }
+}
@@ -1,6 +1,8 @@
-use crate::domain::value_objects::{TaskPriority, TaskStatus};
+use crate::domain::entities::Task;
+use crate::domain::repositories::task_repository::TaskSort;
+use crate::domain::value_objects::{Rank, TaskPriority, TaskStatus};
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use uuid::Uuid;
 use validator::Validate;
 
@@ -20,6 +22,18 @@ pub struct CreateTaskDto {
     pub priority: Option<TaskPriority>,
 
     pub due_date: Option<DateTime<Utc>>,
+
+    #[validate(length(max = 20, message = "Uma tarefa pode ter no máximo 20 tags"))]
+    pub tags: Option<Vec<String>>,
+
+    /// Tarefa pai (cria a tarefa como subtarefa)
+    pub parent_id: Option<Uuid>,
+
+    /// Regra de recorrência no formato RRULE (ex.: `FREQ=WEEKLY;BYDAY=MO`)
+    pub recurrence: Option<String>,
+
+    /// Projeto do workspace ao qual a tarefa pertence
+    pub project_id: Option<Uuid>,
 }
 
 /// DTO para atualização de tarefa
@@ -40,6 +54,28 @@ pub struct UpdateTaskDto {
     pub priority: Option<TaskPriority>,
 
     pub due_date: Option<DateTime<Utc>>,
+
+    #[validate(length(max = 20, message = "Uma tarefa pode ter no máximo 20 tags"))]
+    pub tags: Option<Vec<String>>,
+
+    /// Regra de recorrência no formato RRULE (string vazia remove a recorrência)
+    pub recurrence: Option<String>,
+
+    /// Projeto da tarefa (`null` remove a tarefa do projeto)
+    #[serde(default, deserialize_with = "nullable")]
+    pub project_id: Option<Option<Uuid>>,
+
+    /// Estado do workflow do projeto para o qual a tarefa deve ir
+    pub state_id: Option<Uuid>,
+}
+
+/// Distingue campo ausente (`None`) de `null` explícito (`Some(None)`)
+pub(crate) fn nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::deserialize(deserializer).map(Some)
 }
 
 /// DTO de resposta de tarefa
@@ -47,28 +83,149 @@ pub struct UpdateTaskDto {
 pub struct TaskResponseDto {
     pub id: Uuid,
     pub user_id: Uuid,
+    pub workspace_id: Uuid,
+    pub project_id: Option<Uuid>,
+    pub parent_id: Option<Uuid>,
     pub title: String,
     pub description: Option<String>,
     pub status: TaskStatus,
+    pub rank: Rank,
+    /// Estado no workflow do projeto (ausente quando a tarefa segue os status padrão)
+    pub state_id: Option<Uuid>,
     pub priority: TaskPriority,
     pub due_date: Option<DateTime<Utc>>,
     pub completed_at: Option<DateTime<Utc>>,
     pub is_overdue: bool,
+    pub tags: Vec<String>,
+    pub subtask_count: i64,
+    pub completed_subtask_count: i64,
+    /// Percentual de subtarefas concluídas (ausente quando não há subtarefas)
+    pub progress_percentage: Option<u8>,
+    pub recurrence: Option<String>,
+    pub series_id: Option<Uuid>,
+    pub occurrence: i32,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+impl From<Task> for TaskResponseDto {
+    fn from(task: Task) -> Self {
+        let is_overdue = task.is_overdue();
+
+        Self {
+            id: task.id,
+            user_id: task.user_id,
+            workspace_id: task.workspace_id,
+            project_id: task.project_id,
+            parent_id: task.parent_id,
+            title: task.title,
+            description: task.description,
+            status: task.status,
+            rank: task.rank,
+            state_id: task.state_id,
+            priority: task.priority,
+            due_date: task.due_date,
+            completed_at: task.completed_at,
+            is_overdue,
+            tags: task.tags,
+            subtask_count: task.subtasks.total,
+            completed_subtask_count: task.subtasks.completed,
+            progress_percentage: task.subtasks.percentage(),
+            recurrence: task.recurrence.map(|rule| rule.to_string()),
+            series_id: task.series_id,
+            occurrence: task.occurrence,
+            created_at: task.created_at,
+            updated_at: task.updated_at,
+        }
+    }
+}
+
 /// DTO para filtros de listagem
 #[derive(Debug, Deserialize)]
 pub struct TaskFilterDto {
     pub status: Option<TaskStatus>,
     pub priority: Option<TaskPriority>,
     pub overdue_only: Option<bool>,
+    /// Inclui tarefas compartilhadas com o usuário
+    pub include_shared: Option<bool>,
     pub search: Option<String>,
+    /// Lista de tags separadas por vírgula (qualquer uma)
+    pub tags_any: Option<String>,
+    /// Lista de tags separadas por vírgula (todas)
+    pub tags_all: Option<String>,
+    pub project_id: Option<Uuid>,
+    /// `newest` (padrão) ou `rank` (ordem do quadro)
+    pub sort: Option<TaskSort>,
     pub page: Option<u32>,
     pub page_size: Option<u32>,
 }
 
+/// DTO para mover uma tarefa no quadro
+///
+/// `before_id` é a tarefa que fica imediatamente acima da movida e `after_id` a
+/// que fica imediatamente abaixo, ambas na coluna de destino; omitir um deles
+/// coloca a tarefa no início ou no fim da coluna.
+#[derive(Debug, Deserialize)]
+pub struct MoveTaskDto {
+    pub status: TaskStatus,
+    pub before_id: Option<Uuid>,
+    pub after_id: Option<Uuid>,
+}
+
+/// DTO para reabrir tarefa concluída ou cancelada
+#[derive(Debug, Deserialize, Validate)]
+pub struct ReopenTaskDto {
+    /// Motivo da reabertura (registrado no histórico)
+    #[validate(length(
+        min = 1,
+        max = 500,
+        message = "Motivo deve ter entre 1 e 500 caracteres"
+    ))]
+    pub reason: String,
+}
+
+/// DTO de parâmetros do quadro
+#[derive(Debug, Deserialize)]
+pub struct BoardQueryDto {
+    pub project_id: Option<Uuid>,
+    /// Inclui tarefas compartilhadas com o usuário
+    pub include_shared: Option<bool>,
+    /// Máximo de tarefas por coluna (padrão: 50)
+    pub limit: Option<u32>,
+}
+
+/// DTO de uma coluna do quadro
+#[derive(Debug, Serialize)]
+pub struct BoardColumnDto {
+    pub status: TaskStatus,
+    /// Total de tarefas na coluna (pode exceder as retornadas)
+    pub total: i64,
+    pub tasks: Vec<TaskResponseDto>,
+}
+
+/// DTO de resposta do quadro (uma coluna por status)
+#[derive(Debug, Serialize)]
+pub struct BoardResponseDto {
+    pub columns: Vec<BoardColumnDto>,
+}
+
+/// O que fazer com as subtarefas ao deletar uma tarefa
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SubtaskDeletionMode {
+    /// Deleta as subtarefas junto com a tarefa
+    #[default]
+    Cascade,
+    /// Move as subtarefas para o pai da tarefa deletada (ou para a raiz)
+    Reparent,
+}
+
+/// DTO de parâmetros da deleção de tarefa
+#[derive(Debug, Deserialize)]
+pub struct DeleteTaskQueryDto {
+    pub subtasks: Option<SubtaskDeletionMode>,
+}
+
 /// DTO de resposta paginada
 #[derive(Debug, Serialize)]
 pub struct PaginatedResponseDto<T> {
@@ -0,0 +1,53 @@
+use crate::application::dtos::AuthResponseDto;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// DTO de resposta da inscrição TOTP
+#[derive(Debug, Serialize)]
+pub struct TotpEnrollmentResponseDto {
+    /// Segredo em base32 (para digitação manual)
+    pub secret: String,
+    /// URI `otpauth://` para gerar o QR code
+    pub otpauth_uri: String,
+}
+
+/// DTO com um código TOTP ou de recuperação
+#[derive(Debug, Deserialize, Validate)]
+pub struct TotpCodeDto {
+    #[validate(length(min = 1, message = "Código é obrigatório"))]
+    pub code: String,
+}
+
+/// DTO de resposta com os códigos de recuperação (exibidos uma única vez)
+#[derive(Debug, Serialize)]
+pub struct RecoveryCodesResponseDto {
+    pub recovery_codes: Vec<String>,
+}
+
+/// Desafio de segundo fator retornado pelo login
+#[derive(Debug, Serialize)]
+pub struct MfaChallengeDto {
+    pub mfa_required: bool,
+    /// Token de curta duração a ser trocado junto com o código
+    pub mfa_token: String,
+    /// Validade do token em segundos
+    pub expires_in: i64,
+}
+
+/// DTO de resposta do login: tokens ou desafio de segundo fator
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum LoginResponseDto {
+    Authenticated(AuthResponseDto),
+    MfaRequired(MfaChallengeDto),
+}
+
+/// DTO para concluir o login com o segundo fator
+#[derive(Debug, Deserialize, Validate)]
+pub struct VerifyMfaDto {
+    #[validate(length(min = 1, message = "Token MFA é obrigatório"))]
+    pub mfa_token: String,
+
+    #[validate(length(min = 1, message = "Código é obrigatório"))]
+    pub code: String,
+}
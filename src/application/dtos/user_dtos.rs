@@ -1,3 +1,6 @@
+use crate::application::dtos::TokenResponseDto;
+use crate::domain::entities::User;
+use crate::domain::value_objects::UserRole;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use validator::Validate;
@@ -26,9 +29,12 @@ pub struct LoginDto {
 }
 
 /// DTO de resposta de autenticação
+///
+/// Sem tokens quando o registro exige confirmar o email antes do login.
 #[derive(Debug, Serialize)]
 pub struct AuthResponseDto {
-    pub token: String,
+    #[serde(flatten)]
+    pub tokens: Option<TokenResponseDto>,
     pub user: UserResponseDto,
 }
 
@@ -38,4 +44,57 @@ pub struct UserResponseDto {
     pub id: Uuid,
     pub email: String,
     pub name: String,
+    pub role: UserRole,
+    pub email_verified: bool,
+}
+
+impl UserResponseDto {
+    pub fn from_user(user: &User) -> Self {
+        Self {
+            id: user.id,
+            email: user.email.as_str().to_string(),
+            name: user.name.clone(),
+            role: user.role,
+            email_verified: user.is_email_verified(),
+        }
+    }
+}
+
+/// DTO para atualizar o perfil (campos ausentes não são alterados)
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateProfileDto {
+    #[validate(length(min = 2, message = "Nome deve ter pelo menos 2 caracteres"))]
+    pub name: Option<String>,
+}
+
+/// DTO para trocar a senha
+#[derive(Debug, Deserialize, Validate)]
+pub struct ChangePasswordDto {
+    #[validate(length(min = 1, message = "Senha atual é obrigatória"))]
+    pub current_password: String,
+
+    #[validate(length(min = 8, message = "Senha deve ter pelo menos 8 caracteres"))]
+    pub new_password: String,
+}
+
+/// DTO para solicitar a troca de email
+#[derive(Debug, Deserialize, Validate)]
+pub struct ChangeEmailDto {
+    #[validate(email(message = "Email inválido"))]
+    pub new_email: String,
+
+    #[validate(length(min = 1, message = "Senha atual é obrigatória"))]
+    pub current_password: String,
+}
+
+/// DTO para excluir a conta
+///
+/// `confirmation` deve repetir o email da conta.
+#[derive(Debug, Deserialize, Validate)]
+pub struct DeleteAccountDto {
+    #[validate(length(min = 1, message = "Senha é obrigatória"))]
+    pub password: String,
+
+    #[validate(length(min = 1, message = "Confirmação é obrigatória"))]
+    pub confirmation: String,
 }
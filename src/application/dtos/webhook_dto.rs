@@ -0,0 +1,119 @@
+use crate::domain::entities::{Webhook, WebhookDelivery};
+use crate::domain::events::TaskEventKind;
+use crate::domain::value_objects::WebhookDeliveryStatus;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+/// DTO para criação de webhook
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateWebhookDto {
+    #[validate(length(
+        min = 1,
+        max = 2048,
+        message = "URL deve ter entre 1 e 2048 caracteres"
+    ))]
+    pub url: String,
+
+    #[validate(length(
+        min = 16,
+        max = 255,
+        message = "Segredo deve ter entre 16 e 255 caracteres"
+    ))]
+    pub secret: String,
+
+    #[validate(length(min = 1, message = "Informe ao menos um tipo de evento"))]
+    pub event_types: Vec<TaskEventKind>,
+}
+
+/// DTO para atualização de webhook (campos omitidos não são alterados)
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateWebhookDto {
+    #[validate(length(
+        min = 1,
+        max = 2048,
+        message = "URL deve ter entre 1 e 2048 caracteres"
+    ))]
+    pub url: Option<String>,
+
+    #[validate(length(
+        min = 16,
+        max = 255,
+        message = "Segredo deve ter entre 16 e 255 caracteres"
+    ))]
+    pub secret: Option<String>,
+
+    #[validate(length(min = 1, message = "Informe ao menos um tipo de evento"))]
+    pub event_types: Option<Vec<TaskEventKind>>,
+
+    pub active: Option<bool>,
+}
+
+/// DTO de resposta de webhook (o segredo nunca é exposto)
+#[derive(Debug, Serialize)]
+pub struct WebhookResponseDto {
+    pub id: Uuid,
+    pub url: String,
+    pub event_types: Vec<TaskEventKind>,
+    pub active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Webhook> for WebhookResponseDto {
+    fn from(webhook: Webhook) -> Self {
+        Self {
+            id: webhook.id,
+            url: webhook.url,
+            event_types: webhook.event_types,
+            active: webhook.active,
+            created_at: webhook.created_at,
+            updated_at: webhook.updated_at,
+        }
+    }
+}
+
+/// Query params da listagem de entregas
+#[derive(Debug, Default, Deserialize)]
+pub struct WebhookDeliveryQueryDto {
+    pub limit: Option<i64>,
+}
+
+/// DTO de resposta de entrega de webhook (log)
+#[derive(Debug, Serialize)]
+pub struct WebhookDeliveryResponseDto {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub event_type: TaskEventKind,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    /// Próxima tentativa (apenas entregas pendentes)
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    pub last_status_code: Option<i32>,
+    pub last_error: Option<String>,
+    pub payload: String,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<WebhookDelivery> for WebhookDeliveryResponseDto {
+    fn from(delivery: WebhookDelivery) -> Self {
+        Self {
+            next_attempt_at: (delivery.status == WebhookDeliveryStatus::Pending)
+                .then_some(delivery.next_attempt_at),
+            id: delivery.id,
+            event_id: delivery.event_id,
+            event_type: delivery.event_type,
+            status: delivery.status,
+            attempts: delivery.attempts,
+            last_status_code: delivery.last_status_code,
+            last_error: delivery.last_error,
+            payload: delivery.payload,
+            delivered_at: delivery.delivered_at,
+            created_at: delivery.created_at,
+            updated_at: delivery.updated_at,
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use crate::domain::entities::{Workflow, WorkflowState, WorkflowTransition};
+use crate::domain::value_objects::TaskStatus;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+/// DTO para criação de workflow
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateWorkflowDto {
+    #[validate(length(min = 1, max = 100, message = "Nome deve ter entre 1 e 100 caracteres"))]
+    pub name: String,
+
+    /// Estados na ordem de exibição (ao menos um por categoria)
+    #[validate(length(
+        min = 4,
+        max = 30,
+        message = "Um workflow deve ter entre 4 e 30 estados"
+    ))]
+    pub states: Vec<WorkflowStateDto>,
+
+    /// Transições permitidas, referenciando os estados pelo nome
+    #[validate(length(max = 500, message = "Um workflow pode ter no máximo 500 transições"))]
+    pub transitions: Vec<WorkflowTransitionDto>,
+}
+
+/// Estado informado na criação do workflow
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WorkflowStateDto {
+    pub name: String,
+    /// Categoria do estado (status usado em filtros, quadro e estatísticas)
+    pub category: TaskStatus,
+}
+
+/// Transição informada na criação do workflow (nomes dos estados)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WorkflowTransitionDto {
+    pub from: String,
+    pub to: String,
+}
+
+/// DTO de resposta de estado do workflow
+#[derive(Debug, Serialize)]
+pub struct WorkflowStateResponseDto {
+    pub id: Uuid,
+    pub name: String,
+    pub category: TaskStatus,
+    pub position: i32,
+}
+
+impl From<WorkflowState> for WorkflowStateResponseDto {
+    fn from(state: WorkflowState) -> Self {
+        Self {
+            id: state.id,
+            name: state.name,
+            category: state.category,
+            position: state.position,
+        }
+    }
+}
+
+/// DTO de resposta de transição do workflow
+#[derive(Debug, Serialize)]
+pub struct WorkflowTransitionResponseDto {
+    pub from_state_id: Uuid,
+    pub to_state_id: Uuid,
+}
+
+impl From<WorkflowTransition> for WorkflowTransitionResponseDto {
+    fn from(transition: WorkflowTransition) -> Self {
+        Self {
+            from_state_id: transition.from_state_id,
+            to_state_id: transition.to_state_id,
+        }
+    }
+}
+
+/// DTO de resposta de workflow
+#[derive(Debug, Serialize)]
+pub struct WorkflowResponseDto {
+    pub id: Uuid,
+    pub workspace_id: Uuid,
+    pub name: String,
+    pub states: Vec<WorkflowStateResponseDto>,
+    pub transitions: Vec<WorkflowTransitionResponseDto>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Workflow> for WorkflowResponseDto {
+    fn from(workflow: Workflow) -> Self {
+        Self {
+            id: workflow.id,
+            workspace_id: workflow.workspace_id,
+            name: workflow.name,
+            states: workflow.states.into_iter().map(Into::into).collect(),
+            transitions: workflow.transitions.into_iter().map(Into::into).collect(),
+            created_at: workflow.created_at,
+            updated_at: workflow.updated_at,
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use crate::domain::entities::{Membership, Workspace};
+use crate::domain::value_objects::WorkspaceRole;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+/// DTO para criação de workspace
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateWorkspaceDto {
+    #[validate(length(min = 1, max = 100, message = "Nome deve ter entre 1 e 100 caracteres"))]
+    pub name: String,
+}
+
+/// DTO para convidar um membro para o workspace
+#[derive(Debug, Deserialize, Validate)]
+pub struct InviteMemberDto {
+    #[validate(email(message = "Email inválido"))]
+    pub email: String,
+
+    pub role: Option<WorkspaceRole>,
+}
+
+/// DTO de resposta de workspace (com o papel do usuário nele)
+#[derive(Debug, Serialize)]
+pub struct WorkspaceResponseDto {
+    pub id: Uuid,
+    pub name: String,
+    pub role: WorkspaceRole,
+    pub created_at: DateTime<Utc>,
+}
+
+impl WorkspaceResponseDto {
+    pub fn new(workspace: Workspace, membership: &Membership) -> Self {
+        Self {
+            id: workspace.id,
+            name: workspace.name,
+            role: membership.role,
+            created_at: workspace.created_at,
+        }
+    }
+}
+
+/// DTO de resposta de membro do workspace
+#[derive(Debug, Serialize)]
+pub struct MemberResponseDto {
+    pub user_id: Uuid,
+    pub email: String,
+    pub name: String,
+    pub role: WorkspaceRole,
+    pub invited_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,125 @@
+use crate::domain::entities::{EmailVerificationToken, User};
+use crate::domain::errors::DomainResult;
+use crate::domain::repositories::{EmailMessage, EmailVerificationRepository, Mailer};
+use crate::domain::value_objects::Email;
+use crate::infrastructure::security::{generate_opaque_token, hash_opaque_token};
+use chrono::Duration;
+use std::sync::Arc;
+
+/// Serviço de aplicação para emissão e envio de tokens de verificação de email
+pub struct EmailVerificationService {
+    verification_repository: Arc<dyn EmailVerificationRepository>,
+    mailer: Arc<dyn Mailer>,
+    ttl: Duration,
+    app_base_url: String,
+}
+
+impl EmailVerificationService {
+    pub fn new(
+        verification_repository: Arc<dyn EmailVerificationRepository>,
+        mailer: Arc<dyn Mailer>,
+        ttl: Duration,
+        app_base_url: String,
+    ) -> Self {
+        Self {
+            verification_repository,
+            mailer,
+            ttl,
+            app_base_url,
+        }
+    }
+
+    /// Emite um novo token (invalidando os anteriores) e envia o link ao usuário
+    ///
+    /// Falhas no envio são apenas registradas: o usuário pode pedir o reenvio.
+    pub async fn send(&self, user: &User) -> DomainResult<()> {
+        self.verification_repository
+            .invalidate_for_user(&user.id)
+            .await?;
+
+        let token = generate_opaque_token();
+        let verification =
+            EmailVerificationToken::new(user.id, hash_opaque_token(&token), self.ttl);
+        self.verification_repository.create(&verification).await?;
+
+        let link = self.link(&token);
+
+        let message = EmailMessage {
+            to: user.email.as_str().to_string(),
+            subject: "Confirme seu email".to_string(),
+            body: format!(
+                "Olá, {}!\n\n\
+                 Confirme seu email acessando o link abaixo em até {} horas:\n\n{}\n\n\
+                 Se você não criou esta conta, ignore este email.\n",
+                user.name,
+                self.ttl.num_hours(),
+                link
+            ),
+        };
+
+        if let Err(e) = self.mailer.send(&message).await {
+            tracing::error!("❌ Erro ao enviar email de verificação: {}", e);
+        }
+
+        Ok(())
+    }
+
+    /// Emite um token de troca de email e envia o link ao novo endereço
+    ///
+    /// O endereço atual recebe um aviso; a troca só acontece com a confirmação.
+    pub async fn send_email_change(&self, user: &User, new_email: &Email) -> DomainResult<()> {
+        self.verification_repository
+            .invalidate_for_user(&user.id)
+            .await?;
+
+        let token = generate_opaque_token();
+        let verification = EmailVerificationToken::for_email_change(
+            user.id,
+            hash_opaque_token(&token),
+            new_email.as_str().to_string(),
+            self.ttl,
+        );
+        self.verification_repository.create(&verification).await?;
+
+        let confirmation = EmailMessage {
+            to: new_email.as_str().to_string(),
+            subject: "Confirme seu novo email".to_string(),
+            body: format!(
+                "Olá, {}!\n\n\
+                 Confirme a troca do email da sua conta acessando o link abaixo em até {} horas:\n\n{}\n\n\
+                 Se você não solicitou a troca, ignore este email.\n",
+                user.name,
+                self.ttl.num_hours(),
+                self.link(&token)
+            ),
+        };
+
+        let notice = EmailMessage {
+            to: user.email.as_str().to_string(),
+            subject: "Solicitação de troca de email".to_string(),
+            body: format!(
+                "Olá, {}!\n\n\
+                 Recebemos uma solicitação para trocar o email da sua conta para {}. \
+                 A troca só será concluída após a confirmação no novo endereço.\n\n\
+                 Se você não fez esta solicitação, altere sua senha.\n",
+                user.name, new_email
+            ),
+        };
+
+        for message in [confirmation, notice] {
+            if let Err(e) = self.mailer.send(&message).await {
+                tracing::error!("❌ Erro ao enviar email de troca de email: {}", e);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn link(&self, token: &str) -> String {
+        format!(
+            "{}/api/auth/verify?token={}",
+            self.app_base_url.trim_end_matches('/'),
+            token
+        )
+    }
+}
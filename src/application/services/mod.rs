@@ -1,3 +1,21 @@
+pub mod email_verification_service;
+pub mod login_protection_service;
+pub mod password_reset_queue;
+pub mod password_reset_service;
+pub mod project_service;
+pub mod recurrence_service;
+pub mod session_service;
 pub mod task_service;
+pub mod two_factor_service;
+pub mod workflow_service;
 
+pub use email_verification_service::EmailVerificationService;
+pub use login_protection_service::LoginProtectionService;
+pub use password_reset_queue::{PasswordResetJobs, PasswordResetQueue};
+pub use password_reset_service::PasswordResetService;
+pub use project_service::ProjectService;
+pub use recurrence_service::RecurrenceService;
+pub use session_service::{SessionService, SessionTtl};
 pub use task_service::TaskService;
+pub use two_factor_service::TwoFactorService;
+pub use workflow_service::WorkflowService;
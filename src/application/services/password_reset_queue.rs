@@ -0,0 +1,97 @@
+use crate::application::services::PasswordResetService;
+use crate::domain::entities::User;
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+use tokio::sync::mpsc;
+use tokio::sync::mpsc::error::TrySendError;
+use uuid::Uuid;
+
+/// Fila limitada de envios de links de redefinição de senha
+///
+/// Cada usuário tem no máximo um envio pendente; com a fila cheia, novas
+/// solicitações são descartadas em vez de acumular tarefas em memória.
+#[derive(Clone)]
+pub struct PasswordResetQueue {
+    sender: mpsc::Sender<User>,
+    pending: Arc<Mutex<HashSet<Uuid>>>,
+}
+
+/// Lado consumidor da fila, processado pelo worker
+pub struct PasswordResetJobs {
+    receiver: mpsc::Receiver<User>,
+    pending: Arc<Mutex<HashSet<Uuid>>>,
+    password_reset: PasswordResetService,
+}
+
+impl PasswordResetQueue {
+    pub fn new(password_reset: PasswordResetService, capacity: usize) -> (Self, PasswordResetJobs) {
+        let (sender, receiver) = mpsc::channel(capacity);
+        let pending = Arc::new(Mutex::new(HashSet::new()));
+
+        let queue = Self {
+            sender,
+            pending: pending.clone(),
+        };
+        let jobs = PasswordResetJobs {
+            receiver,
+            pending,
+            password_reset,
+        };
+        (queue, jobs)
+    }
+
+    /// Agenda o envio do link para o usuário
+    ///
+    /// Retorna `false` se o usuário já tem um envio pendente ou se a fila está
+    /// cheia; o envio pendente já emite um token novo.
+    pub fn enqueue(&self, user: User) -> bool {
+        let user_id = user.id;
+        if !self.pending.lock().unwrap().insert(user_id) {
+            return false;
+        }
+
+        match self.sender.try_send(user) {
+            Ok(()) => true,
+            Err(e) => {
+                self.pending.lock().unwrap().remove(&user_id);
+                if let TrySendError::Full(_) = e {
+                    tracing::warn!("Fila de redefinição de senha cheia; solicitação descartada");
+                }
+                false
+            }
+        }
+    }
+}
+
+impl PasswordResetJobs {
+    /// Aguarda e processa o próximo envio; `false` quando a fila foi fechada
+    pub async fn process_next(&mut self) -> bool {
+        match self.receiver.recv().await {
+            Some(user) => {
+                self.process(user).await;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Processa os envios já enfileirados, sem aguardar novos
+    pub async fn drain(&mut self) -> usize {
+        let mut processed = 0;
+        while let Ok(user) = self.receiver.try_recv() {
+            self.process(user).await;
+            processed += 1;
+        }
+        processed
+    }
+
+    async fn process(&self, user: User) {
+        // Liberar o usuário antes do envio: uma nova solicitação durante o
+        // envio gera outro token, invalidando este
+        self.pending.lock().unwrap().remove(&user.id);
+
+        if let Err(e) = self.password_reset.send(&user).await {
+            tracing::error!("❌ Erro ao emitir token de redefinição de senha: {}", e);
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use crate::domain::entities::{PasswordResetToken, User};
+use crate::domain::errors::DomainResult;
+use crate::domain::repositories::{EmailMessage, Mailer, PasswordResetRepository};
+use crate::infrastructure::security::{generate_opaque_token, hash_opaque_token};
+use chrono::Duration;
+use std::sync::Arc;
+
+/// Serviço de aplicação para emissão e envio de links de redefinição de senha
+pub struct PasswordResetService {
+    password_reset_repository: Arc<dyn PasswordResetRepository>,
+    mailer: Arc<dyn Mailer>,
+    ttl: Duration,
+    app_base_url: String,
+}
+
+impl PasswordResetService {
+    pub fn new(
+        password_reset_repository: Arc<dyn PasswordResetRepository>,
+        mailer: Arc<dyn Mailer>,
+        ttl: Duration,
+        app_base_url: String,
+    ) -> Self {
+        Self {
+            password_reset_repository,
+            mailer,
+            ttl,
+            app_base_url,
+        }
+    }
+
+    /// Emite um novo token (invalidando os anteriores) e envia o link ao usuário
+    ///
+    /// Falhas no envio são apenas registradas, sem revelar nada ao cliente.
+    pub async fn send(&self, user: &User) -> DomainResult<()> {
+        self.password_reset_repository
+            .invalidate_for_user(&user.id)
+            .await?;
+
+        // Emitir token (apenas o hash é persistido)
+        let token = generate_opaque_token();
+        let reset = PasswordResetToken::new(user.id, hash_opaque_token(&token), self.ttl);
+        self.password_reset_repository.create(&reset).await?;
+
+        let link = format!(
+            "{}/reset-password?token={}",
+            self.app_base_url.trim_end_matches('/'),
+            token
+        );
+
+        let message = EmailMessage {
+            to: user.email.as_str().to_string(),
+            subject: "Redefinição de senha".to_string(),
+            body: format!(
+                "Olá, {}!\n\n\
+                 Recebemos uma solicitação para redefinir sua senha. \
+                 Use o link abaixo em até {} minutos:\n\n{}\n\n\
+                 Se você não fez esta solicitação, ignore este email.\n",
+                user.name,
+                self.ttl.num_minutes(),
+                link
+            ),
+        };
+
+        if let Err(e) = self.mailer.send(&message).await {
+            tracing::error!("❌ Erro ao enviar email de redefinição de senha: {}", e);
+        }
+
+        Ok(())
+    }
+}
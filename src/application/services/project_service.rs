@@ -0,0 +1,53 @@
+use crate::application::dtos::{ProjectResponseDto, StatusCountDto};
+use crate::domain::entities::Project;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{ProjectRepository, TaskRepository};
+use crate::domain::value_objects::TaskStatus;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Serviço de aplicação compartilhado pelos casos de uso de projetos
+pub struct ProjectService {
+    project_repository: Arc<dyn ProjectRepository>,
+    task_repository: Arc<dyn TaskRepository>,
+}
+
+impl ProjectService {
+    pub fn new(
+        project_repository: Arc<dyn ProjectRepository>,
+        task_repository: Arc<dyn TaskRepository>,
+    ) -> Self {
+        Self {
+            project_repository,
+            task_repository,
+        }
+    }
+
+    /// Busca um projeto do workspace (projetos de outros workspaces não existem para o usuário)
+    pub async fn find_in_workspace(
+        &self,
+        project_id: &Uuid,
+        workspace_id: &Uuid,
+    ) -> DomainResult<Project> {
+        self.project_repository
+            .find_by_id(project_id)
+            .await?
+            .filter(|project| project.workspace_id == *workspace_id)
+            .ok_or(DomainError::NotFound("Projeto não encontrado".to_string()))
+    }
+
+    /// Monta a resposta do projeto com a contagem de tarefas por status
+    pub async fn to_response(&self, project: Project) -> DomainResult<ProjectResponseDto> {
+        let mut task_counts = Vec::with_capacity(TaskStatus::ALL.len());
+
+        for status in TaskStatus::ALL {
+            let count = self
+                .task_repository
+                .count_by_project_status(&project.id, status)
+                .await?;
+            task_counts.push(StatusCountDto { status, count });
+        }
+
+        Ok(ProjectResponseDto::new(project, task_counts))
+    }
+}
@@ -0,0 +1,88 @@
+use crate::application::services::WorkflowService;
+use crate::domain::entities::{Task, TaskHistory};
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::events::{EventPublisher, TaskEvent};
+use crate::domain::repositories::{TaskRepository, WorkflowRepository};
+use crate::domain::value_objects::Rank;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Serviço de aplicação que gera as ocorrências de tarefas recorrentes
+pub struct RecurrenceService {
+    task_repository: Arc<dyn TaskRepository>,
+    event_publisher: Arc<dyn EventPublisher>,
+    workflow_service: WorkflowService,
+}
+
+impl RecurrenceService {
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        event_publisher: Arc<dyn EventPublisher>,
+        workflow_repository: Arc<dyn WorkflowRepository>,
+    ) -> Self {
+        Self {
+            task_repository,
+            event_publisher,
+            workflow_service: WorkflowService::new(workflow_repository),
+        }
+    }
+
+    /// Cria a próxima ocorrência de uma tarefa recorrente e registra no histórico
+    ///
+    /// Idempotente: cada ocorrência da série é criada uma única vez.
+    pub async fn spawn_next_occurrence(&self, task: &Task, user_id: Uuid) -> DomainResult<()> {
+        let Some(mut next) = task.next_occurrence(Uuid::new_v4())? else {
+            return Ok(());
+        };
+
+        // Uma tarefa reaberta e concluída de novo já gerou sua próxima ocorrência
+        let history = self.task_repository.get_history(&task.id).await?;
+        if history
+            .iter()
+            .any(|entry| entry.field_name == "next_occurrence")
+        {
+            return Ok(());
+        }
+
+        // A nova ocorrência entra no fim do quadro
+        let last_rank = self.task_repository.last_rank(&next.workspace_id).await?;
+        next.rank = Rank::between(last_rank.as_ref(), None)?;
+
+        // Entrar no workflow da série (primeiro estado pendente)
+        let workflow = self.workflow_service.for_task(task).await?;
+        next.enter_workflow(workflow.as_ref());
+
+        // Conclusões concorrentes criam a ocorrência uma única vez
+        let next = match self.task_repository.create(&next).await {
+            Ok(next) => next,
+            Err(DomainError::Conflict(_)) => return Ok(()),
+            Err(e) => return Err(e),
+        };
+        self.event_publisher
+            .publish(TaskEvent::created(&next, user_id));
+        let due_date = next.due_date.map(|d| d.to_string()).unwrap_or_default();
+
+        self.task_repository
+            .add_history(&TaskHistory::new(
+                next.id,
+                user_id,
+                "created".to_string(),
+                Some(task.id.to_string()),
+                Some(format!(
+                    "Ocorrência {} da série criada: {}",
+                    next.occurrence, due_date
+                )),
+            ))
+            .await?;
+
+        self.task_repository
+            .add_history(&TaskHistory::new(
+                task.id,
+                user_id,
+                "next_occurrence".to_string(),
+                None,
+                Some(next.id.to_string()),
+            ))
+            .await
+    }
+}
@@ -0,0 +1,199 @@
+use crate::application::dtos::{ClientInfoDto, TokenResponseDto};
+use crate::domain::entities::Session;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{SessionRepository, UserRepository, WorkspaceRepository};
+use crate::infrastructure::security::{
+    TokenRevocationService, generate_opaque_token, generate_session_jwt, hash_opaque_token,
+};
+use chrono::Duration;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Validade dos tokens emitidos para uma sessão
+#[derive(Debug, Clone, Copy)]
+pub struct SessionTtl {
+    pub access_token: Duration,
+    pub refresh_token: Duration,
+}
+
+/// Serviço de aplicação para emissão de tokens de sessão
+pub struct SessionService {
+    user_repository: Arc<dyn UserRepository>,
+    workspace_repository: Arc<dyn WorkspaceRepository>,
+    session_repository: Arc<dyn SessionRepository>,
+    token_revocation: Arc<TokenRevocationService>,
+    ttl: SessionTtl,
+}
+
+impl SessionService {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        workspace_repository: Arc<dyn WorkspaceRepository>,
+        session_repository: Arc<dyn SessionRepository>,
+        token_revocation: Arc<TokenRevocationService>,
+        ttl: SessionTtl,
+    ) -> Self {
+        Self {
+            user_repository,
+            workspace_repository,
+            session_repository,
+            token_revocation,
+            ttl,
+        }
+    }
+
+    /// Inicia uma nova sessão (login) no workspace padrão e emite o par de tokens
+    pub async fn start(
+        &self,
+        user_id: Uuid,
+        client: ClientInfoDto,
+    ) -> DomainResult<TokenResponseDto> {
+        let workspace_id = self
+            .workspace_repository
+            .find_default_for_user(&user_id)
+            .await?;
+
+        let refresh_token = generate_opaque_token();
+        let session = Session::start(
+            user_id,
+            workspace_id,
+            hash_opaque_token(&refresh_token),
+            client.user_agent,
+            client.ip_address.map(|ip| ip.to_string()),
+            self.ttl.refresh_token,
+        );
+
+        let session = self.session_repository.create(&session).await?;
+
+        self.issue(&session, refresh_token).await
+    }
+
+    /// Valida e consome um refresh token, retornando a sessão trocada
+    ///
+    /// Reapresentar um refresh token já trocado indica vazamento: a família
+    /// inteira é revogada e o dispositivo precisa fazer login novamente.
+    pub async fn redeem(&self, refresh_token: &str) -> DomainResult<Session> {
+        let session = self
+            .session_repository
+            .find_by_token_hash(&hash_opaque_token(refresh_token))
+            .await?
+            .ok_or(DomainError::Unauthorized)?;
+
+        if session.revoked_at.is_some() || session.is_expired() {
+            return Err(DomainError::Unauthorized);
+        }
+
+        // Reuso detectado (token já trocado, inclusive em requisição concorrente)
+        if session.is_rotated() || !self.session_repository.mark_rotated(&session.id).await? {
+            tracing::warn!(
+                "Reuso de refresh token detectado; revogando sessão {} do usuário {}",
+                session.family_id,
+                session.user_id
+            );
+            self.session_repository
+                .revoke_family(&session.family_id)
+                .await?;
+            return Err(DomainError::Unauthorized);
+        }
+
+        Ok(session)
+    }
+
+    /// Persiste a sucessora de uma sessão e emite o novo par de tokens
+    ///
+    /// Se o usuário deixou de ser membro do workspace ativo, a sessão volta
+    /// para o workspace padrão.
+    pub async fn rotate(
+        &self,
+        session: &Session,
+        client: ClientInfoDto,
+    ) -> DomainResult<TokenResponseDto> {
+        let workspace_id = match session.workspace_id {
+            Some(workspace_id)
+                if self
+                    .workspace_repository
+                    .find_membership(&workspace_id, &session.user_id)
+                    .await?
+                    .is_some() =>
+            {
+                Some(workspace_id)
+            }
+            _ => {
+                self.workspace_repository
+                    .find_default_for_user(&session.user_id)
+                    .await?
+            }
+        };
+
+        self.rotate_into(session, workspace_id, client).await
+    }
+
+    /// Rotaciona a sessão para outro workspace (o chamador verifica a participação)
+    pub async fn switch(
+        &self,
+        session: &Session,
+        workspace_id: Uuid,
+        client: ClientInfoDto,
+    ) -> DomainResult<TokenResponseDto> {
+        self.rotate_into(session, Some(workspace_id), client).await
+    }
+
+    async fn rotate_into(
+        &self,
+        session: &Session,
+        workspace_id: Option<Uuid>,
+        client: ClientInfoDto,
+    ) -> DomainResult<TokenResponseDto> {
+        let refresh_token = generate_opaque_token();
+        let mut next = session.rotate(
+            hash_opaque_token(&refresh_token),
+            client.user_agent,
+            client.ip_address.map(|ip| ip.to_string()),
+            self.ttl.refresh_token,
+        );
+        next.workspace_id = workspace_id;
+
+        let next = self.session_repository.create(&next).await?;
+
+        self.issue(&next, refresh_token).await
+    }
+
+    async fn issue(
+        &self,
+        session: &Session,
+        refresh_token: String,
+    ) -> DomainResult<TokenResponseDto> {
+        // O papel é lido a cada emissão para refletir promoções e desativações
+        let user = self
+            .user_repository
+            .find_by_id(&session.user_id)
+            .await?
+            .ok_or(DomainError::Unauthorized)?;
+
+        if user.is_disabled() {
+            return Err(DomainError::Forbidden("Conta desativada".to_string()));
+        }
+
+        let token_version = self
+            .token_revocation
+            .token_version(&session.user_id)
+            .await?;
+
+        let token = generate_session_jwt(
+            &session.user_id,
+            token_version,
+            user.role,
+            &session.family_id,
+            session.workspace_id.as_ref(),
+            self.ttl.access_token,
+        )
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(TokenResponseDto {
+            token,
+            refresh_token,
+            token_type: "Bearer".to_string(),
+            expires_in: self.ttl.access_token.num_seconds(),
+        })
+    }
+}
@@ -1,17 +1,25 @@
 use crate::application::dtos::TaskHistoryResponseDto;
 use crate::domain::errors::DomainResult;
-use crate::domain::repositories::TaskRepository;
+use crate::domain::repositories::{CollaboratorRepository, TaskRepository};
+use crate::domain::services::{TaskAccessPolicy, TaskAction};
 use std::sync::Arc;
 use uuid::Uuid;
 
 /// Serviço de aplicação para operações auxiliares de tarefas
 pub struct TaskService {
     task_repository: Arc<dyn TaskRepository>,
+    access_policy: TaskAccessPolicy,
 }
 
 impl TaskService {
-    pub fn new(task_repository: Arc<dyn TaskRepository>) -> Self {
-        Self { task_repository }
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        collaborator_repository: Arc<dyn CollaboratorRepository>,
+    ) -> Self {
+        Self {
+            task_repository,
+            access_policy: TaskAccessPolicy::new(collaborator_repository),
+        }
     }
 
     /// Obtém histórico de alterações de uma tarefa
@@ -20,14 +28,14 @@ impl TaskService {
         task_id: Uuid,
         user_id: Uuid,
     ) -> DomainResult<Vec<TaskHistoryResponseDto>> {
-        // Verificar se tarefa existe e pertence ao usuário
+        // Verificar se tarefa existe e se o usuário tem acesso a ela
         let task = self.task_repository.find_by_id(&task_id).await?.ok_or(
             crate::domain::errors::DomainError::NotFound("Tarefa não encontrada".to_string()),
         )?;
 
-        if !task.is_owned_by(&user_id) {
-            return Err(crate::domain::errors::DomainError::Unauthorized);
-        }
+        self.access_policy
+            .ensure(&task, &user_id, TaskAction::View)
+            .await?;
 
         // Buscar histórico
         let history = self.task_repository.get_history(&task_id).await?;
@@ -0,0 +1,180 @@
+use crate::domain::errors::DomainResult;
+use crate::domain::repositories::TwoFactorRepository;
+use crate::infrastructure::security::{hash_opaque_token, normalize_recovery_code, verify_totp};
+use chrono::Utc;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Serviço de aplicação para verificação do segundo fator
+pub struct TwoFactorService {
+    two_factor_repository: Arc<dyn TwoFactorRepository>,
+}
+
+impl TwoFactorService {
+    pub fn new(two_factor_repository: Arc<dyn TwoFactorRepository>) -> Self {
+        Self {
+            two_factor_repository,
+        }
+    }
+
+    /// Verifica um código TOTP ou, na falta dele, um código de recuperação
+    ///
+    /// Códigos TOTP já aceitos e códigos de recuperação usados são recusados.
+    pub async fn verify_code(&self, user_id: &Uuid, code: &str) -> DomainResult<bool> {
+        let Some(credential) = self
+            .two_factor_repository
+            .find_by_user(user_id)
+            .await?
+            .filter(|credential| credential.is_confirmed())
+        else {
+            return Ok(false);
+        };
+
+        if let Some(step) = verify_totp(&credential.secret, code, Utc::now().timestamp()) {
+            return self
+                .two_factor_repository
+                .mark_step_used(user_id, step)
+                .await;
+        }
+
+        let normalized = normalize_recovery_code(code);
+        if normalized.is_empty() {
+            return Ok(false);
+        }
+
+        self.two_factor_repository
+            .consume_recovery_code(user_id, &hash_opaque_token(&normalized))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{RecoveryCode, TotpCredential};
+    use crate::infrastructure::security::{generate_totp_secret, totp_code};
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    // Mock repository de 2FA em memória
+    #[derive(Default)]
+    struct MockTwoFactorRepository {
+        credential: Mutex<Option<TotpCredential>>,
+        recovery_codes: Mutex<Vec<RecoveryCode>>,
+    }
+
+    #[async_trait]
+    impl TwoFactorRepository for MockTwoFactorRepository {
+        async fn find_by_user(&self, _user_id: &Uuid) -> DomainResult<Option<TotpCredential>> {
+            Ok(self.credential.lock().unwrap().clone())
+        }
+
+        async fn save(&self, credential: &TotpCredential) -> DomainResult<TotpCredential> {
+            *self.credential.lock().unwrap() = Some(credential.clone());
+            Ok(credential.clone())
+        }
+
+        async fn mark_step_used(&self, _user_id: &Uuid, step: i64) -> DomainResult<bool> {
+            let mut credential = self.credential.lock().unwrap();
+            let credential = credential.as_mut().unwrap();
+            if credential.last_used_step.is_some_and(|last| last >= step) {
+                return Ok(false);
+            }
+            credential.last_used_step = Some(step);
+            Ok(true)
+        }
+
+        async fn delete(&self, _user_id: &Uuid) -> DomainResult<()> {
+            unimplemented!()
+        }
+
+        async fn replace_recovery_codes(
+            &self,
+            _user_id: &Uuid,
+            _codes: &[RecoveryCode],
+        ) -> DomainResult<()> {
+            unimplemented!()
+        }
+
+        async fn consume_recovery_code(
+            &self,
+            _user_id: &Uuid,
+            code_hash: &str,
+        ) -> DomainResult<bool> {
+            let mut codes = self.recovery_codes.lock().unwrap();
+            match codes
+                .iter_mut()
+                .find(|code| code.code_hash == code_hash && code.used_at.is_none())
+            {
+                Some(code) => {
+                    code.used_at = Some(Utc::now());
+                    Ok(true)
+                }
+                None => Ok(false),
+            }
+        }
+    }
+
+    fn active(user_id: Uuid) -> (Arc<MockTwoFactorRepository>, String) {
+        let secret = generate_totp_secret();
+        let mut credential = TotpCredential::new(user_id, secret.clone());
+        credential.confirm();
+
+        let repository = Arc::new(MockTwoFactorRepository::default());
+        *repository.credential.lock().unwrap() = Some(credential);
+        repository
+            .recovery_codes
+            .lock()
+            .unwrap()
+            .push(RecoveryCode::new(
+                user_id,
+                hash_opaque_token("abcd2345efgh6777"),
+            ));
+
+        (repository, secret)
+    }
+
+    #[tokio::test]
+    async fn test_totp_code_cannot_be_replayed() {
+        let user_id = Uuid::new_v4();
+        let (repository, secret) = active(user_id);
+        let service = TwoFactorService::new(repository);
+        let code = totp_code(&secret, Utc::now().timestamp()).unwrap();
+
+        assert!(service.verify_code(&user_id, &code).await.unwrap());
+        assert!(!service.verify_code(&user_id, &code).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_recovery_code_is_single_use() {
+        let user_id = Uuid::new_v4();
+        let (repository, _) = active(user_id);
+        let service = TwoFactorService::new(repository);
+
+        assert!(
+            service
+                .verify_code(&user_id, "ABCD-2345-EFGH-6777")
+                .await
+                .unwrap()
+        );
+        assert!(
+            !service
+                .verify_code(&user_id, "abcd-2345-efgh-6777")
+                .await
+                .unwrap()
+        );
+        assert!(!service.verify_code(&user_id, "000000").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_unconfirmed_credential_is_ignored() {
+        let user_id = Uuid::new_v4();
+        let secret = generate_totp_secret();
+        let repository = Arc::new(MockTwoFactorRepository::default());
+        *repository.credential.lock().unwrap() = Some(TotpCredential::new(user_id, secret.clone()));
+        let service = TwoFactorService::new(repository);
+
+        let code = totp_code(&secret, Utc::now().timestamp()).unwrap();
+        assert!(!service.verify_code(&user_id, &code).await.unwrap());
+    }
+}
@@ -0,0 +1,47 @@
+use crate::domain::entities::{Project, Task, Workflow};
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::WorkflowRepository;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Serviço de aplicação que resolve o workflow ativo de projetos e tarefas
+pub struct WorkflowService {
+    workflow_repository: Arc<dyn WorkflowRepository>,
+}
+
+impl WorkflowService {
+    pub fn new(workflow_repository: Arc<dyn WorkflowRepository>) -> Self {
+        Self {
+            workflow_repository,
+        }
+    }
+
+    /// Busca um workflow do workspace (workflows de outros workspaces não existem para o usuário)
+    pub async fn find_in_workspace(
+        &self,
+        workflow_id: &Uuid,
+        workspace_id: &Uuid,
+    ) -> DomainResult<Workflow> {
+        self.workflow_repository
+            .find_by_id(workflow_id)
+            .await?
+            .filter(|workflow| workflow.workspace_id == *workspace_id)
+            .ok_or(DomainError::NotFound("Workflow não encontrado".to_string()))
+    }
+
+    /// Workflow do projeto (`None` quando o projeto usa os status padrão)
+    pub async fn for_project(&self, project: &Project) -> DomainResult<Option<Workflow>> {
+        match project.workflow_id {
+            Some(workflow_id) => self.workflow_repository.find_by_id(&workflow_id).await,
+            None => Ok(None),
+        }
+    }
+
+    /// Workflow em que a tarefa está (`None` quando ela segue os status padrão)
+    pub async fn for_task(&self, task: &Task) -> DomainResult<Option<Workflow>> {
+        match task.state_id {
+            Some(state_id) => self.workflow_repository.find_by_state(&state_id).await,
+            None => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use crate::application::services::PasswordResetService;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{SessionRepository, UserRepository};
+use crate::infrastructure::security::{
+    TokenRevocationService, generate_opaque_token, hash_password,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Forçar a redefinição de senha de um usuário (administração)
+pub struct AdminResetPasswordUseCase {
+    user_repository: Arc<dyn UserRepository>,
+    session_repository: Arc<dyn SessionRepository>,
+    token_revocation: Arc<TokenRevocationService>,
+    password_reset: PasswordResetService,
+}
+
+impl AdminResetPasswordUseCase {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        session_repository: Arc<dyn SessionRepository>,
+        token_revocation: Arc<TokenRevocationService>,
+        password_reset: PasswordResetService,
+    ) -> Self {
+        Self {
+            user_repository,
+            session_repository,
+            token_revocation,
+            password_reset,
+        }
+    }
+
+    /// Executa a redefinição
+    ///
+    /// A senha atual é substituída por uma aleatória, as sessões e access tokens
+    /// são invalidados e o usuário recebe o link para escolher uma nova senha.
+    pub async fn execute(&self, user_id: Uuid) -> DomainResult<()> {
+        let mut user = self
+            .user_repository
+            .find_by_id(&user_id)
+            .await?
+            .ok_or(DomainError::NotFound("Usuário não encontrado".to_string()))?;
+
+        let password_hash = hash_password(&generate_opaque_token())
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        user.update_password(password_hash);
+        self.user_repository.update(&user).await?;
+
+        self.session_repository.revoke_all_by_user(&user.id).await?;
+        self.token_revocation.revoke_all(&user.id).await?;
+
+        self.password_reset.send(&user).await
+    }
+}
@@ -0,0 +1,125 @@
+use crate::application::dtos::AdminUserResponseDto;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{SessionRepository, UserRepository};
+use crate::infrastructure::security::TokenRevocationService;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Desativar uma conta (administração)
+pub struct DisableUserUseCase {
+    user_repository: Arc<dyn UserRepository>,
+    session_repository: Arc<dyn SessionRepository>,
+    token_revocation: Arc<TokenRevocationService>,
+}
+
+impl DisableUserUseCase {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        session_repository: Arc<dyn SessionRepository>,
+        token_revocation: Arc<TokenRevocationService>,
+    ) -> Self {
+        Self {
+            user_repository,
+            session_repository,
+            token_revocation,
+        }
+    }
+
+    /// Executa a desativação
+    ///
+    /// Encerra todas as sessões e invalida os access tokens já emitidos.
+    pub async fn execute(
+        &self,
+        admin_id: Uuid,
+        user_id: Uuid,
+    ) -> DomainResult<AdminUserResponseDto> {
+        if admin_id == user_id {
+            return Err(DomainError::Forbidden(
+                "Não é possível desativar a própria conta".to_string(),
+            ));
+        }
+
+        let mut user = self
+            .user_repository
+            .find_by_id(&user_id)
+            .await?
+            .ok_or(DomainError::NotFound("Usuário não encontrado".to_string()))?;
+
+        user.disable();
+        let updated = self.user_repository.update(&user).await?;
+
+        self.session_repository.revoke_all_by_user(&user.id).await?;
+        self.token_revocation.revoke_all(&user.id).await?;
+
+        Ok(updated.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{Session, User};
+    use crate::domain::value_objects::Email;
+    use crate::test_support::{
+        MockRevokedTokenRepository, MockSessionRepository, MockUserRepository,
+    };
+    use chrono::Duration;
+
+    fn setup() -> (
+        Uuid,
+        Arc<MockUserRepository>,
+        Arc<MockSessionRepository>,
+        DisableUserUseCase,
+    ) {
+        let user = User::new(
+            Uuid::new_v4(),
+            Email::new("ana@example.com").unwrap(),
+            "hash".to_string(),
+            "Ana".to_string(),
+        );
+        let user_id = user.id;
+        let users = Arc::new(MockUserRepository::new(vec![user]));
+        let sessions = Arc::new(MockSessionRepository::default());
+        sessions.sessions.lock().unwrap().push(Session::start(
+            user_id,
+            None,
+            "refresh".to_string(),
+            None,
+            None,
+            Duration::days(30),
+        ));
+        let token_revocation = TokenRevocationService::new(
+            Arc::new(MockRevokedTokenRepository::default()),
+            users.clone(),
+            std::time::Duration::from_secs(30),
+        );
+        let use_case =
+            DisableUserUseCase::new(users.clone(), sessions.clone(), Arc::new(token_revocation));
+
+        (user_id, users, sessions, use_case)
+    }
+
+    #[tokio::test]
+    async fn test_disable_user_revokes_sessions_and_tokens() {
+        let (user_id, users, sessions, use_case) = setup();
+
+        let response = use_case.execute(Uuid::new_v4(), user_id).await.unwrap();
+
+        assert!(response.disabled_at.is_some());
+        let user = users.get(&user_id).unwrap();
+        assert!(user.is_disabled());
+        assert_eq!(user.token_version, 1);
+        assert!(sessions.sessions.lock().unwrap()[0].revoked_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_admin_cannot_disable_self() {
+        let (user_id, users, sessions, use_case) = setup();
+
+        let result = use_case.execute(user_id, user_id).await;
+
+        assert!(matches!(result, Err(DomainError::Forbidden(_))));
+        assert!(!users.get(&user_id).unwrap().is_disabled());
+        assert!(sessions.sessions.lock().unwrap()[0].revoked_at.is_none());
+    }
+}
@@ -0,0 +1,37 @@
+use crate::application::dtos::AdminUserResponseDto;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::UserRepository;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Reativar uma conta desativada (administração)
+pub struct EnableUserUseCase {
+    user_repository: Arc<dyn UserRepository>,
+}
+
+impl EnableUserUseCase {
+    pub fn new(user_repository: Arc<dyn UserRepository>) -> Self {
+        Self { user_repository }
+    }
+
+    /// Executa a reativação
+    ///
+    /// Tokens emitidos antes da desativação continuam inválidos: o usuário
+    /// precisa fazer login novamente.
+    pub async fn execute(&self, user_id: Uuid) -> DomainResult<AdminUserResponseDto> {
+        let mut user = self
+            .user_repository
+            .find_by_id(&user_id)
+            .await?
+            .ok_or(DomainError::NotFound("Usuário não encontrado".to_string()))?;
+
+        if !user.is_disabled() {
+            return Ok(user.into());
+        }
+
+        user.enable();
+        let updated = self.user_repository.update(&user).await?;
+
+        Ok(updated.into())
+    }
+}
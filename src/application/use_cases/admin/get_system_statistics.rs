@@ -0,0 +1,35 @@
+use crate::application::dtos::SystemStatisticsDto;
+use crate::domain::errors::DomainResult;
+use crate::domain::repositories::StatisticsRepository;
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+
+/// Janela (em dias) das tarefas consideradas recentes
+const RECENT_TASKS_DAYS: i64 = 30;
+
+/// Caso de uso: Obter estatísticas de todo o sistema (administração)
+pub struct GetSystemStatisticsUseCase {
+    statistics_repository: Arc<dyn StatisticsRepository>,
+}
+
+impl GetSystemStatisticsUseCase {
+    pub fn new(statistics_repository: Arc<dyn StatisticsRepository>) -> Self {
+        Self {
+            statistics_repository,
+        }
+    }
+
+    /// Executa a consulta
+    pub async fn execute(&self) -> DomainResult<SystemStatisticsDto> {
+        let created_since = Utc::now() - Duration::days(RECENT_TASKS_DAYS);
+        let statistics = self
+            .statistics_repository
+            .system_statistics(created_since)
+            .await?;
+
+        Ok(SystemStatisticsDto::from_statistics(
+            statistics,
+            RECENT_TASKS_DAYS,
+        ))
+    }
+}
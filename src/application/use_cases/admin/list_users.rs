@@ -0,0 +1,48 @@
+use crate::application::dtos::{AdminUserFilterDto, AdminUserResponseDto, PaginatedResponseDto};
+use crate::domain::errors::DomainResult;
+use crate::domain::repositories::task_repository::Pagination;
+use crate::domain::repositories::{UserFilter, UserRepository};
+use std::sync::Arc;
+
+/// Caso de uso: Listar e buscar usuários (administração)
+pub struct ListUsersUseCase {
+    user_repository: Arc<dyn UserRepository>,
+}
+
+impl ListUsersUseCase {
+    pub fn new(user_repository: Arc<dyn UserRepository>) -> Self {
+        Self { user_repository }
+    }
+
+    /// Executa a listagem de usuários
+    pub async fn execute(
+        &self,
+        filter_dto: AdminUserFilterDto,
+    ) -> DomainResult<PaginatedResponseDto<AdminUserResponseDto>> {
+        // Construir filtro
+        let filter = UserFilter {
+            search_query: filter_dto
+                .search
+                .map(|search| search.trim().to_string())
+                .filter(|search| !search.is_empty()),
+            role: filter_dto.role,
+            disabled: filter_dto.disabled,
+        };
+
+        // Construir paginação
+        let pagination = Pagination {
+            page: filter_dto.page.unwrap_or(1).max(1),
+            page_size: filter_dto.page_size.unwrap_or(20).clamp(1, 100),
+        };
+
+        let result = self.user_repository.search(filter, pagination).await?;
+
+        Ok(PaginatedResponseDto {
+            items: result.items.into_iter().map(Into::into).collect(),
+            total: result.total,
+            page: result.page,
+            page_size: result.page_size,
+            total_pages: result.total_pages,
+        })
+    }
+}
@@ -0,0 +1,11 @@
+pub mod admin_reset_password;
+pub mod disable_user;
+pub mod enable_user;
+pub mod get_system_statistics;
+pub mod list_users;
+
+pub use admin_reset_password::AdminResetPasswordUseCase;
+pub use disable_user::DisableUserUseCase;
+pub use enable_user::EnableUserUseCase;
+pub use get_system_statistics::GetSystemStatisticsUseCase;
+pub use list_users::ListUsersUseCase;
@@ -0,0 +1,73 @@
+use crate::domain::entities::TaskHistory;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{
+    AttachmentRepository, AttachmentStorage, CollaboratorRepository, TaskRepository,
+};
+use crate::domain::services::{TaskAccessPolicy, TaskAction};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Deletar anexo
+pub struct DeleteAttachmentUseCase {
+    task_repository: Arc<dyn TaskRepository>,
+    attachment_repository: Arc<dyn AttachmentRepository>,
+    attachment_storage: Arc<dyn AttachmentStorage>,
+    access_policy: TaskAccessPolicy,
+}
+
+impl DeleteAttachmentUseCase {
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        collaborator_repository: Arc<dyn CollaboratorRepository>,
+        attachment_repository: Arc<dyn AttachmentRepository>,
+        attachment_storage: Arc<dyn AttachmentStorage>,
+    ) -> Self {
+        Self {
+            task_repository,
+            attachment_repository,
+            attachment_storage,
+            access_policy: TaskAccessPolicy::new(collaborator_repository),
+        }
+    }
+
+    /// Executa a deleção do anexo
+    pub async fn execute(
+        &self,
+        task_id: Uuid,
+        attachment_id: Uuid,
+        user_id: Uuid,
+    ) -> DomainResult<()> {
+        let task = self
+            .task_repository
+            .find_by_id(&task_id)
+            .await?
+            .ok_or(DomainError::NotFound("Tarefa não encontrada".to_string()))?;
+
+        self.access_policy
+            .ensure(&task, &user_id, TaskAction::Edit)
+            .await?;
+
+        let attachment = self
+            .attachment_repository
+            .find_by_id(&attachment_id)
+            .await?
+            .filter(|attachment| attachment.task_id == task_id)
+            .ok_or(DomainError::NotFound("Anexo não encontrado".to_string()))?;
+
+        self.attachment_repository.delete(&attachment_id).await?;
+        self.attachment_storage
+            .delete(&attachment.storage_key)
+            .await?;
+
+        // Registrar no histórico
+        let history = TaskHistory::new(
+            task_id,
+            user_id,
+            "attachment".to_string(),
+            Some(attachment.file_name),
+            None,
+        );
+
+        self.task_repository.add_history(&history).await
+    }
+}
@@ -0,0 +1,62 @@
+use crate::domain::entities::Attachment;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{
+    AttachmentReader, AttachmentRepository, AttachmentStorage, CollaboratorRepository,
+    TaskRepository,
+};
+use crate::domain::services::{TaskAccessPolicy, TaskAction};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Baixar o conteúdo de um anexo
+pub struct DownloadAttachmentUseCase {
+    task_repository: Arc<dyn TaskRepository>,
+    attachment_repository: Arc<dyn AttachmentRepository>,
+    attachment_storage: Arc<dyn AttachmentStorage>,
+    access_policy: TaskAccessPolicy,
+}
+
+impl DownloadAttachmentUseCase {
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        collaborator_repository: Arc<dyn CollaboratorRepository>,
+        attachment_repository: Arc<dyn AttachmentRepository>,
+        attachment_storage: Arc<dyn AttachmentStorage>,
+    ) -> Self {
+        Self {
+            task_repository,
+            attachment_repository,
+            attachment_storage,
+            access_policy: TaskAccessPolicy::new(collaborator_repository),
+        }
+    }
+
+    /// Executa o download, retornando os metadados e o leitor do conteúdo
+    pub async fn execute(
+        &self,
+        task_id: Uuid,
+        attachment_id: Uuid,
+        user_id: Uuid,
+    ) -> DomainResult<(Attachment, AttachmentReader)> {
+        let task = self
+            .task_repository
+            .find_by_id(&task_id)
+            .await?
+            .ok_or(DomainError::NotFound("Tarefa não encontrada".to_string()))?;
+
+        self.access_policy
+            .ensure(&task, &user_id, TaskAction::View)
+            .await?;
+
+        let attachment = self
+            .attachment_repository
+            .find_by_id(&attachment_id)
+            .await?
+            .filter(|attachment| attachment.task_id == task_id)
+            .ok_or(DomainError::NotFound("Anexo não encontrado".to_string()))?;
+
+        let reader = self.attachment_storage.get(&attachment.storage_key).await?;
+
+        Ok((attachment, reader))
+    }
+}
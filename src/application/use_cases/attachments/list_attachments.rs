@@ -0,0 +1,48 @@
+use crate::application::dtos::AttachmentResponseDto;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{AttachmentRepository, CollaboratorRepository, TaskRepository};
+use crate::domain::services::{TaskAccessPolicy, TaskAction};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Listar anexos de uma tarefa
+pub struct ListAttachmentsUseCase {
+    task_repository: Arc<dyn TaskRepository>,
+    attachment_repository: Arc<dyn AttachmentRepository>,
+    access_policy: TaskAccessPolicy,
+}
+
+impl ListAttachmentsUseCase {
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        collaborator_repository: Arc<dyn CollaboratorRepository>,
+        attachment_repository: Arc<dyn AttachmentRepository>,
+    ) -> Self {
+        Self {
+            task_repository,
+            attachment_repository,
+            access_policy: TaskAccessPolicy::new(collaborator_repository),
+        }
+    }
+
+    /// Executa a listagem de anexos
+    pub async fn execute(
+        &self,
+        task_id: Uuid,
+        user_id: Uuid,
+    ) -> DomainResult<Vec<AttachmentResponseDto>> {
+        let task = self
+            .task_repository
+            .find_by_id(&task_id)
+            .await?
+            .ok_or(DomainError::NotFound("Tarefa não encontrada".to_string()))?;
+
+        self.access_policy
+            .ensure(&task, &user_id, TaskAction::View)
+            .await?;
+
+        let attachments = self.attachment_repository.list_by_task(&task_id).await?;
+
+        Ok(attachments.into_iter().map(Into::into).collect())
+    }
+}
@@ -0,0 +1,9 @@
+pub mod delete_attachment;
+pub mod download_attachment;
+pub mod list_attachments;
+pub mod upload_attachment;
+
+pub use delete_attachment::DeleteAttachmentUseCase;
+pub use download_attachment::DownloadAttachmentUseCase;
+pub use list_attachments::ListAttachmentsUseCase;
+pub use upload_attachment::UploadAttachmentUseCase;
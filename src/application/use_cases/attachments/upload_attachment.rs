@@ -0,0 +1,109 @@
+use crate::application::dtos::{AttachmentResponseDto, UploadAttachmentDto};
+use crate::domain::entities::{Attachment, TaskHistory};
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{
+    AttachmentRepository, AttachmentStorage, CollaboratorRepository, TaskRepository,
+};
+use crate::domain::services::{TaskAccessPolicy, TaskAction};
+use crate::domain::value_objects::AttachmentQuota;
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Anexar arquivo a uma tarefa
+pub struct UploadAttachmentUseCase {
+    task_repository: Arc<dyn TaskRepository>,
+    attachment_repository: Arc<dyn AttachmentRepository>,
+    attachment_storage: Arc<dyn AttachmentStorage>,
+    access_policy: TaskAccessPolicy,
+    quota: AttachmentQuota,
+}
+
+impl UploadAttachmentUseCase {
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        collaborator_repository: Arc<dyn CollaboratorRepository>,
+        attachment_repository: Arc<dyn AttachmentRepository>,
+        attachment_storage: Arc<dyn AttachmentStorage>,
+        quota: AttachmentQuota,
+    ) -> Self {
+        Self {
+            task_repository,
+            attachment_repository,
+            attachment_storage,
+            access_policy: TaskAccessPolicy::new(collaborator_repository),
+            quota,
+        }
+    }
+
+    /// Executa o upload do anexo
+    pub async fn execute(
+        &self,
+        task_id: Uuid,
+        user_id: Uuid,
+        dto: UploadAttachmentDto,
+    ) -> DomainResult<AttachmentResponseDto> {
+        // Buscar tarefa e verificar permissão de edição
+        let task = self
+            .task_repository
+            .find_by_id(&task_id)
+            .await?
+            .ok_or(DomainError::NotFound("Tarefa não encontrada".to_string()))?;
+
+        self.access_policy
+            .ensure(&task, &user_id, TaskAction::Edit)
+            .await?;
+
+        // Verificar tamanho e cota do usuário (revalidada ao registrar o anexo)
+        let size = dto.content.len() as u64;
+        let used = self
+            .attachment_repository
+            .total_size_by_user(&user_id)
+            .await?;
+        self.quota.ensure_allows(used.max(0) as u64, size)?;
+
+        // Montar metadados
+        let sha256 = format!("{:x}", Sha256::digest(&dto.content));
+        let attachment = Attachment::new(
+            Uuid::new_v4(),
+            task_id,
+            user_id,
+            &dto.file_name,
+            dto.content_type,
+            size as i64,
+            sha256,
+        )?;
+
+        // Gravar conteúdo e registrar metadados (desfazendo a gravação em caso de erro)
+        self.attachment_storage
+            .put(&attachment.storage_key, &dto.content)
+            .await?;
+
+        let saved = match self
+            .attachment_repository
+            .create(&attachment, &self.quota)
+            .await
+        {
+            Ok(saved) => saved,
+            Err(e) => {
+                self.attachment_storage
+                    .delete(&attachment.storage_key)
+                    .await
+                    .ok();
+                return Err(e);
+            }
+        };
+
+        // Registrar no histórico
+        let history = TaskHistory::new(
+            task_id,
+            user_id,
+            "attachment".to_string(),
+            None,
+            Some(saved.file_name.clone()),
+        );
+        self.task_repository.add_history(&history).await?;
+
+        Ok(saved.into())
+    }
+}
@@ -0,0 +1,210 @@
+use crate::application::dtos::{RecoveryCodesResponseDto, TotpCodeDto};
+use crate::domain::entities::RecoveryCode;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::TwoFactorRepository;
+use crate::infrastructure::security::{
+    generate_recovery_codes, hash_opaque_token, normalize_recovery_code, verify_totp,
+};
+use chrono::Utc;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Quantidade de códigos de recuperação emitidos na ativação
+const RECOVERY_CODE_COUNT: usize = 10;
+
+/// Caso de uso: Confirmar a inscrição no 2FA e emitir os códigos de recuperação
+pub struct ConfirmTotpUseCase {
+    two_factor_repository: Arc<dyn TwoFactorRepository>,
+}
+
+impl ConfirmTotpUseCase {
+    pub fn new(two_factor_repository: Arc<dyn TwoFactorRepository>) -> Self {
+        Self {
+            two_factor_repository,
+        }
+    }
+
+    /// Executa a confirmação
+    ///
+    /// Os códigos de recuperação são retornados em texto puro apenas aqui;
+    /// somente os hashes são persistidos.
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        dto: TotpCodeDto,
+    ) -> DomainResult<RecoveryCodesResponseDto> {
+        let mut credential = self
+            .two_factor_repository
+            .find_by_user(&user_id)
+            .await?
+            .ok_or_else(|| {
+                DomainError::NotFound("Inscrição em dois fatores não iniciada".to_string())
+            })?;
+
+        if credential.is_confirmed() {
+            return Err(DomainError::Conflict(
+                "Autenticação em dois fatores já está ativa".to_string(),
+            ));
+        }
+
+        let step = verify_totp(&credential.secret, &dto.code, Utc::now().timestamp())
+            .ok_or_else(|| DomainError::ValidationError("Código inválido".to_string()))?;
+
+        credential.confirm();
+        credential.last_used_step = Some(step);
+        self.two_factor_repository.save(&credential).await?;
+
+        let codes = generate_recovery_codes(RECOVERY_CODE_COUNT);
+        let hashed: Vec<RecoveryCode> = codes
+            .iter()
+            .map(|code| {
+                RecoveryCode::new(user_id, hash_opaque_token(&normalize_recovery_code(code)))
+            })
+            .collect();
+        self.two_factor_repository
+            .replace_recovery_codes(&user_id, &hashed)
+            .await?;
+
+        Ok(RecoveryCodesResponseDto {
+            recovery_codes: codes,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::TotpCredential;
+    use crate::infrastructure::security::{generate_totp_secret, totp_code};
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    // Mock repository de 2FA em memória
+    #[derive(Default)]
+    struct MockTwoFactorRepository {
+        credential: Mutex<Option<TotpCredential>>,
+        recovery_codes: Mutex<Vec<RecoveryCode>>,
+    }
+
+    #[async_trait]
+    impl TwoFactorRepository for MockTwoFactorRepository {
+        async fn find_by_user(&self, _user_id: &Uuid) -> DomainResult<Option<TotpCredential>> {
+            Ok(self.credential.lock().unwrap().clone())
+        }
+
+        async fn save(&self, credential: &TotpCredential) -> DomainResult<TotpCredential> {
+            *self.credential.lock().unwrap() = Some(credential.clone());
+            Ok(credential.clone())
+        }
+
+        async fn mark_step_used(&self, _user_id: &Uuid, _step: i64) -> DomainResult<bool> {
+            unimplemented!()
+        }
+
+        async fn delete(&self, _user_id: &Uuid) -> DomainResult<()> {
+            unimplemented!()
+        }
+
+        async fn replace_recovery_codes(
+            &self,
+            _user_id: &Uuid,
+            codes: &[RecoveryCode],
+        ) -> DomainResult<()> {
+            *self.recovery_codes.lock().unwrap() = codes.to_vec();
+            Ok(())
+        }
+
+        async fn consume_recovery_code(
+            &self,
+            _user_id: &Uuid,
+            _code_hash: &str,
+        ) -> DomainResult<bool> {
+            unimplemented!()
+        }
+    }
+
+    fn enrolled(user_id: Uuid) -> Arc<MockTwoFactorRepository> {
+        let repository = Arc::new(MockTwoFactorRepository::default());
+        *repository.credential.lock().unwrap() =
+            Some(TotpCredential::new(user_id, generate_totp_secret()));
+        repository
+    }
+
+    fn current_code(repository: &MockTwoFactorRepository) -> TotpCodeDto {
+        let secret = repository
+            .credential
+            .lock()
+            .unwrap()
+            .clone()
+            .unwrap()
+            .secret;
+        TotpCodeDto {
+            code: totp_code(&secret, Utc::now().timestamp()).unwrap(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_confirm_activates_and_stores_hashed_recovery_codes() {
+        let user_id = Uuid::new_v4();
+        let repository = enrolled(user_id);
+        let use_case = ConfirmTotpUseCase::new(repository.clone());
+
+        let response = use_case
+            .execute(user_id, current_code(&repository))
+            .await
+            .unwrap();
+
+        let credential = repository.credential.lock().unwrap().clone().unwrap();
+        assert!(credential.is_confirmed());
+        assert!(credential.last_used_step.is_some());
+
+        let stored = repository.recovery_codes.lock().unwrap();
+        assert_eq!(response.recovery_codes.len(), RECOVERY_CODE_COUNT);
+        assert_eq!(stored.len(), RECOVERY_CODE_COUNT);
+        assert!(stored.iter().all(|code| {
+            !response.recovery_codes.contains(&code.code_hash) && code.code_hash.len() == 64
+        }));
+    }
+
+    #[tokio::test]
+    async fn test_confirm_rejects_wrong_code() {
+        let user_id = Uuid::new_v4();
+        let repository = enrolled(user_id);
+        let use_case = ConfirmTotpUseCase::new(repository.clone());
+
+        let result = use_case
+            .execute(
+                user_id,
+                TotpCodeDto {
+                    code: "12345".to_string(),
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(DomainError::ValidationError(_))));
+        assert!(
+            !repository
+                .credential
+                .lock()
+                .unwrap()
+                .as_ref()
+                .unwrap()
+                .is_confirmed()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_confirm_twice_is_a_conflict() {
+        let user_id = Uuid::new_v4();
+        let repository = enrolled(user_id);
+        let use_case = ConfirmTotpUseCase::new(repository.clone());
+
+        use_case
+            .execute(user_id, current_code(&repository))
+            .await
+            .unwrap();
+        let again = use_case.execute(user_id, current_code(&repository)).await;
+
+        assert!(matches!(again, Err(DomainError::Conflict(_))));
+    }
+}
@@ -0,0 +1,37 @@
+use crate::application::dtos::TotpCodeDto;
+use crate::application::services::TwoFactorService;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::TwoFactorRepository;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Desativar o 2FA
+pub struct DisableTotpUseCase {
+    two_factor_repository: Arc<dyn TwoFactorRepository>,
+    two_factor_service: TwoFactorService,
+}
+
+impl DisableTotpUseCase {
+    pub fn new(
+        two_factor_repository: Arc<dyn TwoFactorRepository>,
+        two_factor_service: TwoFactorService,
+    ) -> Self {
+        Self {
+            two_factor_repository,
+            two_factor_service,
+        }
+    }
+
+    /// Executa a desativação, exigindo um código TOTP ou de recuperação válido
+    pub async fn execute(&self, user_id: Uuid, dto: TotpCodeDto) -> DomainResult<()> {
+        if !self
+            .two_factor_service
+            .verify_code(&user_id, &dto.code)
+            .await?
+        {
+            return Err(DomainError::ValidationError("Código inválido".to_string()));
+        }
+
+        self.two_factor_repository.delete(&user_id).await
+    }
+}
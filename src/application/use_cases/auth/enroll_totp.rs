@@ -0,0 +1,56 @@
+use crate::application::dtos::TotpEnrollmentResponseDto;
+use crate::domain::entities::TotpCredential;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{TwoFactorRepository, UserRepository};
+use crate::infrastructure::security::{generate_totp_secret, totp_uri};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Iniciar a inscrição no 2FA (TOTP)
+pub struct EnrollTotpUseCase {
+    user_repository: Arc<dyn UserRepository>,
+    two_factor_repository: Arc<dyn TwoFactorRepository>,
+    issuer: String,
+}
+
+impl EnrollTotpUseCase {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        two_factor_repository: Arc<dyn TwoFactorRepository>,
+        issuer: String,
+    ) -> Self {
+        Self {
+            user_repository,
+            two_factor_repository,
+            issuer,
+        }
+    }
+
+    /// Executa a inscrição
+    ///
+    /// Gera um novo segredo (substituindo uma inscrição não confirmada); o 2FA
+    /// só passa a valer após a confirmação com um código.
+    pub async fn execute(&self, user_id: Uuid) -> DomainResult<TotpEnrollmentResponseDto> {
+        let user = self
+            .user_repository
+            .find_by_id(&user_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound(format!("Usuário {} não encontrado", user_id)))?;
+
+        if let Some(credential) = self.two_factor_repository.find_by_user(&user_id).await?
+            && credential.is_confirmed()
+        {
+            return Err(DomainError::Conflict(
+                "Autenticação em dois fatores já está ativa".to_string(),
+            ));
+        }
+
+        let credential = TotpCredential::new(user_id, generate_totp_secret());
+        let credential = self.two_factor_repository.save(&credential).await?;
+
+        Ok(TotpEnrollmentResponseDto {
+            otpauth_uri: totp_uri(&self.issuer, user.email.as_str(), &credential.secret),
+            secret: credential.secret,
+        })
+    }
+}
@@ -0,0 +1,308 @@
+use crate::application::dtos::ForgotPasswordDto;
+use crate::application::services::PasswordResetQueue;
+use crate::domain::errors::DomainResult;
+use crate::domain::repositories::UserRepository;
+use crate::domain::value_objects::Email;
+use std::sync::Arc;
+
+/// Caso de uso: Solicitar redefinição de senha
+pub struct ForgotPasswordUseCase {
+    user_repository: Arc<dyn UserRepository>,
+    password_reset_queue: PasswordResetQueue,
+}
+
+impl ForgotPasswordUseCase {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        password_reset_queue: PasswordResetQueue,
+    ) -> Self {
+        Self {
+            user_repository,
+            password_reset_queue,
+        }
+    }
+
+    /// Executa a solicitação
+    ///
+    /// Sempre retorna sucesso para emails desconhecidos, evitando a enumeração
+    /// de contas. Uma nova solicitação invalida os tokens anteriores.
+    ///
+    /// A emissão do token e o envio do email ficam em uma fila limitada,
+    /// processada em segundo plano: a requisição aguarda apenas a busca do
+    /// usuário, então emails cadastrados e desconhecidos respondem no mesmo
+    /// tempo.
+    pub async fn execute(&self, dto: ForgotPasswordDto) -> DomainResult<()> {
+        // Validar email
+        let email = Email::new(dto.email)?;
+
+        let Some(user) = self.user_repository.find_by_email(&email).await? else {
+            return Ok(());
+        };
+
+        self.password_reset_queue.enqueue(user);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::services::{PasswordResetJobs, PasswordResetService};
+    use crate::domain::entities::{PasswordResetToken, User};
+    use crate::domain::errors::DomainError;
+    use crate::domain::repositories::EmailMessage;
+    use crate::domain::repositories::{Mailer, PasswordResetRepository};
+    use crate::infrastructure::security::hash_opaque_token;
+    use crate::test_support::MockUserRepository;
+    use async_trait::async_trait;
+    use chrono::Duration;
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use uuid::Uuid;
+
+    // Mock repository de tokens de redefinição em memória que conta os acessos
+    #[derive(Default)]
+    struct MockPasswordResetRepository {
+        tokens: Mutex<Vec<PasswordResetToken>>,
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl PasswordResetRepository for MockPasswordResetRepository {
+        async fn create(&self, token: &PasswordResetToken) -> DomainResult<PasswordResetToken> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            self.tokens.lock().unwrap().push(token.clone());
+            Ok(token.clone())
+        }
+
+        async fn find_by_token_hash(
+            &self,
+            token_hash: &str,
+        ) -> DomainResult<Option<PasswordResetToken>> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            let tokens = self.tokens.lock().unwrap();
+            Ok(tokens.iter().find(|t| t.token_hash == token_hash).cloned())
+        }
+
+        async fn consume(&self, id: &Uuid) -> DomainResult<bool> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            let mut tokens = self.tokens.lock().unwrap();
+            let token = tokens.iter_mut().find(|t| &t.id == id).unwrap();
+            if !token.is_usable() {
+                return Ok(false);
+            }
+            token.used_at = Some(chrono::Utc::now());
+            Ok(true)
+        }
+
+        async fn invalidate_for_user(&self, user_id: &Uuid) -> DomainResult<()> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            let mut tokens = self.tokens.lock().unwrap();
+            for token in tokens.iter_mut().filter(|t| &t.user_id == user_id) {
+                token.used_at.get_or_insert_with(chrono::Utc::now);
+            }
+            Ok(())
+        }
+    }
+
+    // Mock mailer que guarda os emails enviados e conta as tentativas
+    #[derive(Default)]
+    struct MockMailer {
+        sent: Mutex<Vec<EmailMessage>>,
+        calls: AtomicUsize,
+        fail: bool,
+    }
+
+    #[async_trait]
+    impl Mailer for MockMailer {
+        async fn send(&self, message: &EmailMessage) -> DomainResult<()> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            if self.fail {
+                return Err(DomainError::Internal("SMTP indisponível".to_string()));
+            }
+            self.sent.lock().unwrap().push(message.clone());
+            Ok(())
+        }
+    }
+
+    fn user() -> User {
+        User::new(
+            Uuid::new_v4(),
+            Email::new("ana@example.com".to_string()).unwrap(),
+            "hash".to_string(),
+            "Ana".to_string(),
+        )
+    }
+
+    fn queue(
+        resets: &Arc<MockPasswordResetRepository>,
+        mailer: &Arc<MockMailer>,
+        capacity: usize,
+    ) -> (PasswordResetQueue, PasswordResetJobs) {
+        PasswordResetQueue::new(
+            PasswordResetService::new(
+                resets.clone(),
+                mailer.clone(),
+                Duration::minutes(60),
+                "https://todo.example.com/".to_string(),
+            ),
+            capacity,
+        )
+    }
+
+    fn use_case(
+        resets: &Arc<MockPasswordResetRepository>,
+        mailer: &Arc<MockMailer>,
+    ) -> (ForgotPasswordUseCase, PasswordResetJobs) {
+        let (queue, jobs) = queue(resets, mailer, 8);
+        let use_case =
+            ForgotPasswordUseCase::new(Arc::new(MockUserRepository::new(vec![user()])), queue);
+        (use_case, jobs)
+    }
+
+    fn forgot(email: &str) -> ForgotPasswordDto {
+        ForgotPasswordDto {
+            email: email.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_forgot_password_sends_link_with_token() {
+        let resets = Arc::new(MockPasswordResetRepository::default());
+        let mailer = Arc::new(MockMailer::default());
+
+        let (use_case, mut jobs) = use_case(&resets, &mailer);
+
+        use_case.execute(forgot("ana@example.com")).await.unwrap();
+        jobs.drain().await;
+
+        let sent = mailer.sent.lock().unwrap();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].to, "ana@example.com");
+
+        // O token do link corresponde ao hash persistido
+        let token = sent[0]
+            .body
+            .split("https://todo.example.com/reset-password?token=")
+            .nth(1)
+            .and_then(|rest| rest.split_whitespace().next())
+            .unwrap();
+        let tokens = resets.tokens.lock().unwrap();
+        assert_eq!(tokens[0].token_hash, hash_opaque_token(token));
+        assert!(tokens[0].is_usable());
+    }
+
+    #[tokio::test]
+    async fn test_forgot_password_unknown_email_is_silent() {
+        let resets = Arc::new(MockPasswordResetRepository::default());
+        let mailer = Arc::new(MockMailer::default());
+
+        let (use_case, mut jobs) = use_case(&resets, &mailer);
+
+        let result = use_case.execute(forgot("ninguem@example.com")).await;
+        jobs.drain().await;
+
+        assert!(result.is_ok());
+        assert!(mailer.sent.lock().unwrap().is_empty());
+        assert!(resets.tokens.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_new_request_invalidates_previous_tokens() {
+        let resets = Arc::new(MockPasswordResetRepository::default());
+        let mailer = Arc::new(MockMailer::default());
+        let (use_case, mut jobs) = use_case(&resets, &mailer);
+
+        use_case.execute(forgot("ana@example.com")).await.unwrap();
+        jobs.drain().await;
+        use_case.execute(forgot("ana@example.com")).await.unwrap();
+        jobs.drain().await;
+
+        let tokens = resets.tokens.lock().unwrap();
+        assert_eq!(tokens.len(), 2);
+        assert!(!tokens[0].is_usable());
+        assert!(tokens[1].is_usable());
+    }
+
+    #[tokio::test]
+    async fn test_mailer_failure_is_not_exposed() {
+        let resets = Arc::new(MockPasswordResetRepository::default());
+        let mailer = Arc::new(MockMailer {
+            fail: true,
+            ..MockMailer::default()
+        });
+
+        let (use_case, mut jobs) = use_case(&resets, &mailer);
+
+        let result = use_case.execute(forgot("ana@example.com")).await;
+        jobs.drain().await;
+
+        assert!(result.is_ok());
+        assert_eq!(mailer.calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_known_and_unknown_emails_await_the_same_io() {
+        let resets = Arc::new(MockPasswordResetRepository::default());
+        let mailer = Arc::new(MockMailer::default());
+        let (use_case, mut jobs) = use_case(&resets, &mailer);
+        let io = || resets.calls.load(Ordering::SeqCst) + mailer.calls.load(Ordering::SeqCst);
+
+        // A requisição aguarda apenas a busca do usuário nos dois casos
+        use_case
+            .execute(forgot("ninguem@example.com"))
+            .await
+            .unwrap();
+        let unknown = io();
+        use_case.execute(forgot("ana@example.com")).await.unwrap();
+        let known = io();
+
+        assert_eq!(unknown, 0);
+        assert_eq!(known, unknown);
+
+        // O token e o email saem em segundo plano
+        jobs.drain().await;
+        assert_eq!(mailer.sent.lock().unwrap().len(), 1);
+        assert_eq!(resets.tokens.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_pending_request_is_not_queued_twice() {
+        let resets = Arc::new(MockPasswordResetRepository::default());
+        let mailer = Arc::new(MockMailer::default());
+        let (use_case, mut jobs) = use_case(&resets, &mailer);
+
+        // Solicitações repetidas antes do envio geram um único email
+        for _ in 0..5 {
+            use_case.execute(forgot("ana@example.com")).await.unwrap();
+        }
+
+        assert_eq!(jobs.drain().await, 1);
+        assert_eq!(mailer.sent.lock().unwrap().len(), 1);
+
+        // Depois do envio, uma nova solicitação volta a ser aceita
+        use_case.execute(forgot("ana@example.com")).await.unwrap();
+        assert_eq!(jobs.drain().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_full_queue_drops_requests() {
+        let resets = Arc::new(MockPasswordResetRepository::default());
+        let mailer = Arc::new(MockMailer::default());
+        let (queue, mut jobs) = queue(&resets, &mailer, 1);
+
+        assert!(queue.enqueue(user()));
+        let other = User::new(
+            Uuid::new_v4(),
+            Email::new("bia@example.com".to_string()).unwrap(),
+            "hash".to_string(),
+            "Bia".to_string(),
+        );
+        assert!(!queue.enqueue(other.clone()));
+
+        // O descarte não deixa o usuário marcado como pendente
+        assert_eq!(jobs.drain().await, 1);
+        assert!(queue.enqueue(other));
+    }
+}
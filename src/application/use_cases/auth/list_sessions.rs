@@ -0,0 +1,33 @@
+use crate::application::dtos::SessionResponseDto;
+use crate::domain::errors::DomainResult;
+use crate::domain::repositories::SessionRepository;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Listar as sessões ativas do usuário
+pub struct ListSessionsUseCase {
+    session_repository: Arc<dyn SessionRepository>,
+}
+
+impl ListSessionsUseCase {
+    pub fn new(session_repository: Arc<dyn SessionRepository>) -> Self {
+        Self { session_repository }
+    }
+
+    /// Executa a listagem, marcando a sessão da requisição atual
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        current_session_id: Option<Uuid>,
+    ) -> DomainResult<Vec<SessionResponseDto>> {
+        let sessions = self
+            .session_repository
+            .list_active_by_user(&user_id)
+            .await?;
+
+        Ok(sessions
+            .into_iter()
+            .map(|session| SessionResponseDto::from_session(session, current_session_id))
+            .collect())
+    }
+}
@@ -1,49 +1,139 @@
-use crate::application::dtos::{AuthResponseDto, LoginDto, UserResponseDto};
+use crate::application::dtos::{
+    AuthResponseDto, ClientInfoDto, LoginDto, LoginResponseDto, MfaChallengeDto, UserResponseDto,
+};
+use crate::application::services::{LoginProtectionService, SessionService};
 use crate::domain::errors::{DomainError, DomainResult};
-use crate::domain::repositories::UserRepository;
-use crate::domain::value_objects::Email;
-use crate::infrastructure::security::{generate_jwt, verify_password};
+use crate::domain::repositories::{TwoFactorRepository, UserRepository};
+use crate::domain::value_objects::{Email, EmailVerificationPolicy, LoginOutcome};
+use crate::infrastructure::security::{
+    dummy_verify_password, generate_mfa_pending_jwt, verify_password,
+};
+use chrono::Duration;
 use std::sync::Arc;
 
+/// Validade do token de login pendente de segundo fator
+const MFA_PENDING_TTL_MINUTES: i64 = 5;
+
 /// Caso de uso: Login de usuário
 pub struct LoginUseCase {
     user_repository: Arc<dyn UserRepository>,
+    two_factor_repository: Arc<dyn TwoFactorRepository>,
+    session_service: SessionService,
+    login_protection: LoginProtectionService,
+    verification_policy: EmailVerificationPolicy,
 }
 
 impl LoginUseCase {
-    pub fn new(user_repository: Arc<dyn UserRepository>) -> Self {
-        Self { user_repository }
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        two_factor_repository: Arc<dyn TwoFactorRepository>,
+        session_service: SessionService,
+        login_protection: LoginProtectionService,
+        verification_policy: EmailVerificationPolicy,
+    ) -> Self {
+        Self {
+            user_repository,
+            two_factor_repository,
+            session_service,
+            login_protection,
+            verification_policy,
+        }
     }
 
     /// Executa o login
-    pub async fn execute(&self, dto: LoginDto) -> DomainResult<AuthResponseDto> {
+    ///
+    /// Com o 2FA ativo, retorna apenas um desafio: os tokens da sessão são
+    /// emitidos pelo `VerifyMfaLoginUseCase` após a validação do código.
+    /// Falhas repetidas atrasam a resposta e bloqueiam a conta/IP temporariamente.
+    pub async fn execute(
+        &self,
+        dto: LoginDto,
+        client: ClientInfoDto,
+    ) -> DomainResult<LoginResponseDto> {
         // Validar email
         let email = Email::new(dto.email)?;
 
         // Buscar usuário
-        let user = self
-            .user_repository
-            .find_by_email(&email)
-            .await?
-            .ok_or(DomainError::Unauthorized)?;
-
-        // Verificar senha
-        if !verify_password(&dto.password, &user.password_hash)
-            .map_err(|e| DomainError::Internal(e.to_string()))?
-        {
+        let user = self.user_repository.find_by_email(&email).await?;
+        let user_id = user.as_ref().map(|user| user.id);
+
+        // Recusar contas/IPs bloqueados antes de testar a senha
+        self.login_protection
+            .ensure_not_locked(user_id, email.as_str(), &client)
+            .await?;
+
+        // Verificar senha (com hash de referência para emails desconhecidos,
+        // de modo que o tempo de resposta não revele a existência da conta)
+        let password_matches = match &user {
+            Some(user) => verify_password(&dto.password, &user.password_hash)
+                .map_err(|e| DomainError::Internal(e.to_string()))?,
+            None => {
+                dummy_verify_password(&dto.password);
+                false
+            }
+        };
+
+        let Some(user) = user.filter(|_| password_matches) else {
+            self.login_protection
+                .record_failure(
+                    user_id,
+                    email.as_str(),
+                    &client,
+                    LoginOutcome::InvalidCredentials,
+                )
+                .await?;
             return Err(DomainError::Unauthorized);
+        };
+
+        // Recusar contas desativadas por um administrador
+        if user.is_disabled() {
+            return Err(DomainError::Forbidden("Conta desativada".to_string()));
         }
 
-        // Gerar token JWT
-        let token = generate_jwt(&user.id).map_err(|e| DomainError::Internal(e.to_string()))?;
-
-        Ok(AuthResponseDto {
-            token,
-            user: UserResponseDto {
-                id: user.id,
-                email: user.email.as_str().to_string(),
-                name: user.name,
-            },
-        })
+        // Verificar confirmação do email (apenas após a senha, para não revelar a conta)
+        if self.verification_policy.blocks_login() && !user.is_email_verified() {
+            return Err(DomainError::Forbidden(
+                "Confirme seu email antes de fazer login".to_string(),
+            ));
+        }
+
+        // Exigir segundo fator quando o 2FA está ativo
+        if let Some(credential) = self.two_factor_repository.find_by_user(&user.id).await?
+            && credential.is_confirmed()
+        {
+            let ttl = Duration::minutes(MFA_PENDING_TTL_MINUTES);
+            let mfa_token = generate_mfa_pending_jwt(&user.id, ttl)
+                .map_err(|e| DomainError::Internal(e.to_string()))?;
+            self.login_protection
+                .record(
+                    Some(user.id),
+                    email.as_str(),
+                    &client,
+                    LoginOutcome::MfaRequired,
+                )
+                .await?;
+
+            return Ok(LoginResponseDto::MfaRequired(MfaChallengeDto {
+                mfa_required: true,
+                mfa_token,
+                expires_in: ttl.num_seconds(),
+            }));
+        }
+
+        // Iniciar sessão (access token + refresh token)
+        self.login_protection
+            .record(
+                Some(user.id),
+                email.as_str(),
+                &client,
+                LoginOutcome::Success,
+            )
+            .await?;
+        let tokens = self.session_service.start(user.id, client).await?;
+
+        Ok(LoginResponseDto::Authenticated(AuthResponseDto {
+            tokens: Some(tokens),
+            user: UserResponseDto::from_user(&user),
+        }))
     }
 }
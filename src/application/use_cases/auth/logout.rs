@@ -0,0 +1,53 @@
+use crate::application::dtos::RefreshTokenDto;
+use crate::domain::errors::DomainResult;
+use crate::domain::repositories::SessionRepository;
+use crate::infrastructure::security::{Claims, TokenRevocationService, hash_opaque_token};
+use std::sync::Arc;
+
+/// Caso de uso: Encerrar a sessão do refresh token informado
+pub struct LogoutUseCase {
+    session_repository: Arc<dyn SessionRepository>,
+    token_revocation: Arc<TokenRevocationService>,
+}
+
+impl LogoutUseCase {
+    pub fn new(
+        session_repository: Arc<dyn SessionRepository>,
+        token_revocation: Arc<TokenRevocationService>,
+    ) -> Self {
+        Self {
+            session_repository,
+            token_revocation,
+        }
+    }
+
+    /// Executa o logout (idempotente: tokens desconhecidos são ignorados)
+    ///
+    /// Se o access token da requisição for informado, ele é revogado também,
+    /// em vez de continuar válido até expirar.
+    pub async fn execute(
+        &self,
+        dto: RefreshTokenDto,
+        access_token: Option<Claims>,
+    ) -> DomainResult<()> {
+        let session = self
+            .session_repository
+            .find_by_token_hash(&hash_opaque_token(&dto.refresh_token))
+            .await?;
+
+        if let Some(session) = session {
+            self.session_repository
+                .revoke_family(&session.family_id)
+                .await?;
+
+            // Só revoga o access token se ele for do dono da sessão
+            if let Some(claims) = access_token
+                && claims.user_id().ok() == Some(session.user_id)
+            {
+                self.token_revocation.revoke(&claims).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
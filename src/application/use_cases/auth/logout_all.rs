@@ -0,0 +1,31 @@
+use crate::domain::errors::DomainResult;
+use crate::domain::repositories::SessionRepository;
+use crate::infrastructure::security::TokenRevocationService;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Sair de todos os dispositivos
+pub struct LogoutAllUseCase {
+    session_repository: Arc<dyn SessionRepository>,
+    token_revocation: Arc<TokenRevocationService>,
+}
+
+impl LogoutAllUseCase {
+    pub fn new(
+        session_repository: Arc<dyn SessionRepository>,
+        token_revocation: Arc<TokenRevocationService>,
+    ) -> Self {
+        Self {
+            session_repository,
+            token_revocation,
+        }
+    }
+
+    /// Executa o logout global: revoga as sessões e invalida os access tokens emitidos
+    pub async fn execute(&self, user_id: Uuid) -> DomainResult<()> {
+        self.session_repository.revoke_all_by_user(&user_id).await?;
+        self.token_revocation.revoke_all(&user_id).await?;
+
+        Ok(())
+    }
+}
@@ -1,5 +1,31 @@
+pub mod confirm_totp;
+pub mod disable_totp;
+pub mod enroll_totp;
+pub mod forgot_password;
+pub mod list_sessions;
 pub mod login;
+pub mod logout;
+pub mod logout_all;
+pub mod refresh_token;
 pub mod register;
+pub mod resend_verification;
+pub mod reset_password;
+pub mod revoke_session;
+pub mod verify_email;
+pub mod verify_mfa_login;
 
+pub use confirm_totp::ConfirmTotpUseCase;
+pub use disable_totp::DisableTotpUseCase;
+pub use enroll_totp::EnrollTotpUseCase;
+pub use forgot_password::ForgotPasswordUseCase;
+pub use list_sessions::ListSessionsUseCase;
 pub use login::LoginUseCase;
+pub use logout::LogoutUseCase;
+pub use logout_all::LogoutAllUseCase;
+pub use refresh_token::RefreshTokenUseCase;
 pub use register::RegisterUseCase;
+pub use resend_verification::ResendVerificationUseCase;
+pub use reset_password::ResetPasswordUseCase;
+pub use revoke_session::RevokeSessionUseCase;
+pub use verify_email::VerifyEmailUseCase;
+pub use verify_mfa_login::VerifyMfaLoginUseCase;
@@ -0,0 +1,222 @@
+use crate::application::dtos::{ClientInfoDto, RefreshTokenDto, TokenResponseDto};
+use crate::application::services::SessionService;
+use crate::domain::errors::DomainResult;
+
+/// Caso de uso: Renovar o access token trocando o refresh token (rotação)
+pub struct RefreshTokenUseCase {
+    session_service: SessionService,
+}
+
+impl RefreshTokenUseCase {
+    pub fn new(session_service: SessionService) -> Self {
+        Self { session_service }
+    }
+
+    /// Executa a renovação (reuso de um token já trocado revoga a sessão inteira)
+    pub async fn execute(
+        &self,
+        dto: RefreshTokenDto,
+        client: ClientInfoDto,
+    ) -> DomainResult<TokenResponseDto> {
+        let session = self.session_service.redeem(&dto.refresh_token).await?;
+
+        self.session_service.rotate(&session, client).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::services::SessionTtl;
+    use crate::domain::entities::{Membership, User, Workspace};
+    use crate::domain::errors::DomainError;
+    use crate::domain::repositories::WorkspaceRepository;
+    use crate::domain::value_objects::Email;
+    use crate::infrastructure::security::TokenRevocationService;
+    use crate::test_support::{
+        MockRevokedTokenRepository, MockSessionRepository, MockUserRepository,
+    };
+    use async_trait::async_trait;
+    use chrono::Duration;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    // Mock repository de workspaces (cada usuário no seu workspace pessoal)
+    struct MockWorkspaceRepository;
+
+    #[async_trait]
+    impl WorkspaceRepository for MockWorkspaceRepository {
+        async fn create(
+            &self,
+            workspace: &Workspace,
+            _owner: &Membership,
+        ) -> DomainResult<Workspace> {
+            Ok(workspace.clone())
+        }
+
+        async fn find_by_id(&self, _id: &Uuid) -> DomainResult<Option<Workspace>> {
+            unimplemented!()
+        }
+
+        async fn list_by_user(
+            &self,
+            _user_id: &Uuid,
+        ) -> DomainResult<Vec<(Workspace, Membership)>> {
+            unimplemented!()
+        }
+
+        async fn find_default_for_user(&self, user_id: &Uuid) -> DomainResult<Option<Uuid>> {
+            Ok(Some(*user_id))
+        }
+
+        async fn find_membership(
+            &self,
+            workspace_id: &Uuid,
+            user_id: &Uuid,
+        ) -> DomainResult<Option<Membership>> {
+            Ok((workspace_id == user_id).then(|| Membership::owner(*workspace_id, *user_id)))
+        }
+
+        async fn add_member(&self, _membership: &Membership) -> DomainResult<Membership> {
+            unimplemented!()
+        }
+
+        async fn list_members(&self, _workspace_id: &Uuid) -> DomainResult<Vec<Membership>> {
+            unimplemented!()
+        }
+    }
+
+    fn ttl() -> SessionTtl {
+        SessionTtl {
+            access_token: Duration::minutes(15),
+            refresh_token: Duration::days(30),
+        }
+    }
+
+    struct Fixture {
+        user_id: Uuid,
+        users: Arc<MockUserRepository>,
+        sessions: Arc<MockSessionRepository>,
+    }
+
+    fn fixture() -> Fixture {
+        let user = User::new(
+            Uuid::new_v4(),
+            Email::new("ana@example.com").unwrap(),
+            "hash".to_string(),
+            "Ana".to_string(),
+        );
+
+        Fixture {
+            user_id: user.id,
+            users: Arc::new(MockUserRepository::new(vec![user])),
+            sessions: Arc::new(MockSessionRepository::default()),
+        }
+    }
+
+    impl Fixture {
+        fn session_service(&self) -> SessionService {
+            let token_revocation = TokenRevocationService::new(
+                Arc::new(MockRevokedTokenRepository::default()),
+                self.users.clone(),
+                std::time::Duration::from_secs(30),
+            );
+
+            SessionService::new(
+                self.users.clone(),
+                Arc::new(MockWorkspaceRepository),
+                self.sessions.clone(),
+                Arc::new(token_revocation),
+                ttl(),
+            )
+        }
+
+        fn use_case(&self) -> RefreshTokenUseCase {
+            RefreshTokenUseCase::new(self.session_service())
+        }
+    }
+
+    fn refresh(token: &str) -> RefreshTokenDto {
+        RefreshTokenDto {
+            refresh_token: token.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_refresh_rotates_token() {
+        unsafe { std::env::set_var("JWT_SECRET", "test-secret-key") };
+        let fixture = fixture();
+        let login = fixture
+            .session_service()
+            .start(fixture.user_id, ClientInfoDto::default())
+            .await
+            .unwrap();
+
+        let refreshed = fixture
+            .use_case()
+            .execute(refresh(&login.refresh_token), ClientInfoDto::default())
+            .await
+            .unwrap();
+
+        assert_ne!(refreshed.refresh_token, login.refresh_token);
+        assert_eq!(refreshed.expires_in, 15 * 60);
+
+        let sessions = fixture.sessions.sessions.lock().unwrap();
+        assert_eq!(sessions.len(), 2);
+        assert!(sessions[0].is_rotated());
+        assert_eq!(sessions[1].family_id, sessions[0].family_id);
+        assert_eq!(sessions[1].workspace_id, sessions[0].workspace_id);
+    }
+
+    #[tokio::test]
+    async fn test_reuse_revokes_family() {
+        unsafe { std::env::set_var("JWT_SECRET", "test-secret-key") };
+        let fixture = fixture();
+        let login = fixture
+            .session_service()
+            .start(fixture.user_id, ClientInfoDto::default())
+            .await
+            .unwrap();
+
+        let refreshed = fixture
+            .use_case()
+            .execute(refresh(&login.refresh_token), ClientInfoDto::default())
+            .await
+            .unwrap();
+
+        // Token antigo reapresentado: rejeitado e família revogada
+        let reuse = fixture
+            .use_case()
+            .execute(refresh(&login.refresh_token), ClientInfoDto::default())
+            .await;
+        assert!(matches!(reuse, Err(DomainError::Unauthorized)));
+
+        // O token legítimo mais recente também deixa de valer
+        let latest = fixture
+            .use_case()
+            .execute(refresh(&refreshed.refresh_token), ClientInfoDto::default())
+            .await;
+        assert!(matches!(latest, Err(DomainError::Unauthorized)));
+        assert!(
+            fixture
+                .sessions
+                .sessions
+                .lock()
+                .unwrap()
+                .iter()
+                .all(|s| s.revoked_at.is_some())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unknown_token_is_unauthorized() {
+        let fixture = fixture();
+
+        let result = fixture
+            .use_case()
+            .execute(refresh("unknown"), ClientInfoDto::default())
+            .await;
+
+        assert!(matches!(result, Err(DomainError::Unauthorized)));
+    }
+}
@@ -1,24 +1,45 @@
-use crate::application::dtos::{AuthResponseDto, RegisterUserDto, UserResponseDto};
-use crate::domain::entities::User;
+use crate::application::dtos::{AuthResponseDto, ClientInfoDto, RegisterUserDto, UserResponseDto};
+use crate::application::services::{EmailVerificationService, SessionService};
+use crate::domain::entities::{Membership, User, Workspace};
 use crate::domain::errors::{DomainError, DomainResult};
-use crate::domain::repositories::UserRepository;
-use crate::domain::value_objects::Email;
-use crate::infrastructure::security::{generate_jwt, hash_password};
+use crate::domain::repositories::{UserRepository, WorkspaceRepository};
+use crate::domain::value_objects::{Email, EmailVerificationPolicy};
+use crate::infrastructure::security::hash_password;
 use std::sync::Arc;
 use uuid::Uuid;
 
 /// Caso de uso: Registrar novo usuário
 pub struct RegisterUseCase {
     user_repository: Arc<dyn UserRepository>,
+    workspace_repository: Arc<dyn WorkspaceRepository>,
+    session_service: SessionService,
+    email_verification: EmailVerificationService,
+    verification_policy: EmailVerificationPolicy,
 }
 
 impl RegisterUseCase {
-    pub fn new(user_repository: Arc<dyn UserRepository>) -> Self {
-        Self { user_repository }
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        workspace_repository: Arc<dyn WorkspaceRepository>,
+        session_service: SessionService,
+        email_verification: EmailVerificationService,
+        verification_policy: EmailVerificationPolicy,
+    ) -> Self {
+        Self {
+            user_repository,
+            workspace_repository,
+            session_service,
+            email_verification,
+            verification_policy,
+        }
     }
 
     /// Executa o registro de usuário
-    pub async fn execute(&self, dto: RegisterUserDto) -> DomainResult<AuthResponseDto> {
+    pub async fn execute(
+        &self,
+        dto: RegisterUserDto,
+        client: ClientInfoDto,
+    ) -> DomainResult<AuthResponseDto> {
         // Validar email
         let email = Email::new(dto.email)?;
 
@@ -37,17 +58,25 @@ impl RegisterUseCase {
         // Salvar no repositório
         let saved_user = self.user_repository.create(&user).await?;
 
-        // Gerar token JWT
-        let token =
-            generate_jwt(&saved_user.id).map_err(|e| DomainError::Internal(e.to_string()))?;
+        // Criar o workspace pessoal, ativo no primeiro login
+        let workspace = Workspace::personal();
+        self.workspace_repository
+            .create(&workspace, &Membership::owner(workspace.id, saved_user.id))
+            .await?;
+
+        // Enviar link de confirmação do email
+        self.email_verification.send(&saved_user).await?;
+
+        // Iniciar sessão (access token + refresh token), salvo se o login exige email confirmado
+        let tokens = if self.verification_policy.blocks_login() {
+            None
+        } else {
+            Some(self.session_service.start(saved_user.id, client).await?)
+        };
 
         Ok(AuthResponseDto {
-            token,
-            user: UserResponseDto {
-                id: saved_user.id,
-                email: saved_user.email.as_str().to_string(),
-                name: saved_user.name,
-            },
+            tokens,
+            user: UserResponseDto::from_user(&saved_user),
         })
     }
 }
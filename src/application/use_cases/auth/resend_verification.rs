@@ -0,0 +1,173 @@
+use crate::application::dtos::ResendVerificationDto;
+use crate::application::services::EmailVerificationService;
+use crate::domain::errors::DomainResult;
+use crate::domain::repositories::UserRepository;
+use crate::domain::value_objects::Email;
+use std::sync::Arc;
+
+/// Caso de uso: Reenviar o email de verificação
+pub struct ResendVerificationUseCase {
+    user_repository: Arc<dyn UserRepository>,
+    email_verification: EmailVerificationService,
+}
+
+impl ResendVerificationUseCase {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        email_verification: EmailVerificationService,
+    ) -> Self {
+        Self {
+            user_repository,
+            email_verification,
+        }
+    }
+
+    /// Executa o reenvio
+    ///
+    /// Emails desconhecidos ou já verificados são ignorados silenciosamente,
+    /// evitando a enumeração de contas.
+    pub async fn execute(&self, dto: ResendVerificationDto) -> DomainResult<()> {
+        // Validar email
+        let email = Email::new(dto.email)?;
+
+        match self.user_repository.find_by_email(&email).await? {
+            Some(user) if !user.is_email_verified() => self.email_verification.send(&user).await,
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{EmailVerificationToken, User};
+    use crate::domain::repositories::{EmailMessage, EmailVerificationRepository, Mailer};
+    use crate::test_support::MockUserRepository;
+    use async_trait::async_trait;
+    use chrono::Duration;
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    // Mock repository de tokens de verificação em memória
+    #[derive(Default)]
+    struct MockVerificationRepository {
+        tokens: Mutex<Vec<EmailVerificationToken>>,
+    }
+
+    #[async_trait]
+    impl EmailVerificationRepository for MockVerificationRepository {
+        async fn create(
+            &self,
+            token: &EmailVerificationToken,
+        ) -> DomainResult<EmailVerificationToken> {
+            self.tokens.lock().unwrap().push(token.clone());
+            Ok(token.clone())
+        }
+
+        async fn find_by_token_hash(
+            &self,
+            _token_hash: &str,
+        ) -> DomainResult<Option<EmailVerificationToken>> {
+            unimplemented!()
+        }
+
+        async fn consume(&self, _id: &Uuid) -> DomainResult<bool> {
+            unimplemented!()
+        }
+
+        async fn invalidate_for_user(&self, user_id: &Uuid) -> DomainResult<()> {
+            let mut tokens = self.tokens.lock().unwrap();
+            for token in tokens.iter_mut().filter(|t| &t.user_id == user_id) {
+                token.used_at.get_or_insert_with(chrono::Utc::now);
+            }
+            Ok(())
+        }
+    }
+
+    // Mock mailer que guarda os emails enviados
+    #[derive(Default)]
+    struct MockMailer {
+        sent: Mutex<Vec<EmailMessage>>,
+    }
+
+    #[async_trait]
+    impl Mailer for MockMailer {
+        async fn send(&self, message: &EmailMessage) -> DomainResult<()> {
+            self.sent.lock().unwrap().push(message.clone());
+            Ok(())
+        }
+    }
+
+    fn use_case(
+        verified: bool,
+        verifications: &Arc<MockVerificationRepository>,
+        mailer: &Arc<MockMailer>,
+    ) -> ResendVerificationUseCase {
+        let mut user = User::new(
+            Uuid::new_v4(),
+            Email::new("ana@example.com".to_string()).unwrap(),
+            "hash".to_string(),
+            "Ana".to_string(),
+        );
+        if verified {
+            user.verify_email();
+        }
+
+        ResendVerificationUseCase::new(
+            Arc::new(MockUserRepository::new(vec![user])),
+            EmailVerificationService::new(
+                verifications.clone(),
+                mailer.clone(),
+                Duration::hours(48),
+                "http://localhost:8080".to_string(),
+            ),
+        )
+    }
+
+    fn resend(email: &str) -> ResendVerificationDto {
+        ResendVerificationDto {
+            email: email.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resend_issues_new_token_and_invalidates_previous() {
+        let verifications = Arc::new(MockVerificationRepository::default());
+        let mailer = Arc::new(MockMailer::default());
+        let use_case = use_case(false, &verifications, &mailer);
+
+        use_case.execute(resend("ana@example.com")).await.unwrap();
+        use_case.execute(resend("ana@example.com")).await.unwrap();
+
+        let tokens = verifications.tokens.lock().unwrap();
+        assert_eq!(tokens.len(), 2);
+        assert!(!tokens[0].is_usable());
+        assert!(tokens[1].is_usable());
+
+        let sent = mailer.sent.lock().unwrap();
+        assert_eq!(sent.len(), 2);
+        assert!(
+            sent[1]
+                .body
+                .contains("http://localhost:8080/api/auth/verify?token=")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resend_ignores_verified_or_unknown_accounts() {
+        let verifications = Arc::new(MockVerificationRepository::default());
+        let mailer = Arc::new(MockMailer::default());
+
+        use_case(true, &verifications, &mailer)
+            .execute(resend("ana@example.com"))
+            .await
+            .unwrap();
+        use_case(false, &verifications, &mailer)
+            .execute(resend("ninguem@example.com"))
+            .await
+            .unwrap();
+
+        assert!(mailer.sent.lock().unwrap().is_empty());
+        assert!(verifications.tokens.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,257 @@
+use crate::application::dtos::ResetPasswordDto;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{PasswordResetRepository, SessionRepository, UserRepository};
+use crate::infrastructure::security::{TokenRevocationService, hash_opaque_token, hash_password};
+use std::sync::Arc;
+
+/// Caso de uso: Redefinir a senha com um token de uso único
+pub struct ResetPasswordUseCase {
+    user_repository: Arc<dyn UserRepository>,
+    password_reset_repository: Arc<dyn PasswordResetRepository>,
+    session_repository: Arc<dyn SessionRepository>,
+    token_revocation: Arc<TokenRevocationService>,
+}
+
+impl ResetPasswordUseCase {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        password_reset_repository: Arc<dyn PasswordResetRepository>,
+        session_repository: Arc<dyn SessionRepository>,
+        token_revocation: Arc<TokenRevocationService>,
+    ) -> Self {
+        Self {
+            user_repository,
+            password_reset_repository,
+            session_repository,
+            token_revocation,
+        }
+    }
+
+    /// Executa a redefinição
+    ///
+    /// O token é consumido antes da troca de senha; em seguida todas as sessões
+    /// e access tokens do usuário são invalidados.
+    pub async fn execute(&self, dto: ResetPasswordDto) -> DomainResult<()> {
+        let invalid_token = || {
+            DomainError::ValidationError("Token de redefinição inválido ou expirado".to_string())
+        };
+
+        let token = self
+            .password_reset_repository
+            .find_by_token_hash(&hash_opaque_token(&dto.token))
+            .await?
+            .filter(|token| token.is_usable())
+            .ok_or_else(invalid_token)?;
+
+        // Consumo condicional: dois usos concorrentes não passam ambos
+        if !self.password_reset_repository.consume(&token.id).await? {
+            return Err(invalid_token());
+        }
+
+        let mut user = self
+            .user_repository
+            .find_by_id(&token.user_id)
+            .await?
+            .ok_or_else(invalid_token)?;
+
+        // Atualizar senha
+        let password_hash =
+            hash_password(&dto.new_password).map_err(|e| DomainError::Internal(e.to_string()))?;
+        user.update_password(password_hash);
+        self.user_repository.update(&user).await?;
+
+        // Invalidar demais tokens de redefinição, sessões e access tokens
+        self.password_reset_repository
+            .invalidate_for_user(&user.id)
+            .await?;
+        self.session_repository.revoke_all_by_user(&user.id).await?;
+        self.token_revocation.revoke_all(&user.id).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{PasswordResetToken, Session, User};
+    use crate::domain::value_objects::Email;
+    use crate::infrastructure::security::verify_password;
+    use crate::test_support::{
+        MockRevokedTokenRepository, MockSessionRepository, MockUserRepository,
+    };
+    use async_trait::async_trait;
+    use chrono::{Duration, Utc};
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    // Mock repository de tokens de redefinição em memória
+    #[derive(Default)]
+    struct MockPasswordResetRepository {
+        tokens: Mutex<Vec<PasswordResetToken>>,
+    }
+
+    #[async_trait]
+    impl PasswordResetRepository for MockPasswordResetRepository {
+        async fn create(&self, token: &PasswordResetToken) -> DomainResult<PasswordResetToken> {
+            self.tokens.lock().unwrap().push(token.clone());
+            Ok(token.clone())
+        }
+
+        async fn find_by_token_hash(
+            &self,
+            token_hash: &str,
+        ) -> DomainResult<Option<PasswordResetToken>> {
+            let tokens = self.tokens.lock().unwrap();
+            Ok(tokens.iter().find(|t| t.token_hash == token_hash).cloned())
+        }
+
+        async fn consume(&self, id: &Uuid) -> DomainResult<bool> {
+            let mut tokens = self.tokens.lock().unwrap();
+            let token = tokens.iter_mut().find(|t| &t.id == id).unwrap();
+            if !token.is_usable() {
+                return Ok(false);
+            }
+            token.used_at = Some(Utc::now());
+            Ok(true)
+        }
+
+        async fn invalidate_for_user(&self, user_id: &Uuid) -> DomainResult<()> {
+            let mut tokens = self.tokens.lock().unwrap();
+            for token in tokens.iter_mut().filter(|t| &t.user_id == user_id) {
+                token.used_at.get_or_insert_with(Utc::now);
+            }
+            Ok(())
+        }
+    }
+
+    struct Fixture {
+        user_id: Uuid,
+        users: Arc<MockUserRepository>,
+        resets: Arc<MockPasswordResetRepository>,
+        sessions: Arc<MockSessionRepository>,
+        use_case: ResetPasswordUseCase,
+    }
+
+    fn fixture() -> Fixture {
+        let user = User::new(
+            Uuid::new_v4(),
+            Email::new("ana@example.com".to_string()).unwrap(),
+            "old-hash".to_string(),
+            "Ana".to_string(),
+        );
+        let user_id = user.id;
+        let users = Arc::new(MockUserRepository::new(vec![user]));
+        let resets = Arc::new(MockPasswordResetRepository::default());
+        let sessions = Arc::new(MockSessionRepository::default());
+        let token_revocation = TokenRevocationService::new(
+            Arc::new(MockRevokedTokenRepository::default()),
+            users.clone(),
+            std::time::Duration::from_secs(30),
+        );
+
+        let use_case = ResetPasswordUseCase::new(
+            users.clone(),
+            resets.clone(),
+            sessions.clone(),
+            Arc::new(token_revocation),
+        );
+
+        Fixture {
+            user_id,
+            users,
+            resets,
+            sessions,
+            use_case,
+        }
+    }
+
+    impl Fixture {
+        fn issue(&self, token: &str, ttl: Duration) {
+            let reset = PasswordResetToken::new(self.user_id, hash_opaque_token(token), ttl);
+            self.resets.tokens.lock().unwrap().push(reset);
+        }
+    }
+
+    fn reset(token: &str) -> ResetPasswordDto {
+        ResetPasswordDto {
+            token: token.to_string(),
+            new_password: "nova-senha-123".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reset_updates_password_and_revokes_sessions() {
+        let fixture = fixture();
+        fixture.issue("token-valido", Duration::hours(1));
+        fixture.issue("token-antigo", Duration::hours(1));
+        fixture
+            .sessions
+            .sessions
+            .lock()
+            .unwrap()
+            .push(Session::start(
+                fixture.user_id,
+                None,
+                "refresh".to_string(),
+                None,
+                None,
+                Duration::days(30),
+            ));
+
+        fixture
+            .use_case
+            .execute(reset("token-valido"))
+            .await
+            .unwrap();
+
+        let user = fixture.users.get(&fixture.user_id).unwrap();
+        assert!(verify_password("nova-senha-123", &user.password_hash).unwrap());
+        assert_eq!(user.token_version, 1);
+        assert!(
+            fixture.sessions.sessions.lock().unwrap()[0]
+                .revoked_at
+                .is_some()
+        );
+        assert!(
+            fixture
+                .resets
+                .tokens
+                .lock()
+                .unwrap()
+                .iter()
+                .all(|t| !t.is_usable())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_token_is_single_use() {
+        let fixture = fixture();
+        fixture.issue("token-valido", Duration::hours(1));
+
+        fixture
+            .use_case
+            .execute(reset("token-valido"))
+            .await
+            .unwrap();
+        let reuse = fixture.use_case.execute(reset("token-valido")).await;
+
+        assert!(matches!(reuse, Err(DomainError::ValidationError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_expired_or_unknown_token_is_rejected() {
+        let fixture = fixture();
+        fixture.issue("token-expirado", Duration::seconds(-1));
+
+        let expired = fixture.use_case.execute(reset("token-expirado")).await;
+        let unknown = fixture.use_case.execute(reset("token-inexistente")).await;
+
+        assert!(matches!(expired, Err(DomainError::ValidationError(_))));
+        assert!(matches!(unknown, Err(DomainError::ValidationError(_))));
+        assert_eq!(
+            fixture.users.get(&fixture.user_id).unwrap().password_hash,
+            "old-hash"
+        );
+    }
+}
@@ -0,0 +1,31 @@
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::SessionRepository;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Revogar uma sessão (dispositivo) do usuário
+pub struct RevokeSessionUseCase {
+    session_repository: Arc<dyn SessionRepository>,
+}
+
+impl RevokeSessionUseCase {
+    pub fn new(session_repository: Arc<dyn SessionRepository>) -> Self {
+        Self { session_repository }
+    }
+
+    /// Executa a revogação
+    pub async fn execute(&self, user_id: Uuid, session_id: Uuid) -> DomainResult<()> {
+        let owned = self
+            .session_repository
+            .list_active_by_user(&user_id)
+            .await?
+            .iter()
+            .any(|session| session.family_id == session_id);
+
+        if !owned {
+            return Err(DomainError::NotFound("Sessão não encontrada".to_string()));
+        }
+
+        self.session_repository.revoke_family(&session_id).await
+    }
+}
@@ -0,0 +1,199 @@
+use crate::application::dtos::UserResponseDto;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{EmailVerificationRepository, UserRepository};
+use crate::domain::value_objects::Email;
+use crate::infrastructure::security::hash_opaque_token;
+use std::sync::Arc;
+
+/// Caso de uso: Confirmar o email com o token recebido
+pub struct VerifyEmailUseCase {
+    user_repository: Arc<dyn UserRepository>,
+    verification_repository: Arc<dyn EmailVerificationRepository>,
+}
+
+impl VerifyEmailUseCase {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        verification_repository: Arc<dyn EmailVerificationRepository>,
+    ) -> Self {
+        Self {
+            user_repository,
+            verification_repository,
+        }
+    }
+
+    /// Executa a confirmação
+    ///
+    /// Tokens de troca de email aplicam o novo endereço (já confirmado pelo link).
+    pub async fn execute(&self, token: &str) -> DomainResult<UserResponseDto> {
+        let invalid_token = || {
+            DomainError::ValidationError("Token de verificação inválido ou expirado".to_string())
+        };
+
+        let verification = self
+            .verification_repository
+            .find_by_token_hash(&hash_opaque_token(token))
+            .await?
+            .filter(|verification| verification.is_usable())
+            .ok_or_else(invalid_token)?;
+
+        if !self
+            .verification_repository
+            .consume(&verification.id)
+            .await?
+        {
+            return Err(invalid_token());
+        }
+
+        let mut user = self
+            .user_repository
+            .find_by_id(&verification.user_id)
+            .await?
+            .ok_or_else(invalid_token)?;
+
+        match verification.new_email {
+            Some(new_email) => {
+                let new_email = Email::new(new_email)?;
+                if self.user_repository.email_exists(&new_email).await? {
+                    return Err(DomainError::Conflict("Email já cadastrado".to_string()));
+                }
+                user.change_email(new_email);
+            }
+            None => user.verify_email(),
+        }
+        let user = self.user_repository.update(&user).await?;
+
+        Ok(UserResponseDto::from_user(&user))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{EmailVerificationToken, User};
+    use crate::domain::value_objects::Email;
+    use crate::test_support::MockUserRepository;
+    use async_trait::async_trait;
+    use chrono::{Duration, Utc};
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    // Mock repository de tokens de verificação em memória
+    #[derive(Default)]
+    struct MockVerificationRepository {
+        tokens: Mutex<Vec<EmailVerificationToken>>,
+    }
+
+    #[async_trait]
+    impl EmailVerificationRepository for MockVerificationRepository {
+        async fn create(
+            &self,
+            token: &EmailVerificationToken,
+        ) -> DomainResult<EmailVerificationToken> {
+            self.tokens.lock().unwrap().push(token.clone());
+            Ok(token.clone())
+        }
+
+        async fn find_by_token_hash(
+            &self,
+            token_hash: &str,
+        ) -> DomainResult<Option<EmailVerificationToken>> {
+            let tokens = self.tokens.lock().unwrap();
+            Ok(tokens.iter().find(|t| t.token_hash == token_hash).cloned())
+        }
+
+        async fn consume(&self, id: &Uuid) -> DomainResult<bool> {
+            let mut tokens = self.tokens.lock().unwrap();
+            let token = tokens.iter_mut().find(|t| &t.id == id).unwrap();
+            if !token.is_usable() {
+                return Ok(false);
+            }
+            token.used_at = Some(Utc::now());
+            Ok(true)
+        }
+
+        async fn invalidate_for_user(&self, _user_id: &Uuid) -> DomainResult<()> {
+            Ok(())
+        }
+    }
+
+    fn setup(ttl: Duration) -> (Uuid, Arc<MockUserRepository>, VerifyEmailUseCase) {
+        let user = User::new(
+            Uuid::new_v4(),
+            Email::new("ana@example.com".to_string()).unwrap(),
+            "hash".to_string(),
+            "Ana".to_string(),
+        );
+        let verifications = Arc::new(MockVerificationRepository::default());
+        verifications
+            .tokens
+            .lock()
+            .unwrap()
+            .push(EmailVerificationToken::new(
+                user.id,
+                hash_opaque_token("token-valido"),
+                ttl,
+            ));
+
+        let user_id = user.id;
+        let users = Arc::new(MockUserRepository::new(vec![user]));
+        let use_case = VerifyEmailUseCase::new(users.clone(), verifications);
+
+        (user_id, users, use_case)
+    }
+
+    #[tokio::test]
+    async fn test_verify_marks_email_as_verified_once() {
+        let (user_id, users, use_case) = setup(Duration::hours(48));
+
+        let response = use_case.execute("token-valido").await.unwrap();
+        assert!(response.email_verified);
+        assert!(users.get(&user_id).unwrap().is_email_verified());
+
+        let reuse = use_case.execute("token-valido").await;
+        assert!(matches!(reuse, Err(DomainError::ValidationError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_verify_rejects_expired_token() {
+        let (user_id, users, use_case) = setup(Duration::seconds(-1));
+
+        let result = use_case.execute("token-valido").await;
+
+        assert!(matches!(result, Err(DomainError::ValidationError(_))));
+        assert!(!users.get(&user_id).unwrap().is_email_verified());
+    }
+
+    #[tokio::test]
+    async fn test_email_change_token_switches_address() {
+        let user = User::new(
+            Uuid::new_v4(),
+            Email::new("ana@example.com".to_string()).unwrap(),
+            "hash".to_string(),
+            "Ana".to_string(),
+        );
+        let verifications = Arc::new(MockVerificationRepository::default());
+        verifications
+            .tokens
+            .lock()
+            .unwrap()
+            .push(EmailVerificationToken::for_email_change(
+                user.id,
+                hash_opaque_token("token-troca"),
+                "ana.nova@example.com".to_string(),
+                Duration::hours(48),
+            ));
+        let user_id = user.id;
+        let users = Arc::new(MockUserRepository::new(vec![user]));
+        let use_case = VerifyEmailUseCase::new(users.clone(), verifications);
+
+        let response = use_case.execute("token-troca").await.unwrap();
+
+        assert_eq!(response.email, "ana.nova@example.com");
+        assert!(response.email_verified);
+        assert_eq!(
+            users.get(&user_id).unwrap().email.as_str(),
+            "ana.nova@example.com"
+        );
+    }
+}
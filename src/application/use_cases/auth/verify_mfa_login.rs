@@ -0,0 +1,76 @@
+use crate::application::dtos::{AuthResponseDto, ClientInfoDto, UserResponseDto, VerifyMfaDto};
+use crate::application::services::{LoginProtectionService, SessionService, TwoFactorService};
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::UserRepository;
+use crate::domain::value_objects::LoginOutcome;
+use crate::infrastructure::security::validate_mfa_pending_jwt;
+use std::sync::Arc;
+
+/// Caso de uso: Concluir o login com o segundo fator
+pub struct VerifyMfaLoginUseCase {
+    user_repository: Arc<dyn UserRepository>,
+    two_factor_service: TwoFactorService,
+    session_service: SessionService,
+    login_protection: LoginProtectionService,
+}
+
+impl VerifyMfaLoginUseCase {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        two_factor_service: TwoFactorService,
+        session_service: SessionService,
+        login_protection: LoginProtectionService,
+    ) -> Self {
+        Self {
+            user_repository,
+            two_factor_service,
+            session_service,
+            login_protection,
+        }
+    }
+
+    /// Troca o token de login pendente + código pelos tokens da sessão
+    ///
+    /// Códigos errados contam para o mesmo bloqueio das senhas erradas.
+    pub async fn execute(
+        &self,
+        dto: VerifyMfaDto,
+        client: ClientInfoDto,
+    ) -> DomainResult<AuthResponseDto> {
+        let user_id = validate_mfa_pending_jwt(&dto.mfa_token)
+            .and_then(|claims| claims.user_id())
+            .map_err(|_| DomainError::Unauthorized)?;
+
+        let user = self
+            .user_repository
+            .find_by_id(&user_id)
+            .await?
+            .ok_or(DomainError::Unauthorized)?;
+
+        let email = user.email.as_str();
+        self.login_protection
+            .ensure_not_locked(Some(user.id), email, &client)
+            .await?;
+
+        if !self
+            .two_factor_service
+            .verify_code(&user.id, &dto.code)
+            .await?
+        {
+            self.login_protection
+                .record_failure(Some(user.id), email, &client, LoginOutcome::InvalidMfaCode)
+                .await?;
+            return Err(DomainError::Unauthorized);
+        }
+
+        self.login_protection
+            .record(Some(user.id), email, &client, LoginOutcome::Success)
+            .await?;
+        let tokens = self.session_service.start(user.id, client).await?;
+
+        Ok(AuthResponseDto {
+            tokens: Some(tokens),
+            user: UserResponseDto::from_user(&user),
+        })
+    }
+}
@@ -0,0 +1,106 @@
+use crate::application::dtos::{CollaboratorResponseDto, InviteCollaboratorDto};
+use crate::domain::entities::{TaskCollaborator, TaskHistory};
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{
+    CollaboratorRepository, TaskRepository, UserRepository, WorkspaceRepository,
+};
+use crate::domain::services::{TaskAccessPolicy, TaskAction};
+use crate::domain::value_objects::Email;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Convidar colaborador para uma tarefa
+pub struct InviteCollaboratorUseCase {
+    task_repository: Arc<dyn TaskRepository>,
+    collaborator_repository: Arc<dyn CollaboratorRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    workspace_repository: Arc<dyn WorkspaceRepository>,
+    access_policy: TaskAccessPolicy,
+}
+
+impl InviteCollaboratorUseCase {
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        collaborator_repository: Arc<dyn CollaboratorRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        workspace_repository: Arc<dyn WorkspaceRepository>,
+    ) -> Self {
+        Self {
+            task_repository,
+            access_policy: TaskAccessPolicy::new(collaborator_repository.clone()),
+            collaborator_repository,
+            user_repository,
+            workspace_repository,
+        }
+    }
+
+    /// Executa o convite (ou altera o papel de um colaborador existente)
+    pub async fn execute(
+        &self,
+        task_id: Uuid,
+        user_id: Uuid,
+        dto: InviteCollaboratorDto,
+    ) -> DomainResult<CollaboratorResponseDto> {
+        // Buscar tarefa e verificar permissão de compartilhamento
+        let task = self
+            .task_repository
+            .find_by_id(&task_id)
+            .await?
+            .ok_or(DomainError::NotFound("Tarefa não encontrada".to_string()))?;
+
+        self.access_policy
+            .ensure(&task, &user_id, TaskAction::ManageCollaborators)
+            .await?;
+
+        // Buscar usuário convidado
+        let email = Email::new(dto.email)?;
+        let invitee = self
+            .user_repository
+            .find_by_email(&email)
+            .await?
+            .ok_or(DomainError::NotFound("Usuário não encontrado".to_string()))?;
+
+        if task.is_owned_by(&invitee.id) {
+            return Err(DomainError::Conflict(
+                "O dono da tarefa já possui acesso total".to_string(),
+            ));
+        }
+
+        // Só membros do workspace enxergam as tarefas dele
+        if self
+            .workspace_repository
+            .find_membership(&task.workspace_id, &invitee.id)
+            .await?
+            .is_none()
+        {
+            return Err(DomainError::ValidationError(
+                "O convidado precisa ser membro do workspace da tarefa".to_string(),
+            ));
+        }
+
+        // Salvar colaborador
+        let collaborator =
+            TaskCollaborator::new(task_id, invitee.id, dto.role.unwrap_or_default(), user_id);
+        let saved = self.collaborator_repository.upsert(&collaborator).await?;
+
+        // Registrar no histórico
+        let history = TaskHistory::new(
+            task_id,
+            user_id,
+            "collaborator".to_string(),
+            None,
+            Some(format!("{} ({})", invitee.email, saved.role)),
+        );
+        self.task_repository.add_history(&history).await?;
+
+        Ok(CollaboratorResponseDto {
+            task_id: saved.task_id,
+            user_id: saved.user_id,
+            email: invitee.email.into_string(),
+            name: invitee.name,
+            role: saved.role,
+            invited_by: saved.invited_by,
+            created_at: saved.created_at,
+        })
+    }
+}
@@ -0,0 +1,71 @@
+use crate::application::dtos::CollaboratorResponseDto;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{CollaboratorRepository, TaskRepository, UserRepository};
+use crate::domain::services::{TaskAccessPolicy, TaskAction};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Listar colaboradores de uma tarefa
+pub struct ListCollaboratorsUseCase {
+    task_repository: Arc<dyn TaskRepository>,
+    collaborator_repository: Arc<dyn CollaboratorRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    access_policy: TaskAccessPolicy,
+}
+
+impl ListCollaboratorsUseCase {
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        collaborator_repository: Arc<dyn CollaboratorRepository>,
+        user_repository: Arc<dyn UserRepository>,
+    ) -> Self {
+        Self {
+            task_repository,
+            access_policy: TaskAccessPolicy::new(collaborator_repository.clone()),
+            collaborator_repository,
+            user_repository,
+        }
+    }
+
+    /// Executa a listagem de colaboradores
+    pub async fn execute(
+        &self,
+        task_id: Uuid,
+        user_id: Uuid,
+    ) -> DomainResult<Vec<CollaboratorResponseDto>> {
+        let task = self
+            .task_repository
+            .find_by_id(&task_id)
+            .await?
+            .ok_or(DomainError::NotFound("Tarefa não encontrada".to_string()))?;
+
+        self.access_policy
+            .ensure(&task, &user_id, TaskAction::View)
+            .await?;
+
+        let collaborators = self.collaborator_repository.list_by_task(&task_id).await?;
+
+        let mut response = Vec::with_capacity(collaborators.len());
+        for collaborator in collaborators {
+            let Some(user) = self
+                .user_repository
+                .find_by_id(&collaborator.user_id)
+                .await?
+            else {
+                continue;
+            };
+
+            response.push(CollaboratorResponseDto {
+                task_id: collaborator.task_id,
+                user_id: collaborator.user_id,
+                email: user.email.into_string(),
+                name: user.name,
+                role: collaborator.role,
+                invited_by: collaborator.invited_by,
+                created_at: collaborator.created_at,
+            });
+        }
+
+        Ok(response)
+    }
+}
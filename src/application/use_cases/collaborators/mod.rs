@@ -0,0 +1,7 @@
+pub mod invite_collaborator;
+pub mod list_collaborators;
+pub mod revoke_collaborator;
+
+pub use invite_collaborator::InviteCollaboratorUseCase;
+pub use list_collaborators::ListCollaboratorsUseCase;
+pub use revoke_collaborator::RevokeCollaboratorUseCase;
@@ -0,0 +1,77 @@
+use crate::domain::entities::TaskHistory;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{CollaboratorRepository, TaskRepository, UserRepository};
+use crate::domain::services::{TaskAccessPolicy, TaskAction};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Revogar acesso de um colaborador
+pub struct RevokeCollaboratorUseCase {
+    task_repository: Arc<dyn TaskRepository>,
+    collaborator_repository: Arc<dyn CollaboratorRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    access_policy: TaskAccessPolicy,
+}
+
+impl RevokeCollaboratorUseCase {
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        collaborator_repository: Arc<dyn CollaboratorRepository>,
+        user_repository: Arc<dyn UserRepository>,
+    ) -> Self {
+        Self {
+            task_repository,
+            access_policy: TaskAccessPolicy::new(collaborator_repository.clone()),
+            collaborator_repository,
+            user_repository,
+        }
+    }
+
+    /// Executa a revogação (o próprio colaborador pode sair da tarefa)
+    pub async fn execute(
+        &self,
+        task_id: Uuid,
+        user_id: Uuid,
+        collaborator_id: Uuid,
+    ) -> DomainResult<()> {
+        let task = self
+            .task_repository
+            .find_by_id(&task_id)
+            .await?
+            .ok_or(DomainError::NotFound("Tarefa não encontrada".to_string()))?;
+
+        if user_id != collaborator_id {
+            self.access_policy
+                .ensure(&task, &user_id, TaskAction::ManageCollaborators)
+                .await?;
+        }
+
+        let collaborator = self
+            .collaborator_repository
+            .find(&task_id, &collaborator_id)
+            .await?
+            .ok_or(DomainError::NotFound(
+                "Colaborador não encontrado".to_string(),
+            ))?;
+
+        self.collaborator_repository
+            .remove(&task_id, &collaborator_id)
+            .await?;
+
+        // Registrar no histórico
+        let collaborator_label = match self.user_repository.find_by_id(&collaborator_id).await? {
+            Some(user) => format!("{} ({})", user.email, collaborator.role),
+            None => format!("{} ({})", collaborator_id, collaborator.role),
+        };
+
+        let history = TaskHistory::new(
+            task_id,
+            user_id,
+            "collaborator".to_string(),
+            Some(collaborator_label),
+            None,
+        );
+
+        self.task_repository.add_history(&history).await
+    }
+}
@@ -0,0 +1,63 @@
+use crate::application::dtos::{CommentResponseDto, CreateCommentDto};
+use crate::domain::entities::{Comment, TaskHistory};
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{CollaboratorRepository, CommentRepository, TaskRepository};
+use crate::domain::services::{TaskAccessPolicy, TaskAction};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Comentar em uma tarefa
+pub struct CreateCommentUseCase {
+    task_repository: Arc<dyn TaskRepository>,
+    comment_repository: Arc<dyn CommentRepository>,
+    access_policy: TaskAccessPolicy,
+}
+
+impl CreateCommentUseCase {
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        collaborator_repository: Arc<dyn CollaboratorRepository>,
+        comment_repository: Arc<dyn CommentRepository>,
+    ) -> Self {
+        Self {
+            task_repository,
+            comment_repository,
+            access_policy: TaskAccessPolicy::new(collaborator_repository),
+        }
+    }
+
+    /// Executa a criação do comentário
+    pub async fn execute(
+        &self,
+        task_id: Uuid,
+        user_id: Uuid,
+        dto: CreateCommentDto,
+    ) -> DomainResult<CommentResponseDto> {
+        // Buscar tarefa e verificar acesso (dono ou colaborador)
+        let task = self
+            .task_repository
+            .find_by_id(&task_id)
+            .await?
+            .ok_or(DomainError::NotFound("Tarefa não encontrada".to_string()))?;
+
+        self.access_policy
+            .ensure(&task, &user_id, TaskAction::View)
+            .await?;
+
+        // Criar e salvar comentário
+        let comment = Comment::new(Uuid::new_v4(), task_id, user_id, dto.body)?;
+        let saved = self.comment_repository.create(&comment).await?;
+
+        // Registrar no histórico
+        let history = TaskHistory::new(
+            task_id,
+            user_id,
+            "comment".to_string(),
+            None,
+            Some(saved.excerpt()),
+        );
+        self.task_repository.add_history(&history).await?;
+
+        Ok(saved.into())
+    }
+}
@@ -0,0 +1,73 @@
+use crate::domain::entities::TaskHistory;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{CollaboratorRepository, CommentRepository, TaskRepository};
+use crate::domain::services::{TaskAccessPolicy, TaskAction};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Deletar comentário (autor ou dono da tarefa)
+pub struct DeleteCommentUseCase {
+    task_repository: Arc<dyn TaskRepository>,
+    comment_repository: Arc<dyn CommentRepository>,
+    access_policy: TaskAccessPolicy,
+}
+
+impl DeleteCommentUseCase {
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        collaborator_repository: Arc<dyn CollaboratorRepository>,
+        comment_repository: Arc<dyn CommentRepository>,
+    ) -> Self {
+        Self {
+            task_repository,
+            comment_repository,
+            access_policy: TaskAccessPolicy::new(collaborator_repository),
+        }
+    }
+
+    /// Executa a deleção do comentário
+    pub async fn execute(
+        &self,
+        task_id: Uuid,
+        comment_id: Uuid,
+        user_id: Uuid,
+    ) -> DomainResult<()> {
+        let task = self
+            .task_repository
+            .find_by_id(&task_id)
+            .await?
+            .ok_or(DomainError::NotFound("Tarefa não encontrada".to_string()))?;
+
+        let role = self
+            .access_policy
+            .ensure(&task, &user_id, TaskAction::View)
+            .await?;
+
+        let comment = self
+            .comment_repository
+            .find_by_id(&comment_id)
+            .await?
+            .filter(|comment| comment.task_id == task_id)
+            .ok_or(DomainError::NotFound(
+                "Comentário não encontrado".to_string(),
+            ))?;
+
+        // O autor ou quem pode deletar a tarefa pode remover o comentário
+        if !comment.is_authored_by(&user_id) && !TaskAction::Delete.is_allowed_for(role) {
+            return Err(DomainError::Unauthorized);
+        }
+
+        self.comment_repository.delete(&comment_id).await?;
+
+        // Registrar no histórico
+        let history = TaskHistory::new(
+            task_id,
+            user_id,
+            "comment".to_string(),
+            Some(comment.excerpt()),
+            None,
+        );
+
+        self.task_repository.add_history(&history).await
+    }
+}
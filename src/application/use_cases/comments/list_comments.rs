@@ -0,0 +1,48 @@
+use crate::application::dtos::CommentResponseDto;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{CollaboratorRepository, CommentRepository, TaskRepository};
+use crate::domain::services::{TaskAccessPolicy, TaskAction};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Listar comentários de uma tarefa
+pub struct ListCommentsUseCase {
+    task_repository: Arc<dyn TaskRepository>,
+    comment_repository: Arc<dyn CommentRepository>,
+    access_policy: TaskAccessPolicy,
+}
+
+impl ListCommentsUseCase {
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        collaborator_repository: Arc<dyn CollaboratorRepository>,
+        comment_repository: Arc<dyn CommentRepository>,
+    ) -> Self {
+        Self {
+            task_repository,
+            comment_repository,
+            access_policy: TaskAccessPolicy::new(collaborator_repository),
+        }
+    }
+
+    /// Executa a listagem de comentários
+    pub async fn execute(
+        &self,
+        task_id: Uuid,
+        user_id: Uuid,
+    ) -> DomainResult<Vec<CommentResponseDto>> {
+        let task = self
+            .task_repository
+            .find_by_id(&task_id)
+            .await?
+            .ok_or(DomainError::NotFound("Tarefa não encontrada".to_string()))?;
+
+        self.access_policy
+            .ensure(&task, &user_id, TaskAction::View)
+            .await?;
+
+        let comments = self.comment_repository.list_by_task(&task_id).await?;
+
+        Ok(comments.into_iter().map(Into::into).collect())
+    }
+}
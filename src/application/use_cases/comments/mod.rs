@@ -0,0 +1,9 @@
+pub mod create_comment;
+pub mod delete_comment;
+pub mod list_comments;
+pub mod update_comment;
+
+pub use create_comment::CreateCommentUseCase;
+pub use delete_comment::DeleteCommentUseCase;
+pub use list_comments::ListCommentsUseCase;
+pub use update_comment::UpdateCommentUseCase;
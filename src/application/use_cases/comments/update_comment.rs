@@ -0,0 +1,65 @@
+use crate::application::dtos::{CommentResponseDto, UpdateCommentDto};
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{CollaboratorRepository, CommentRepository, TaskRepository};
+use crate::domain::services::{TaskAccessPolicy, TaskAction};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Editar comentário (apenas o autor)
+pub struct UpdateCommentUseCase {
+    task_repository: Arc<dyn TaskRepository>,
+    comment_repository: Arc<dyn CommentRepository>,
+    access_policy: TaskAccessPolicy,
+}
+
+impl UpdateCommentUseCase {
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        collaborator_repository: Arc<dyn CollaboratorRepository>,
+        comment_repository: Arc<dyn CommentRepository>,
+    ) -> Self {
+        Self {
+            task_repository,
+            comment_repository,
+            access_policy: TaskAccessPolicy::new(collaborator_repository),
+        }
+    }
+
+    /// Executa a edição do comentário
+    pub async fn execute(
+        &self,
+        task_id: Uuid,
+        comment_id: Uuid,
+        user_id: Uuid,
+        dto: UpdateCommentDto,
+    ) -> DomainResult<CommentResponseDto> {
+        let task = self
+            .task_repository
+            .find_by_id(&task_id)
+            .await?
+            .ok_or(DomainError::NotFound("Tarefa não encontrada".to_string()))?;
+
+        // O autor precisa continuar com acesso à tarefa
+        self.access_policy
+            .ensure(&task, &user_id, TaskAction::View)
+            .await?;
+
+        let mut comment = self
+            .comment_repository
+            .find_by_id(&comment_id)
+            .await?
+            .filter(|comment| comment.task_id == task_id)
+            .ok_or(DomainError::NotFound(
+                "Comentário não encontrado".to_string(),
+            ))?;
+
+        if !comment.is_authored_by(&user_id) {
+            return Err(DomainError::Unauthorized);
+        }
+
+        comment.update_body(dto.body)?;
+        let updated = self.comment_repository.update(&comment).await?;
+
+        Ok(updated.into())
+    }
+}
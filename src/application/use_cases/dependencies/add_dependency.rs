@@ -0,0 +1,76 @@
+use crate::application::dtos::{AddDependencyDto, DependencyResponseDto};
+use crate::domain::entities::{TaskDependency, TaskHistory};
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{CollaboratorRepository, DependencyRepository, TaskRepository};
+use crate::domain::services::{TaskAccessPolicy, TaskAction};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Marcar uma tarefa como bloqueada por outra
+pub struct AddDependencyUseCase {
+    task_repository: Arc<dyn TaskRepository>,
+    dependency_repository: Arc<dyn DependencyRepository>,
+    access_policy: TaskAccessPolicy,
+}
+
+impl AddDependencyUseCase {
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        collaborator_repository: Arc<dyn CollaboratorRepository>,
+        dependency_repository: Arc<dyn DependencyRepository>,
+    ) -> Self {
+        Self {
+            task_repository,
+            access_policy: TaskAccessPolicy::new(collaborator_repository),
+            dependency_repository,
+        }
+    }
+
+    /// Executa a criação da dependência
+    pub async fn execute(
+        &self,
+        task_id: Uuid,
+        user_id: Uuid,
+        dto: AddDependencyDto,
+    ) -> DomainResult<DependencyResponseDto> {
+        // Buscar tarefa bloqueada e verificar permissão de edição
+        let task = self
+            .task_repository
+            .find_by_id(&task_id)
+            .await?
+            .ok_or(DomainError::NotFound("Tarefa não encontrada".to_string()))?;
+
+        self.access_policy
+            .ensure(&task, &user_id, TaskAction::Edit)
+            .await?;
+
+        // Buscar tarefa bloqueadora (basta o usuário conseguir visualizá-la)
+        let blocker = self
+            .task_repository
+            .find_by_id(&dto.blocked_by_id)
+            .await?
+            .ok_or(DomainError::NotFound(
+                "Tarefa bloqueadora não encontrada".to_string(),
+            ))?;
+
+        self.access_policy
+            .ensure(&blocker, &user_id, TaskAction::View)
+            .await?;
+
+        // Validar e salvar dependência (duplicatas e ciclos são recusados pelo repositório)
+        let dependency = TaskDependency::new(task_id, blocker.id)?;
+        self.dependency_repository.add(&dependency).await?;
+
+        // Registrar no histórico
+        let history = TaskHistory::new(
+            task_id,
+            user_id,
+            "dependency".to_string(),
+            None,
+            Some(blocker.title),
+        );
+        self.task_repository.add_history(&history).await?;
+
+        Ok(dependency.into())
+    }
+}
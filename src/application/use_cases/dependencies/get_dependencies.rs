@@ -0,0 +1,77 @@
+use crate::application::dtos::{TaskDependenciesResponseDto, TaskResponseDto};
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{CollaboratorRepository, DependencyRepository, TaskRepository};
+use crate::domain::services::{TaskAccessPolicy, TaskAction};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Obter as dependências de uma tarefa
+pub struct GetDependenciesUseCase {
+    task_repository: Arc<dyn TaskRepository>,
+    dependency_repository: Arc<dyn DependencyRepository>,
+    access_policy: TaskAccessPolicy,
+}
+
+impl GetDependenciesUseCase {
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        collaborator_repository: Arc<dyn CollaboratorRepository>,
+        dependency_repository: Arc<dyn DependencyRepository>,
+    ) -> Self {
+        Self {
+            task_repository,
+            dependency_repository,
+            access_policy: TaskAccessPolicy::new(collaborator_repository),
+        }
+    }
+
+    /// Executa a busca das dependências
+    pub async fn execute(
+        &self,
+        task_id: Uuid,
+        user_id: Uuid,
+    ) -> DomainResult<TaskDependenciesResponseDto> {
+        let task = self
+            .task_repository
+            .find_by_id(&task_id)
+            .await?
+            .ok_or(DomainError::NotFound("Tarefa não encontrada".to_string()))?;
+
+        self.access_policy
+            .ensure(&task, &user_id, TaskAction::View)
+            .await?;
+
+        let blocker_ids = self
+            .dependency_repository
+            .list_blocker_ids(&task_id)
+            .await?;
+        let dependent_ids = self
+            .dependency_repository
+            .list_dependent_ids(&task_id)
+            .await?;
+
+        let blocked_by = self.load_tasks(&blocker_ids).await?;
+        let blocking = self.load_tasks(&dependent_ids).await?;
+        let ready = blocked_by.iter().all(|blocker| blocker.status.is_closed());
+
+        Ok(TaskDependenciesResponseDto {
+            task_id,
+            blocked_by,
+            blocking,
+            ready,
+        })
+    }
+
+    /// Helper para carregar as tarefas relacionadas
+    async fn load_tasks(&self, task_ids: &[Uuid]) -> DomainResult<Vec<TaskResponseDto>> {
+        let mut tasks = Vec::with_capacity(task_ids.len());
+
+        for id in task_ids {
+            if let Some(task) = self.task_repository.find_by_id(id).await? {
+                tasks.push(task.into());
+            }
+        }
+
+        Ok(tasks)
+    }
+}
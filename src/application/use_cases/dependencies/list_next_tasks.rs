@@ -0,0 +1,78 @@
+use crate::application::dtos::NextTaskResponseDto;
+use crate::domain::errors::DomainResult;
+use crate::domain::repositories::{DependencyRepository, TaskRepository};
+use crate::domain::services::DependencyGraph;
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Listar "o que fazer a seguir"
+///
+/// Retorna as tarefas em aberto do usuário ordenadas de forma que as
+/// bloqueadoras venham antes das bloqueadas; entre tarefas independentes
+/// vale a prioridade (maior primeiro) e depois o prazo (mais próximo primeiro).
+pub struct ListNextTasksUseCase {
+    task_repository: Arc<dyn TaskRepository>,
+    dependency_repository: Arc<dyn DependencyRepository>,
+}
+
+impl ListNextTasksUseCase {
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        dependency_repository: Arc<dyn DependencyRepository>,
+    ) -> Self {
+        Self {
+            task_repository,
+            dependency_repository,
+        }
+    }
+
+    /// Executa a listagem
+    pub async fn execute(&self, user_id: Uuid) -> DomainResult<Vec<NextTaskResponseDto>> {
+        let mut tasks = self.task_repository.list_open(&user_id).await?;
+        let dependencies = self
+            .dependency_repository
+            .list_unfinished_for_user(&user_id)
+            .await?;
+
+        // Ordem base: prioridade desc, prazo asc (sem prazo por último)
+        tasks.sort_by(|a, b| {
+            b.priority
+                .as_number()
+                .cmp(&a.priority.as_number())
+                .then_with(|| match (a.due_date, b.due_date) {
+                    (Some(a), Some(b)) => a.cmp(&b),
+                    (Some(_), None) => std::cmp::Ordering::Less,
+                    (None, Some(_)) => std::cmp::Ordering::Greater,
+                    (None, None) => std::cmp::Ordering::Equal,
+                })
+        });
+
+        let ids: Vec<Uuid> = tasks.iter().map(|task| task.id).collect();
+        let ordered = DependencyGraph::new(&dependencies).topological_order(&ids)?;
+
+        let mut blockers: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+        for dependency in &dependencies {
+            blockers
+                .entry(dependency.task_id)
+                .or_default()
+                .push(dependency.blocked_by_id);
+        }
+
+        let mut by_id: HashMap<Uuid, _> = tasks.into_iter().map(|task| (task.id, task)).collect();
+
+        Ok(ordered
+            .into_iter()
+            .filter_map(|id| by_id.remove(&id))
+            .map(|task| {
+                let blocked_by = blockers.remove(&task.id).unwrap_or_default();
+
+                NextTaskResponseDto {
+                    ready: blocked_by.is_empty(),
+                    blocked_by,
+                    task: task.into(),
+                }
+            })
+            .collect())
+    }
+}
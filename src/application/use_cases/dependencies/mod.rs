@@ -0,0 +1,9 @@
+pub mod add_dependency;
+pub mod get_dependencies;
+pub mod list_next_tasks;
+pub mod remove_dependency;
+
+pub use add_dependency::AddDependencyUseCase;
+pub use get_dependencies::GetDependenciesUseCase;
+pub use list_next_tasks::ListNextTasksUseCase;
+pub use remove_dependency::RemoveDependencyUseCase;
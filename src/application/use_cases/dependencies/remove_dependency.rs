@@ -0,0 +1,65 @@
+use crate::domain::entities::TaskHistory;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{CollaboratorRepository, DependencyRepository, TaskRepository};
+use crate::domain::services::{TaskAccessPolicy, TaskAction};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Remover uma dependência entre tarefas
+pub struct RemoveDependencyUseCase {
+    task_repository: Arc<dyn TaskRepository>,
+    dependency_repository: Arc<dyn DependencyRepository>,
+    access_policy: TaskAccessPolicy,
+}
+
+impl RemoveDependencyUseCase {
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        collaborator_repository: Arc<dyn CollaboratorRepository>,
+        dependency_repository: Arc<dyn DependencyRepository>,
+    ) -> Self {
+        Self {
+            task_repository,
+            dependency_repository,
+            access_policy: TaskAccessPolicy::new(collaborator_repository),
+        }
+    }
+
+    /// Executa a remoção da dependência
+    pub async fn execute(
+        &self,
+        task_id: Uuid,
+        user_id: Uuid,
+        blocked_by_id: Uuid,
+    ) -> DomainResult<()> {
+        let task = self
+            .task_repository
+            .find_by_id(&task_id)
+            .await?
+            .ok_or(DomainError::NotFound("Tarefa não encontrada".to_string()))?;
+
+        self.access_policy
+            .ensure(&task, &user_id, TaskAction::Edit)
+            .await?;
+
+        self.dependency_repository
+            .remove(&task_id, &blocked_by_id)
+            .await?;
+
+        // Registrar no histórico
+        let blocker_label = match self.task_repository.find_by_id(&blocked_by_id).await? {
+            Some(blocker) => blocker.title,
+            None => blocked_by_id.to_string(),
+        };
+
+        let history = TaskHistory::new(
+            task_id,
+            user_id,
+            "dependency".to_string(),
+            Some(blocker_label),
+            None,
+        );
+
+        self.task_repository.add_history(&history).await
+    }
+}
@@ -1,5 +1,27 @@
+pub mod admin;
+pub mod attachments;
 pub mod auth;
+pub mod collaborators;
+pub mod comments;
+pub mod dependencies;
+pub mod privacy;
+pub mod projects;
 pub mod tasks;
+pub mod users;
+pub mod webhooks;
+pub mod workflows;
+pub mod workspaces;
 
+pub use admin::*;
+pub use attachments::*;
 pub use auth::*;
+pub use collaborators::*;
+pub use comments::*;
+pub use dependencies::*;
+pub use privacy::*;
+pub use projects::*;
 pub use tasks::*;
+pub use users::*;
+pub use webhooks::*;
+pub use workflows::*;
+pub use workspaces::*;
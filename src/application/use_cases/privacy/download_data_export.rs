@@ -0,0 +1,41 @@
+use crate::domain::entities::DataRequest;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{AttachmentReader, AttachmentStorage, DataRequestRepository};
+use crate::domain::value_objects::DataRequestKind;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Baixar o arquivo da exportação de dados mais recente
+pub struct DownloadDataExportUseCase {
+    data_request_repository: Arc<dyn DataRequestRepository>,
+    storage: Arc<dyn AttachmentStorage>,
+}
+
+impl DownloadDataExportUseCase {
+    pub fn new(
+        data_request_repository: Arc<dyn DataRequestRepository>,
+        storage: Arc<dyn AttachmentStorage>,
+    ) -> Self {
+        Self {
+            data_request_repository,
+            storage,
+        }
+    }
+
+    /// Executa o download, retornando a solicitação e o leitor do arquivo
+    pub async fn execute(&self, user_id: Uuid) -> DomainResult<(DataRequest, AttachmentReader)> {
+        let request = self
+            .data_request_repository
+            .find_latest(&user_id, DataRequestKind::Export)
+            .await?
+            .filter(|request| request.is_downloadable())
+            .ok_or(DomainError::NotFound(
+                "Nenhuma exportação disponível para download".to_string(),
+            ))?;
+
+        let key = request.storage_key.as_deref().unwrap_or_default();
+        let reader = self.storage.get(key).await?;
+
+        Ok((request, reader))
+    }
+}
@@ -0,0 +1,32 @@
+use crate::application::dtos::DataRequestResponseDto;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::DataRequestRepository;
+use crate::domain::value_objects::DataRequestKind;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Consultar a exportação de dados mais recente do usuário
+pub struct GetDataExportUseCase {
+    data_request_repository: Arc<dyn DataRequestRepository>,
+}
+
+impl GetDataExportUseCase {
+    pub fn new(data_request_repository: Arc<dyn DataRequestRepository>) -> Self {
+        Self {
+            data_request_repository,
+        }
+    }
+
+    /// Executa a consulta
+    pub async fn execute(&self, user_id: Uuid) -> DomainResult<DataRequestResponseDto> {
+        let request = self
+            .data_request_repository
+            .find_latest(&user_id, DataRequestKind::Export)
+            .await?
+            .ok_or(DomainError::NotFound(
+                "Nenhuma exportação solicitada".to_string(),
+            ))?;
+
+        Ok(request.into())
+    }
+}
@@ -0,0 +1,36 @@
+use crate::application::dtos::DataRequestResponseDto;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::DataRequestRepository;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Consultar a situação de uma solicitação de dados
+pub struct GetDataRequestUseCase {
+    data_request_repository: Arc<dyn DataRequestRepository>,
+}
+
+impl GetDataRequestUseCase {
+    pub fn new(data_request_repository: Arc<dyn DataRequestRepository>) -> Self {
+        Self {
+            data_request_repository,
+        }
+    }
+
+    /// Executa a consulta (apenas solicitações do próprio usuário)
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        request_id: Uuid,
+    ) -> DomainResult<DataRequestResponseDto> {
+        let request = self
+            .data_request_repository
+            .find_by_id(&request_id)
+            .await?
+            .filter(|request| request.user_id == user_id)
+            .ok_or(DomainError::NotFound(
+                "Solicitação não encontrada".to_string(),
+            ))?;
+
+        Ok(request.into())
+    }
+}
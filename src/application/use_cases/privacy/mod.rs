@@ -0,0 +1,11 @@
+pub mod download_data_export;
+pub mod get_data_export;
+pub mod get_data_request;
+pub mod process_data_requests;
+pub mod request_data_export;
+
+pub use download_data_export::DownloadDataExportUseCase;
+pub use get_data_export::GetDataExportUseCase;
+pub use get_data_request::GetDataRequestUseCase;
+pub use process_data_requests::ProcessDataRequestsUseCase;
+pub use request_data_export::RequestDataExportUseCase;
@@ -0,0 +1,430 @@
+use crate::application::dtos::PersonalDataExportDto;
+use crate::domain::entities::DataRequest;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{
+    AttachmentStorage, DataRequestRepository, PersonalDataRepository,
+};
+use crate::domain::value_objects::DataRequestKind;
+use crate::infrastructure::security::TokenRevocationService;
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+
+/// Quantidade máxima de solicitações processadas por execução
+const PROCESS_BATCH_SIZE: usize = 5;
+
+/// Tempo após o qual uma solicitação em processamento é considerada abandonada
+const PROCESSING_LEASE: Duration = Duration::minutes(15);
+
+/// Caso de uso: Processar as solicitações de exportação e eliminação de dados pendentes
+pub struct ProcessDataRequestsUseCase {
+    data_request_repository: Arc<dyn DataRequestRepository>,
+    personal_data_repository: Arc<dyn PersonalDataRepository>,
+    storage: Arc<dyn AttachmentStorage>,
+    token_revocation: Arc<TokenRevocationService>,
+    export_ttl: Duration,
+}
+
+impl ProcessDataRequestsUseCase {
+    pub fn new(
+        data_request_repository: Arc<dyn DataRequestRepository>,
+        personal_data_repository: Arc<dyn PersonalDataRepository>,
+        storage: Arc<dyn AttachmentStorage>,
+        token_revocation: Arc<TokenRevocationService>,
+        export_ttl: Duration,
+    ) -> Self {
+        Self {
+            data_request_repository,
+            personal_data_repository,
+            storage,
+            token_revocation,
+            export_ttl,
+        }
+    }
+
+    /// Executa um lote e retorna quantas solicitações foram processadas
+    ///
+    /// Também remove os arquivos de exportação cujo prazo de retenção terminou.
+    pub async fn execute(&self) -> DomainResult<usize> {
+        self.purge_expired_exports().await?;
+
+        let mut processed = 0;
+        while processed < PROCESS_BATCH_SIZE {
+            let Some(mut request) = self
+                .data_request_repository
+                .claim_next(Utc::now() - PROCESSING_LEASE)
+                .await?
+            else {
+                break;
+            };
+
+            let result = match request.kind {
+                DataRequestKind::Export => self.export(&mut request).await,
+                DataRequestKind::Erasure => self.erase(&mut request).await,
+            };
+
+            let failed = result.is_err();
+            if let Err(e) = result {
+                tracing::warn!(
+                    "Falha ao processar solicitação de dados {}: {}",
+                    request.id,
+                    e
+                );
+                request.record_failure(&e.to_string());
+            }
+
+            self.data_request_repository.update(&request).await?;
+            processed += 1;
+
+            // Nova tentativa apenas no próximo ciclo do worker
+            if failed {
+                break;
+            }
+        }
+
+        Ok(processed)
+    }
+
+    /// Gera o arquivo JSON com os dados pessoais do usuário
+    async fn export(&self, request: &mut DataRequest) -> DomainResult<()> {
+        let data = self
+            .personal_data_repository
+            .export(&request.user_id)
+            .await?;
+        let archive = PersonalDataExportDto {
+            request_id: request.id,
+            generated_at: Utc::now(),
+            data,
+        };
+        let content = serde_json::to_vec_pretty(&archive)
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        let key = format!("exports/{}/{}.json", request.user_id, request.id);
+        self.storage.put(&key, &content).await?;
+
+        request.complete(Some(key), Some(Utc::now() + self.export_ttl));
+        Ok(())
+    }
+
+    /// Anonimiza a conta e apaga os arquivos do usuário
+    async fn erase(&self, request: &mut DataRequest) -> DomainResult<()> {
+        let storage_keys = self
+            .personal_data_repository
+            .erase(&request.user_id)
+            .await?;
+
+        // Os registros já foram removidos; falhas aqui deixam apenas arquivos órfãos
+        for key in storage_keys {
+            if let Err(e) = self.storage.delete(&key).await {
+                tracing::warn!("Falha ao remover anexo {}: {}", key, e);
+            }
+        }
+
+        // Exportações anteriores também contêm dados pessoais
+        for mut export in self
+            .data_request_repository
+            .list_by_user(&request.user_id)
+            .await?
+        {
+            if let Some(key) = export.storage_key.take() {
+                self.storage.delete(&key).await?;
+                export.expire();
+                self.data_request_repository.update(&export).await?;
+            }
+        }
+
+        self.token_revocation.revoke_all(&request.user_id).await?;
+
+        request.complete(None, None);
+        Ok(())
+    }
+
+    /// Remove os arquivos de exportação vencidos
+    async fn purge_expired_exports(&self) -> DomainResult<()> {
+        for mut export in self
+            .data_request_repository
+            .list_expired_exports(Utc::now())
+            .await?
+        {
+            if let Some(key) = export.storage_key.as_deref() {
+                self.storage.delete(key).await?;
+            }
+
+            export.expire();
+            self.data_request_repository.update(&export).await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::User;
+    use crate::domain::entities::data_request::MAX_DATA_REQUEST_ATTEMPTS;
+    use crate::domain::repositories::PersonalData;
+    use crate::domain::value_objects::{DataRequestStatus, Email};
+    use crate::infrastructure::storage::InMemoryAttachmentStorage;
+    use crate::test_support::{MockRevokedTokenRepository, MockUserRepository};
+    use async_trait::async_trait;
+    use chrono::DateTime;
+    use serde_json::json;
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    // Mock repository de solicitações em memória
+    #[derive(Default)]
+    struct MockDataRequestRepository {
+        requests: Mutex<Vec<DataRequest>>,
+    }
+
+    #[async_trait]
+    impl DataRequestRepository for MockDataRequestRepository {
+        async fn create(&self, request: &DataRequest) -> DomainResult<DataRequest> {
+            self.requests.lock().unwrap().push(request.clone());
+            Ok(request.clone())
+        }
+
+        async fn find_by_id(&self, id: &Uuid) -> DomainResult<Option<DataRequest>> {
+            let requests = self.requests.lock().unwrap();
+            Ok(requests.iter().find(|request| &request.id == id).cloned())
+        }
+
+        async fn find_latest(
+            &self,
+            _user_id: &Uuid,
+            _kind: DataRequestKind,
+        ) -> DomainResult<Option<DataRequest>> {
+            unimplemented!()
+        }
+
+        async fn list_by_user(&self, user_id: &Uuid) -> DomainResult<Vec<DataRequest>> {
+            let requests = self.requests.lock().unwrap();
+            Ok(requests
+                .iter()
+                .filter(|request| &request.user_id == user_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn claim_next(
+            &self,
+            _stale_before: DateTime<Utc>,
+        ) -> DomainResult<Option<DataRequest>> {
+            let mut requests = self.requests.lock().unwrap();
+            let next = requests
+                .iter_mut()
+                .find(|request| request.status == DataRequestStatus::Pending);
+
+            Ok(next.map(|request| {
+                request.start();
+                request.clone()
+            }))
+        }
+
+        async fn list_expired_exports(&self, now: DateTime<Utc>) -> DomainResult<Vec<DataRequest>> {
+            let requests = self.requests.lock().unwrap();
+            Ok(requests
+                .iter()
+                .filter(|request| {
+                    request.is_downloadable() && request.expires_at.is_some_and(|at| at <= now)
+                })
+                .cloned()
+                .collect())
+        }
+
+        async fn update(&self, request: &DataRequest) -> DomainResult<()> {
+            let mut requests = self.requests.lock().unwrap();
+            if let Some(stored) = requests.iter_mut().find(|stored| stored.id == request.id) {
+                *stored = request.clone();
+            }
+            Ok(())
+        }
+    }
+
+    // Mock repository de dados pessoais
+    #[derive(Default)]
+    struct MockPersonalDataRepository {
+        erased: Mutex<bool>,
+        fail: bool,
+    }
+
+    #[async_trait]
+    impl PersonalDataRepository for MockPersonalDataRepository {
+        async fn export(&self, user_id: &Uuid) -> DomainResult<PersonalData> {
+            if self.fail {
+                return Err(DomainError::Internal("banco indisponível".to_string()));
+            }
+
+            Ok(PersonalData {
+                profile: json!({ "id": user_id, "name": "Ana" }),
+                tasks: json!([{ "title": "Comprar pão" }]),
+                task_history: json!([]),
+                comments: json!([]),
+                attachments: json!([]),
+                tags: json!([]),
+                collaborations: json!([]),
+                workspaces: json!([]),
+                webhooks: json!([]),
+                sessions: json!([]),
+                login_attempts: json!([]),
+            })
+        }
+
+        async fn erase(&self, _user_id: &Uuid) -> DomainResult<Vec<String>> {
+            *self.erased.lock().unwrap() = true;
+            Ok(vec!["tasks/anexo.pdf".to_string()])
+        }
+    }
+
+    struct Setup {
+        requests: Arc<MockDataRequestRepository>,
+        personal_data: Arc<MockPersonalDataRepository>,
+        storage: Arc<InMemoryAttachmentStorage>,
+        user_id: Uuid,
+        users: Arc<MockUserRepository>,
+        use_case: ProcessDataRequestsUseCase,
+    }
+
+    fn setup(personal_data: MockPersonalDataRepository) -> Setup {
+        let requests = Arc::new(MockDataRequestRepository::default());
+        let personal_data = Arc::new(personal_data);
+        let storage = Arc::new(InMemoryAttachmentStorage::new());
+        let user = User::new(
+            Uuid::new_v4(),
+            Email::new("ana@example.com").unwrap(),
+            "hash".to_string(),
+            "Ana".to_string(),
+        );
+        let user_id = user.id;
+        let users = Arc::new(MockUserRepository::new(vec![user]));
+        let token_revocation = TokenRevocationService::new(
+            Arc::new(MockRevokedTokenRepository::default()),
+            users.clone(),
+            std::time::Duration::from_secs(30),
+        );
+        let use_case = ProcessDataRequestsUseCase::new(
+            requests.clone(),
+            personal_data.clone(),
+            storage.clone(),
+            Arc::new(token_revocation),
+            Duration::days(7),
+        );
+
+        Setup {
+            requests,
+            personal_data,
+            storage,
+            user_id,
+            users,
+            use_case,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_export_writes_archive() {
+        let setup = setup(MockPersonalDataRepository::default());
+        let request = DataRequest::new(Uuid::new_v4(), DataRequestKind::Export);
+        setup.requests.create(&request).await.unwrap();
+
+        let processed = setup.use_case.execute().await.unwrap();
+        let stored = setup
+            .requests
+            .find_by_id(&request.id)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(processed, 1);
+        assert_eq!(stored.status, DataRequestStatus::Completed);
+        assert!(stored.is_downloadable());
+        assert!(
+            setup
+                .storage
+                .contains(stored.storage_key.as_deref().unwrap())
+        );
+        assert!(stored.expires_at.unwrap() > Utc::now() + Duration::days(6));
+    }
+
+    #[tokio::test]
+    async fn test_export_failure_is_retried() {
+        let setup = setup(MockPersonalDataRepository {
+            fail: true,
+            ..Default::default()
+        });
+        let request = DataRequest::new(Uuid::new_v4(), DataRequestKind::Export);
+        setup.requests.create(&request).await.unwrap();
+
+        // Cada falha devolve a solicitação à fila para o próximo ciclo
+        setup.use_case.execute().await.unwrap();
+        let stored = setup
+            .requests
+            .find_by_id(&request.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.status, DataRequestStatus::Pending);
+
+        for _ in 1..MAX_DATA_REQUEST_ATTEMPTS {
+            setup.use_case.execute().await.unwrap();
+        }
+        let stored = setup
+            .requests
+            .find_by_id(&request.id)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(stored.status, DataRequestStatus::Failed);
+        assert_eq!(stored.attempts, MAX_DATA_REQUEST_ATTEMPTS);
+        assert_eq!(
+            stored.error.as_deref(),
+            Some("Erro interno: banco indisponível")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_erasure_removes_files_and_revokes_tokens() {
+        let setup = setup(MockPersonalDataRepository::default());
+        let user_id = setup.user_id;
+
+        let mut export = DataRequest::new(user_id, DataRequestKind::Export);
+        export.start();
+        export.complete(
+            Some("exports/antiga.json".to_string()),
+            Some(Utc::now() + Duration::days(1)),
+        );
+        setup.requests.create(&export).await.unwrap();
+        setup
+            .storage
+            .put("exports/antiga.json", b"{}")
+            .await
+            .unwrap();
+        setup.storage.put("tasks/anexo.pdf", b"pdf").await.unwrap();
+
+        let erasure = DataRequest::new(user_id, DataRequestKind::Erasure);
+        setup.requests.create(&erasure).await.unwrap();
+
+        setup.use_case.execute().await.unwrap();
+
+        let erasure = setup
+            .requests
+            .find_by_id(&erasure.id)
+            .await
+            .unwrap()
+            .unwrap();
+        let export = setup
+            .requests
+            .find_by_id(&export.id)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(erasure.status, DataRequestStatus::Completed);
+        assert!(*setup.personal_data.erased.lock().unwrap());
+        assert_eq!(export.status, DataRequestStatus::Expired);
+        assert!(!setup.storage.contains("exports/antiga.json"));
+        assert!(!setup.storage.contains("tasks/anexo.pdf"));
+        assert_eq!(setup.users.get(&user_id).unwrap().token_version, 1);
+    }
+}
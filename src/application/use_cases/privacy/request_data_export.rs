@@ -0,0 +1,40 @@
+use crate::application::dtos::DataRequestResponseDto;
+use crate::domain::entities::DataRequest;
+use crate::domain::errors::DomainResult;
+use crate::domain::repositories::DataRequestRepository;
+use crate::domain::value_objects::DataRequestKind;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Solicitar a exportação dos dados pessoais (processada em background)
+pub struct RequestDataExportUseCase {
+    data_request_repository: Arc<dyn DataRequestRepository>,
+}
+
+impl RequestDataExportUseCase {
+    pub fn new(data_request_repository: Arc<dyn DataRequestRepository>) -> Self {
+        Self {
+            data_request_repository,
+        }
+    }
+
+    /// Executa a solicitação
+    ///
+    /// Se já houver uma exportação em andamento, ela é retornada em vez de
+    /// enfileirar outra.
+    pub async fn execute(&self, user_id: Uuid) -> DomainResult<DataRequestResponseDto> {
+        if let Some(request) = self
+            .data_request_repository
+            .find_latest(&user_id, DataRequestKind::Export)
+            .await?
+            && request.status.is_active()
+        {
+            return Ok(request.into());
+        }
+
+        let request = DataRequest::new(user_id, DataRequestKind::Export);
+        let created = self.data_request_repository.create(&request).await?;
+
+        Ok(created.into())
+    }
+}
@@ -0,0 +1,39 @@
+use crate::application::dtos::{CreateProjectDto, ProjectResponseDto};
+use crate::application::services::ProjectService;
+use crate::domain::entities::Project;
+use crate::domain::errors::DomainResult;
+use crate::domain::repositories::{ProjectRepository, TaskRepository};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Criar projeto no fim da lista do workspace
+pub struct CreateProjectUseCase {
+    project_repository: Arc<dyn ProjectRepository>,
+    project_service: ProjectService,
+}
+
+impl CreateProjectUseCase {
+    pub fn new(
+        project_repository: Arc<dyn ProjectRepository>,
+        task_repository: Arc<dyn TaskRepository>,
+    ) -> Self {
+        Self {
+            project_service: ProjectService::new(project_repository.clone(), task_repository),
+            project_repository,
+        }
+    }
+
+    /// Executa a criação do projeto
+    pub async fn execute(
+        &self,
+        workspace_id: Uuid,
+        dto: CreateProjectDto,
+    ) -> DomainResult<ProjectResponseDto> {
+        let position = self.project_repository.next_position(&workspace_id).await?;
+        let project = Project::new(workspace_id, &dto.name, dto.color.as_deref(), position)?;
+
+        let saved = self.project_repository.create(&project).await?;
+
+        self.project_service.to_response(saved).await
+    }
+}
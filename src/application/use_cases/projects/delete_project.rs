@@ -0,0 +1,95 @@
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::ProjectRepository;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Deletar projeto (as tarefas ficam sem projeto)
+pub struct DeleteProjectUseCase {
+    project_repository: Arc<dyn ProjectRepository>,
+}
+
+impl DeleteProjectUseCase {
+    pub fn new(project_repository: Arc<dyn ProjectRepository>) -> Self {
+        Self { project_repository }
+    }
+
+    /// Executa a remoção do projeto
+    pub async fn execute(&self, project_id: Uuid, workspace_id: Uuid) -> DomainResult<()> {
+        self.project_repository
+            .find_by_id(&project_id)
+            .await?
+            .filter(|project| project.workspace_id == workspace_id)
+            .ok_or(DomainError::NotFound("Projeto não encontrado".to_string()))?;
+
+        self.project_repository.delete(&project_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::Project;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    // Mock repository de projetos em memória
+    #[derive(Default)]
+    struct MockProjectRepository {
+        projects: Mutex<Vec<Project>>,
+    }
+
+    #[async_trait]
+    impl ProjectRepository for MockProjectRepository {
+        async fn create(&self, project: &Project) -> DomainResult<Project> {
+            self.projects.lock().unwrap().push(project.clone());
+            Ok(project.clone())
+        }
+
+        async fn find_by_id(&self, id: &Uuid) -> DomainResult<Option<Project>> {
+            let projects = self.projects.lock().unwrap();
+            Ok(projects.iter().find(|p| &p.id == id).cloned())
+        }
+
+        async fn list_by_workspace(
+            &self,
+            _workspace_id: &Uuid,
+            _include_archived: bool,
+        ) -> DomainResult<Vec<Project>> {
+            unimplemented!()
+        }
+
+        async fn next_position(&self, _workspace_id: &Uuid) -> DomainResult<i32> {
+            unimplemented!()
+        }
+
+        async fn update(&self, _project: &Project) -> DomainResult<Project> {
+            unimplemented!()
+        }
+
+        async fn assign_workflow(&self, _project: &Project) -> DomainResult<Project> {
+            unimplemented!()
+        }
+
+        async fn delete(&self, id: &Uuid) -> DomainResult<()> {
+            self.projects.lock().unwrap().retain(|p| &p.id != id);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_delete_project_only_within_workspace() {
+        let repo = Arc::new(MockProjectRepository::default());
+        let workspace_id = Uuid::new_v4();
+        let project = Project::new(workspace_id, "Casa", None, 0).unwrap();
+        repo.create(&project).await.unwrap();
+
+        let use_case = DeleteProjectUseCase::new(repo.clone());
+
+        let foreign = use_case.execute(project.id, Uuid::new_v4()).await;
+        assert!(matches!(foreign, Err(DomainError::NotFound(_))));
+        assert!(repo.find_by_id(&project.id).await.unwrap().is_some());
+
+        use_case.execute(project.id, workspace_id).await.unwrap();
+        assert!(repo.find_by_id(&project.id).await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,36 @@
+use crate::application::dtos::ProjectResponseDto;
+use crate::application::services::ProjectService;
+use crate::domain::errors::DomainResult;
+use crate::domain::repositories::{ProjectRepository, TaskRepository};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Obter projeto com a contagem de tarefas por status
+pub struct GetProjectUseCase {
+    project_service: ProjectService,
+}
+
+impl GetProjectUseCase {
+    pub fn new(
+        project_repository: Arc<dyn ProjectRepository>,
+        task_repository: Arc<dyn TaskRepository>,
+    ) -> Self {
+        Self {
+            project_service: ProjectService::new(project_repository, task_repository),
+        }
+    }
+
+    /// Executa a busca do projeto
+    pub async fn execute(
+        &self,
+        project_id: Uuid,
+        workspace_id: Uuid,
+    ) -> DomainResult<ProjectResponseDto> {
+        let project = self
+            .project_service
+            .find_in_workspace(&project_id, &workspace_id)
+            .await?;
+
+        self.project_service.to_response(project).await
+    }
+}
@@ -0,0 +1,43 @@
+use crate::application::dtos::{ProjectFilterDto, ProjectResponseDto};
+use crate::application::services::ProjectService;
+use crate::domain::errors::DomainResult;
+use crate::domain::repositories::{ProjectRepository, TaskRepository};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Listar projetos do workspace na ordem de exibição
+pub struct ListProjectsUseCase {
+    project_repository: Arc<dyn ProjectRepository>,
+    project_service: ProjectService,
+}
+
+impl ListProjectsUseCase {
+    pub fn new(
+        project_repository: Arc<dyn ProjectRepository>,
+        task_repository: Arc<dyn TaskRepository>,
+    ) -> Self {
+        Self {
+            project_service: ProjectService::new(project_repository.clone(), task_repository),
+            project_repository,
+        }
+    }
+
+    /// Executa a listagem de projetos
+    pub async fn execute(
+        &self,
+        workspace_id: Uuid,
+        filter: ProjectFilterDto,
+    ) -> DomainResult<Vec<ProjectResponseDto>> {
+        let projects = self
+            .project_repository
+            .list_by_workspace(&workspace_id, filter.include_archived.unwrap_or(false))
+            .await?;
+
+        let mut responses = Vec::with_capacity(projects.len());
+        for project in projects {
+            responses.push(self.project_service.to_response(project).await?);
+        }
+
+        Ok(responses)
+    }
+}
@@ -0,0 +1,11 @@
+pub mod create_project;
+pub mod delete_project;
+pub mod get_project;
+pub mod list_projects;
+pub mod update_project;
+
+pub use create_project::CreateProjectUseCase;
+pub use delete_project::DeleteProjectUseCase;
+pub use get_project::GetProjectUseCase;
+pub use list_projects::ListProjectsUseCase;
+pub use update_project::UpdateProjectUseCase;
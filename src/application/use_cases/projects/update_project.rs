@@ -0,0 +1,81 @@
+use crate::application::dtos::{ProjectResponseDto, UpdateProjectDto};
+use crate::application::services::{ProjectService, WorkflowService};
+use crate::domain::errors::DomainResult;
+use crate::domain::repositories::{ProjectRepository, TaskRepository, WorkflowRepository};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Atualizar projeto (renomear, recolorir, reordenar, arquivar ou trocar o workflow)
+pub struct UpdateProjectUseCase {
+    project_repository: Arc<dyn ProjectRepository>,
+    project_service: ProjectService,
+    workflow_service: WorkflowService,
+}
+
+impl UpdateProjectUseCase {
+    pub fn new(
+        project_repository: Arc<dyn ProjectRepository>,
+        task_repository: Arc<dyn TaskRepository>,
+        workflow_repository: Arc<dyn WorkflowRepository>,
+    ) -> Self {
+        Self {
+            project_service: ProjectService::new(project_repository.clone(), task_repository),
+            project_repository,
+            workflow_service: WorkflowService::new(workflow_repository),
+        }
+    }
+
+    /// Executa a atualização do projeto
+    pub async fn execute(
+        &self,
+        project_id: Uuid,
+        workspace_id: Uuid,
+        dto: UpdateProjectDto,
+    ) -> DomainResult<ProjectResponseDto> {
+        let mut project = self
+            .project_service
+            .find_in_workspace(&project_id, &workspace_id)
+            .await?;
+
+        // Resolver o workflow antes de alterar o projeto
+        let workflow = match dto.workflow_id {
+            Some(Some(workflow_id)) => Some(Some(
+                self.workflow_service
+                    .find_in_workspace(&workflow_id, &workspace_id)
+                    .await?,
+            )),
+            Some(None) => Some(None),
+            None => None,
+        };
+
+        if let Some(name) = dto.name {
+            project.rename(&name)?;
+        }
+
+        if let Some(color) = dto.color {
+            project.set_color(Some(color.trim()).filter(|color| !color.is_empty()))?;
+        }
+
+        if let Some(position) = dto.position {
+            project.move_to(position);
+        }
+
+        match dto.archived {
+            Some(true) => project.archive(),
+            Some(false) => project.unarchive(),
+            None => {}
+        }
+
+        let mut updated = self.project_repository.update(&project).await?;
+
+        // Trocar o workflow recoloca as tarefas do projeto nos estados do novo workflow
+        if let Some(workflow) = workflow
+            && workflow.as_ref().map(|w| w.id) != updated.workflow_id
+        {
+            updated.set_workflow(workflow.as_ref())?;
+            updated = self.project_repository.assign_workflow(&updated).await?;
+        }
+
+        self.project_service.to_response(updated).await
+    }
+}
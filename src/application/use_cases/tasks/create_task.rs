@@ -1,37 +1,132 @@
 use crate::application::dtos::{CreateTaskDto, TaskResponseDto};
+use crate::application::services::WorkflowService;
 use crate::domain::entities::{Task, TaskHistory};
 use crate::domain::errors::{DomainError, DomainResult};
-use crate::domain::repositories::TaskRepository;
-use crate::domain::value_objects::TaskPriority;
+use crate::domain::events::{EventPublisher, TaskEvent};
+use crate::domain::repositories::{
+    CollaboratorRepository, ProjectRepository, TaskRepository, UserRepository, WorkflowRepository,
+};
+use crate::domain::services::{TaskAccessPolicy, TaskAction};
+use crate::domain::value_objects::{
+    EmailVerificationPolicy, Rank, SubtaskCompletionPolicy, TaskPriority,
+};
 use std::sync::Arc;
 use uuid::Uuid;
 
 /// Caso de uso: Criar nova tarefa
 pub struct CreateTaskUseCase {
     task_repository: Arc<dyn TaskRepository>,
+    access_policy: TaskAccessPolicy,
+    event_publisher: Arc<dyn EventPublisher>,
+    user_repository: Arc<dyn UserRepository>,
+    verification_policy: EmailVerificationPolicy,
+    project_repository: Arc<dyn ProjectRepository>,
+    workflow_service: WorkflowService,
+    subtask_policy: SubtaskCompletionPolicy,
 }
 
 impl CreateTaskUseCase {
-    pub fn new(task_repository: Arc<dyn TaskRepository>) -> Self {
-        Self { task_repository }
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        collaborator_repository: Arc<dyn CollaboratorRepository>,
+        event_publisher: Arc<dyn EventPublisher>,
+        user_repository: Arc<dyn UserRepository>,
+        verification_policy: EmailVerificationPolicy,
+        project_repository: Arc<dyn ProjectRepository>,
+        workflow_repository: Arc<dyn WorkflowRepository>,
+        subtask_policy: SubtaskCompletionPolicy,
+    ) -> Self {
+        Self {
+            task_repository,
+            access_policy: TaskAccessPolicy::new(collaborator_repository),
+            event_publisher,
+            user_repository,
+            verification_policy,
+            project_repository,
+            workflow_service: WorkflowService::new(workflow_repository),
+            subtask_policy,
+        }
     }
 
     /// Executa a criação de tarefa
     pub async fn execute(
         &self,
         user_id: Uuid,
+        workspace_id: Uuid,
         dto: CreateTaskDto,
     ) -> DomainResult<TaskResponseDto> {
+        // Exigir email confirmado, se configurado
+        if self.verification_policy.blocks_task_creation() {
+            let verified = self
+                .user_repository
+                .find_by_id(&user_id)
+                .await?
+                .is_some_and(|user| user.is_email_verified());
+
+            if !verified {
+                return Err(DomainError::Forbidden(
+                    "Confirme seu email antes de criar tarefas".to_string(),
+                ));
+            }
+        }
+
         // Criar entidade de tarefa
-        let task = Task::new(
+        let mut task = Task::new(
             Uuid::new_v4(),
             user_id,
+            workspace_id,
             dto.title.clone(),
             dto.description.clone(),
             dto.priority.unwrap_or(TaskPriority::Medium),
             dto.due_date,
         )?;
 
+        if let Some(tags) = dto.tags {
+            task.update_tags(tags)?;
+        }
+
+        if let Some(recurrence) = dto.recurrence {
+            task.set_recurrence(Some(recurrence.parse()?));
+        }
+
+        // Vincular ao projeto (do mesmo workspace e não arquivado)
+        if let Some(project_id) = dto.project_id {
+            let project = self
+                .project_repository
+                .find_by_id(&project_id)
+                .await?
+                .ok_or(DomainError::NotFound("Projeto não encontrado".to_string()))?;
+
+            task.set_project(Some(&project))?;
+
+            // Entrar no workflow do projeto, se houver
+            let workflow = self.workflow_service.for_project(&project).await?;
+            task.enter_workflow(workflow.as_ref());
+        }
+
+        // Vincular à tarefa pai (o usuário precisa poder editá-la e ela não pode
+        // estar concluída, conforme a política de subtarefas)
+        let parent = match dto.parent_id {
+            Some(parent_id) => {
+                let parent = self.task_repository.find_by_id(&parent_id).await?.ok_or(
+                    DomainError::NotFound("Tarefa pai não encontrada".to_string()),
+                )?;
+
+                self.access_policy
+                    .ensure(&parent, &user_id, TaskAction::Edit)
+                    .await?;
+
+                task.set_parent(&parent, self.subtask_policy)?;
+                Some(parent)
+            }
+            None => None,
+        };
+
+        // Posicionar no fim do quadro
+        let last_rank = self.task_repository.last_rank(&workspace_id).await?;
+        task.rank = Rank::between(last_rank.as_ref(), None)?;
+
         // Salvar no repositório
         let saved_task = self.task_repository.create(&task).await?;
 
@@ -46,41 +141,56 @@ impl CreateTaskUseCase {
 
         self.task_repository.add_history(&history).await?;
 
+        if let Some(parent) = parent {
+            let history = TaskHistory::new(
+                parent.id,
+                user_id,
+                "subtask".to_string(),
+                None,
+                Some(format!("Subtarefa criada: {}", saved_task.title)),
+            );
+
+            self.task_repository.add_history(&history).await?;
+        }
+
+        // Notificar assinantes
+        self.event_publisher
+            .publish(TaskEvent::created(&saved_task, user_id));
+
         // Converter para DTO de resposta
-        Ok(TaskResponseDto {
-            id: saved_task.id,
-            user_id: saved_task.user_id,
-            title: saved_task.title,
-            description: saved_task.description,
-            status: saved_task.status,
-            priority: saved_task.priority,
-            due_date: saved_task.due_date,
-            completed_at: saved_task.completed_at,
-            is_overdue: saved_task.is_overdue(),
-            created_at: saved_task.created_at,
-            updated_at: saved_task.updated_at,
-        })
+        Ok(saved_task.into())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::repositories::{PaginatedResult, Pagination, TaskFilter};
-    use crate::domain::value_objects::TaskStatus;
+    use crate::domain::entities::{Project, TaskCollaborator, User, Workflow};
+    use crate::domain::events::TaskEventKind;
+    use crate::domain::repositories::{DeletedTaskTree, PaginatedResult, Pagination, TaskFilter};
+    use crate::domain::value_objects::{Email, HistoryCursor, TaskStatus};
+    use crate::infrastructure::events::InMemoryEventBus;
+    use crate::test_support::MockUserRepository;
     use async_trait::async_trait;
 
-    // Mock repository para testes
-    struct MockTaskRepository;
+    // Mock repository para testes (busca apenas as tarefas informadas)
+    #[derive(Default)]
+    struct MockTaskRepository {
+        tasks: Vec<Task>,
+    }
 
     #[async_trait]
     impl TaskRepository for MockTaskRepository {
+        fn for_workspace(&self, _workspace_id: Uuid) -> Arc<dyn TaskRepository> {
+            unimplemented!()
+        }
+
         async fn create(&self, task: &Task) -> DomainResult<Task> {
             Ok(task.clone())
         }
 
-        async fn find_by_id(&self, _id: &Uuid) -> DomainResult<Option<Task>> {
-            unimplemented!()
+        async fn find_by_id(&self, id: &Uuid) -> DomainResult<Option<Task>> {
+            Ok(self.tasks.iter().find(|task| &task.id == id).cloned())
         }
 
         async fn list(
@@ -107,45 +217,347 @@ mod tests {
             Ok(vec![])
         }
 
+        async fn list_history_since(
+            &self,
+            _user_id: &Uuid,
+            _after: &HistoryCursor,
+            _limit: i64,
+        ) -> DomainResult<Vec<TaskHistory>> {
+            Ok(vec![])
+        }
+
+        async fn list_open(&self, _user_id: &Uuid) -> DomainResult<Vec<Task>> {
+            Ok(vec![])
+        }
+
+        async fn list_subtasks(&self, _parent_id: &Uuid) -> DomainResult<Vec<Task>> {
+            Ok(vec![])
+        }
+
+        async fn list_descendants(&self, _id: &Uuid) -> DomainResult<Vec<Task>> {
+            Ok(vec![])
+        }
+
+        async fn delete_with_subtasks(
+            &self,
+            _id: &Uuid,
+            _reparent: bool,
+            _descendants: &[Uuid],
+        ) -> DomainResult<DeletedTaskTree> {
+            Ok(DeletedTaskTree::default())
+        }
+
         async fn count_by_status(&self, _user_id: &Uuid, _status: TaskStatus) -> DomainResult<i64> {
             Ok(0)
         }
+
+        async fn last_rank(&self, _workspace_id: &Uuid) -> DomainResult<Option<Rank>> {
+            Ok(None)
+        }
+
+        async fn count_by_project_status(
+            &self,
+            _project_id: &Uuid,
+            _status: TaskStatus,
+        ) -> DomainResult<i64> {
+            Ok(0)
+        }
+    }
+
+    // Mock repository de colaboradores (nenhuma tarefa compartilhada)
+    struct MockCollaboratorRepository;
+
+    #[async_trait]
+    impl CollaboratorRepository for MockCollaboratorRepository {
+        async fn upsert(&self, collaborator: &TaskCollaborator) -> DomainResult<TaskCollaborator> {
+            Ok(collaborator.clone())
+        }
+
+        async fn find(
+            &self,
+            _task_id: &Uuid,
+            _user_id: &Uuid,
+        ) -> DomainResult<Option<TaskCollaborator>> {
+            Ok(None)
+        }
+
+        async fn list_by_task(&self, _task_id: &Uuid) -> DomainResult<Vec<TaskCollaborator>> {
+            Ok(vec![])
+        }
+
+        async fn remove(&self, _task_id: &Uuid, _user_id: &Uuid) -> DomainResult<()> {
+            Ok(())
+        }
+    }
+
+    // Mock repository de projetos (um projeto arquivado por id)
+    struct MockProjectRepository;
+
+    #[async_trait]
+    impl ProjectRepository for MockProjectRepository {
+        async fn create(&self, project: &Project) -> DomainResult<Project> {
+            Ok(project.clone())
+        }
+
+        async fn find_by_id(&self, id: &Uuid) -> DomainResult<Option<Project>> {
+            let mut project = Project::new(Uuid::new_v4(), "Arquivado", None, 0)?;
+            project.id = *id;
+            project.archive();
+            Ok(Some(project))
+        }
+
+        async fn list_by_workspace(
+            &self,
+            _workspace_id: &Uuid,
+            _include_archived: bool,
+        ) -> DomainResult<Vec<Project>> {
+            Ok(vec![])
+        }
+
+        async fn next_position(&self, _workspace_id: &Uuid) -> DomainResult<i32> {
+            Ok(0)
+        }
+
+        async fn update(&self, project: &Project) -> DomainResult<Project> {
+            Ok(project.clone())
+        }
+
+        async fn assign_workflow(&self, project: &Project) -> DomainResult<Project> {
+            Ok(project.clone())
+        }
+
+        async fn delete(&self, _id: &Uuid) -> DomainResult<()> {
+            Ok(())
+        }
+    }
+
+    // Mock repository de workflows (nenhum projeto dos testes usa workflow)
+    struct MockWorkflowRepository;
+
+    #[async_trait]
+    impl WorkflowRepository for MockWorkflowRepository {
+        async fn create(&self, workflow: &Workflow) -> DomainResult<Workflow> {
+            Ok(workflow.clone())
+        }
+
+        async fn find_by_id(&self, _id: &Uuid) -> DomainResult<Option<Workflow>> {
+            Ok(None)
+        }
+
+        async fn find_by_state(&self, _state_id: &Uuid) -> DomainResult<Option<Workflow>> {
+            Ok(None)
+        }
+
+        async fn list_by_workspace(&self, _workspace_id: &Uuid) -> DomainResult<Vec<Workflow>> {
+            Ok(vec![])
+        }
+
+        async fn delete(&self, _id: &Uuid) -> DomainResult<()> {
+            Ok(())
+        }
     }
 
     #[tokio::test]
     async fn test_create_task_success() {
-        let repo = Arc::new(MockTaskRepository);
-        let use_case = CreateTaskUseCase::new(repo);
+        let repo = Arc::new(MockTaskRepository::default());
+        let bus = Arc::new(InMemoryEventBus::default());
+        let mut events = bus.subscribe();
+        let use_case = CreateTaskUseCase::new(
+            repo,
+            Arc::new(MockCollaboratorRepository),
+            bus.clone(),
+            Arc::new(MockUserRepository::default()),
+            EmailVerificationPolicy::Optional,
+            Arc::new(MockProjectRepository),
+            Arc::new(MockWorkflowRepository),
+            SubtaskCompletionPolicy::default(),
+        );
 
         let dto = CreateTaskDto {
             title: "Test Task".to_string(),
             description: Some("Description".to_string()),
             priority: Some(TaskPriority::High),
             due_date: None,
+            tags: Some(vec!["Backend".to_string(), "q3-release".to_string()]),
+            parent_id: None,
+            recurrence: None,
+            project_id: None,
         };
 
-        let result = use_case.execute(Uuid::new_v4(), dto).await;
+        let result = use_case.execute(Uuid::new_v4(), Uuid::new_v4(), dto).await;
         assert!(result.is_ok());
 
         let task = result.unwrap();
         assert_eq!(task.title, "Test Task");
         assert_eq!(task.priority, TaskPriority::High);
+        assert_eq!(task.tags, vec!["backend", "q3-release"]);
+
+        let event = events.try_recv().unwrap();
+        assert_eq!(event.kind, TaskEventKind::TaskCreated);
+        assert_eq!(event.task_id, task.id);
     }
 
     #[tokio::test]
     async fn test_create_task_with_default_priority() {
-        let repo = Arc::new(MockTaskRepository);
-        let use_case = CreateTaskUseCase::new(repo);
+        let repo = Arc::new(MockTaskRepository::default());
+        let use_case = CreateTaskUseCase::new(
+            repo,
+            Arc::new(MockCollaboratorRepository),
+            Arc::new(InMemoryEventBus::default()),
+            Arc::new(MockUserRepository::default()),
+            EmailVerificationPolicy::Optional,
+            Arc::new(MockProjectRepository),
+            Arc::new(MockWorkflowRepository),
+            SubtaskCompletionPolicy::default(),
+        );
 
         let dto = CreateTaskDto {
             title: "Test".to_string(),
             description: None,
             priority: None, // Sem prioridade
             due_date: None,
+            tags: None,
+            parent_id: None,
+            recurrence: None,
+            project_id: None,
         };
 
-        let result = use_case.execute(Uuid::new_v4(), dto).await;
+        let result = use_case.execute(Uuid::new_v4(), Uuid::new_v4(), dto).await;
         assert!(result.is_ok());
         assert_eq!(result.unwrap().priority, TaskPriority::Medium);
     }
+
+    #[tokio::test]
+    async fn test_create_task_requires_verified_email_when_configured() {
+        let use_case = |email_verified| {
+            let mut user = User::new(
+                Uuid::new_v4(),
+                Email::new("ana@example.com".to_string()).unwrap(),
+                "hash".to_string(),
+                "Ana".to_string(),
+            );
+            if email_verified {
+                user.verify_email();
+            }
+            let user_id = user.id;
+
+            let use_case = CreateTaskUseCase::new(
+                Arc::new(MockTaskRepository::default()),
+                Arc::new(MockCollaboratorRepository),
+                Arc::new(InMemoryEventBus::default()),
+                Arc::new(MockUserRepository::new(vec![user])),
+                EmailVerificationPolicy::BlockTaskCreation,
+                Arc::new(MockProjectRepository),
+                Arc::new(MockWorkflowRepository),
+                SubtaskCompletionPolicy::default(),
+            );
+
+            (user_id, use_case)
+        };
+        let dto = || CreateTaskDto {
+            title: "Test".to_string(),
+            description: None,
+            priority: None,
+            due_date: None,
+            tags: None,
+            parent_id: None,
+            recurrence: None,
+            project_id: None,
+        };
+
+        let (user_id, blocked) = use_case(false);
+        let blocked = blocked.execute(user_id, Uuid::new_v4(), dto()).await;
+        assert!(matches!(blocked, Err(DomainError::Forbidden(_))));
+
+        let (user_id, allowed) = use_case(true);
+        let allowed = allowed.execute(user_id, Uuid::new_v4(), dto()).await;
+        assert!(allowed.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_create_task_rejects_foreign_or_archived_project() {
+        let use_case = CreateTaskUseCase::new(
+            Arc::new(MockTaskRepository::default()),
+            Arc::new(MockCollaboratorRepository),
+            Arc::new(InMemoryEventBus::default()),
+            Arc::new(MockUserRepository::default()),
+            EmailVerificationPolicy::Optional,
+            Arc::new(MockProjectRepository),
+            Arc::new(MockWorkflowRepository),
+            SubtaskCompletionPolicy::default(),
+        );
+
+        let dto = CreateTaskDto {
+            title: "Test".to_string(),
+            description: None,
+            priority: None,
+            due_date: None,
+            tags: None,
+            parent_id: None,
+            recurrence: None,
+            project_id: Some(Uuid::new_v4()),
+        };
+
+        let result = use_case.execute(Uuid::new_v4(), Uuid::new_v4(), dto).await;
+        assert!(matches!(result, Err(DomainError::ValidationError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_create_subtask_requires_open_parent() {
+        let user_id = Uuid::new_v4();
+        let workspace_id = Uuid::new_v4();
+        let mut parent = Task::new(
+            Uuid::new_v4(),
+            user_id,
+            workspace_id,
+            "Pai".to_string(),
+            None,
+            TaskPriority::Medium,
+            None,
+        )
+        .unwrap();
+        let policy = SubtaskCompletionPolicy::default();
+        parent
+            .update_status(None, TaskStatus::InProgress, policy)
+            .unwrap();
+        parent
+            .update_status(None, TaskStatus::Completed, policy)
+            .unwrap();
+
+        let use_case = |subtask_policy| {
+            CreateTaskUseCase::new(
+                Arc::new(MockTaskRepository {
+                    tasks: vec![parent.clone()],
+                }),
+                Arc::new(MockCollaboratorRepository),
+                Arc::new(InMemoryEventBus::default()),
+                Arc::new(MockUserRepository::default()),
+                EmailVerificationPolicy::Optional,
+                Arc::new(MockProjectRepository),
+                Arc::new(MockWorkflowRepository),
+                subtask_policy,
+            )
+        };
+        let dto = || CreateTaskDto {
+            title: "Subtarefa".to_string(),
+            description: None,
+            priority: None,
+            due_date: None,
+            tags: None,
+            parent_id: Some(parent.id),
+            recurrence: None,
+            project_id: None,
+        };
+
+        let rejected = use_case(SubtaskCompletionPolicy::RequireClosed)
+            .execute(user_id, workspace_id, dto())
+            .await;
+        assert!(matches!(rejected, Err(DomainError::ValidationError(_))));
+
+        let allowed = use_case(SubtaskCompletionPolicy::AllowOpen)
+            .execute(user_id, workspace_id, dto())
+            .await
+            .unwrap();
+        assert_eq!(allowed.parent_id, Some(parent.id));
+    }
 }
@@ -1,20 +1,41 @@
+use crate::application::dtos::SubtaskDeletionMode;
 use crate::domain::errors::{DomainError, DomainResult};
-use crate::domain::repositories::TaskRepository;
+use crate::domain::events::{EventPublisher, TaskEvent};
+use crate::domain::repositories::{AttachmentStorage, CollaboratorRepository, TaskRepository};
+use crate::domain::services::{TaskAccessPolicy, TaskAction};
 use std::sync::Arc;
 use uuid::Uuid;
 
 /// Caso de uso: Deletar tarefa
 pub struct DeleteTaskUseCase {
     task_repository: Arc<dyn TaskRepository>,
+    attachment_storage: Arc<dyn AttachmentStorage>,
+    access_policy: TaskAccessPolicy,
+    event_publisher: Arc<dyn EventPublisher>,
 }
 
 impl DeleteTaskUseCase {
-    pub fn new(task_repository: Arc<dyn TaskRepository>) -> Self {
-        Self { task_repository }
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        collaborator_repository: Arc<dyn CollaboratorRepository>,
+        attachment_storage: Arc<dyn AttachmentStorage>,
+        event_publisher: Arc<dyn EventPublisher>,
+    ) -> Self {
+        Self {
+            task_repository,
+            attachment_storage,
+            access_policy: TaskAccessPolicy::new(collaborator_repository),
+            event_publisher,
+        }
     }
 
     /// Executa a deleção de tarefa
-    pub async fn execute(&self, task_id: Uuid, user_id: Uuid) -> DomainResult<()> {
+    pub async fn execute(
+        &self,
+        task_id: Uuid,
+        user_id: Uuid,
+        subtasks: SubtaskDeletionMode,
+    ) -> DomainResult<()> {
         // Buscar tarefa para verificar propriedade
         let task = self
             .task_repository
@@ -22,12 +43,50 @@ impl DeleteTaskUseCase {
             .await?
             .ok_or(DomainError::NotFound("Tarefa não encontrada".to_string()))?;
 
-        // Verificar se o usuário é dono
-        if !task.is_owned_by(&user_id) {
-            return Err(DomainError::Unauthorized);
+        // Verificar se o usuário pode deletar (dono ou colaborador owner)
+        self.access_policy
+            .ensure(&task, &user_id, TaskAction::Delete)
+            .await?;
+
+        // Preservar subtarefas movendo-as para o pai da tarefa deletada
+        let reparent = subtasks == SubtaskDeletionMode::Reparent;
+
+        // Na cascata, o usuário precisa poder deletar cada subtarefa
+        let descendants = if reparent {
+            Vec::new()
+        } else {
+            self.task_repository.list_descendants(&task_id).await?
+        };
+        for subtask in &descendants {
+            let role = self.access_policy.role_for(subtask, &user_id).await?;
+            if !role.is_some_and(|role| TaskAction::Delete.is_allowed_for(role)) {
+                return Err(DomainError::Forbidden(
+                    "A tarefa possui subtarefas que você não pode deletar; use subtasks=reparent para preservá-las"
+                        .to_string(),
+                ));
+            }
+        }
+        let descendant_ids: Vec<Uuid> = descendants.iter().map(|subtask| subtask.id).collect();
+
+        // Deletar tarefa, anexos e subtarefas autorizadas (histórico em cascata)
+        let removed = self
+            .task_repository
+            .delete_with_subtasks(&task_id, reparent, &descendant_ids)
+            .await?;
+        self.event_publisher
+            .publish(TaskEvent::deleted(&task, user_id));
+        for subtask in &removed.subtasks {
+            self.event_publisher
+                .publish(TaskEvent::deleted(subtask, user_id));
+        }
+
+        // Remover o conteúdo dos anexos (falhas deixam apenas arquivos órfãos)
+        for storage_key in removed.storage_keys {
+            if let Err(e) = self.attachment_storage.delete(&storage_key).await {
+                tracing::warn!("Falha ao remover conteúdo do anexo {}: {}", storage_key, e);
+            }
         }
 
-        // Deletar tarefa (histórico é deletado em cascata)
-        self.task_repository.delete(&task_id).await
+        Ok(())
     }
 }
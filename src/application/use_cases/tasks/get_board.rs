@@ -0,0 +1,60 @@
+use crate::application::dtos::{BoardColumnDto, BoardQueryDto, BoardResponseDto};
+use crate::domain::errors::DomainResult;
+use crate::domain::repositories::TaskRepository;
+use crate::domain::repositories::task_repository::{Pagination, TaskFilter, TaskSort};
+use crate::domain::value_objects::TaskStatus;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Quadro kanban com as tarefas agrupadas por status
+pub struct GetBoardUseCase {
+    task_repository: Arc<dyn TaskRepository>,
+}
+
+impl GetBoardUseCase {
+    pub fn new(task_repository: Arc<dyn TaskRepository>) -> Self {
+        Self { task_repository }
+    }
+
+    /// Executa a montagem do quadro (colunas na ordem do fluxo, tarefas por posição)
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        query: BoardQueryDto,
+    ) -> DomainResult<BoardResponseDto> {
+        let pagination = Pagination {
+            page: 1,
+            page_size: query.limit.unwrap_or(50).clamp(1, 100),
+        };
+
+        let mut columns = Vec::with_capacity(TaskStatus::ALL.len());
+
+        for status in TaskStatus::ALL {
+            let filter = TaskFilter {
+                user_id,
+                include_shared: query.include_shared.unwrap_or(false),
+                status: Some(status),
+                priority: None,
+                overdue_only: false,
+                search_query: None,
+                tags_any: None,
+                tags_all: None,
+                project_id: query.project_id,
+                sort: TaskSort::Rank,
+            };
+
+            let result = self
+                .task_repository
+                .list(filter, pagination.clone())
+                .await?;
+
+            columns.push(BoardColumnDto {
+                status,
+                total: result.total,
+                tasks: result.items.into_iter().map(Into::into).collect(),
+            });
+        }
+
+        Ok(BoardResponseDto { columns })
+    }
+}
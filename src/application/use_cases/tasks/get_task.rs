@@ -1,17 +1,25 @@
 use crate::application::dtos::TaskResponseDto;
 use crate::domain::errors::{DomainError, DomainResult};
-use crate::domain::repositories::TaskRepository;
+use crate::domain::repositories::{CollaboratorRepository, TaskRepository};
+use crate::domain::services::{TaskAccessPolicy, TaskAction};
 use std::sync::Arc;
 use uuid::Uuid;
 
 /// Caso de uso: Obter tarefa por ID
 pub struct GetTaskUseCase {
     task_repository: Arc<dyn TaskRepository>,
+    access_policy: TaskAccessPolicy,
 }
 
 impl GetTaskUseCase {
-    pub fn new(task_repository: Arc<dyn TaskRepository>) -> Self {
-        Self { task_repository }
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        collaborator_repository: Arc<dyn CollaboratorRepository>,
+    ) -> Self {
+        Self {
+            task_repository,
+            access_policy: TaskAccessPolicy::new(collaborator_repository),
+        }
     }
 
     /// Executa a busca de tarefa
@@ -23,24 +31,12 @@ impl GetTaskUseCase {
             .await?
             .ok_or(DomainError::NotFound("Tarefa não encontrada".to_string()))?;
 
-        // Verificar propriedade
-        if !task.is_owned_by(&user_id) {
-            return Err(DomainError::Unauthorized);
-        }
+        // Verificar acesso (dono ou colaborador)
+        self.access_policy
+            .ensure(&task, &user_id, TaskAction::View)
+            .await?;
 
         // Converter para DTO
-        Ok(TaskResponseDto {
-            id: task.id,
-            user_id: task.user_id,
-            title: task.title,
-            description: task.description,
-            status: task.status,
-            priority: task.priority,
-            due_date: task.due_date,
-            completed_at: task.completed_at,
-            is_overdue: task.is_overdue(),
-            created_at: task.created_at,
-            updated_at: task.updated_at,
-        })
+        Ok(task.into())
     }
 }
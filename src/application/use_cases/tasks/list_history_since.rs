@@ -0,0 +1,31 @@
+use crate::domain::entities::TaskHistory;
+use crate::domain::errors::DomainResult;
+use crate::domain::repositories::TaskRepository;
+use crate::domain::value_objects::HistoryCursor;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Quantidade máxima de registros lidos por consulta
+pub const HISTORY_BATCH_SIZE: i64 = 100;
+
+/// Caso de uso: Listar o histórico novo das tarefas do usuário a partir de um cursor
+pub struct ListHistorySinceUseCase {
+    task_repository: Arc<dyn TaskRepository>,
+}
+
+impl ListHistorySinceUseCase {
+    pub fn new(task_repository: Arc<dyn TaskRepository>) -> Self {
+        Self { task_repository }
+    }
+
+    /// Executa a leitura de um lote de histórico posterior ao cursor
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        after: &HistoryCursor,
+    ) -> DomainResult<Vec<TaskHistory>> {
+        self.task_repository
+            .list_history_since(&user_id, after, HISTORY_BATCH_SIZE)
+            .await
+    }
+}
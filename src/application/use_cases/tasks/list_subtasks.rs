@@ -0,0 +1,47 @@
+use crate::application::dtos::TaskResponseDto;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{CollaboratorRepository, TaskRepository};
+use crate::domain::services::{TaskAccessPolicy, TaskAction};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Listar subtarefas diretas de uma tarefa
+pub struct ListSubtasksUseCase {
+    task_repository: Arc<dyn TaskRepository>,
+    access_policy: TaskAccessPolicy,
+}
+
+impl ListSubtasksUseCase {
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        collaborator_repository: Arc<dyn CollaboratorRepository>,
+    ) -> Self {
+        Self {
+            task_repository,
+            access_policy: TaskAccessPolicy::new(collaborator_repository),
+        }
+    }
+
+    /// Executa a listagem de subtarefas
+    pub async fn execute(
+        &self,
+        task_id: Uuid,
+        user_id: Uuid,
+    ) -> DomainResult<Vec<TaskResponseDto>> {
+        // Buscar tarefa pai e verificar acesso
+        let task = self
+            .task_repository
+            .find_by_id(&task_id)
+            .await?
+            .ok_or(DomainError::NotFound("Tarefa não encontrada".to_string()))?;
+
+        self.access_policy
+            .ensure(&task, &user_id, TaskAction::View)
+            .await?;
+
+        // Buscar subtarefas
+        let subtasks = self.task_repository.list_subtasks(&task_id).await?;
+
+        Ok(subtasks.into_iter().map(Into::into).collect())
+    }
+}
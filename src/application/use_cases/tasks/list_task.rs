@@ -1,4 +1,5 @@
 use crate::application::dtos::{PaginatedResponseDto, TaskFilterDto, TaskResponseDto};
+use crate::domain::entities::Tag;
 use crate::domain::errors::DomainResult;
 use crate::domain::repositories::{Pagination, TaskFilter, TaskRepository};
 use std::sync::Arc;
@@ -23,10 +24,15 @@ impl ListTasksUseCase {
         // Construir filtro
         let filter = TaskFilter {
             user_id,
+            include_shared: filter_dto.include_shared.unwrap_or(false),
             status: filter_dto.status,
             priority: filter_dto.priority,
             overdue_only: filter_dto.overdue_only.unwrap_or(false),
             search_query: filter_dto.search,
+            tags_any: parse_tags(filter_dto.tags_any.as_deref())?,
+            tags_all: parse_tags(filter_dto.tags_all.as_deref())?,
+            project_id: filter_dto.project_id,
+            sort: filter_dto.sort.unwrap_or_default(),
         };
 
         // Construir paginação
@@ -39,23 +45,7 @@ impl ListTasksUseCase {
         let result = self.task_repository.list(filter, pagination).await?;
 
         // Converter para DTOs
-        let items: Vec<TaskResponseDto> = result
-            .items
-            .into_iter()
-            .map(|task| TaskResponseDto {
-                id: task.id,
-                user_id: task.user_id,
-                title: task.title,
-                description: task.description,
-                status: task.status,
-                priority: task.priority,
-                due_date: task.due_date,
-                completed_at: task.completed_at,
-                is_overdue: task.is_overdue(),
-                created_at: task.created_at,
-                updated_at: task.updated_at,
-            })
-            .collect();
+        let items: Vec<TaskResponseDto> = result.items.into_iter().map(Into::into).collect();
 
         Ok(PaginatedResponseDto {
             items,
@@ -66,3 +56,20 @@ impl ListTasksUseCase {
         })
     }
 }
+
+/// Converte uma lista de tags separadas por vírgula em nomes normalizados
+fn parse_tags(raw: Option<&str>) -> DomainResult<Option<Vec<String>>> {
+    let Some(raw) = raw else {
+        return Ok(None);
+    };
+
+    let mut tags = raw
+        .split(',')
+        .filter(|tag| !tag.trim().is_empty())
+        .map(Tag::normalize_name)
+        .collect::<DomainResult<Vec<String>>>()?;
+    tags.sort();
+    tags.dedup();
+
+    Ok(if tags.is_empty() { None } else { Some(tags) })
+}
@@ -1,11 +1,21 @@
 pub mod create_task;
 pub mod delete_task;
+pub mod get_board;
 pub mod get_task;
+pub mod list_history_since;
+pub mod list_subtasks;
 pub mod list_tasks;
+pub mod move_task;
+pub mod reopen_task;
 pub mod update_task;
 
 pub use create_task::CreateTaskUseCase;
 pub use delete_task::DeleteTaskUseCase;
+pub use get_board::GetBoardUseCase;
 pub use get_task::GetTaskUseCase;
+pub use list_history_since::ListHistorySinceUseCase;
+pub use list_subtasks::ListSubtasksUseCase;
 pub use list_tasks::ListTasksUseCase;
+pub use move_task::MoveTaskUseCase;
+pub use reopen_task::ReopenTaskUseCase;
 pub use update_task::UpdateTaskUseCase;
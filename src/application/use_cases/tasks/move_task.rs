@@ -0,0 +1,153 @@
+use crate::application::dtos::{MoveTaskDto, TaskResponseDto};
+use crate::application::services::{RecurrenceService, WorkflowService};
+use crate::domain::entities::{Task, TaskHistory};
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::events::{EventPublisher, TaskEvent};
+use crate::domain::repositories::{
+    CollaboratorRepository, DependencyRepository, TaskRepository, WorkflowRepository,
+};
+use crate::domain::services::{TaskAccessPolicy, TaskAction, TaskDependencyPolicy};
+use crate::domain::value_objects::{Rank, SubtaskCompletionPolicy, TaskStatus};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Mover tarefa no quadro (coluna e posição em uma única gravação)
+pub struct MoveTaskUseCase {
+    task_repository: Arc<dyn TaskRepository>,
+    access_policy: TaskAccessPolicy,
+    dependency_policy: TaskDependencyPolicy,
+    subtask_policy: SubtaskCompletionPolicy,
+    event_publisher: Arc<dyn EventPublisher>,
+    recurrence_service: RecurrenceService,
+    workflow_service: WorkflowService,
+}
+
+impl MoveTaskUseCase {
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        collaborator_repository: Arc<dyn CollaboratorRepository>,
+        dependency_repository: Arc<dyn DependencyRepository>,
+        subtask_policy: SubtaskCompletionPolicy,
+        event_publisher: Arc<dyn EventPublisher>,
+        workflow_repository: Arc<dyn WorkflowRepository>,
+    ) -> Self {
+        Self {
+            recurrence_service: RecurrenceService::new(
+                task_repository.clone(),
+                event_publisher.clone(),
+                workflow_repository.clone(),
+            ),
+            task_repository,
+            access_policy: TaskAccessPolicy::new(collaborator_repository),
+            dependency_policy: TaskDependencyPolicy::new(dependency_repository),
+            subtask_policy,
+            event_publisher,
+            workflow_service: WorkflowService::new(workflow_repository),
+        }
+    }
+
+    /// Executa a movimentação da tarefa
+    pub async fn execute(
+        &self,
+        task_id: Uuid,
+        user_id: Uuid,
+        dto: MoveTaskDto,
+    ) -> DomainResult<TaskResponseDto> {
+        let mut task = self
+            .task_repository
+            .find_by_id(&task_id)
+            .await?
+            .ok_or(DomainError::NotFound("Tarefa não encontrada".to_string()))?;
+
+        self.access_policy
+            .ensure(&task, &user_id, TaskAction::Edit)
+            .await?;
+
+        // Calcular a nova posição entre as vizinhas da coluna de destino
+        let before = self
+            .find_neighbor(dto.before_id, &task, &user_id, dto.status)
+            .await?;
+        let after = self
+            .find_neighbor(dto.after_id, &task, &user_id, dto.status)
+            .await?;
+        let rank = Rank::between(
+            before.as_ref().map(|t| &t.rank),
+            after.as_ref().map(|t| &t.rank),
+        )?;
+
+        let old_status = task.status;
+        if dto.status != old_status {
+            self.dependency_policy
+                .ensure_can_transition(&task, dto.status)
+                .await?;
+        }
+
+        let workflow = self.workflow_service.for_task(&task).await?;
+        task.move_to(workflow.as_ref(), dto.status, rank, self.subtask_policy)?;
+
+        let moved = self.task_repository.update(&task).await?;
+
+        if moved.status != old_status {
+            self.task_repository
+                .add_history(&TaskHistory::new(
+                    task_id,
+                    user_id,
+                    "status".to_string(),
+                    Some(old_status.to_string()),
+                    Some(moved.status.to_string()),
+                ))
+                .await?;
+        }
+
+        self.event_publisher
+            .publish(TaskEvent::updated(&moved, user_id));
+
+        // Tarefa recorrente concluída: gerar a próxima ocorrência da série
+        if old_status != TaskStatus::Completed && moved.status == TaskStatus::Completed {
+            self.recurrence_service
+                .spawn_next_occurrence(&moved, user_id)
+                .await?;
+        }
+
+        Ok(moved.into())
+    }
+
+    /// Busca uma tarefa vizinha (visível ao usuário e já na coluna de destino)
+    async fn find_neighbor(
+        &self,
+        neighbor_id: Option<Uuid>,
+        task: &Task,
+        user_id: &Uuid,
+        status: TaskStatus,
+    ) -> DomainResult<Option<Task>> {
+        let Some(neighbor_id) = neighbor_id else {
+            return Ok(None);
+        };
+
+        if neighbor_id == task.id {
+            return Err(DomainError::ValidationError(
+                "Uma tarefa não pode ser vizinha de si mesma".to_string(),
+            ));
+        }
+
+        let neighbor =
+            self.task_repository
+                .find_by_id(&neighbor_id)
+                .await?
+                .ok_or(DomainError::NotFound(
+                    "Tarefa vizinha não encontrada".to_string(),
+                ))?;
+
+        self.access_policy
+            .ensure(&neighbor, user_id, TaskAction::View)
+            .await?;
+
+        if neighbor.status != status {
+            return Err(DomainError::ValidationError(
+                "As tarefas vizinhas devem estar na coluna de destino".to_string(),
+            ));
+        }
+
+        Ok(Some(neighbor))
+    }
+}
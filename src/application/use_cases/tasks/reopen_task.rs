@@ -0,0 +1,96 @@
+use crate::application::dtos::{ReopenTaskDto, TaskResponseDto};
+use crate::application::services::WorkflowService;
+use crate::domain::entities::TaskHistory;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::events::{EventPublisher, TaskEvent};
+use crate::domain::repositories::{CollaboratorRepository, TaskRepository, WorkflowRepository};
+use crate::domain::services::{TaskAccessPolicy, TaskAction};
+use crate::domain::value_objects::{ReopenPolicy, SubtaskCompletionPolicy};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Reabrir tarefa concluída ou cancelada
+pub struct ReopenTaskUseCase {
+    task_repository: Arc<dyn TaskRepository>,
+    access_policy: TaskAccessPolicy,
+    workflow_service: WorkflowService,
+    reopen_policy: ReopenPolicy,
+    subtask_policy: SubtaskCompletionPolicy,
+    event_publisher: Arc<dyn EventPublisher>,
+}
+
+impl ReopenTaskUseCase {
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        collaborator_repository: Arc<dyn CollaboratorRepository>,
+        workflow_repository: Arc<dyn WorkflowRepository>,
+        reopen_policy: ReopenPolicy,
+        subtask_policy: SubtaskCompletionPolicy,
+        event_publisher: Arc<dyn EventPublisher>,
+    ) -> Self {
+        Self {
+            task_repository,
+            access_policy: TaskAccessPolicy::new(collaborator_repository),
+            workflow_service: WorkflowService::new(workflow_repository),
+            reopen_policy,
+            subtask_policy,
+            event_publisher,
+        }
+    }
+
+    /// Executa a reabertura da tarefa
+    pub async fn execute(
+        &self,
+        task_id: Uuid,
+        user_id: Uuid,
+        dto: ReopenTaskDto,
+    ) -> DomainResult<TaskResponseDto> {
+        let reason = dto.reason.trim();
+        if reason.is_empty() {
+            return Err(DomainError::ValidationError(
+                "Informe o motivo da reabertura".to_string(),
+            ));
+        }
+
+        let mut task = self
+            .task_repository
+            .find_by_id(&task_id)
+            .await?
+            .ok_or(DomainError::NotFound("Tarefa não encontrada".to_string()))?;
+
+        self.access_policy
+            .ensure(&task, &user_id, TaskAction::Edit)
+            .await?;
+
+        let old_status = task.status;
+        let workflow = self.workflow_service.for_task(&task).await?;
+        let parent = match task.parent_id {
+            Some(parent_id) => self.task_repository.find_by_id(&parent_id).await?,
+            None => None,
+        };
+        task.reopen(
+            workflow.as_ref(),
+            parent.as_ref(),
+            self.reopen_policy,
+            self.subtask_policy,
+        )?;
+
+        let reopened = self.task_repository.update(&task).await?;
+
+        // Entrada própria no histórico: status anterior e motivo
+        self.task_repository
+            .add_history(&TaskHistory::new(
+                task_id,
+                user_id,
+                "reopened".to_string(),
+                Some(old_status.to_string()),
+                Some(reason.to_string()),
+            ))
+            .await?;
+
+        self.event_publisher
+            .publish(TaskEvent::updated(&reopened, user_id));
+
+        Ok(reopened.into())
+    }
+}
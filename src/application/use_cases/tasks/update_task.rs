@@ -1,18 +1,53 @@
 use crate::application::dtos::{TaskResponseDto, UpdateTaskDto};
+use crate::application::services::{RecurrenceService, WorkflowService};
 use crate::domain::entities::TaskHistory;
 use crate::domain::errors::{DomainError, DomainResult};
-use crate::domain::repositories::TaskRepository;
+use crate::domain::events::{EventPublisher, TaskEvent};
+use crate::domain::repositories::{
+    CollaboratorRepository, DependencyRepository, ProjectRepository, TaskRepository,
+    WorkflowRepository,
+};
+use crate::domain::services::{TaskAccessPolicy, TaskAction, TaskDependencyPolicy};
+use crate::domain::value_objects::{SubtaskCompletionPolicy, TaskStatus};
 use std::sync::Arc;
 use uuid::Uuid;
 
 /// Caso de uso: Atualizar tarefa existente
 pub struct UpdateTaskUseCase {
     task_repository: Arc<dyn TaskRepository>,
+    access_policy: TaskAccessPolicy,
+    dependency_policy: TaskDependencyPolicy,
+    subtask_policy: SubtaskCompletionPolicy,
+    event_publisher: Arc<dyn EventPublisher>,
+    recurrence_service: RecurrenceService,
+    project_repository: Arc<dyn ProjectRepository>,
+    workflow_service: WorkflowService,
 }
 
 impl UpdateTaskUseCase {
-    pub fn new(task_repository: Arc<dyn TaskRepository>) -> Self {
-        Self { task_repository }
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        collaborator_repository: Arc<dyn CollaboratorRepository>,
+        dependency_repository: Arc<dyn DependencyRepository>,
+        subtask_policy: SubtaskCompletionPolicy,
+        event_publisher: Arc<dyn EventPublisher>,
+        project_repository: Arc<dyn ProjectRepository>,
+        workflow_repository: Arc<dyn WorkflowRepository>,
+    ) -> Self {
+        Self {
+            recurrence_service: RecurrenceService::new(
+                task_repository.clone(),
+                event_publisher.clone(),
+                workflow_repository.clone(),
+            ),
+            task_repository,
+            access_policy: TaskAccessPolicy::new(collaborator_repository),
+            dependency_policy: TaskDependencyPolicy::new(dependency_repository),
+            subtask_policy,
+            event_publisher,
+            project_repository,
+            workflow_service: WorkflowService::new(workflow_repository),
+        }
     }
 
     /// Executa a atualização de tarefa
@@ -29,10 +64,12 @@ impl UpdateTaskUseCase {
             .await?
             .ok_or(DomainError::NotFound("Tarefa não encontrada".to_string()))?;
 
-        // Verificar se o usuário é dono da tarefa
-        if !task.is_owned_by(&user_id) {
-            return Err(DomainError::Unauthorized);
-        }
+        // Verificar se o usuário pode editar a tarefa (dono ou editor)
+        self.access_policy
+            .ensure(&task, &user_id, TaskAction::Edit)
+            .await?;
+
+        let was_completed = task.status == TaskStatus::Completed;
 
         // Atualizar campos e registrar histórico
         if let Some(title) = dto.title {
@@ -53,7 +90,11 @@ impl UpdateTaskUseCase {
 
         if let Some(status) = dto.status {
             let old_status = task.status;
-            task.update_status(status)?;
+            self.dependency_policy
+                .ensure_can_transition(&task, status)
+                .await?;
+            let workflow = self.workflow_service.for_task(&task).await?;
+            task.update_status(workflow.as_ref(), status, self.subtask_policy)?;
 
             self.add_history_entry(
                 task_id,
@@ -65,6 +106,47 @@ impl UpdateTaskUseCase {
             .await?;
         }
 
+        if let Some(state_id) = dto.state_id {
+            let workflow = self.workflow_service.for_task(&task).await?.ok_or(
+                DomainError::ValidationError("A tarefa não está em um workflow".to_string()),
+            )?;
+            let old_status = task.status;
+            let old_state = task
+                .state_id
+                .and_then(|id| workflow.state(&id))
+                .map(|s| s.name.clone());
+
+            if let Some(target) = workflow.state(&state_id)
+                && target.category != old_status
+            {
+                self.dependency_policy
+                    .ensure_can_transition(&task, target.category)
+                    .await?;
+            }
+            task.update_state(&workflow, state_id, self.subtask_policy)?;
+            let new_state = workflow.state(&state_id).map(|s| s.name.clone());
+
+            self.add_history_entry(
+                task_id,
+                user_id,
+                "state",
+                &old_state.unwrap_or_default(),
+                &new_state.unwrap_or_default(),
+            )
+            .await?;
+
+            if task.status != old_status {
+                self.add_history_entry(
+                    task_id,
+                    user_id,
+                    "status",
+                    &old_status.to_string(),
+                    &task.status.to_string(),
+                )
+                .await?;
+            }
+        }
+
         if let Some(priority) = dto.priority {
             let old_priority = task.priority;
             task.update_priority(priority);
@@ -88,23 +170,79 @@ impl UpdateTaskUseCase {
                 .await?;
         }
 
+        if let Some(tags) = dto.tags {
+            let old_tags = task.tags.join(", ");
+            task.update_tags(tags)?;
+            let new_tags = task.tags.join(", ");
+
+            self.add_history_entry(task_id, user_id, "tags", &old_tags, &new_tags)
+                .await?;
+        }
+
+        if let Some(recurrence) = dto.recurrence {
+            let old_rule = task.recurrence.as_ref().map(ToString::to_string);
+            let rule = match recurrence.trim() {
+                "" => None,
+                value => Some(value.parse()?),
+            };
+            task.set_recurrence(rule);
+            let new_rule = task.recurrence.as_ref().map(ToString::to_string);
+
+            self.add_history_entry(
+                task_id,
+                user_id,
+                "recurrence",
+                &old_rule.unwrap_or_default(),
+                &new_rule.unwrap_or_default(),
+            )
+            .await?;
+        }
+
+        if let Some(project_id) = dto.project_id {
+            let old_project = task.project_id.map(|id| id.to_string());
+            let project = match project_id {
+                Some(project_id) => Some(
+                    self.project_repository
+                        .find_by_id(&project_id)
+                        .await?
+                        .ok_or(DomainError::NotFound("Projeto não encontrado".to_string()))?,
+                ),
+                None => None,
+            };
+            task.set_project(project.as_ref())?;
+
+            // Entrar no workflow do novo projeto (ou voltar aos status padrão)
+            let workflow = match &project {
+                Some(project) => self.workflow_service.for_project(project).await?,
+                None => None,
+            };
+            task.enter_workflow(workflow.as_ref());
+            let new_project = task.project_id.map(|id| id.to_string());
+
+            self.add_history_entry(
+                task_id,
+                user_id,
+                "project",
+                &old_project.unwrap_or_default(),
+                &new_project.unwrap_or_default(),
+            )
+            .await?;
+        }
+
         // Salvar alterações
         let updated_task = self.task_repository.update(&task).await?;
+        self.event_publisher
+            .publish(TaskEvent::updated(&updated_task, user_id));
+
+        // Tarefa recorrente concluída: gerar a próxima ocorrência da série
+        if !was_completed && updated_task.status == TaskStatus::Completed {
+            self.recurrence_service
+                .spawn_next_occurrence(&updated_task, user_id)
+                .await?;
+        }
 
         // Converter para DTO
-        Ok(TaskResponseDto {
-            id: updated_task.id,
-            user_id: updated_task.user_id,
-            title: updated_task.title,
-            description: updated_task.description,
-            status: updated_task.status,
-            priority: updated_task.priority,
-            due_date: updated_task.due_date,
-            completed_at: updated_task.completed_at,
-            is_overdue: updated_task.is_overdue(),
-            created_at: updated_task.created_at,
-            updated_at: updated_task.updated_at,
-        })
+        Ok(updated_task.into())
     }
 
     /// Helper para adicionar entrada no histórico
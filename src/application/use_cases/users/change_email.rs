@@ -0,0 +1,61 @@
+use crate::application::dtos::ChangeEmailDto;
+use crate::application::services::EmailVerificationService;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::UserRepository;
+use crate::domain::value_objects::Email;
+use crate::infrastructure::security::verify_password;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Solicitar a troca de email do usuário autenticado
+pub struct ChangeEmailUseCase {
+    user_repository: Arc<dyn UserRepository>,
+    email_verification: EmailVerificationService,
+}
+
+impl ChangeEmailUseCase {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        email_verification: EmailVerificationService,
+    ) -> Self {
+        Self {
+            user_repository,
+            email_verification,
+        }
+    }
+
+    /// Executa a solicitação
+    ///
+    /// O email só é trocado quando o link enviado ao novo endereço é confirmado.
+    pub async fn execute(&self, user_id: Uuid, dto: ChangeEmailDto) -> DomainResult<()> {
+        // Validar email
+        let new_email = Email::new(dto.new_email)?;
+
+        let user = self
+            .user_repository
+            .find_by_id(&user_id)
+            .await?
+            .ok_or(DomainError::NotFound("Usuário não encontrado".to_string()))?;
+
+        // Verificar senha atual
+        if !verify_password(&dto.current_password, &user.password_hash)
+            .map_err(|e| DomainError::Internal(e.to_string()))?
+        {
+            return Err(DomainError::Forbidden("Senha atual incorreta".to_string()));
+        }
+
+        if new_email == user.email {
+            return Err(DomainError::ValidationError(
+                "O novo email deve ser diferente do atual".to_string(),
+            ));
+        }
+
+        if self.user_repository.email_exists(&new_email).await? {
+            return Err(DomainError::Conflict("Email já cadastrado".to_string()));
+        }
+
+        self.email_verification
+            .send_email_change(&user, &new_email)
+            .await
+    }
+}
@@ -0,0 +1,73 @@
+use crate::application::dtos::{ChangePasswordDto, ClientInfoDto, TokenResponseDto};
+use crate::application::services::SessionService;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{SessionRepository, UserRepository};
+use crate::infrastructure::security::{TokenRevocationService, hash_password, verify_password};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Trocar a senha do usuário autenticado
+pub struct ChangePasswordUseCase {
+    user_repository: Arc<dyn UserRepository>,
+    session_repository: Arc<dyn SessionRepository>,
+    token_revocation: Arc<TokenRevocationService>,
+    session_service: SessionService,
+}
+
+impl ChangePasswordUseCase {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        session_repository: Arc<dyn SessionRepository>,
+        token_revocation: Arc<TokenRevocationService>,
+        session_service: SessionService,
+    ) -> Self {
+        Self {
+            user_repository,
+            session_repository,
+            token_revocation,
+            session_service,
+        }
+    }
+
+    /// Executa a troca
+    ///
+    /// Encerra todas as sessões (inclusive a atual) e inicia uma nova para
+    /// o cliente que fez a troca.
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        dto: ChangePasswordDto,
+        client: ClientInfoDto,
+    ) -> DomainResult<TokenResponseDto> {
+        let mut user = self
+            .user_repository
+            .find_by_id(&user_id)
+            .await?
+            .ok_or(DomainError::NotFound("Usuário não encontrado".to_string()))?;
+
+        // Verificar senha atual
+        if !verify_password(&dto.current_password, &user.password_hash)
+            .map_err(|e| DomainError::Internal(e.to_string()))?
+        {
+            return Err(DomainError::Forbidden("Senha atual incorreta".to_string()));
+        }
+
+        if dto.current_password == dto.new_password {
+            return Err(DomainError::ValidationError(
+                "A nova senha deve ser diferente da atual".to_string(),
+            ));
+        }
+
+        // Atualizar senha
+        let password_hash =
+            hash_password(&dto.new_password).map_err(|e| DomainError::Internal(e.to_string()))?;
+        user.update_password(password_hash);
+        self.user_repository.update(&user).await?;
+
+        // Invalidar sessões e access tokens emitidos com a senha antiga
+        self.session_repository.revoke_all_by_user(&user.id).await?;
+        self.token_revocation.revoke_all(&user.id).await?;
+
+        self.session_service.start(user.id, client).await
+    }
+}
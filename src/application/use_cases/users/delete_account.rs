@@ -0,0 +1,368 @@
+use crate::application::dtos::{DataRequestResponseDto, DeleteAccountDto};
+use crate::domain::entities::DataRequest;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{DataRequestRepository, UserRepository, WorkspaceRepository};
+use crate::domain::value_objects::{DataRequestKind, WorkspaceRole};
+use crate::infrastructure::security::{TokenRevocationService, verify_password};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Excluir a conta do usuário autenticado
+pub struct DeleteAccountUseCase {
+    user_repository: Arc<dyn UserRepository>,
+    data_request_repository: Arc<dyn DataRequestRepository>,
+    workspace_repository: Arc<dyn WorkspaceRepository>,
+    token_revocation: Arc<TokenRevocationService>,
+}
+
+impl DeleteAccountUseCase {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        data_request_repository: Arc<dyn DataRequestRepository>,
+        workspace_repository: Arc<dyn WorkspaceRepository>,
+        token_revocation: Arc<TokenRevocationService>,
+    ) -> Self {
+        Self {
+            user_repository,
+            data_request_repository,
+            workspace_repository,
+            token_revocation,
+        }
+    }
+
+    /// Executa a exclusão
+    ///
+    /// Exige a senha e a repetição do email da conta como confirmação. A
+    /// eliminação (anonimização da conta e remoção dos dados pessoais) é
+    /// enfileirada para o worker; os tokens são invalidados imediatamente.
+    ///
+    /// É recusada enquanto o usuário for o único owner de um workspace com
+    /// outros membros, que ficaria sem dono.
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        dto: DeleteAccountDto,
+    ) -> DomainResult<DataRequestResponseDto> {
+        let user = self
+            .user_repository
+            .find_by_id(&user_id)
+            .await?
+            .ok_or(DomainError::NotFound("Usuário não encontrado".to_string()))?;
+
+        // Verificar senha
+        if !verify_password(&dto.password, &user.password_hash)
+            .map_err(|e| DomainError::Internal(e.to_string()))?
+        {
+            return Err(DomainError::Forbidden("Senha incorreta".to_string()));
+        }
+
+        // Confirmação: o email da conta digitado novamente
+        if !dto
+            .confirmation
+            .trim()
+            .eq_ignore_ascii_case(user.email.as_str())
+        {
+            return Err(DomainError::ValidationError(
+                "Confirme a exclusão informando o email da conta".to_string(),
+            ));
+        }
+
+        self.ensure_not_sole_owner(&user.id).await?;
+
+        // Invalidar access tokens antes de enfileirar a eliminação
+        self.token_revocation.revoke_all(&user.id).await?;
+
+        if let Some(request) = self
+            .data_request_repository
+            .find_latest(&user.id, DataRequestKind::Erasure)
+            .await?
+            && request.status.is_active()
+        {
+            return Ok(request.into());
+        }
+
+        let request = DataRequest::new(user.id, DataRequestKind::Erasure);
+        let created = self.data_request_repository.create(&request).await?;
+
+        Ok(created.into())
+    }
+
+    /// Garante que nenhum workspace compartilhado ficará sem owner
+    async fn ensure_not_sole_owner(&self, user_id: &Uuid) -> DomainResult<()> {
+        let mut orphaned = Vec::new();
+        for (workspace, membership) in self.workspace_repository.list_by_user(user_id).await? {
+            if membership.role != WorkspaceRole::Owner {
+                continue;
+            }
+
+            let members = self
+                .workspace_repository
+                .list_members(&workspace.id)
+                .await?;
+            let others: Vec<_> = members.iter().filter(|m| &m.user_id != user_id).collect();
+            if !others.is_empty() && others.iter().all(|m| m.role != WorkspaceRole::Owner) {
+                orphaned.push(workspace.name);
+            }
+        }
+
+        if orphaned.is_empty() {
+            return Ok(());
+        }
+
+        Err(DomainError::Conflict(format!(
+            "Transfira a propriedade dos workspaces antes de excluir a conta: {}",
+            orphaned.join(", ")
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{Membership, User, Workspace};
+    use crate::domain::value_objects::{DataRequestStatus, Email};
+    use crate::infrastructure::security::hash_password;
+    use crate::test_support::{MockRevokedTokenRepository, MockUserRepository};
+    use async_trait::async_trait;
+    use chrono::{DateTime, Utc};
+    use std::sync::Mutex;
+
+    // Mock repository de solicitações de dados em memória
+    #[derive(Default)]
+    struct MockDataRequestRepository {
+        requests: Mutex<Vec<DataRequest>>,
+    }
+
+    #[async_trait]
+    impl DataRequestRepository for MockDataRequestRepository {
+        async fn create(&self, request: &DataRequest) -> DomainResult<DataRequest> {
+            self.requests.lock().unwrap().push(request.clone());
+            Ok(request.clone())
+        }
+
+        async fn find_by_id(&self, _id: &Uuid) -> DomainResult<Option<DataRequest>> {
+            unimplemented!()
+        }
+
+        async fn find_latest(
+            &self,
+            user_id: &Uuid,
+            kind: DataRequestKind,
+        ) -> DomainResult<Option<DataRequest>> {
+            let requests = self.requests.lock().unwrap();
+            Ok(requests
+                .iter()
+                .rev()
+                .find(|request| &request.user_id == user_id && request.kind == kind)
+                .cloned())
+        }
+
+        async fn list_by_user(&self, _user_id: &Uuid) -> DomainResult<Vec<DataRequest>> {
+            unimplemented!()
+        }
+
+        async fn claim_next(
+            &self,
+            _stale_before: DateTime<Utc>,
+        ) -> DomainResult<Option<DataRequest>> {
+            unimplemented!()
+        }
+
+        async fn list_expired_exports(
+            &self,
+            _now: DateTime<Utc>,
+        ) -> DomainResult<Vec<DataRequest>> {
+            unimplemented!()
+        }
+
+        async fn update(&self, _request: &DataRequest) -> DomainResult<()> {
+            unimplemented!()
+        }
+    }
+
+    // Mock repository de workspaces em memória
+    #[derive(Default)]
+    struct MockWorkspaceRepository {
+        workspaces: Mutex<Vec<Workspace>>,
+        members: Mutex<Vec<Membership>>,
+    }
+
+    impl MockWorkspaceRepository {
+        fn add(&self, name: &str, members: Vec<(Uuid, WorkspaceRole)>) {
+            let workspace = Workspace::new(name).unwrap();
+            for (user_id, role) in members {
+                self.members.lock().unwrap().push(Membership {
+                    role,
+                    ..Membership::owner(workspace.id, user_id)
+                });
+            }
+            self.workspaces.lock().unwrap().push(workspace);
+        }
+    }
+
+    #[async_trait]
+    impl WorkspaceRepository for MockWorkspaceRepository {
+        async fn create(
+            &self,
+            _workspace: &Workspace,
+            _owner: &Membership,
+        ) -> DomainResult<Workspace> {
+            unimplemented!()
+        }
+
+        async fn find_by_id(&self, _id: &Uuid) -> DomainResult<Option<Workspace>> {
+            unimplemented!()
+        }
+
+        async fn list_by_user(&self, user_id: &Uuid) -> DomainResult<Vec<(Workspace, Membership)>> {
+            let workspaces = self.workspaces.lock().unwrap();
+            let members = self.members.lock().unwrap();
+            Ok(members
+                .iter()
+                .filter(|m| &m.user_id == user_id)
+                .map(|m| {
+                    let workspace = workspaces.iter().find(|w| w.id == m.workspace_id).unwrap();
+                    (workspace.clone(), m.clone())
+                })
+                .collect())
+        }
+
+        async fn find_default_for_user(&self, _user_id: &Uuid) -> DomainResult<Option<Uuid>> {
+            unimplemented!()
+        }
+
+        async fn find_membership(
+            &self,
+            _workspace_id: &Uuid,
+            _user_id: &Uuid,
+        ) -> DomainResult<Option<Membership>> {
+            unimplemented!()
+        }
+
+        async fn add_member(&self, _membership: &Membership) -> DomainResult<Membership> {
+            unimplemented!()
+        }
+
+        async fn list_members(&self, workspace_id: &Uuid) -> DomainResult<Vec<Membership>> {
+            let members = self.members.lock().unwrap();
+            Ok(members
+                .iter()
+                .filter(|m| &m.workspace_id == workspace_id)
+                .cloned()
+                .collect())
+        }
+    }
+
+    fn setup() -> (
+        Uuid,
+        Arc<MockUserRepository>,
+        Arc<MockDataRequestRepository>,
+        Arc<MockWorkspaceRepository>,
+        DeleteAccountUseCase,
+    ) {
+        let user = User::new(
+            Uuid::new_v4(),
+            Email::new("ana@example.com".to_string()).unwrap(),
+            hash_password("senha-123").unwrap(),
+            "Ana".to_string(),
+        );
+        let user_id = user.id;
+        let users = Arc::new(MockUserRepository::new(vec![user]));
+        let token_revocation = TokenRevocationService::new(
+            Arc::new(MockRevokedTokenRepository::default()),
+            users.clone(),
+            std::time::Duration::from_secs(30),
+        );
+        let requests = Arc::new(MockDataRequestRepository::default());
+        let workspaces = Arc::new(MockWorkspaceRepository::default());
+        let use_case = DeleteAccountUseCase::new(
+            users.clone(),
+            requests.clone(),
+            workspaces.clone(),
+            Arc::new(token_revocation),
+        );
+
+        (user_id, users, requests, workspaces, use_case)
+    }
+
+    fn delete(password: &str, confirmation: &str) -> DeleteAccountDto {
+        DeleteAccountDto {
+            password: password.to_string(),
+            confirmation: confirmation.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_delete_account_enqueues_erasure() {
+        let (user_id, users, requests, workspaces, use_case) = setup();
+        // Workspace pessoal e workspace em que é apenas membro
+        workspaces.add("Pessoal", vec![(user_id, WorkspaceRole::Owner)]);
+        workspaces.add(
+            "Time",
+            vec![
+                (Uuid::new_v4(), WorkspaceRole::Owner),
+                (user_id, WorkspaceRole::Admin),
+            ],
+        );
+
+        let response = use_case
+            .execute(user_id, delete("senha-123", " ANA@example.com "))
+            .await
+            .unwrap();
+
+        assert_eq!(response.kind, DataRequestKind::Erasure);
+        assert_eq!(response.status, DataRequestStatus::Pending);
+        assert_eq!(users.get(&user_id).unwrap().token_version, 1);
+
+        // Nova confirmação retorna a solicitação em andamento
+        let again = use_case
+            .execute(user_id, delete("senha-123", "ana@example.com"))
+            .await
+            .unwrap();
+
+        assert_eq!(again.id, response.id);
+        assert_eq!(requests.requests.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_delete_account_requires_password_and_confirmation() {
+        let (user_id, users, requests, _, use_case) = setup();
+
+        let wrong_password = use_case
+            .execute(user_id, delete("errada", "ana@example.com"))
+            .await;
+        let wrong_confirmation = use_case
+            .execute(user_id, delete("senha-123", "outra@example.com"))
+            .await;
+
+        assert!(matches!(wrong_password, Err(DomainError::Forbidden(_))));
+        assert!(matches!(
+            wrong_confirmation,
+            Err(DomainError::ValidationError(_))
+        ));
+        assert!(users.get(&user_id).is_some());
+        assert!(requests.requests.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_sole_owner_of_shared_workspace_cannot_delete_account() {
+        let (user_id, users, requests, workspaces, use_case) = setup();
+        workspaces.add(
+            "Time de Produto",
+            vec![
+                (user_id, WorkspaceRole::Owner),
+                (Uuid::new_v4(), WorkspaceRole::Admin),
+            ],
+        );
+
+        let result = use_case
+            .execute(user_id, delete("senha-123", "ana@example.com"))
+            .await;
+
+        assert!(
+            matches!(result, Err(DomainError::Conflict(message)) if message.contains("Time de Produto"))
+        );
+        assert_eq!(users.get(&user_id).unwrap().token_version, 0);
+        assert!(requests.requests.lock().unwrap().is_empty());
+    }
+}
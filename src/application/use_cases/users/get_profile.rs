@@ -0,0 +1,27 @@
+use crate::application::dtos::UserResponseDto;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::UserRepository;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Obter o perfil do usuário autenticado
+pub struct GetProfileUseCase {
+    user_repository: Arc<dyn UserRepository>,
+}
+
+impl GetProfileUseCase {
+    pub fn new(user_repository: Arc<dyn UserRepository>) -> Self {
+        Self { user_repository }
+    }
+
+    /// Executa a busca
+    pub async fn execute(&self, user_id: Uuid) -> DomainResult<UserResponseDto> {
+        let user = self
+            .user_repository
+            .find_by_id(&user_id)
+            .await?
+            .ok_or(DomainError::NotFound("Usuário não encontrado".to_string()))?;
+
+        Ok(UserResponseDto::from_user(&user))
+    }
+}
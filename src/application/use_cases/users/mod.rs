@@ -0,0 +1,11 @@
+pub mod change_email;
+pub mod change_password;
+pub mod delete_account;
+pub mod get_profile;
+pub mod update_profile;
+
+pub use change_email::ChangeEmailUseCase;
+pub use change_password::ChangePasswordUseCase;
+pub use delete_account::DeleteAccountUseCase;
+pub use get_profile::GetProfileUseCase;
+pub use update_profile::UpdateProfileUseCase;
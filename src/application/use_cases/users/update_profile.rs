@@ -0,0 +1,37 @@
+use crate::application::dtos::{UpdateProfileDto, UserResponseDto};
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::UserRepository;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Atualizar o perfil do usuário autenticado
+pub struct UpdateProfileUseCase {
+    user_repository: Arc<dyn UserRepository>,
+}
+
+impl UpdateProfileUseCase {
+    pub fn new(user_repository: Arc<dyn UserRepository>) -> Self {
+        Self { user_repository }
+    }
+
+    /// Executa a atualização
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        dto: UpdateProfileDto,
+    ) -> DomainResult<UserResponseDto> {
+        let mut user = self
+            .user_repository
+            .find_by_id(&user_id)
+            .await?
+            .ok_or(DomainError::NotFound("Usuário não encontrado".to_string()))?;
+
+        if let Some(name) = dto.name {
+            user.update_name(name.trim().to_string());
+        }
+
+        let user = self.user_repository.update(&user).await?;
+
+        Ok(UserResponseDto::from_user(&user))
+    }
+}
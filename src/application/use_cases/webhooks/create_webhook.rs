@@ -0,0 +1,48 @@
+use crate::application::dtos::{CreateWebhookDto, WebhookResponseDto};
+use crate::domain::entities::Webhook;
+use crate::domain::errors::DomainResult;
+use crate::domain::repositories::{WebhookClient, WebhookRepository};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Cadastrar webhook
+pub struct CreateWebhookUseCase {
+    webhook_repository: Arc<dyn WebhookRepository>,
+    webhook_client: Arc<dyn WebhookClient>,
+}
+
+impl CreateWebhookUseCase {
+    pub fn new(
+        webhook_repository: Arc<dyn WebhookRepository>,
+        webhook_client: Arc<dyn WebhookClient>,
+    ) -> Self {
+        Self {
+            webhook_repository,
+            webhook_client,
+        }
+    }
+
+    /// Executa o cadastro do webhook
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        dto: CreateWebhookDto,
+    ) -> DomainResult<WebhookResponseDto> {
+        let webhook = Webhook::new(
+            Uuid::new_v4(),
+            user_id,
+            dto.url,
+            dto.secret,
+            dto.event_types,
+        )?;
+
+        // Rejeitar destinos internos (SSRF); revalidado a cada entrega
+        self.webhook_client
+            .ensure_allowed_destination(&webhook.url)
+            .await?;
+
+        let saved = self.webhook_repository.create(&webhook).await?;
+
+        Ok(saved.into())
+    }
+}
@@ -0,0 +1,26 @@
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::WebhookRepository;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Deletar webhook (e seu log de entregas)
+pub struct DeleteWebhookUseCase {
+    webhook_repository: Arc<dyn WebhookRepository>,
+}
+
+impl DeleteWebhookUseCase {
+    pub fn new(webhook_repository: Arc<dyn WebhookRepository>) -> Self {
+        Self { webhook_repository }
+    }
+
+    /// Executa a remoção do webhook
+    pub async fn execute(&self, webhook_id: Uuid, user_id: Uuid) -> DomainResult<()> {
+        self.webhook_repository
+            .find_by_id(&webhook_id)
+            .await?
+            .filter(|webhook| webhook.is_owned_by(&user_id))
+            .ok_or(DomainError::NotFound("Webhook não encontrado".to_string()))?;
+
+        self.webhook_repository.delete(&webhook_id).await
+    }
+}
@@ -0,0 +1,245 @@
+use crate::domain::errors::DomainResult;
+use crate::domain::repositories::{WebhookClient, WebhookDeliveryRepository, WebhookRepository};
+use crate::domain::value_objects::WebhookRetryPolicy;
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+
+/// Quantidade máxima de entregas processadas por execução
+const DISPATCH_BATCH_SIZE: i64 = 20;
+
+/// Tempo de reserva de uma entrega enquanto é processada
+const DELIVERY_LEASE: Duration = Duration::minutes(5);
+
+/// Caso de uso: Enviar as entregas de webhooks pendentes e vencidas
+pub struct DispatchWebhookDeliveriesUseCase {
+    webhook_repository: Arc<dyn WebhookRepository>,
+    delivery_repository: Arc<dyn WebhookDeliveryRepository>,
+    webhook_client: Arc<dyn WebhookClient>,
+    retry_policy: WebhookRetryPolicy,
+}
+
+impl DispatchWebhookDeliveriesUseCase {
+    pub fn new(
+        webhook_repository: Arc<dyn WebhookRepository>,
+        delivery_repository: Arc<dyn WebhookDeliveryRepository>,
+        webhook_client: Arc<dyn WebhookClient>,
+        retry_policy: WebhookRetryPolicy,
+    ) -> Self {
+        Self {
+            webhook_repository,
+            delivery_repository,
+            webhook_client,
+            retry_policy,
+        }
+    }
+
+    /// Executa um lote de envios e retorna quantas entregas foram processadas
+    pub async fn execute(&self) -> DomainResult<usize> {
+        let deliveries = self
+            .delivery_repository
+            .claim_due(DISPATCH_BATCH_SIZE, Utc::now() + DELIVERY_LEASE)
+            .await?;
+        let processed = deliveries.len();
+
+        for mut delivery in deliveries {
+            let webhook = self
+                .webhook_repository
+                .find_by_id(&delivery.webhook_id)
+                .await?;
+
+            match webhook {
+                Some(webhook) if webhook.active => {
+                    match self.webhook_client.send(&webhook, &delivery).await {
+                        Ok(status) if (200..300).contains(&status) => {
+                            delivery.record_success(status)
+                        }
+                        Ok(status) => delivery.record_failure(
+                            Some(status),
+                            &format!("Resposta HTTP {}", status),
+                            &self.retry_policy,
+                        ),
+                        Err(e) => delivery.record_failure(None, &e.to_string(), &self.retry_policy),
+                    }
+                }
+                _ => delivery.dead_letter("Webhook removido ou desativado"),
+            }
+
+            self.delivery_repository.update(&delivery).await?;
+        }
+
+        Ok(processed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{Webhook, WebhookDelivery};
+    use crate::domain::errors::DomainError;
+    use crate::domain::events::TaskEventKind;
+    use crate::domain::value_objects::WebhookDeliveryStatus;
+    use async_trait::async_trait;
+    use chrono::DateTime;
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    // Mock repository de webhooks com um único webhook
+    struct MockWebhookRepository {
+        webhook: Webhook,
+    }
+
+    #[async_trait]
+    impl WebhookRepository for MockWebhookRepository {
+        async fn create(&self, webhook: &Webhook) -> DomainResult<Webhook> {
+            Ok(webhook.clone())
+        }
+
+        async fn find_by_id(&self, id: &Uuid) -> DomainResult<Option<Webhook>> {
+            Ok(Some(self.webhook.clone()).filter(|webhook| &webhook.id == id))
+        }
+
+        async fn list_by_user(&self, _user_id: &Uuid) -> DomainResult<Vec<Webhook>> {
+            Ok(vec![self.webhook.clone()])
+        }
+
+        async fn list_subscribed(
+            &self,
+            _user_id: &Uuid,
+            _kind: TaskEventKind,
+        ) -> DomainResult<Vec<Webhook>> {
+            Ok(vec![self.webhook.clone()])
+        }
+
+        async fn update(&self, webhook: &Webhook) -> DomainResult<Webhook> {
+            Ok(webhook.clone())
+        }
+
+        async fn delete(&self, _id: &Uuid) -> DomainResult<()> {
+            Ok(())
+        }
+    }
+
+    // Mock da fila: entrega pendente e registro das atualizações
+    struct MockDeliveryRepository {
+        pending: Mutex<Vec<WebhookDelivery>>,
+        updated: Mutex<Vec<WebhookDelivery>>,
+    }
+
+    #[async_trait]
+    impl WebhookDeliveryRepository for MockDeliveryRepository {
+        async fn enqueue(&self, delivery: &WebhookDelivery) -> DomainResult<()> {
+            self.pending.lock().unwrap().push(delivery.clone());
+            Ok(())
+        }
+
+        async fn claim_due(
+            &self,
+            _limit: i64,
+            _lease_until: DateTime<Utc>,
+        ) -> DomainResult<Vec<WebhookDelivery>> {
+            Ok(std::mem::take(&mut *self.pending.lock().unwrap()))
+        }
+
+        async fn update(&self, delivery: &WebhookDelivery) -> DomainResult<()> {
+            self.updated.lock().unwrap().push(delivery.clone());
+            Ok(())
+        }
+
+        async fn list_by_webhook(
+            &self,
+            _webhook_id: &Uuid,
+            _limit: i64,
+        ) -> DomainResult<Vec<WebhookDelivery>> {
+            Ok(self.updated.lock().unwrap().clone())
+        }
+    }
+
+    // Mock do cliente HTTP com resposta fixa
+    struct MockWebhookClient {
+        response: Result<u16, String>,
+    }
+
+    #[async_trait]
+    impl WebhookClient for MockWebhookClient {
+        async fn send(&self, _webhook: &Webhook, _delivery: &WebhookDelivery) -> DomainResult<u16> {
+            self.response.clone().map_err(DomainError::Internal)
+        }
+
+        async fn ensure_allowed_destination(&self, _url: &str) -> DomainResult<()> {
+            Ok(())
+        }
+    }
+
+    async fn dispatch(
+        response: Result<u16, String>,
+        active: bool,
+        max_attempts: u32,
+    ) -> WebhookDelivery {
+        let mut webhook = Webhook::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "http://localhost:9000/hook".to_string(),
+            "0123456789abcdef".to_string(),
+            vec![TaskEventKind::TaskCreated],
+        )
+        .unwrap();
+        webhook.set_active(active);
+
+        let deliveries = Arc::new(MockDeliveryRepository {
+            pending: Mutex::new(vec![WebhookDelivery::new(
+                webhook.id,
+                Uuid::new_v4(),
+                TaskEventKind::TaskCreated,
+                "{}".to_string(),
+            )]),
+            updated: Mutex::new(vec![]),
+        });
+
+        let use_case = DispatchWebhookDeliveriesUseCase::new(
+            Arc::new(MockWebhookRepository { webhook }),
+            deliveries.clone(),
+            Arc::new(MockWebhookClient { response }),
+            WebhookRetryPolicy {
+                max_attempts,
+                ..WebhookRetryPolicy::default()
+            },
+        );
+
+        assert_eq!(use_case.execute().await.unwrap(), 1);
+        deliveries.updated.lock().unwrap().pop().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_success() {
+        let delivery = dispatch(Ok(200), true, 3).await;
+
+        assert_eq!(delivery.status, WebhookDeliveryStatus::Delivered);
+        assert_eq!(delivery.last_status_code, Some(200));
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_failure_schedules_retry() {
+        let delivery = dispatch(Ok(503), true, 3).await;
+
+        assert_eq!(delivery.status, WebhookDeliveryStatus::Pending);
+        assert_eq!(delivery.attempts, 1);
+        assert_eq!(delivery.last_status_code, Some(503));
+        assert!(delivery.next_attempt_at > Utc::now());
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_dead_letters_after_last_attempt() {
+        let delivery = dispatch(Err("connection refused".to_string()), true, 1).await;
+
+        assert_eq!(delivery.status, WebhookDeliveryStatus::DeadLettered);
+        assert!(delivery.last_error.unwrap().contains("connection refused"));
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_skips_inactive_webhook() {
+        let delivery = dispatch(Ok(200), false, 3).await;
+
+        assert_eq!(delivery.status, WebhookDeliveryStatus::DeadLettered);
+        assert_eq!(delivery.attempts, 0);
+    }
+}
@@ -0,0 +1,48 @@
+use crate::application::dtos::TaskEventMessageDto;
+use crate::domain::entities::WebhookDelivery;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::events::TaskEvent;
+use crate::domain::repositories::{WebhookDeliveryRepository, WebhookRepository};
+use std::sync::Arc;
+
+/// Caso de uso: Enfileirar entregas de um evento para os webhooks inscritos
+pub struct EnqueueWebhookDeliveriesUseCase {
+    webhook_repository: Arc<dyn WebhookRepository>,
+    delivery_repository: Arc<dyn WebhookDeliveryRepository>,
+}
+
+impl EnqueueWebhookDeliveriesUseCase {
+    pub fn new(
+        webhook_repository: Arc<dyn WebhookRepository>,
+        delivery_repository: Arc<dyn WebhookDeliveryRepository>,
+    ) -> Self {
+        Self {
+            webhook_repository,
+            delivery_repository,
+        }
+    }
+
+    /// Executa o enfileiramento e retorna quantas entregas foram criadas
+    pub async fn execute(&self, event: TaskEvent) -> DomainResult<usize> {
+        let webhooks = self
+            .webhook_repository
+            .list_subscribed(&event.owner_id, event.kind)
+            .await?;
+
+        if webhooks.is_empty() {
+            return Ok(0);
+        }
+
+        // Mesmo formato das mensagens do WebSocket
+        let (event_id, kind) = (event.id, event.kind);
+        let payload = serde_json::to_string(&TaskEventMessageDto::from(event))
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        for webhook in &webhooks {
+            let delivery = WebhookDelivery::new(webhook.id, event_id, kind, payload.clone());
+            self.delivery_repository.enqueue(&delivery).await?;
+        }
+
+        Ok(webhooks.len())
+    }
+}
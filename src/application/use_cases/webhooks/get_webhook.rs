@@ -0,0 +1,32 @@
+use crate::application::dtos::WebhookResponseDto;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::WebhookRepository;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Obter webhook
+pub struct GetWebhookUseCase {
+    webhook_repository: Arc<dyn WebhookRepository>,
+}
+
+impl GetWebhookUseCase {
+    pub fn new(webhook_repository: Arc<dyn WebhookRepository>) -> Self {
+        Self { webhook_repository }
+    }
+
+    /// Executa a busca do webhook (apenas o dono o enxerga)
+    pub async fn execute(
+        &self,
+        webhook_id: Uuid,
+        user_id: Uuid,
+    ) -> DomainResult<WebhookResponseDto> {
+        let webhook = self
+            .webhook_repository
+            .find_by_id(&webhook_id)
+            .await?
+            .filter(|webhook| webhook.is_owned_by(&user_id))
+            .ok_or(DomainError::NotFound("Webhook não encontrado".to_string()))?;
+
+        Ok(webhook.into())
+    }
+}
@@ -0,0 +1,55 @@
+use crate::application::dtos::{WebhookDeliveryQueryDto, WebhookDeliveryResponseDto};
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{WebhookDeliveryRepository, WebhookRepository};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Quantidade padrão de entregas retornadas
+const DEFAULT_DELIVERY_LIMIT: i64 = 50;
+
+/// Quantidade máxima de entregas retornadas
+const MAX_DELIVERY_LIMIT: i64 = 200;
+
+/// Caso de uso: Listar o log de entregas de um webhook
+pub struct ListWebhookDeliveriesUseCase {
+    webhook_repository: Arc<dyn WebhookRepository>,
+    delivery_repository: Arc<dyn WebhookDeliveryRepository>,
+}
+
+impl ListWebhookDeliveriesUseCase {
+    pub fn new(
+        webhook_repository: Arc<dyn WebhookRepository>,
+        delivery_repository: Arc<dyn WebhookDeliveryRepository>,
+    ) -> Self {
+        Self {
+            webhook_repository,
+            delivery_repository,
+        }
+    }
+
+    /// Executa a listagem das entregas mais recentes
+    pub async fn execute(
+        &self,
+        webhook_id: Uuid,
+        user_id: Uuid,
+        query: WebhookDeliveryQueryDto,
+    ) -> DomainResult<Vec<WebhookDeliveryResponseDto>> {
+        self.webhook_repository
+            .find_by_id(&webhook_id)
+            .await?
+            .filter(|webhook| webhook.is_owned_by(&user_id))
+            .ok_or(DomainError::NotFound("Webhook não encontrado".to_string()))?;
+
+        let limit = query
+            .limit
+            .unwrap_or(DEFAULT_DELIVERY_LIMIT)
+            .clamp(1, MAX_DELIVERY_LIMIT);
+
+        let deliveries = self
+            .delivery_repository
+            .list_by_webhook(&webhook_id, limit)
+            .await?;
+
+        Ok(deliveries.into_iter().map(Into::into).collect())
+    }
+}
@@ -0,0 +1,23 @@
+use crate::application::dtos::WebhookResponseDto;
+use crate::domain::errors::DomainResult;
+use crate::domain::repositories::WebhookRepository;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Listar webhooks do usuário
+pub struct ListWebhooksUseCase {
+    webhook_repository: Arc<dyn WebhookRepository>,
+}
+
+impl ListWebhooksUseCase {
+    pub fn new(webhook_repository: Arc<dyn WebhookRepository>) -> Self {
+        Self { webhook_repository }
+    }
+
+    /// Executa a listagem de webhooks
+    pub async fn execute(&self, user_id: Uuid) -> DomainResult<Vec<WebhookResponseDto>> {
+        let webhooks = self.webhook_repository.list_by_user(&user_id).await?;
+
+        Ok(webhooks.into_iter().map(Into::into).collect())
+    }
+}
@@ -0,0 +1,17 @@
+pub mod create_webhook;
+pub mod delete_webhook;
+pub mod dispatch_webhook_deliveries;
+pub mod enqueue_webhook_deliveries;
+pub mod get_webhook;
+pub mod list_webhook_deliveries;
+pub mod list_webhooks;
+pub mod update_webhook;
+
+pub use create_webhook::CreateWebhookUseCase;
+pub use delete_webhook::DeleteWebhookUseCase;
+pub use dispatch_webhook_deliveries::DispatchWebhookDeliveriesUseCase;
+pub use enqueue_webhook_deliveries::EnqueueWebhookDeliveriesUseCase;
+pub use get_webhook::GetWebhookUseCase;
+pub use list_webhook_deliveries::ListWebhookDeliveriesUseCase;
+pub use list_webhooks::ListWebhooksUseCase;
+pub use update_webhook::UpdateWebhookUseCase;
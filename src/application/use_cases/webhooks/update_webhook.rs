@@ -0,0 +1,63 @@
+use crate::application::dtos::{UpdateWebhookDto, WebhookResponseDto};
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{WebhookClient, WebhookRepository};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Atualizar webhook
+pub struct UpdateWebhookUseCase {
+    webhook_repository: Arc<dyn WebhookRepository>,
+    webhook_client: Arc<dyn WebhookClient>,
+}
+
+impl UpdateWebhookUseCase {
+    pub fn new(
+        webhook_repository: Arc<dyn WebhookRepository>,
+        webhook_client: Arc<dyn WebhookClient>,
+    ) -> Self {
+        Self {
+            webhook_repository,
+            webhook_client,
+        }
+    }
+
+    /// Executa a atualização do webhook
+    pub async fn execute(
+        &self,
+        webhook_id: Uuid,
+        user_id: Uuid,
+        dto: UpdateWebhookDto,
+    ) -> DomainResult<WebhookResponseDto> {
+        let mut webhook = self
+            .webhook_repository
+            .find_by_id(&webhook_id)
+            .await?
+            .filter(|webhook| webhook.is_owned_by(&user_id))
+            .ok_or(DomainError::NotFound("Webhook não encontrado".to_string()))?;
+
+        if let Some(url) = dto.url {
+            webhook.update_url(url)?;
+
+            // Rejeitar destinos internos (SSRF); revalidado a cada entrega
+            self.webhook_client
+                .ensure_allowed_destination(&webhook.url)
+                .await?;
+        }
+
+        if let Some(secret) = dto.secret {
+            webhook.update_secret(secret)?;
+        }
+
+        if let Some(event_types) = dto.event_types {
+            webhook.update_event_types(event_types)?;
+        }
+
+        if let Some(active) = dto.active {
+            webhook.set_active(active);
+        }
+
+        let updated = self.webhook_repository.update(&webhook).await?;
+
+        Ok(updated.into())
+    }
+}
@@ -0,0 +1,133 @@
+use crate::application::dtos::{CreateWorkflowDto, WorkflowResponseDto};
+use crate::domain::entities::Workflow;
+use crate::domain::errors::DomainResult;
+use crate::domain::repositories::WorkflowRepository;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Criar workflow com estados e transições
+pub struct CreateWorkflowUseCase {
+    workflow_repository: Arc<dyn WorkflowRepository>,
+}
+
+impl CreateWorkflowUseCase {
+    pub fn new(workflow_repository: Arc<dyn WorkflowRepository>) -> Self {
+        Self {
+            workflow_repository,
+        }
+    }
+
+    /// Executa a criação do workflow
+    pub async fn execute(
+        &self,
+        workspace_id: Uuid,
+        dto: CreateWorkflowDto,
+    ) -> DomainResult<WorkflowResponseDto> {
+        let workflow = Workflow::new(
+            workspace_id,
+            &dto.name,
+            dto.states
+                .into_iter()
+                .map(|state| (state.name, state.category))
+                .collect(),
+            dto.transitions
+                .into_iter()
+                .map(|transition| (transition.from, transition.to))
+                .collect(),
+        )?;
+
+        let saved = self.workflow_repository.create(&workflow).await?;
+
+        Ok(saved.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::dtos::{WorkflowStateDto, WorkflowTransitionDto};
+    use crate::domain::errors::DomainError;
+    use crate::domain::value_objects::TaskStatus;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    // Mock repository de workflows em memória
+    #[derive(Default)]
+    struct MockWorkflowRepository {
+        workflows: Mutex<Vec<Workflow>>,
+    }
+
+    #[async_trait]
+    impl WorkflowRepository for MockWorkflowRepository {
+        async fn create(&self, workflow: &Workflow) -> DomainResult<Workflow> {
+            self.workflows.lock().unwrap().push(workflow.clone());
+            Ok(workflow.clone())
+        }
+
+        async fn find_by_id(&self, _id: &Uuid) -> DomainResult<Option<Workflow>> {
+            unimplemented!()
+        }
+
+        async fn find_by_state(&self, _state_id: &Uuid) -> DomainResult<Option<Workflow>> {
+            unimplemented!()
+        }
+
+        async fn list_by_workspace(&self, _workspace_id: &Uuid) -> DomainResult<Vec<Workflow>> {
+            unimplemented!()
+        }
+
+        async fn delete(&self, _id: &Uuid) -> DomainResult<()> {
+            unimplemented!()
+        }
+    }
+
+    fn dto(transitions: &[(&str, &str)]) -> CreateWorkflowDto {
+        CreateWorkflowDto {
+            name: "Suporte".to_string(),
+            states: [
+                ("Novo", TaskStatus::Pending),
+                ("Atendendo", TaskStatus::InProgress),
+                ("Resolvido", TaskStatus::Completed),
+                ("Descartado", TaskStatus::Cancelled),
+            ]
+            .into_iter()
+            .map(|(name, category)| WorkflowStateDto {
+                name: name.to_string(),
+                category,
+            })
+            .collect(),
+            transitions: transitions
+                .iter()
+                .map(|(from, to)| WorkflowTransitionDto {
+                    from: from.to_string(),
+                    to: to.to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_workflow_resolves_transitions_by_name() {
+        let repo = Arc::new(MockWorkflowRepository::default());
+        let use_case = CreateWorkflowUseCase::new(repo.clone());
+
+        let response = use_case
+            .execute(
+                Uuid::new_v4(),
+                dto(&[("Novo", "Atendendo"), ("Atendendo", "Resolvido")]),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.states.len(), 4);
+        assert_eq!(response.transitions.len(), 2);
+        assert_eq!(response.transitions[0].from_state_id, response.states[0].id);
+        assert_eq!(response.transitions[0].to_state_id, response.states[1].id);
+
+        let unknown = use_case
+            .execute(Uuid::new_v4(), dto(&[("Novo", "Arquivado")]))
+            .await;
+        assert!(matches!(unknown, Err(DomainError::ValidationError(_))));
+        assert_eq!(repo.workflows.lock().unwrap().len(), 1);
+    }
+}
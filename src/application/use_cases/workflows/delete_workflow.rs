@@ -0,0 +1,29 @@
+use crate::application::services::WorkflowService;
+use crate::domain::errors::DomainResult;
+use crate::domain::repositories::WorkflowRepository;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Deletar workflow (projetos e tarefas voltam aos status padrão)
+pub struct DeleteWorkflowUseCase {
+    workflow_repository: Arc<dyn WorkflowRepository>,
+    workflow_service: WorkflowService,
+}
+
+impl DeleteWorkflowUseCase {
+    pub fn new(workflow_repository: Arc<dyn WorkflowRepository>) -> Self {
+        Self {
+            workflow_service: WorkflowService::new(workflow_repository.clone()),
+            workflow_repository,
+        }
+    }
+
+    /// Executa a remoção do workflow
+    pub async fn execute(&self, workflow_id: Uuid, workspace_id: Uuid) -> DomainResult<()> {
+        self.workflow_service
+            .find_in_workspace(&workflow_id, &workspace_id)
+            .await?;
+
+        self.workflow_repository.delete(&workflow_id).await
+    }
+}
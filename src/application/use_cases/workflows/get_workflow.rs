@@ -0,0 +1,33 @@
+use crate::application::dtos::WorkflowResponseDto;
+use crate::application::services::WorkflowService;
+use crate::domain::errors::DomainResult;
+use crate::domain::repositories::WorkflowRepository;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Obter workflow com seus estados e transições
+pub struct GetWorkflowUseCase {
+    workflow_service: WorkflowService,
+}
+
+impl GetWorkflowUseCase {
+    pub fn new(workflow_repository: Arc<dyn WorkflowRepository>) -> Self {
+        Self {
+            workflow_service: WorkflowService::new(workflow_repository),
+        }
+    }
+
+    /// Executa a busca do workflow
+    pub async fn execute(
+        &self,
+        workflow_id: Uuid,
+        workspace_id: Uuid,
+    ) -> DomainResult<WorkflowResponseDto> {
+        let workflow = self
+            .workflow_service
+            .find_in_workspace(&workflow_id, &workspace_id)
+            .await?;
+
+        Ok(workflow.into())
+    }
+}
@@ -0,0 +1,28 @@
+use crate::application::dtos::WorkflowResponseDto;
+use crate::domain::errors::DomainResult;
+use crate::domain::repositories::WorkflowRepository;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Listar workflows do workspace
+pub struct ListWorkflowsUseCase {
+    workflow_repository: Arc<dyn WorkflowRepository>,
+}
+
+impl ListWorkflowsUseCase {
+    pub fn new(workflow_repository: Arc<dyn WorkflowRepository>) -> Self {
+        Self {
+            workflow_repository,
+        }
+    }
+
+    /// Executa a listagem de workflows
+    pub async fn execute(&self, workspace_id: Uuid) -> DomainResult<Vec<WorkflowResponseDto>> {
+        let workflows = self
+            .workflow_repository
+            .list_by_workspace(&workspace_id)
+            .await?;
+
+        Ok(workflows.into_iter().map(Into::into).collect())
+    }
+}
@@ -0,0 +1,9 @@
+pub mod create_workflow;
+pub mod delete_workflow;
+pub mod get_workflow;
+pub mod list_workflows;
+
+pub use create_workflow::CreateWorkflowUseCase;
+pub use delete_workflow::DeleteWorkflowUseCase;
+pub use get_workflow::GetWorkflowUseCase;
+pub use list_workflows::ListWorkflowsUseCase;
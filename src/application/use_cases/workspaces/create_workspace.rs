@@ -0,0 +1,33 @@
+use crate::application::dtos::{CreateWorkspaceDto, WorkspaceResponseDto};
+use crate::domain::entities::{Membership, Workspace};
+use crate::domain::errors::DomainResult;
+use crate::domain::repositories::WorkspaceRepository;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Criar workspace (o criador se torna owner)
+pub struct CreateWorkspaceUseCase {
+    workspace_repository: Arc<dyn WorkspaceRepository>,
+}
+
+impl CreateWorkspaceUseCase {
+    pub fn new(workspace_repository: Arc<dyn WorkspaceRepository>) -> Self {
+        Self {
+            workspace_repository,
+        }
+    }
+
+    /// Executa a criação do workspace
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        dto: CreateWorkspaceDto,
+    ) -> DomainResult<WorkspaceResponseDto> {
+        let workspace = Workspace::new(&dto.name)?;
+        let owner = Membership::owner(workspace.id, user_id);
+
+        let saved = self.workspace_repository.create(&workspace, &owner).await?;
+
+        Ok(WorkspaceResponseDto::new(saved, &owner))
+    }
+}
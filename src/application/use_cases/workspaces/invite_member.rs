@@ -0,0 +1,254 @@
+use crate::application::dtos::{InviteMemberDto, MemberResponseDto};
+use crate::domain::entities::Membership;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{UserRepository, WorkspaceRepository};
+use crate::domain::value_objects::Email;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Convidar um usuário para o workspace
+pub struct InviteMemberUseCase {
+    workspace_repository: Arc<dyn WorkspaceRepository>,
+    user_repository: Arc<dyn UserRepository>,
+}
+
+impl InviteMemberUseCase {
+    pub fn new(
+        workspace_repository: Arc<dyn WorkspaceRepository>,
+        user_repository: Arc<dyn UserRepository>,
+    ) -> Self {
+        Self {
+            workspace_repository,
+            user_repository,
+        }
+    }
+
+    /// Executa o convite (apenas admins e owners do workspace podem convidar)
+    pub async fn execute(
+        &self,
+        workspace_id: Uuid,
+        user_id: Uuid,
+        dto: InviteMemberDto,
+    ) -> DomainResult<MemberResponseDto> {
+        let inviter = self
+            .workspace_repository
+            .find_membership(&workspace_id, &user_id)
+            .await?
+            .ok_or(DomainError::NotFound(
+                "Workspace não encontrado".to_string(),
+            ))?;
+
+        if !inviter.role.can_invite() {
+            return Err(DomainError::Forbidden(
+                "Apenas administradores do workspace podem convidar membros".to_string(),
+            ));
+        }
+
+        // Buscar usuário convidado
+        let email = Email::new(dto.email)?;
+        let invitee = self
+            .user_repository
+            .find_by_email(&email)
+            .await?
+            .ok_or(DomainError::NotFound("Usuário não encontrado".to_string()))?;
+
+        let membership = Membership::invited(
+            workspace_id,
+            invitee.id,
+            dto.role.unwrap_or_default(),
+            user_id,
+        )?;
+        let saved = self.workspace_repository.add_member(&membership).await?;
+
+        Ok(MemberResponseDto {
+            user_id: saved.user_id,
+            email: invitee.email.into_string(),
+            name: invitee.name,
+            role: saved.role,
+            invited_by: saved.invited_by,
+            created_at: saved.created_at,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{User, Workspace};
+    use crate::domain::value_objects::WorkspaceRole;
+    use crate::test_support::MockUserRepository;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    // Mock repository de workspaces em memória
+    #[derive(Default)]
+    struct MockWorkspaceRepository {
+        members: Mutex<Vec<Membership>>,
+    }
+
+    #[async_trait]
+    impl WorkspaceRepository for MockWorkspaceRepository {
+        async fn create(
+            &self,
+            workspace: &Workspace,
+            owner: &Membership,
+        ) -> DomainResult<Workspace> {
+            self.members.lock().unwrap().push(owner.clone());
+            Ok(workspace.clone())
+        }
+
+        async fn find_by_id(&self, _id: &Uuid) -> DomainResult<Option<Workspace>> {
+            unimplemented!()
+        }
+
+        async fn list_by_user(
+            &self,
+            _user_id: &Uuid,
+        ) -> DomainResult<Vec<(Workspace, Membership)>> {
+            unimplemented!()
+        }
+
+        async fn find_default_for_user(&self, _user_id: &Uuid) -> DomainResult<Option<Uuid>> {
+            unimplemented!()
+        }
+
+        async fn find_membership(
+            &self,
+            workspace_id: &Uuid,
+            user_id: &Uuid,
+        ) -> DomainResult<Option<Membership>> {
+            let members = self.members.lock().unwrap();
+            Ok(members
+                .iter()
+                .find(|m| &m.workspace_id == workspace_id && &m.user_id == user_id)
+                .cloned())
+        }
+
+        async fn add_member(&self, membership: &Membership) -> DomainResult<Membership> {
+            let mut members = self.members.lock().unwrap();
+            if members.iter().any(|m| {
+                m.workspace_id == membership.workspace_id && m.user_id == membership.user_id
+            }) {
+                return Err(DomainError::Conflict(
+                    "Usuário já é membro do workspace".to_string(),
+                ));
+            }
+            members.push(membership.clone());
+            Ok(membership.clone())
+        }
+
+        async fn list_members(&self, _workspace_id: &Uuid) -> DomainResult<Vec<Membership>> {
+            unimplemented!()
+        }
+    }
+
+    struct Fixture {
+        workspaces: Arc<MockWorkspaceRepository>,
+        use_case: InviteMemberUseCase,
+        workspace_id: Uuid,
+        owner_id: Uuid,
+        invitee_id: Uuid,
+    }
+
+    fn fixture() -> Fixture {
+        let workspaces = Arc::new(MockWorkspaceRepository::default());
+        let workspace_id = Uuid::new_v4();
+        let owner_id = Uuid::new_v4();
+        let invitee = User::new(
+            Uuid::new_v4(),
+            Email::new("bruno@example.com").unwrap(),
+            "hash".to_string(),
+            "Bruno".to_string(),
+        );
+        let invitee_id = invitee.id;
+        workspaces
+            .members
+            .lock()
+            .unwrap()
+            .push(Membership::owner(workspace_id, owner_id));
+
+        Fixture {
+            use_case: InviteMemberUseCase::new(
+                workspaces.clone(),
+                Arc::new(MockUserRepository::new(vec![invitee])),
+            ),
+            workspaces,
+            workspace_id,
+            owner_id,
+            invitee_id,
+        }
+    }
+
+    fn invite(role: Option<WorkspaceRole>) -> InviteMemberDto {
+        InviteMemberDto {
+            email: "bruno@example.com".to_string(),
+            role,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_owner_invites_member() {
+        let fixture = fixture();
+
+        let member = fixture
+            .use_case
+            .execute(fixture.workspace_id, fixture.owner_id, invite(None))
+            .await
+            .unwrap();
+
+        assert_eq!(member.user_id, fixture.invitee_id);
+        assert_eq!(member.role, WorkspaceRole::Member);
+        assert_eq!(member.invited_by, Some(fixture.owner_id));
+
+        // Convidar de novo é conflito
+        let again = fixture
+            .use_case
+            .execute(fixture.workspace_id, fixture.owner_id, invite(None))
+            .await;
+        assert!(matches!(again, Err(DomainError::Conflict(_))));
+    }
+
+    #[tokio::test]
+    async fn test_member_cannot_invite() {
+        let fixture = fixture();
+        let member_id = Uuid::new_v4();
+        fixture.workspaces.members.lock().unwrap().push(
+            Membership::invited(
+                fixture.workspace_id,
+                member_id,
+                WorkspaceRole::Member,
+                fixture.owner_id,
+            )
+            .unwrap(),
+        );
+
+        let result = fixture
+            .use_case
+            .execute(fixture.workspace_id, member_id, invite(None))
+            .await;
+        assert!(matches!(result, Err(DomainError::Forbidden(_))));
+
+        // Quem não é membro nem enxerga o workspace
+        let outsider = fixture
+            .use_case
+            .execute(fixture.workspace_id, Uuid::new_v4(), invite(None))
+            .await;
+        assert!(matches!(outsider, Err(DomainError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_cannot_invite_as_owner() {
+        let fixture = fixture();
+
+        let result = fixture
+            .use_case
+            .execute(
+                fixture.workspace_id,
+                fixture.owner_id,
+                invite(Some(WorkspaceRole::Owner)),
+            )
+            .await;
+
+        assert!(matches!(result, Err(DomainError::ValidationError(_))));
+    }
+}
@@ -0,0 +1,60 @@
+use crate::application::dtos::MemberResponseDto;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{UserRepository, WorkspaceRepository};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Listar os membros de um workspace
+pub struct ListMembersUseCase {
+    workspace_repository: Arc<dyn WorkspaceRepository>,
+    user_repository: Arc<dyn UserRepository>,
+}
+
+impl ListMembersUseCase {
+    pub fn new(
+        workspace_repository: Arc<dyn WorkspaceRepository>,
+        user_repository: Arc<dyn UserRepository>,
+    ) -> Self {
+        Self {
+            workspace_repository,
+            user_repository,
+        }
+    }
+
+    /// Executa a listagem (apenas para membros do workspace)
+    pub async fn execute(
+        &self,
+        workspace_id: Uuid,
+        user_id: Uuid,
+    ) -> DomainResult<Vec<MemberResponseDto>> {
+        self.workspace_repository
+            .find_membership(&workspace_id, &user_id)
+            .await?
+            .ok_or(DomainError::NotFound(
+                "Workspace não encontrado".to_string(),
+            ))?;
+
+        let members = self
+            .workspace_repository
+            .list_members(&workspace_id)
+            .await?;
+
+        let mut response = Vec::with_capacity(members.len());
+        for member in members {
+            let Some(user) = self.user_repository.find_by_id(&member.user_id).await? else {
+                continue;
+            };
+
+            response.push(MemberResponseDto {
+                user_id: member.user_id,
+                email: user.email.into_string(),
+                name: user.name,
+                role: member.role,
+                invited_by: member.invited_by,
+                created_at: member.created_at,
+            });
+        }
+
+        Ok(response)
+    }
+}
@@ -0,0 +1,28 @@
+use crate::application::dtos::WorkspaceResponseDto;
+use crate::domain::errors::DomainResult;
+use crate::domain::repositories::WorkspaceRepository;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Listar os workspaces do usuário
+pub struct ListWorkspacesUseCase {
+    workspace_repository: Arc<dyn WorkspaceRepository>,
+}
+
+impl ListWorkspacesUseCase {
+    pub fn new(workspace_repository: Arc<dyn WorkspaceRepository>) -> Self {
+        Self {
+            workspace_repository,
+        }
+    }
+
+    /// Executa a listagem
+    pub async fn execute(&self, user_id: Uuid) -> DomainResult<Vec<WorkspaceResponseDto>> {
+        let workspaces = self.workspace_repository.list_by_user(&user_id).await?;
+
+        Ok(workspaces
+            .into_iter()
+            .map(|(workspace, membership)| WorkspaceResponseDto::new(workspace, &membership))
+            .collect())
+    }
+}
@@ -0,0 +1,11 @@
+pub mod create_workspace;
+pub mod invite_member;
+pub mod list_members;
+pub mod list_workspaces;
+pub mod switch_workspace;
+
+pub use create_workspace::CreateWorkspaceUseCase;
+pub use invite_member::InviteMemberUseCase;
+pub use list_members::ListMembersUseCase;
+pub use list_workspaces::ListWorkspacesUseCase;
+pub use switch_workspace::SwitchWorkspaceUseCase;
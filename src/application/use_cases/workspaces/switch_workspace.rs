@@ -0,0 +1,66 @@
+use crate::application::dtos::{ClientInfoDto, RefreshTokenDto, TokenResponseDto};
+use crate::application::services::SessionService;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::WorkspaceRepository;
+use crate::infrastructure::security::{Claims, TokenRevocationService};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Caso de uso: Trocar o workspace ativo da sessão
+pub struct SwitchWorkspaceUseCase {
+    workspace_repository: Arc<dyn WorkspaceRepository>,
+    session_service: SessionService,
+    token_revocation: Arc<TokenRevocationService>,
+}
+
+impl SwitchWorkspaceUseCase {
+    pub fn new(
+        workspace_repository: Arc<dyn WorkspaceRepository>,
+        session_service: SessionService,
+        token_revocation: Arc<TokenRevocationService>,
+    ) -> Self {
+        Self {
+            workspace_repository,
+            session_service,
+            token_revocation,
+        }
+    }
+
+    /// Executa a troca
+    ///
+    /// O refresh token da sessão é rotacionado para o novo workspace e o
+    /// access token atual é revogado, já que carrega o workspace anterior.
+    pub async fn execute(
+        &self,
+        workspace_id: Uuid,
+        access_token: Claims,
+        dto: RefreshTokenDto,
+        client: ClientInfoDto,
+    ) -> DomainResult<TokenResponseDto> {
+        let user_id = access_token
+            .user_id()
+            .map_err(|_| DomainError::Unauthorized)?;
+
+        self.workspace_repository
+            .find_membership(&workspace_id, &user_id)
+            .await?
+            .ok_or(DomainError::NotFound(
+                "Workspace não encontrado".to_string(),
+            ))?;
+
+        let session = self.session_service.redeem(&dto.refresh_token).await?;
+
+        if session.user_id != user_id {
+            return Err(DomainError::Unauthorized);
+        }
+
+        let tokens = self
+            .session_service
+            .switch(&session, workspace_id, client)
+            .await?;
+
+        self.token_revocation.revoke(&access_token).await?;
+
+        Ok(tokens)
+    }
+}
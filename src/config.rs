@@ -1,4 +1,12 @@
+use crate::application::services::SessionTtl;
+use crate::domain::value_objects::{
+    AttachmentQuota, EmailVerificationPolicy, LockoutPolicy, ReopenPolicy, SubtaskCompletionPolicy,
+    WebhookDestinationPolicy, WebhookRetryPolicy,
+};
+use crate::infrastructure::mail::{MailTransport, SmtpSecurity, SmtpSettings};
+use crate::infrastructure::security::{JwtKeys, TrustedProxies};
 use anyhow::{Context, Result};
+use jsonwebtoken::Algorithm;
 use serde::Deserialize;
 
 /// Configuração da aplicação carregada de variáveis de ambiente
@@ -7,17 +15,125 @@ pub struct Config {
     /// URL de conexão com o banco de dados PostgreSQL
     pub database_url: String,
 
-    /// Chave secreta para assinar tokens JWT
+    /// Chave secreta para assinar tokens JWT (HS256)
     pub jwt_secret: String,
 
+    /// Algoritmo de assinatura dos tokens (HS256, RS256 ou EdDSA)
+    pub jwt_algorithm: Algorithm,
+
+    /// Caminho da chave privada PEM (RS256/EdDSA)
+    pub jwt_private_key_path: Option<String>,
+
+    /// Caminho da chave pública PEM do par de assinatura (RS256/EdDSA)
+    pub jwt_public_key_path: Option<String>,
+
+    /// Chaves públicas PEM de rotações anteriores, ainda aceitas na verificação
+    pub jwt_previous_public_key_paths: Vec<String>,
+
     /// Tempo de expiração do token JWT em horas
     pub jwt_expiration_hours: u64,
 
+    /// Validade do access token de uma sessão em minutos
+    pub access_token_ttl_minutes: u64,
+
+    /// Validade do refresh token de uma sessão em dias
+    pub refresh_token_ttl_days: u64,
+
+    /// Tempo de cache das consultas de revogação de tokens em segundos
+    pub token_revocation_cache_secs: u64,
+
+    /// Validade dos tokens de redefinição de senha em minutos
+    pub password_reset_ttl_minutes: u64,
+
+    /// URL pública usada nos links enviados por email
+    pub app_base_url: String,
+
+    /// Restrições aplicadas a contas com email não confirmado
+    pub email_verification_policy: EmailVerificationPolicy,
+
+    /// Validade dos tokens de verificação de email em horas
+    pub email_verification_ttl_hours: u64,
+
+    /// Emissor exibido nos apps autenticadores (2FA)
+    pub totp_issuer: String,
+
+    /// Falhas de login por conta antes do bloqueio temporário
+    pub login_max_failures: u32,
+
+    /// Falhas de login por IP antes do bloqueio temporário
+    pub login_max_failures_per_ip: u32,
+
+    /// Janela de contagem das falhas de login em minutos
+    pub login_failure_window_minutes: u64,
+
+    /// Bloqueio inicial em minutos (dobra a cada nova falha, até a janela)
+    pub login_lockout_minutes: u64,
+
+    /// Atraso da resposta após a primeira falha em milissegundos (dobra a cada falha)
+    pub login_delay_ms: u64,
+
+    /// Remetente dos emails (ex: Todo API <no-reply@exemplo.com>)
+    pub mail_from: String,
+
+    /// Transporte de envio de emails (log ou smtp)
+    pub mail_transport: MailTransport,
+
+    /// Diretório onde o transporte `log` grava os emails
+    pub mail_outbox_dir: String,
+
+    /// Host do servidor SMTP
+    pub smtp_host: Option<String>,
+
+    /// Porta do servidor SMTP
+    pub smtp_port: u16,
+
+    /// Usuário SMTP (opcional)
+    pub smtp_username: Option<String>,
+
+    /// Senha SMTP (opcional)
+    pub smtp_password: Option<String>,
+
+    /// Segurança da conexão SMTP (starttls, tls ou none)
+    pub smtp_security: SmtpSecurity,
+
+    /// Timeout do envio de cada email em segundos
+    pub smtp_timeout_secs: u64,
+
     /// Host do servidor (ex: 0.0.0.0 ou 127.0.0.1)
     pub server_host: String,
 
     /// Porta do servidor (ex: 8080)
     pub server_port: u16,
+
+    /// Proxies reversos cujo `X-Forwarded-For` é considerado
+    pub trusted_proxies: TrustedProxies,
+
+    /// Política para concluir tarefas com subtarefas em aberto
+    pub subtask_completion_policy: SubtaskCompletionPolicy,
+
+    /// Política para reabrir tarefas concluídas ou canceladas
+    pub reopen_policy: ReopenPolicy,
+
+    /// Diretório onde o conteúdo dos anexos é armazenado
+    pub attachment_storage_dir: String,
+
+    /// Tamanho máximo de um anexo em bytes
+    pub attachment_max_bytes: u64,
+
+    /// Cota total de anexos por usuário em bytes
+    pub attachment_quota_bytes: u64,
+
+    /// Tentativas de entrega de um webhook antes do dead letter
+    pub webhook_max_attempts: u32,
+
+    /// Timeout de cada requisição de webhook em segundos
+    pub webhook_timeout_secs: u64,
+
+    /// Hosts liberados como destino de webhooks mesmo resolvendo para endereços internos
+    pub webhook_allowed_hosts: Vec<String>,
+
+    /// Retenção dos arquivos de exportação de dados em horas
+    pub data_export_ttl_hours: u64,
 }
 
 impl Config {
@@ -25,12 +141,45 @@ impl Config {
     ///
     /// # Variáveis Obrigatórias
     /// - `DATABASE_URL`: String de conexão PostgreSQL
-    /// - `JWT_SECRET`: Chave secreta para JWT
+    /// - `JWT_SECRET`: Chave secreta para JWT (apenas com `JWT_ALGORITHM=HS256`)
     ///
     /// # Variáveis Opcionais (com valores padrão)
+    /// - `JWT_ALGORITHM`: `HS256`, `RS256` ou `EdDSA` (padrão: HS256)
+    /// - `JWT_PRIVATE_KEY_PATH` / `JWT_PUBLIC_KEY_PATH`: Par de chaves PEM (RS256/EdDSA)
+    /// - `JWT_PREVIOUS_PUBLIC_KEY_PATHS`: Chaves públicas anteriores, separadas por vírgula
     /// - `JWT_EXPIRATION_HOURS`: Tempo de expiração em horas (padrão: 24)
+    /// - `ACCESS_TOKEN_TTL_MINUTES`: Validade do access token (padrão: 15)
+    /// - `REFRESH_TOKEN_TTL_DAYS`: Validade do refresh token (padrão: 30)
+    /// - `TOKEN_REVOCATION_CACHE_SECS`: Cache das consultas de revogação (padrão: 30)
+    /// - `PASSWORD_RESET_TTL_MINUTES`: Validade do token de redefinição (padrão: 60)
+    /// - `APP_BASE_URL`: URL pública dos links de email (padrão: http://localhost:8080)
+    /// - `EMAIL_VERIFICATION_POLICY`: `optional`, `block_login` ou `block_task_creation` (padrão: optional)
+    /// - `EMAIL_VERIFICATION_TTL_HOURS`: Validade do token de verificação (padrão: 48)
+    /// - `TOTP_ISSUER`: Emissor exibido nos apps autenticadores (padrão: Todo API)
+    /// - `LOGIN_MAX_FAILURES`: Falhas por conta antes do bloqueio (padrão: 5)
+    /// - `LOGIN_MAX_FAILURES_PER_IP`: Falhas por IP antes do bloqueio (padrão: 50)
+    /// - `LOGIN_FAILURE_WINDOW_MINUTES`: Janela de contagem das falhas (padrão: 60)
+    /// - `LOGIN_LOCKOUT_MINUTES`: Bloqueio inicial, dobrado a cada nova falha (padrão: 15)
+    /// - `LOGIN_DELAY_MS`: Atraso progressivo após falhas; 0 desativa (padrão: 250)
+    /// - `MAIL_FROM`: Remetente dos emails (padrão: Todo API <no-reply@localhost>)
+    /// - `MAIL_TRANSPORT`: `log` ou `smtp` (padrão: log)
+    /// - `MAIL_OUTBOX_DIR`: Diretório dos emails do transporte `log` (padrão: ./data/outbox)
+    /// - `SMTP_HOST` / `SMTP_PORT`: Servidor SMTP (obrigatório com `smtp`; porta padrão: 587)
+    /// - `SMTP_USERNAME` / `SMTP_PASSWORD`: Credenciais SMTP (AUTH PLAIN)
+    /// - `SMTP_SECURITY`: `starttls`, `tls` ou `none` (padrão: starttls)
+    /// - `SMTP_TIMEOUT_SECS`: Timeout do envio de cada email (padrão: 30)
     /// - `SERVER_HOST`: Host do servidor (padrão: 0.0.0.0)
     /// - `SERVER_PORT`: Porta do servidor (padrão: 8080)
+    /// - `TRUSTED_PROXIES`: IPs ou faixas CIDR de proxies confiáveis, separados por vírgula (padrão: nenhum)
+    /// - `SUBTASK_COMPLETION_POLICY`: `require_closed` ou `allow_open` (padrão: require_closed)
+    /// - `TASK_REOPEN_POLICY`: `all`, `completed_only`, `cancelled_only` ou `disabled` (padrão: all)
+    /// - `ATTACHMENT_STORAGE_DIR`: Diretório dos anexos (padrão: ./data/attachments)
+    /// - `ATTACHMENT_MAX_BYTES`: Tamanho máximo de um anexo (padrão: 10 MiB)
+    /// - `ATTACHMENT_QUOTA_BYTES`: Cota de anexos por usuário (padrão: 100 MiB)
+    /// - `WEBHOOK_MAX_ATTEMPTS`: Tentativas de entrega de webhook (padrão: 8)
+    /// - `WEBHOOK_TIMEOUT_SECS`: Timeout das requisições de webhook (padrão: 10)
+    /// - `WEBHOOK_ALLOWED_HOSTS`: Hosts internos liberados para webhooks, separados por vírgula (padrão: nenhum)
+    /// - `DATA_EXPORT_TTL_HOURS`: Retenção das exportações de dados (padrão: 168)
     ///
     /// # Exemplo
     ///
@@ -47,29 +196,302 @@ impl Config {
         // Carrega o arquivo .env se existir (ignora erro se não existir)
         dotenv::dotenv().ok();
 
+        let jwt_algorithm: Algorithm = std::env::var("JWT_ALGORITHM")
+            .unwrap_or_else(|_| "HS256".to_string())
+            .parse()
+            .context("JWT_ALGORITHM deve ser 'HS256', 'RS256' ou 'EdDSA'")?;
+
+        // O segredo compartilhado só é necessário com HS256
+        let jwt_secret = match jwt_algorithm {
+            Algorithm::HS256 => std::env::var("JWT_SECRET").context(
+                "JWT_SECRET deve estar definida no arquivo .env ou nas variáveis de ambiente",
+            )?,
+            _ => std::env::var("JWT_SECRET").unwrap_or_default(),
+        };
+
         Ok(Self {
             database_url: std::env::var("DATABASE_URL").context(
                 "DATABASE_URL deve estar definida no arquivo .env ou nas variáveis de ambiente",
             )?,
 
-            jwt_secret: std::env::var("JWT_SECRET").context(
-                "JWT_SECRET deve estar definida no arquivo .env ou nas variáveis de ambiente",
-            )?,
+            jwt_secret,
+
+            jwt_algorithm,
+
+            jwt_private_key_path: std::env::var("JWT_PRIVATE_KEY_PATH").ok(),
+
+            jwt_public_key_path: std::env::var("JWT_PUBLIC_KEY_PATH").ok(),
+
+            jwt_previous_public_key_paths: std::env::var("JWT_PREVIOUS_PUBLIC_KEY_PATHS")
+                .map(|paths| {
+                    paths
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|path| !path.is_empty())
+                        .map(ToString::to_string)
+                        .collect()
+                })
+                .unwrap_or_default(),
 
             jwt_expiration_hours: std::env::var("JWT_EXPIRATION_HOURS")
                 .unwrap_or_else(|_| "24".to_string())
                 .parse()
                 .context("JWT_EXPIRATION_HOURS deve ser um número válido")?,
 
+            access_token_ttl_minutes: std::env::var("ACCESS_TOKEN_TTL_MINUTES")
+                .unwrap_or_else(|_| "15".to_string())
+                .parse()
+                .context("ACCESS_TOKEN_TTL_MINUTES deve ser um número válido")?,
+
+            refresh_token_ttl_days: std::env::var("REFRESH_TOKEN_TTL_DAYS")
+                .unwrap_or_else(|_| "30".to_string())
+                .parse()
+                .context("REFRESH_TOKEN_TTL_DAYS deve ser um número válido")?,
+
+            token_revocation_cache_secs: std::env::var("TOKEN_REVOCATION_CACHE_SECS")
+                .unwrap_or_else(|_| "30".to_string())
+                .parse()
+                .context("TOKEN_REVOCATION_CACHE_SECS deve ser um número válido")?,
+
+            password_reset_ttl_minutes: std::env::var("PASSWORD_RESET_TTL_MINUTES")
+                .unwrap_or_else(|_| "60".to_string())
+                .parse()
+                .context("PASSWORD_RESET_TTL_MINUTES deve ser um número válido")?,
+
+            app_base_url: std::env::var("APP_BASE_URL")
+                .unwrap_or_else(|_| "http://localhost:8080".to_string()),
+
+            email_verification_policy: std::env::var("EMAIL_VERIFICATION_POLICY")
+                .unwrap_or_else(|_| "optional".to_string())
+                .parse()
+                .context(
+                    "EMAIL_VERIFICATION_POLICY deve ser 'optional', 'block_login' ou 'block_task_creation'",
+                )?,
+
+            email_verification_ttl_hours: std::env::var("EMAIL_VERIFICATION_TTL_HOURS")
+                .unwrap_or_else(|_| "48".to_string())
+                .parse()
+                .context("EMAIL_VERIFICATION_TTL_HOURS deve ser um número válido")?,
+
+            totp_issuer: std::env::var("TOTP_ISSUER").unwrap_or_else(|_| "Todo API".to_string()),
+
+            login_max_failures: std::env::var("LOGIN_MAX_FAILURES")
+                .unwrap_or_else(|_| "5".to_string())
+                .parse()
+                .context("LOGIN_MAX_FAILURES deve ser um número válido")?,
+
+            login_max_failures_per_ip: std::env::var("LOGIN_MAX_FAILURES_PER_IP")
+                .unwrap_or_else(|_| "50".to_string())
+                .parse()
+                .context("LOGIN_MAX_FAILURES_PER_IP deve ser um número válido")?,
+
+            login_failure_window_minutes: std::env::var("LOGIN_FAILURE_WINDOW_MINUTES")
+                .unwrap_or_else(|_| "60".to_string())
+                .parse()
+                .context("LOGIN_FAILURE_WINDOW_MINUTES deve ser um número válido")?,
+
+            login_lockout_minutes: std::env::var("LOGIN_LOCKOUT_MINUTES")
+                .unwrap_or_else(|_| "15".to_string())
+                .parse()
+                .context("LOGIN_LOCKOUT_MINUTES deve ser um número válido")?,
+
+            login_delay_ms: std::env::var("LOGIN_DELAY_MS")
+                .unwrap_or_else(|_| "250".to_string())
+                .parse()
+                .context("LOGIN_DELAY_MS deve ser um número válido")?,
+
+            mail_from: std::env::var("MAIL_FROM")
+                .unwrap_or_else(|_| "Todo API <no-reply@localhost>".to_string()),
+
+            mail_transport: std::env::var("MAIL_TRANSPORT")
+                .unwrap_or_else(|_| "log".to_string())
+                .parse()
+                .context("MAIL_TRANSPORT deve ser 'log' ou 'smtp'")?,
+
+            mail_outbox_dir: std::env::var("MAIL_OUTBOX_DIR")
+                .unwrap_or_else(|_| "./data/outbox".to_string()),
+
+            smtp_host: std::env::var("SMTP_HOST").ok(),
+
+            smtp_port: std::env::var("SMTP_PORT")
+                .unwrap_or_else(|_| "587".to_string())
+                .parse()
+                .context("SMTP_PORT deve ser um número válido entre 1 e 65535")?,
+
+            smtp_username: std::env::var("SMTP_USERNAME").ok(),
+
+            smtp_password: std::env::var("SMTP_PASSWORD").ok(),
+
+            smtp_security: std::env::var("SMTP_SECURITY")
+                .unwrap_or_else(|_| "starttls".to_string())
+                .parse()
+                .context("SMTP_SECURITY deve ser 'starttls', 'tls' ou 'none'")?,
+
+            smtp_timeout_secs: std::env::var("SMTP_TIMEOUT_SECS")
+                .unwrap_or_else(|_| "30".to_string())
+                .parse()
+                .context("SMTP_TIMEOUT_SECS deve ser um número válido")?,
+
             server_host: std::env::var("SERVER_HOST").unwrap_or_else(|_| "0.0.0.0".to_string()),
 
             server_port: std::env::var("SERVER_PORT")
                 .unwrap_or_else(|_| "8080".to_string())
                 .parse()
                 .context("SERVER_PORT deve ser um número válido entre 1 e 65535")?,
+
+            trusted_proxies: TrustedProxies::parse(
+                &std::env::var("TRUSTED_PROXIES")
+                    .unwrap_or_default()
+                    .split(',')
+                    .filter(|entry| !entry.trim().is_empty())
+                    .collect::<Vec<_>>(),
+            )
+            .context("TRUSTED_PROXIES deve conter IPs ou faixas CIDR válidos")?,
+
+            subtask_completion_policy: std::env::var("SUBTASK_COMPLETION_POLICY")
+                .unwrap_or_else(|_| "require_closed".to_string())
+                .parse()
+                .context("SUBTASK_COMPLETION_POLICY deve ser 'require_closed' ou 'allow_open'")?,
+
+            reopen_policy: std::env::var("TASK_REOPEN_POLICY")
+                .unwrap_or_else(|_| "all".to_string())
+                .parse()
+                .context(
+                    "TASK_REOPEN_POLICY deve ser 'all', 'completed_only', 'cancelled_only' ou 'disabled'",
+                )?,
+
+            attachment_storage_dir: std::env::var("ATTACHMENT_STORAGE_DIR")
+                .unwrap_or_else(|_| "./data/attachments".to_string()),
+
+            attachment_max_bytes: std::env::var("ATTACHMENT_MAX_BYTES")
+                .unwrap_or_else(|_| "10485760".to_string())
+                .parse()
+                .context("ATTACHMENT_MAX_BYTES deve ser um número válido")?,
+
+            attachment_quota_bytes: std::env::var("ATTACHMENT_QUOTA_BYTES")
+                .unwrap_or_else(|_| "104857600".to_string())
+                .parse()
+                .context("ATTACHMENT_QUOTA_BYTES deve ser um número válido")?,
+
+            webhook_max_attempts: std::env::var("WEBHOOK_MAX_ATTEMPTS")
+                .unwrap_or_else(|_| "8".to_string())
+                .parse()
+                .context("WEBHOOK_MAX_ATTEMPTS deve ser um número válido")?,
+
+            webhook_timeout_secs: std::env::var("WEBHOOK_TIMEOUT_SECS")
+                .unwrap_or_else(|_| "10".to_string())
+                .parse()
+                .context("WEBHOOK_TIMEOUT_SECS deve ser um número válido")?,
+
+            webhook_allowed_hosts: std::env::var("WEBHOOK_ALLOWED_HOSTS")
+                .map(|hosts| {
+                    hosts
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|host| !host.is_empty())
+                        .map(ToString::to_string)
+                        .collect()
+                })
+                .unwrap_or_default(),
+
+            data_export_ttl_hours: std::env::var("DATA_EXPORT_TTL_HOURS")
+                .unwrap_or_else(|_| "168".to_string())
+                .parse()
+                .context("DATA_EXPORT_TTL_HOURS deve ser um número válido")?,
         })
     }
 
+    /// Carrega as chaves de assinatura e verificação de JWT configuradas
+    pub fn jwt_keys(&self) -> Result<JwtKeys> {
+        if self.jwt_algorithm == Algorithm::HS256 {
+            return Ok(JwtKeys::hmac(&self.jwt_secret));
+        }
+
+        let read = |path: &str| {
+            std::fs::read(path).with_context(|| format!("Erro ao ler chave JWT em {}", path))
+        };
+
+        let private_key = read(self.jwt_private_key_path.as_deref().unwrap_or_default())?;
+        let public_key = read(self.jwt_public_key_path.as_deref().unwrap_or_default())?;
+        let previous_keys = self
+            .jwt_previous_public_key_paths
+            .iter()
+            .map(|path| read(path))
+            .collect::<Result<Vec<_>>>()?;
+
+        JwtKeys::asymmetric(
+            self.jwt_algorithm,
+            &private_key,
+            &public_key,
+            &previous_keys,
+        )
+    }
+
+    /// Validade dos tokens de sessão configurada
+    pub fn session_ttl(&self) -> SessionTtl {
+        SessionTtl {
+            access_token: chrono::Duration::minutes(self.access_token_ttl_minutes as i64),
+            refresh_token: chrono::Duration::days(self.refresh_token_ttl_days as i64),
+        }
+    }
+
+    /// Validade dos tokens de redefinição de senha
+    pub fn password_reset_ttl(&self) -> chrono::Duration {
+        chrono::Duration::minutes(self.password_reset_ttl_minutes as i64)
+    }
+
+    /// Validade dos tokens de verificação de email
+    pub fn email_verification_ttl(&self) -> chrono::Duration {
+        chrono::Duration::hours(self.email_verification_ttl_hours as i64)
+    }
+
+    /// Parâmetros de conexão SMTP configurados
+    pub fn smtp_settings(&self) -> SmtpSettings {
+        SmtpSettings {
+            host: self.smtp_host.clone().unwrap_or_default(),
+            port: self.smtp_port,
+            security: self.smtp_security,
+            username: self.smtp_username.clone(),
+            password: self.smtp_password.clone(),
+            timeout: std::time::Duration::from_secs(self.smtp_timeout_secs),
+        }
+    }
+
+    /// Retenção dos arquivos de exportação de dados configurada
+    pub fn data_export_ttl(&self) -> chrono::Duration {
+        chrono::Duration::hours(self.data_export_ttl_hours as i64)
+    }
+
+    /// Limites de anexos configurados
+    pub fn attachment_quota(&self) -> AttachmentQuota {
+        AttachmentQuota::new(self.attachment_max_bytes, self.attachment_quota_bytes)
+    }
+
+    /// Política de proteção contra força bruta no login configurada
+    pub fn lockout_policy(&self) -> LockoutPolicy {
+        LockoutPolicy {
+            max_failures_per_account: self.login_max_failures,
+            max_failures_per_ip: self.login_max_failures_per_ip,
+            window: chrono::Duration::minutes(self.login_failure_window_minutes as i64),
+            lockout: chrono::Duration::minutes(self.login_lockout_minutes as i64),
+            base_delay: chrono::Duration::milliseconds(self.login_delay_ms as i64),
+            ..LockoutPolicy::default()
+        }
+    }
+
+    /// Política de novas tentativas de webhooks configurada
+    pub fn webhook_retry_policy(&self) -> WebhookRetryPolicy {
+        WebhookRetryPolicy {
+            max_attempts: self.webhook_max_attempts,
+            ..WebhookRetryPolicy::default()
+        }
+    }
+
+    /// Política de destinos de webhooks configurada
+    pub fn webhook_destination_policy(&self) -> WebhookDestinationPolicy {
+        WebhookDestinationPolicy::new(self.webhook_allowed_hosts.clone())
+    }
+
     /// Valida se a configuração está correta
     pub fn validate(&self) -> Result<()> {
         // Validar URL do banco
@@ -79,9 +501,22 @@ impl Config {
             anyhow::bail!("DATABASE_URL deve começar com 'postgres://' ou 'postgresql://'");
         }
 
-        // Validar JWT secret (mínimo de 32 caracteres para segurança)
-        if self.jwt_secret.len() < 32 {
-            anyhow::bail!("JWT_SECRET deve ter pelo menos 32 caracteres para ser seguro");
+        // Validar chaves JWT
+        match self.jwt_algorithm {
+            // JWT secret com mínimo de 32 caracteres para segurança
+            Algorithm::HS256 => {
+                if self.jwt_secret.len() < 32 {
+                    anyhow::bail!("JWT_SECRET deve ter pelo menos 32 caracteres para ser seguro");
+                }
+            }
+            Algorithm::RS256 | Algorithm::EdDSA => {
+                if self.jwt_private_key_path.is_none() || self.jwt_public_key_path.is_none() {
+                    anyhow::bail!(
+                        "JWT_PRIVATE_KEY_PATH e JWT_PUBLIC_KEY_PATH são obrigatórias com RS256/EdDSA"
+                    );
+                }
+            }
+            _ => anyhow::bail!("JWT_ALGORITHM deve ser 'HS256', 'RS256' ou 'EdDSA'"),
         }
 
         // Validar tempo de expiração
@@ -89,11 +524,89 @@ impl Config {
             anyhow::bail!("JWT_EXPIRATION_HOURS deve estar entre 1 e 8760 (1 ano)");
         }
 
+        // Validar sessões (access token de até 24h, refresh token de até 1 ano)
+        if self.access_token_ttl_minutes == 0 || self.access_token_ttl_minutes > 1440 {
+            anyhow::bail!("ACCESS_TOKEN_TTL_MINUTES deve estar entre 1 e 1440 (24 horas)");
+        }
+
+        if self.refresh_token_ttl_days == 0 || self.refresh_token_ttl_days > 365 {
+            anyhow::bail!("REFRESH_TOKEN_TTL_DAYS deve estar entre 1 e 365");
+        }
+
+        // Cache de revogação maior que o access token atrasaria revogações sem ganho
+        if self.token_revocation_cache_secs > self.access_token_ttl_minutes * 60 {
+            anyhow::bail!(
+                "TOKEN_REVOCATION_CACHE_SECS não pode exceder a validade do access token"
+            );
+        }
+
+        // Validar redefinição de senha (até 24 horas)
+        if self.password_reset_ttl_minutes == 0 || self.password_reset_ttl_minutes > 1440 {
+            anyhow::bail!("PASSWORD_RESET_TTL_MINUTES deve estar entre 1 e 1440 (24 horas)");
+        }
+
+        if !self.app_base_url.starts_with("http://") && !self.app_base_url.starts_with("https://") {
+            anyhow::bail!("APP_BASE_URL deve começar com 'http://' ou 'https://'");
+        }
+
+        if self.email_verification_ttl_hours == 0 || self.email_verification_ttl_hours > 720 {
+            anyhow::bail!("EMAIL_VERIFICATION_TTL_HOURS deve estar entre 1 e 720 (30 dias)");
+        }
+
+        if self.totp_issuer.trim().is_empty() || self.totp_issuer.contains(':') {
+            anyhow::bail!("TOTP_ISSUER não pode ser vazio nem conter ':'");
+        }
+
+        // Validar proteção contra força bruta
+        if self.login_max_failures == 0 || self.login_max_failures_per_ip == 0 {
+            anyhow::bail!("LOGIN_MAX_FAILURES e LOGIN_MAX_FAILURES_PER_IP devem ser maiores que 0");
+        }
+
+        if self.login_failure_window_minutes == 0
+            || self.login_lockout_minutes == 0
+            || self.login_lockout_minutes > self.login_failure_window_minutes
+        {
+            anyhow::bail!(
+                "LOGIN_LOCKOUT_MINUTES deve estar entre 1 e LOGIN_FAILURE_WINDOW_MINUTES"
+            );
+        }
+
+        if self.login_delay_ms > 10_000 {
+            anyhow::bail!("LOGIN_DELAY_MS deve ser no máximo 10000");
+        }
+
+        // Validar envio de emails
+        if self.mail_transport == MailTransport::Smtp && self.smtp_host.is_none() {
+            anyhow::bail!("SMTP_HOST é obrigatória com MAIL_TRANSPORT=smtp");
+        }
+
+        if self.smtp_port == 0 || self.smtp_timeout_secs == 0 {
+            anyhow::bail!("SMTP_PORT e SMTP_TIMEOUT_SECS devem ser maiores que 0");
+        }
+
         // Validar porta
         if self.server_port == 0 {
             anyhow::bail!("SERVER_PORT não pode ser 0");
         }
 
+        // Validar limites de anexos
+        if self.attachment_max_bytes == 0 || self.attachment_max_bytes > self.attachment_quota_bytes
+        {
+            anyhow::bail!(
+                "ATTACHMENT_MAX_BYTES deve ser maior que 0 e não exceder ATTACHMENT_QUOTA_BYTES"
+            );
+        }
+
+        // Validar webhooks
+        if self.webhook_max_attempts == 0 || self.webhook_timeout_secs == 0 {
+            anyhow::bail!("WEBHOOK_MAX_ATTEMPTS e WEBHOOK_TIMEOUT_SECS devem ser maiores que 0");
+        }
+
+        // Validar retenção das exportações de dados
+        if self.data_export_ttl_hours == 0 || self.data_export_ttl_hours > 720 {
+            anyhow::bail!("DATA_EXPORT_TTL_HOURS deve estar entre 1 e 720 (30 dias)");
+        }
+
         Ok(())
     }
 }
@@ -208,9 +721,45 @@ mod tests {
         let config = Config {
             database_url: "postgres://localhost/test".to_string(),
             jwt_secret: "short".to_string(), // Muito curto
+            jwt_algorithm: Algorithm::HS256,
+            jwt_private_key_path: None,
+            jwt_public_key_path: None,
+            jwt_previous_public_key_paths: vec![],
             jwt_expiration_hours: 24,
+            access_token_ttl_minutes: 15,
+            refresh_token_ttl_days: 30,
+            token_revocation_cache_secs: 30,
+            password_reset_ttl_minutes: 60,
+            app_base_url: "http://localhost:8080".to_string(),
+            email_verification_policy: EmailVerificationPolicy::Optional,
+            email_verification_ttl_hours: 48,
+            totp_issuer: "Todo API".to_string(),
+            login_max_failures: 5,
+            login_max_failures_per_ip: 50,
+            login_failure_window_minutes: 60,
+            login_lockout_minutes: 15,
+            login_delay_ms: 250,
+            mail_from: "Todo API <no-reply@localhost>".to_string(),
+            mail_transport: MailTransport::Log,
+            mail_outbox_dir: "./data/outbox".to_string(),
+            smtp_host: None,
+            smtp_port: 587,
+            smtp_username: None,
+            smtp_password: None,
+            smtp_security: SmtpSecurity::Starttls,
+            smtp_timeout_secs: 30,
             server_host: "0.0.0.0".to_string(),
             server_port: 8080,
+            trusted_proxies: TrustedProxies::default(),
+            subtask_completion_policy: SubtaskCompletionPolicy::default(),
+            reopen_policy: ReopenPolicy::default(),
+            attachment_storage_dir: "./data/attachments".to_string(),
+            attachment_max_bytes: 10_485_760,
+            attachment_quota_bytes: 104_857_600,
+            webhook_max_attempts: 8,
+            webhook_timeout_secs: 10,
+            webhook_allowed_hosts: vec![],
+            data_export_ttl_hours: 168,
         };
 
         let result = config.validate();
@@ -223,9 +772,45 @@ mod tests {
         let config = Config {
             database_url: "invalid://localhost/test".to_string(),
             jwt_secret: "test-secret-key-with-at-least-32-chars-for-security".to_string(),
+            jwt_algorithm: Algorithm::HS256,
+            jwt_private_key_path: None,
+            jwt_public_key_path: None,
+            jwt_previous_public_key_paths: vec![],
             jwt_expiration_hours: 24,
+            access_token_ttl_minutes: 15,
+            refresh_token_ttl_days: 30,
+            token_revocation_cache_secs: 30,
+            password_reset_ttl_minutes: 60,
+            app_base_url: "http://localhost:8080".to_string(),
+            email_verification_policy: EmailVerificationPolicy::Optional,
+            email_verification_ttl_hours: 48,
+            totp_issuer: "Todo API".to_string(),
+            login_max_failures: 5,
+            login_max_failures_per_ip: 50,
+            login_failure_window_minutes: 60,
+            login_lockout_minutes: 15,
+            login_delay_ms: 250,
+            mail_from: "Todo API <no-reply@localhost>".to_string(),
+            mail_transport: MailTransport::Log,
+            mail_outbox_dir: "./data/outbox".to_string(),
+            smtp_host: None,
+            smtp_port: 587,
+            smtp_username: None,
+            smtp_password: None,
+            smtp_security: SmtpSecurity::Starttls,
+            smtp_timeout_secs: 30,
             server_host: "0.0.0.0".to_string(),
             server_port: 8080,
+            trusted_proxies: TrustedProxies::default(),
+            subtask_completion_policy: SubtaskCompletionPolicy::default(),
+            reopen_policy: ReopenPolicy::default(),
+            attachment_storage_dir: "./data/attachments".to_string(),
+            attachment_max_bytes: 10_485_760,
+            attachment_quota_bytes: 104_857_600,
+            webhook_max_attempts: 8,
+            webhook_timeout_secs: 10,
+            webhook_allowed_hosts: vec![],
+            data_export_ttl_hours: 168,
         };
 
         let result = config.validate();
@@ -233,14 +818,157 @@ mod tests {
         assert!(result.unwrap_err().to_string().contains("postgres://"));
     }
 
+    #[test]
+    fn test_validate_asymmetric_requires_key_paths() {
+        let config = Config {
+            database_url: "postgres://localhost/test".to_string(),
+            jwt_secret: String::new(),
+            jwt_algorithm: Algorithm::EdDSA,
+            jwt_private_key_path: Some("./keys/jwt.pem".to_string()),
+            jwt_public_key_path: None,
+            jwt_previous_public_key_paths: vec![],
+            jwt_expiration_hours: 24,
+            access_token_ttl_minutes: 15,
+            refresh_token_ttl_days: 30,
+            token_revocation_cache_secs: 30,
+            password_reset_ttl_minutes: 60,
+            app_base_url: "http://localhost:8080".to_string(),
+            email_verification_policy: EmailVerificationPolicy::Optional,
+            email_verification_ttl_hours: 48,
+            totp_issuer: "Todo API".to_string(),
+            login_max_failures: 5,
+            login_max_failures_per_ip: 50,
+            login_failure_window_minutes: 60,
+            login_lockout_minutes: 15,
+            login_delay_ms: 250,
+            mail_from: "Todo API <no-reply@localhost>".to_string(),
+            mail_transport: MailTransport::Log,
+            mail_outbox_dir: "./data/outbox".to_string(),
+            smtp_host: None,
+            smtp_port: 587,
+            smtp_username: None,
+            smtp_password: None,
+            smtp_security: SmtpSecurity::Starttls,
+            smtp_timeout_secs: 30,
+            server_host: "0.0.0.0".to_string(),
+            server_port: 8080,
+            trusted_proxies: TrustedProxies::default(),
+            subtask_completion_policy: SubtaskCompletionPolicy::default(),
+            reopen_policy: ReopenPolicy::default(),
+            attachment_storage_dir: "./data/attachments".to_string(),
+            attachment_max_bytes: 10_485_760,
+            attachment_quota_bytes: 104_857_600,
+            webhook_max_attempts: 8,
+            webhook_timeout_secs: 10,
+            webhook_allowed_hosts: vec![],
+            data_export_ttl_hours: 168,
+        };
+
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("JWT_PUBLIC_KEY_PATH")
+        );
+    }
+
+    #[test]
+    fn test_validate_smtp_requires_host() {
+        let config = Config {
+            database_url: "postgres://localhost/test".to_string(),
+            jwt_secret: "test-secret-key-with-at-least-32-chars-for-security".to_string(),
+            jwt_algorithm: Algorithm::HS256,
+            jwt_private_key_path: None,
+            jwt_public_key_path: None,
+            jwt_previous_public_key_paths: vec![],
+            jwt_expiration_hours: 24,
+            access_token_ttl_minutes: 15,
+            refresh_token_ttl_days: 30,
+            token_revocation_cache_secs: 30,
+            password_reset_ttl_minutes: 60,
+            app_base_url: "http://localhost:8080".to_string(),
+            email_verification_policy: EmailVerificationPolicy::Optional,
+            email_verification_ttl_hours: 48,
+            totp_issuer: "Todo API".to_string(),
+            login_max_failures: 5,
+            login_max_failures_per_ip: 50,
+            login_failure_window_minutes: 60,
+            login_lockout_minutes: 15,
+            login_delay_ms: 250,
+            mail_from: "Todo API <no-reply@localhost>".to_string(),
+            mail_transport: MailTransport::Smtp,
+            mail_outbox_dir: "./data/outbox".to_string(),
+            smtp_host: None,
+            smtp_port: 587,
+            smtp_username: None,
+            smtp_password: None,
+            smtp_security: SmtpSecurity::Starttls,
+            smtp_timeout_secs: 30,
+            server_host: "0.0.0.0".to_string(),
+            server_port: 8080,
+            trusted_proxies: TrustedProxies::default(),
+            subtask_completion_policy: SubtaskCompletionPolicy::default(),
+            reopen_policy: ReopenPolicy::default(),
+            attachment_storage_dir: "./data/attachments".to_string(),
+            attachment_max_bytes: 10_485_760,
+            attachment_quota_bytes: 104_857_600,
+            webhook_max_attempts: 8,
+            webhook_timeout_secs: 10,
+            webhook_allowed_hosts: vec![],
+            data_export_ttl_hours: 168,
+        };
+
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("SMTP_HOST"));
+    }
+
     #[test]
     fn test_validate_success() {
         let config = Config {
             database_url: "postgres://localhost/test".to_string(),
             jwt_secret: "test-secret-key-with-at-least-32-chars-for-security".to_string(),
+            jwt_algorithm: Algorithm::HS256,
+            jwt_private_key_path: None,
+            jwt_public_key_path: None,
+            jwt_previous_public_key_paths: vec![],
             jwt_expiration_hours: 24,
+            access_token_ttl_minutes: 15,
+            refresh_token_ttl_days: 30,
+            token_revocation_cache_secs: 30,
+            password_reset_ttl_minutes: 60,
+            app_base_url: "http://localhost:8080".to_string(),
+            email_verification_policy: EmailVerificationPolicy::Optional,
+            email_verification_ttl_hours: 48,
+            totp_issuer: "Todo API".to_string(),
+            login_max_failures: 5,
+            login_max_failures_per_ip: 50,
+            login_failure_window_minutes: 60,
+            login_lockout_minutes: 15,
+            login_delay_ms: 250,
+            mail_from: "Todo API <no-reply@localhost>".to_string(),
+            mail_transport: MailTransport::Log,
+            mail_outbox_dir: "./data/outbox".to_string(),
+            smtp_host: None,
+            smtp_port: 587,
+            smtp_username: None,
+            smtp_password: None,
+            smtp_security: SmtpSecurity::Starttls,
+            smtp_timeout_secs: 30,
             server_host: "0.0.0.0".to_string(),
             server_port: 8080,
+            trusted_proxies: TrustedProxies::default(),
+            subtask_completion_policy: SubtaskCompletionPolicy::default(),
+            reopen_policy: ReopenPolicy::default(),
+            attachment_storage_dir: "./data/attachments".to_string(),
+            attachment_max_bytes: 10_485_760,
+            attachment_quota_bytes: 104_857_600,
+            webhook_max_attempts: 8,
+            webhook_timeout_secs: 10,
+            webhook_allowed_hosts: vec![],
+            data_export_ttl_hours: 168,
         };
 
         assert!(config.validate().is_ok());
@@ -0,0 +1,188 @@
+use crate::domain::errors::{DomainError, DomainResult};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Tamanho máximo do nome de um anexo
+pub const MAX_FILE_NAME_LENGTH: usize = 255;
+
+/// Content-types servidos como enviados no download; os demais (ex.: HTML,
+/// SVG, JavaScript) são servidos como `application/octet-stream`
+pub const SERVED_CONTENT_TYPES: &[&str] = &[
+    "application/json",
+    "application/pdf",
+    "application/zip",
+    "image/gif",
+    "image/jpeg",
+    "image/png",
+    "image/webp",
+    "text/csv",
+    "text/plain",
+];
+
+/// Entidade Attachment - Arquivo anexado a uma tarefa
+///
+/// O conteúdo fica no `AttachmentStorage`, sob `storage_key`; aqui ficam
+/// apenas os metadados.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Attachment {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub user_id: Uuid,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub sha256: String,
+    pub storage_key: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Attachment {
+    /// Cria um novo anexo
+    pub fn new(
+        id: Uuid,
+        task_id: Uuid,
+        user_id: Uuid,
+        file_name: &str,
+        content_type: Option<String>,
+        size_bytes: i64,
+        sha256: String,
+    ) -> DomainResult<Self> {
+        Ok(Self {
+            id,
+            task_id,
+            user_id,
+            file_name: Self::sanitize_file_name(file_name)?,
+            content_type: content_type
+                .map(|ct| ct.trim().to_lowercase())
+                .filter(|ct| Self::is_valid_content_type(ct))
+                .unwrap_or_else(|| "application/octet-stream".to_string()),
+            size_bytes,
+            sha256,
+            storage_key: format!("{}/{}", user_id, id),
+            created_at: Utc::now(),
+        })
+    }
+
+    /// Content-type usado no download (`application/octet-stream` fora da lista permitida)
+    pub fn served_content_type(&self) -> &str {
+        let essence = self
+            .content_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim();
+
+        if SERVED_CONTENT_TYPES.contains(&essence) {
+            essence
+        } else {
+            "application/octet-stream"
+        }
+    }
+
+    /// Verifica se o content-type tem o formato `tipo/subtipo`
+    fn is_valid_content_type(content_type: &str) -> bool {
+        content_type.len() <= 255
+            && content_type.is_ascii()
+            && !content_type.chars().any(|c| c.is_ascii_control())
+            && content_type
+                .split_once('/')
+                .is_some_and(|(kind, subtype)| !kind.is_empty() && !subtype.is_empty())
+    }
+
+    /// Normaliza o nome do arquivo (remove diretórios e caracteres de controle)
+    pub fn sanitize_file_name(file_name: &str) -> DomainResult<String> {
+        let base_name = file_name.rsplit(['/', '\\']).next().unwrap_or_default();
+        let sanitized: String = base_name
+            .chars()
+            .filter(|c| !c.is_control() && *c != '"')
+            .collect();
+        let sanitized = sanitized.trim();
+
+        if sanitized.is_empty() || sanitized == "." || sanitized == ".." {
+            return Err(DomainError::ValidationError(
+                "Nome do arquivo inválido".to_string(),
+            ));
+        }
+
+        if sanitized.chars().count() > MAX_FILE_NAME_LENGTH {
+            return Err(DomainError::ValidationError(format!(
+                "Nome do arquivo não pode exceder {} caracteres",
+                MAX_FILE_NAME_LENGTH
+            )));
+        }
+
+        Ok(sanitized.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_file_name_strips_directories() {
+        assert_eq!(
+            Attachment::sanitize_file_name("../../etc/passwd").unwrap(),
+            "passwd"
+        );
+        assert_eq!(
+            Attachment::sanitize_file_name("C:\\Users\\spec \"v2\".pdf").unwrap(),
+            "spec v2.pdf"
+        );
+        assert!(Attachment::sanitize_file_name("uploads/").is_err());
+    }
+
+    #[test]
+    fn test_default_content_type() {
+        let attachment = Attachment::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "screenshot.png",
+            None,
+            10,
+            String::new(),
+        )
+        .unwrap();
+
+        assert_eq!(attachment.content_type, "application/octet-stream");
+        assert!(Attachment::is_valid_content_type("image/png"));
+        assert!(!Attachment::is_valid_content_type("png"));
+        assert!(
+            attachment
+                .storage_key
+                .starts_with(&attachment.user_id.to_string())
+        );
+    }
+
+    #[test]
+    fn test_served_content_type_uses_allowlist() {
+        let attachment = |content_type: &str| {
+            Attachment::new(
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                "arquivo",
+                Some(content_type.to_string()),
+                10,
+                String::new(),
+            )
+            .unwrap()
+        };
+
+        assert_eq!(attachment("image/png").served_content_type(), "image/png");
+        assert_eq!(
+            attachment("text/plain; charset=utf-8").served_content_type(),
+            "text/plain"
+        );
+        assert_eq!(
+            attachment("text/html").served_content_type(),
+            "application/octet-stream"
+        );
+        assert_eq!(
+            attachment("image/svg+xml").served_content_type(),
+            "application/octet-stream"
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use crate::domain::errors::{DomainError, DomainResult};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Tamanho máximo do texto de um comentário
+pub const MAX_COMMENT_LENGTH: usize = 5000;
+
+/// Tamanho do trecho do comentário exibido no histórico da tarefa
+const EXCERPT_LENGTH: usize = 100;
+
+/// Entidade Comment - Comentário de um usuário em uma tarefa
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Comment {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub user_id: Uuid,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Comment {
+    /// Cria um novo comentário
+    pub fn new(id: Uuid, task_id: Uuid, user_id: Uuid, body: String) -> DomainResult<Self> {
+        let now = Utc::now();
+        Ok(Self {
+            id,
+            task_id,
+            user_id,
+            body: Self::validate_body(body)?,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Edita o texto do comentário
+    pub fn update_body(&mut self, body: String) -> DomainResult<()> {
+        self.body = Self::validate_body(body)?;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Verifica se o usuário é o autor do comentário
+    pub fn is_authored_by(&self, user_id: &Uuid) -> bool {
+        &self.user_id == user_id
+    }
+
+    /// Indica se o comentário foi editado após a criação
+    pub fn is_edited(&self) -> bool {
+        self.updated_at > self.created_at
+    }
+
+    /// Trecho inicial do comentário (usado no histórico da tarefa)
+    pub fn excerpt(&self) -> String {
+        if self.body.chars().count() <= EXCERPT_LENGTH {
+            return self.body.clone();
+        }
+
+        let excerpt: String = self.body.chars().take(EXCERPT_LENGTH).collect();
+        format!("{}…", excerpt)
+    }
+
+    fn validate_body(body: String) -> DomainResult<String> {
+        let body = body.trim().to_string();
+
+        if body.is_empty() {
+            return Err(DomainError::ValidationError(
+                "Comentário não pode ser vazio".to_string(),
+            ));
+        }
+
+        if body.chars().count() > MAX_COMMENT_LENGTH {
+            return Err(DomainError::ValidationError(format!(
+                "Comentário não pode exceder {} caracteres",
+                MAX_COMMENT_LENGTH
+            )));
+        }
+
+        Ok(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_comment_fails() {
+        let comment = Comment::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "   ".to_string(),
+        );
+        assert!(comment.is_err());
+    }
+
+    #[test]
+    fn test_update_body_marks_as_edited() {
+        let mut comment = Comment::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            " Primeira versão ".to_string(),
+        )
+        .unwrap();
+        assert_eq!(comment.body, "Primeira versão");
+        assert!(!comment.is_edited());
+
+        comment.update_body("Segunda versão".to_string()).unwrap();
+        assert_eq!(comment.body, "Segunda versão");
+        assert!(comment.is_edited());
+    }
+}
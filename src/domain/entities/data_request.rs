@@ -0,0 +1,130 @@
+use crate::domain::value_objects::{DataRequestKind, DataRequestStatus};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Número máximo de tentativas de processamento
+pub const MAX_DATA_REQUEST_ATTEMPTS: i32 = 3;
+
+/// Tamanho máximo da mensagem de erro registrada
+const MAX_ERROR_LENGTH: usize = 500;
+
+/// Entidade DataRequest - Solicitação de exportação ou eliminação de dados (job em background)
+#[derive(Debug, Clone)]
+pub struct DataRequest {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub kind: DataRequestKind,
+    pub status: DataRequestStatus,
+    /// Chave do arquivo de exportação no armazenamento
+    pub storage_key: Option<String>,
+    pub error: Option<String>,
+    pub attempts: i32,
+    pub requested_at: DateTime<Utc>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+    /// Fim da retenção do arquivo de exportação
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl DataRequest {
+    /// Cria uma nova solicitação pendente
+    pub fn new(user_id: Uuid, kind: DataRequestKind) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            kind,
+            status: DataRequestStatus::Pending,
+            storage_key: None,
+            error: None,
+            attempts: 0,
+            requested_at: Utc::now(),
+            started_at: None,
+            completed_at: None,
+            expires_at: None,
+        }
+    }
+
+    /// Marca o início de uma tentativa de processamento
+    pub fn start(&mut self) {
+        self.status = DataRequestStatus::Processing;
+        self.attempts += 1;
+        self.started_at = Some(Utc::now());
+    }
+
+    /// Conclui a solicitação (com o arquivo gerado, no caso de exportação)
+    pub fn complete(&mut self, storage_key: Option<String>, expires_at: Option<DateTime<Utc>>) {
+        self.status = DataRequestStatus::Completed;
+        self.storage_key = storage_key;
+        self.expires_at = expires_at;
+        self.error = None;
+        self.completed_at = Some(Utc::now());
+    }
+
+    /// Registra uma falha, devolvendo à fila ou encerrando após esgotar as tentativas
+    pub fn record_failure(&mut self, error: &str) {
+        self.error = Some(error.chars().take(MAX_ERROR_LENGTH).collect());
+
+        if self.attempts >= MAX_DATA_REQUEST_ATTEMPTS {
+            self.status = DataRequestStatus::Failed;
+            self.completed_at = Some(Utc::now());
+        } else {
+            self.status = DataRequestStatus::Pending;
+        }
+    }
+
+    /// Marca o arquivo de exportação como removido
+    pub fn expire(&mut self) {
+        self.status = DataRequestStatus::Expired;
+        self.storage_key = None;
+    }
+
+    /// Verifica se o arquivo de exportação está disponível para download
+    pub fn is_downloadable(&self) -> bool {
+        self.kind == DataRequestKind::Export
+            && self.status == DataRequestStatus::Completed
+            && self.storage_key.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_complete_export() {
+        let mut request = DataRequest::new(Uuid::new_v4(), DataRequestKind::Export);
+        assert!(!request.is_downloadable());
+
+        request.start();
+        request.complete(
+            Some("exports/arquivo.json".to_string()),
+            Some(Utc::now() + Duration::days(7)),
+        );
+
+        assert_eq!(request.status, DataRequestStatus::Completed);
+        assert!(request.is_downloadable());
+
+        request.expire();
+        assert_eq!(request.status, DataRequestStatus::Expired);
+        assert!(!request.is_downloadable());
+    }
+
+    #[test]
+    fn test_record_failure_retries_then_fails() {
+        let mut request = DataRequest::new(Uuid::new_v4(), DataRequestKind::Erasure);
+
+        for _ in 1..MAX_DATA_REQUEST_ATTEMPTS {
+            request.start();
+            request.record_failure("falha temporária");
+            assert_eq!(request.status, DataRequestStatus::Pending);
+        }
+
+        request.start();
+        request.record_failure("falha definitiva");
+
+        assert_eq!(request.status, DataRequestStatus::Failed);
+        assert_eq!(request.error.as_deref(), Some("falha definitiva"));
+        assert!(request.completed_at.is_some());
+    }
+}
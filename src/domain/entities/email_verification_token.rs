@@ -0,0 +1,75 @@
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+/// Entidade EmailVerificationToken - Token de uso único para confirmar o email
+///
+/// Apenas o hash do token é persistido; o valor original só existe no email.
+/// Com `new_email`, confirma uma troca de email em vez do email do cadastro.
+#[derive(Debug, Clone)]
+pub struct EmailVerificationToken {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub token_hash: String,
+    pub new_email: Option<String>,
+    pub expires_at: DateTime<Utc>,
+    pub used_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl EmailVerificationToken {
+    /// Emite um novo token para o usuário
+    pub fn new(user_id: Uuid, token_hash: String, ttl: Duration) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            token_hash,
+            new_email: None,
+            expires_at: now + ttl,
+            used_at: None,
+            created_at: now,
+        }
+    }
+
+    /// Emite um token que confirma a troca para `new_email`
+    pub fn for_email_change(
+        user_id: Uuid,
+        token_hash: String,
+        new_email: String,
+        ttl: Duration,
+    ) -> Self {
+        Self {
+            new_email: Some(new_email),
+            ..Self::new(user_id, token_hash, ttl)
+        }
+    }
+
+    /// Verifica se o token expirou
+    pub fn is_expired(&self) -> bool {
+        self.expires_at <= Utc::now()
+    }
+
+    /// Verifica se o token ainda pode ser usado
+    pub fn is_usable(&self) -> bool {
+        self.used_at.is_none() && !self.is_expired()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_usable_until_used_or_expired() {
+        let mut token =
+            EmailVerificationToken::new(Uuid::new_v4(), "hash".to_string(), Duration::hours(48));
+        assert!(token.is_usable());
+
+        token.used_at = Some(Utc::now());
+        assert!(!token.is_usable());
+
+        token.used_at = None;
+        token.expires_at = Utc::now() - Duration::seconds(1);
+        assert!(!token.is_usable());
+    }
+}
@@ -0,0 +1,46 @@
+use crate::domain::value_objects::LoginOutcome;
+use chrono::{DateTime, Utc};
+use std::net::IpAddr;
+use uuid::Uuid;
+
+/// Entidade LoginAttempt - Registro de auditoria de uma tentativa de login
+///
+/// Também é a base da contagem de falhas para o bloqueio por força bruta.
+#[derive(Debug, Clone)]
+pub struct LoginAttempt {
+    pub id: Uuid,
+    /// Usuário da tentativa (ausente quando o email não está cadastrado)
+    pub user_id: Option<Uuid>,
+    pub email: String,
+    pub ip_address: Option<IpAddr>,
+    pub user_agent: Option<String>,
+    pub outcome: LoginOutcome,
+    pub created_at: DateTime<Utc>,
+}
+
+impl LoginAttempt {
+    pub fn new(
+        user_id: Option<Uuid>,
+        email: String,
+        ip_address: Option<IpAddr>,
+        user_agent: Option<String>,
+        outcome: LoginOutcome,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            email,
+            ip_address,
+            user_agent,
+            outcome,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// Falhas recentes de uma conta ou IP
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LoginFailures {
+    pub count: u32,
+    pub last_failed_at: Option<DateTime<Utc>>,
+}
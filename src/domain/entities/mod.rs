@@ -1,7 +1,41 @@
+pub mod attachment;
+pub mod comment;
+pub mod data_request;
+pub mod email_verification_token;
+pub mod login_attempt;
+pub mod password_reset_token;
+pub mod project;
+pub mod recovery_code;
+pub mod session;
+pub mod tag;
 pub mod task;
+pub mod task_collaborator;
+pub mod task_dependency;
 pub mod task_history;
+pub mod totp_credential;
 pub mod user;
+pub mod webhook;
+pub mod webhook_delivery;
+pub mod workflow;
+pub mod workspace;
 
+pub use attachment::Attachment;
+pub use comment::Comment;
+pub use data_request::DataRequest;
+pub use email_verification_token::EmailVerificationToken;
+pub use login_attempt::{LoginAttempt, LoginFailures};
+pub use password_reset_token::PasswordResetToken;
+pub use project::Project;
+pub use recovery_code::RecoveryCode;
+pub use session::Session;
+pub use tag::Tag;
 pub use task::Task;
+pub use task_collaborator::TaskCollaborator;
+pub use task_dependency::TaskDependency;
 pub use task_history::TaskHistory;
+pub use totp_credential::TotpCredential;
 pub use user::User;
+pub use webhook::Webhook;
+pub use webhook_delivery::WebhookDelivery;
+pub use workflow::{Workflow, WorkflowState, WorkflowTransition};
+pub use workspace::{Membership, Workspace};
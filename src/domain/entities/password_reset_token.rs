@@ -0,0 +1,66 @@
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+/// Entidade PasswordResetToken - Token de uso único para redefinir a senha
+///
+/// Apenas o hash do token é persistido; o valor original só existe no email.
+#[derive(Debug, Clone)]
+pub struct PasswordResetToken {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub token_hash: String,
+    pub expires_at: DateTime<Utc>,
+    pub used_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl PasswordResetToken {
+    /// Emite um novo token para o usuário
+    pub fn new(user_id: Uuid, token_hash: String, ttl: Duration) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            token_hash,
+            expires_at: now + ttl,
+            used_at: None,
+            created_at: now,
+        }
+    }
+
+    /// Verifica se o token expirou
+    pub fn is_expired(&self) -> bool {
+        self.expires_at <= Utc::now()
+    }
+
+    /// Verifica se o token ainda pode ser usado
+    pub fn is_usable(&self) -> bool {
+        self.used_at.is_none() && !self.is_expired()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_token_is_usable() {
+        let token = PasswordResetToken::new(Uuid::new_v4(), "hash".to_string(), Duration::hours(1));
+
+        assert!(token.is_usable());
+        assert!(!token.is_expired());
+    }
+
+    #[test]
+    fn test_used_or_expired_token_is_not_usable() {
+        let mut used = PasswordResetToken::new(Uuid::new_v4(), "a".to_string(), Duration::hours(1));
+        used.used_at = Some(Utc::now());
+
+        let expired =
+            PasswordResetToken::new(Uuid::new_v4(), "b".to_string(), Duration::seconds(-1));
+
+        assert!(!used.is_usable());
+        assert!(expired.is_expired());
+        assert!(!expired.is_usable());
+    }
+}
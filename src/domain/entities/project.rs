@@ -0,0 +1,170 @@
+use crate::domain::entities::Workflow;
+use crate::domain::errors::{DomainError, DomainResult};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Tamanho máximo do nome de um projeto
+const MAX_NAME_LENGTH: usize = 100;
+
+/// Entidade Project - Lista que agrupa tarefas de um workspace
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Project {
+    pub id: Uuid,
+    pub workspace_id: Uuid,
+    pub name: String,
+    /// Cor no formato hexadecimal `#rrggbb`
+    pub color: Option<String>,
+    /// Ordem de exibição (crescente)
+    pub position: i32,
+    /// Workflow que define os estados das tarefas; `None` usa os status padrão
+    pub workflow_id: Option<Uuid>,
+    pub archived_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Project {
+    /// Cria um novo projeto
+    pub fn new(
+        workspace_id: Uuid,
+        name: &str,
+        color: Option<&str>,
+        position: i32,
+    ) -> DomainResult<Self> {
+        let now = Utc::now();
+        Ok(Self {
+            id: Uuid::new_v4(),
+            workspace_id,
+            name: Self::validate_name(name)?,
+            color: color.map(Self::validate_color).transpose()?,
+            position,
+            workflow_id: None,
+            archived_at: None,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Renomeia o projeto
+    pub fn rename(&mut self, name: &str) -> DomainResult<()> {
+        self.name = Self::validate_name(name)?;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Define (ou remove) a cor
+    pub fn set_color(&mut self, color: Option<&str>) -> DomainResult<()> {
+        self.color = color.map(Self::validate_color).transpose()?;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Move o projeto para outra posição
+    pub fn move_to(&mut self, position: i32) {
+        self.position = position;
+        self.updated_at = Utc::now();
+    }
+
+    /// Define (ou remove) o workflow das tarefas do projeto
+    pub fn set_workflow(&mut self, workflow: Option<&Workflow>) -> DomainResult<()> {
+        if let Some(workflow) = workflow
+            && workflow.workspace_id != self.workspace_id
+        {
+            return Err(DomainError::ValidationError(
+                "O workflow deve pertencer ao mesmo workspace do projeto".to_string(),
+            ));
+        }
+
+        self.workflow_id = workflow.map(|workflow| workflow.id);
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Arquiva o projeto (ele deixa de receber tarefas)
+    pub fn archive(&mut self) {
+        if self.archived_at.is_none() {
+            self.archived_at = Some(Utc::now());
+            self.updated_at = Utc::now();
+        }
+    }
+
+    /// Restaura um projeto arquivado
+    pub fn unarchive(&mut self) {
+        self.archived_at = None;
+        self.updated_at = Utc::now();
+    }
+
+    /// Verifica se o projeto está arquivado
+    pub fn is_archived(&self) -> bool {
+        self.archived_at.is_some()
+    }
+
+    fn validate_name(name: &str) -> DomainResult<String> {
+        let name = name.trim();
+
+        if name.is_empty() {
+            return Err(DomainError::ValidationError(
+                "Nome do projeto não pode ser vazio".to_string(),
+            ));
+        }
+
+        if name.chars().count() > MAX_NAME_LENGTH {
+            return Err(DomainError::ValidationError(format!(
+                "Nome do projeto deve ter no máximo {} caracteres",
+                MAX_NAME_LENGTH
+            )));
+        }
+
+        Ok(name.to_string())
+    }
+
+    /// Normaliza a cor para `#rrggbb` (minúsculas)
+    fn validate_color(color: &str) -> DomainResult<String> {
+        let color = color.trim().to_lowercase();
+        let valid = color.len() == 7
+            && color.starts_with('#')
+            && color[1..].chars().all(|c| c.is_ascii_hexdigit());
+
+        if !valid {
+            return Err(DomainError::ValidationError(
+                "Cor deve estar no formato #rrggbb".to_string(),
+            ));
+        }
+
+        Ok(color)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_project_normalizes_fields() {
+        let project = Project::new(Uuid::new_v4(), "  Backend ", Some("#1E90FF"), 0).unwrap();
+
+        assert_eq!(project.name, "Backend");
+        assert_eq!(project.color.as_deref(), Some("#1e90ff"));
+        assert!(!project.is_archived());
+    }
+
+    #[test]
+    fn test_invalid_name_and_color_fail() {
+        assert!(Project::new(Uuid::new_v4(), "   ", None, 0).is_err());
+        assert!(Project::new(Uuid::new_v4(), &"a".repeat(101), None, 0).is_err());
+        assert!(Project::new(Uuid::new_v4(), "Backend", Some("azul"), 0).is_err());
+        assert!(Project::new(Uuid::new_v4(), "Backend", Some("#12345g"), 0).is_err());
+    }
+
+    #[test]
+    fn test_archive_and_unarchive() {
+        let mut project = Project::new(Uuid::new_v4(), "Backend", None, 0).unwrap();
+
+        project.archive();
+        assert!(project.is_archived());
+
+        project.unarchive();
+        assert!(!project.is_archived());
+    }
+}
@@ -0,0 +1,27 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Entidade RecoveryCode - Código de recuperação de uso único do 2FA
+///
+/// Apenas o hash é persistido; os códigos são exibidos uma única vez.
+#[derive(Debug, Clone)]
+pub struct RecoveryCode {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub code_hash: String,
+    pub used_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl RecoveryCode {
+    /// Cria um novo código a partir do hash
+    pub fn new(user_id: Uuid, code_hash: String) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            code_hash,
+            used_at: None,
+            created_at: Utc::now(),
+        }
+    }
+}
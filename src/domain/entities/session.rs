@@ -0,0 +1,155 @@
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+/// Entidade Session - Refresh token emitido para um dispositivo
+///
+/// Cada rotação gera uma nova linha na mesma família (`family_id`); a família
+/// representa a sessão do dispositivo e é revogada por inteiro em caso de reuso.
+#[derive(Debug, Clone)]
+pub struct Session {
+    pub id: Uuid,
+    pub family_id: Uuid,
+    pub user_id: Uuid,
+    /// Workspace ativo nos access tokens da sessão
+    pub workspace_id: Option<Uuid>,
+    pub refresh_token_hash: String,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    /// Início da família (login)
+    pub started_at: DateTime<Utc>,
+    /// Emissão deste refresh token (último uso da sessão)
+    pub last_used_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub rotated_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl Session {
+    /// Inicia uma nova sessão (nova família)
+    pub fn start(
+        user_id: Uuid,
+        workspace_id: Option<Uuid>,
+        refresh_token_hash: String,
+        user_agent: Option<String>,
+        ip_address: Option<String>,
+        ttl: Duration,
+    ) -> Self {
+        let now = Utc::now();
+        let id = Uuid::new_v4();
+        Self {
+            id,
+            family_id: id,
+            user_id,
+            workspace_id,
+            refresh_token_hash,
+            user_agent,
+            ip_address,
+            started_at: now,
+            last_used_at: now,
+            expires_at: now + ttl,
+            rotated_at: None,
+            revoked_at: None,
+        }
+    }
+
+    /// Cria a sucessora desta sessão com um novo refresh token
+    pub fn rotate(
+        &self,
+        refresh_token_hash: String,
+        user_agent: Option<String>,
+        ip_address: Option<String>,
+        ttl: Duration,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            family_id: self.family_id,
+            user_id: self.user_id,
+            workspace_id: self.workspace_id,
+            refresh_token_hash,
+            user_agent: user_agent.or_else(|| self.user_agent.clone()),
+            ip_address: ip_address.or_else(|| self.ip_address.clone()),
+            started_at: self.started_at,
+            last_used_at: now,
+            expires_at: now + ttl,
+            rotated_at: None,
+            revoked_at: None,
+        }
+    }
+
+    /// Indica se o refresh token expirou
+    pub fn is_expired(&self) -> bool {
+        self.expires_at <= Utc::now()
+    }
+
+    /// Indica se o refresh token já foi trocado por outro
+    pub fn is_rotated(&self) -> bool {
+        self.rotated_at.is_some()
+    }
+
+    /// Indica se o refresh token ainda pode ser usado
+    pub fn is_active(&self) -> bool {
+        self.revoked_at.is_none() && !self.is_rotated() && !self.is_expired()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_start_session() {
+        let session = Session::start(
+            Uuid::new_v4(),
+            None,
+            "hash".to_string(),
+            Some("curl/8.0".to_string()),
+            None,
+            Duration::days(30),
+        );
+
+        assert_eq!(session.family_id, session.id);
+        assert!(session.is_active());
+    }
+
+    #[test]
+    fn test_rotate_keeps_family() {
+        let session = Session::start(
+            Uuid::new_v4(),
+            None,
+            "hash-1".to_string(),
+            Some("curl/8.0".to_string()),
+            Some("10.0.0.1".to_string()),
+            Duration::days(30),
+        );
+
+        let next = session.rotate(
+            "hash-2".to_string(),
+            None,
+            Some("10.0.0.2".to_string()),
+            Duration::days(30),
+        );
+
+        assert_ne!(next.id, session.id);
+        assert_eq!(next.family_id, session.family_id);
+        assert_eq!(next.workspace_id, session.workspace_id);
+        assert_eq!(next.started_at, session.started_at);
+        assert_eq!(next.user_agent.as_deref(), Some("curl/8.0"));
+        assert_eq!(next.ip_address.as_deref(), Some("10.0.0.2"));
+    }
+
+    #[test]
+    fn test_expired_session_is_inactive() {
+        let session = Session::start(
+            Uuid::new_v4(),
+            None,
+            "hash".to_string(),
+            None,
+            None,
+            Duration::seconds(-1),
+        );
+
+        assert!(session.is_expired());
+        assert!(!session.is_active());
+    }
+}
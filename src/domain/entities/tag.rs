@@ -0,0 +1,70 @@
+use crate::domain::errors::{DomainError, DomainResult};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Tamanho máximo do nome de uma tag
+pub const MAX_TAG_NAME_LENGTH: usize = 50;
+
+/// Entidade Tag - Rótulo aplicado às tarefas de um usuário
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tag {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Tag {
+    /// Cria uma nova tag
+    pub fn new(id: Uuid, user_id: Uuid, name: &str) -> DomainResult<Self> {
+        Ok(Self {
+            id,
+            user_id,
+            name: Self::normalize_name(name)?,
+            created_at: Utc::now(),
+        })
+    }
+
+    /// Normaliza o nome da tag (sem espaços nas pontas e em minúsculas)
+    pub fn normalize_name(name: &str) -> DomainResult<String> {
+        let normalized = name.trim().to_lowercase();
+
+        if normalized.is_empty() {
+            return Err(DomainError::ValidationError(
+                "Nome da tag não pode ser vazio".to_string(),
+            ));
+        }
+
+        if normalized.chars().count() > MAX_TAG_NAME_LENGTH {
+            return Err(DomainError::ValidationError(format!(
+                "Nome da tag não pode exceder {} caracteres",
+                MAX_TAG_NAME_LENGTH
+            )));
+        }
+
+        Ok(normalized)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_name() {
+        assert_eq!(Tag::normalize_name("  Backend ").unwrap(), "backend");
+        assert_eq!(Tag::normalize_name("Q3-Release").unwrap(), "q3-release");
+    }
+
+    #[test]
+    fn test_empty_name_fails() {
+        assert!(Tag::new(Uuid::new_v4(), Uuid::new_v4(), "   ").is_err());
+    }
+
+    #[test]
+    fn test_long_name_fails() {
+        let name = "a".repeat(MAX_TAG_NAME_LENGTH + 1);
+        assert!(Tag::normalize_name(&name).is_err());
+    }
+}
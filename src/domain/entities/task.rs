@@ -1,5 +1,9 @@
+use crate::domain::entities::{Project, Tag, Workflow};
 use crate::domain::errors::{DomainError, DomainResult};
-use crate::domain::value_objects::{TaskPriority, TaskStatus};
+use crate::domain::value_objects::{
+    Rank, RecurrenceRule, ReopenPolicy, SubtaskCompletionPolicy, SubtaskProgress, TaskPriority,
+    TaskStatus,
+};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -9,12 +13,30 @@ use uuid::Uuid;
 pub struct Task {
     pub id: Uuid,
     pub user_id: Uuid,
+    /// Workspace (tenant) ao qual a tarefa pertence
+    pub workspace_id: Uuid,
+    /// Projeto (lista) ao qual a tarefa pertence; `None` deixa a tarefa na caixa de entrada
+    pub project_id: Option<Uuid>,
+    pub parent_id: Option<Uuid>,
     pub title: String,
     pub description: Option<String>,
     pub status: TaskStatus,
+    /// Estado no workflow do projeto; `None` segue as transições padrão do status
+    pub state_id: Option<Uuid>,
+    /// Posição da tarefa no quadro (ordem dentro da coluna do status)
+    pub rank: Rank,
     pub priority: TaskPriority,
     pub due_date: Option<DateTime<Utc>>,
     pub completed_at: Option<DateTime<Utc>>,
+    pub tags: Vec<String>,
+    pub subtasks: SubtaskProgress,
+    pub recurrence: Option<RecurrenceRule>,
+    /// Primeira tarefa da série de recorrência (a própria tarefa quando é a primeira)
+    pub series_id: Option<Uuid>,
+    /// Posição da tarefa na série de recorrência (a partir de 1)
+    pub occurrence: i32,
+    /// Prazo da primeira ocorrência da série (âncora de MONTHLY/YEARLY)
+    pub series_start: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -24,6 +46,7 @@ impl Task {
     pub fn new(
         id: Uuid,
         user_id: Uuid,
+        workspace_id: Uuid,
         title: String,
         description: Option<String>,
         priority: TaskPriority,
@@ -39,12 +62,23 @@ impl Task {
         Ok(Self {
             id,
             user_id,
+            workspace_id,
+            project_id: None,
+            parent_id: None,
             title,
             description,
             status: TaskStatus::Pending,
+            state_id: None,
+            rank: Rank::initial(),
             priority,
             due_date,
             completed_at: None,
+            tags: Vec::new(),
+            subtasks: SubtaskProgress::default(),
+            recurrence: None,
+            series_id: None,
+            occurrence: 1,
+            series_start: None,
             created_at: now,
             updated_at: now,
         })
@@ -68,9 +102,183 @@ impl Task {
         self.updated_at = Utc::now();
     }
 
+    /// Define a tarefa pai (transforma a tarefa em subtarefa)
+    pub fn set_parent(
+        &mut self,
+        parent: &Task,
+        policy: SubtaskCompletionPolicy,
+    ) -> DomainResult<()> {
+        if parent.id == self.id {
+            return Err(DomainError::ValidationError(
+                "Uma tarefa não pode ser subtarefa de si mesma".to_string(),
+            ));
+        }
+
+        if parent.workspace_id != self.workspace_id {
+            return Err(DomainError::ValidationError(
+                "A tarefa pai deve pertencer ao mesmo workspace".to_string(),
+            ));
+        }
+
+        // Uma tarefa concluída não pode ganhar subtarefas em aberto
+        if parent.status == TaskStatus::Completed
+            && !self.status.is_closed()
+            && policy == SubtaskCompletionPolicy::RequireClosed
+        {
+            return Err(DomainError::ValidationError(
+                "Reabra a tarefa pai concluída antes de adicionar subtarefas".to_string(),
+            ));
+        }
+
+        self.parent_id = Some(parent.id);
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Move a tarefa para um projeto (ou de volta para a caixa de entrada)
+    pub fn set_project(&mut self, project: Option<&Project>) -> DomainResult<()> {
+        if let Some(project) = project {
+            if project.workspace_id != self.workspace_id {
+                return Err(DomainError::ValidationError(
+                    "O projeto deve pertencer ao mesmo workspace da tarefa".to_string(),
+                ));
+            }
+
+            if project.is_archived() {
+                return Err(DomainError::ValidationError(
+                    "Projetos arquivados não recebem tarefas".to_string(),
+                ));
+            }
+        }
+
+        self.project_id = project.map(|project| project.id);
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
     /// Atualiza o status da tarefa
-    pub fn update_status(&mut self, new_status: TaskStatus) -> DomainResult<()> {
-        let validated_status = self.status.transition(new_status)?;
+    ///
+    /// Tarefas em um workflow vão para o estado da categoria alcançável a partir do
+    /// estado atual; as demais seguem as transições padrão do status. Concluir a
+    /// tarefa respeita a política de subtarefas informada.
+    pub fn update_status(
+        &mut self,
+        workflow: Option<&Workflow>,
+        new_status: TaskStatus,
+        policy: SubtaskCompletionPolicy,
+    ) -> DomainResult<()> {
+        let Some(state_id) = self.state_id else {
+            let validated_status = self.status.transition(new_status)?;
+            return self.apply_status(validated_status, policy);
+        };
+
+        let workflow = workflow.ok_or(DomainError::ValidationError(
+            "O workflow da tarefa não foi informado".to_string(),
+        ))?;
+        let target = workflow.target_for(&state_id, new_status)?.id;
+        self.update_state(workflow, target, policy)
+    }
+
+    /// Move a tarefa para outro estado do workflow (o status passa a ser a categoria do estado)
+    pub fn update_state(
+        &mut self,
+        workflow: &Workflow,
+        state_id: Uuid,
+        policy: SubtaskCompletionPolicy,
+    ) -> DomainResult<()> {
+        let Some(current) = self.state_id else {
+            return Err(DomainError::ValidationError(
+                "A tarefa não está em um workflow".to_string(),
+            ));
+        };
+
+        let target = workflow.transition(&current, &state_id)?;
+
+        if target.category != self.status {
+            self.apply_status(target.category, policy)?;
+        }
+
+        self.state_id = Some(target.id);
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Reabre uma tarefa concluída ou cancelada, voltando para pendente
+    ///
+    /// Ignora as transições (padrão ou do workflow), que tratam esses status como
+    /// finais; a tarefa vai para o primeiro estado pendente do workflow, se houver.
+    /// Subtarefas exigem a tarefa pai: com `RequireClosed`, uma tarefa concluída
+    /// não pode voltar a ter subtarefas em aberto.
+    pub fn reopen(
+        &mut self,
+        workflow: Option<&Workflow>,
+        parent: Option<&Task>,
+        policy: ReopenPolicy,
+        subtask_policy: SubtaskCompletionPolicy,
+    ) -> DomainResult<()> {
+        if !self.status.is_closed() {
+            return Err(DomainError::ValidationError(
+                "Apenas tarefas concluídas ou canceladas podem ser reabertas".to_string(),
+            ));
+        }
+
+        if !policy.allows(self.status) {
+            return Err(DomainError::Forbidden(format!(
+                "Tarefas com status {} não podem ser reabertas",
+                self.status
+            )));
+        }
+
+        if self.parent_id != parent.map(|parent| parent.id) {
+            return Err(DomainError::ValidationError(
+                "A tarefa pai informada não corresponde à da subtarefa".to_string(),
+            ));
+        }
+
+        if let Some(parent) = parent
+            && parent.status == TaskStatus::Completed
+            && subtask_policy == SubtaskCompletionPolicy::RequireClosed
+        {
+            return Err(DomainError::ValidationError(
+                "Reabra a tarefa pai concluída antes de reabrir a subtarefa".to_string(),
+            ));
+        }
+
+        self.status = TaskStatus::Pending;
+        self.completed_at = None;
+        if self.state_id.is_some() {
+            self.enter_workflow(workflow);
+        }
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Coloca a tarefa no workflow (no primeiro estado da categoria do status atual)
+    ///
+    /// Sem workflow, a tarefa volta às transições padrão do status.
+    pub fn enter_workflow(&mut self, workflow: Option<&Workflow>) {
+        self.state_id =
+            workflow.and_then(|workflow| workflow.first_state_of(self.status).map(|s| s.id));
+        self.updated_at = Utc::now();
+    }
+
+    /// Aplica um status já validado, respeitando a política de subtarefas
+    fn apply_status(
+        &mut self,
+        validated_status: TaskStatus,
+        policy: SubtaskCompletionPolicy,
+    ) -> DomainResult<()> {
+        if validated_status == TaskStatus::Completed
+            && self.status != TaskStatus::Completed
+            && policy == SubtaskCompletionPolicy::RequireClosed
+            && self.subtasks.open > 0
+        {
+            return Err(DomainError::ValidationError(format!(
+                "Não é possível concluir a tarefa com {} subtarefa(s) em aberto",
+                self.subtasks.open
+            )));
+        }
+
         self.status = validated_status;
 
         if validated_status == TaskStatus::Completed {
@@ -83,6 +291,23 @@ impl Task {
         Ok(())
     }
 
+    /// Move a tarefa no quadro: muda de coluna (status) e de posição de uma vez
+    pub fn move_to(
+        &mut self,
+        workflow: Option<&Workflow>,
+        status: TaskStatus,
+        rank: Rank,
+        policy: SubtaskCompletionPolicy,
+    ) -> DomainResult<()> {
+        if status != self.status {
+            self.update_status(workflow, status, policy)?;
+        }
+
+        self.rank = rank;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
     /// Atualiza a prioridade
     pub fn update_priority(&mut self, priority: TaskPriority) {
         self.priority = priority;
@@ -95,6 +320,76 @@ impl Task {
         self.updated_at = Utc::now();
     }
 
+    /// Substitui as tags da tarefa (normalizadas, sem duplicatas e ordenadas)
+    pub fn update_tags(&mut self, tags: Vec<String>) -> DomainResult<()> {
+        let mut normalized = tags
+            .iter()
+            .map(|tag| Tag::normalize_name(tag))
+            .collect::<DomainResult<Vec<String>>>()?;
+        normalized.sort();
+        normalized.dedup();
+
+        self.tags = normalized;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Define (ou remove) a regra de recorrência
+    pub fn set_recurrence(&mut self, recurrence: Option<RecurrenceRule>) {
+        self.series_id = recurrence
+            .as_ref()
+            .map(|_| self.series_id.unwrap_or(self.id));
+        // Ao trocar a regra, a âncora anterior deixa de valer
+        if self.recurrence != recurrence {
+            self.series_start = None;
+        }
+        self.recurrence = recurrence;
+        self.updated_at = Utc::now();
+    }
+
+    /// Gera a próxima ocorrência de uma tarefa recorrente concluída
+    ///
+    /// O novo prazo é calculado a partir do prazo atual (ou da data de conclusão,
+    /// se a tarefa não tiver prazo) e, em MONTHLY/YEARLY, do início da série.
+    /// Retorna `None` se a tarefa não é recorrente, não está concluída ou se a
+    /// série terminou.
+    pub fn next_occurrence(&self, id: Uuid) -> DomainResult<Option<Task>> {
+        let Some(recurrence) = &self.recurrence else {
+            return Ok(None);
+        };
+
+        if self.status != TaskStatus::Completed {
+            return Ok(None);
+        }
+
+        let base = self.due_date.or(self.completed_at).unwrap_or_else(Utc::now);
+        // A primeira ocorrência define o início da série
+        let series_start = self.series_start.or((self.occurrence == 1).then_some(base));
+        let Some(due_date) = recurrence.next_occurrence(series_start, base, self.occurrence as u32)
+        else {
+            return Ok(None);
+        };
+
+        let mut next = Task::new(
+            id,
+            self.user_id,
+            self.workspace_id,
+            self.title.clone(),
+            self.description.clone(),
+            self.priority,
+            Some(due_date),
+        )?;
+        next.parent_id = self.parent_id;
+        next.project_id = self.project_id;
+        next.tags = self.tags.clone();
+        next.recurrence = Some(recurrence.clone());
+        next.series_id = Some(self.series_id.unwrap_or(self.id));
+        next.occurrence = self.occurrence + 1;
+        next.series_start = series_start;
+
+        Ok(Some(next))
+    }
+
     /// Verifica se a tarefa está atrasada
     pub fn is_overdue(&self) -> bool {
         if let Some(due_date) = self.due_date {
@@ -118,6 +413,7 @@ mod tests {
     #[test]
     fn test_create_task() {
         let task = Task::new(
+            Uuid::new_v4(),
             Uuid::new_v4(),
             Uuid::new_v4(),
             "Test Task".to_string(),
@@ -131,6 +427,7 @@ mod tests {
     #[test]
     fn test_empty_title_fails() {
         let task = Task::new(
+            Uuid::new_v4(),
             Uuid::new_v4(),
             Uuid::new_v4(),
             "".to_string(),
@@ -144,6 +441,7 @@ mod tests {
     #[test]
     fn test_status_transition() {
         let mut task = Task::new(
+            Uuid::new_v4(),
             Uuid::new_v4(),
             Uuid::new_v4(),
             "Test".to_string(),
@@ -153,7 +451,434 @@ mod tests {
         )
         .unwrap();
 
-        assert!(task.update_status(TaskStatus::InProgress).is_ok());
+        assert!(
+            task.update_status(
+                None,
+                TaskStatus::InProgress,
+                SubtaskCompletionPolicy::default()
+            )
+            .is_ok()
+        );
         assert_eq!(task.status, TaskStatus::InProgress);
     }
+
+    #[test]
+    fn test_cannot_complete_with_open_subtasks() {
+        let mut task = Task::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "Parent".to_string(),
+            None,
+            TaskPriority::Medium,
+            None,
+        )
+        .unwrap();
+        task.subtasks = SubtaskProgress {
+            total: 2,
+            completed: 1,
+            open: 1,
+        };
+
+        task.update_status(
+            None,
+            TaskStatus::InProgress,
+            SubtaskCompletionPolicy::default(),
+        )
+        .unwrap();
+        assert!(
+            task.update_status(
+                None,
+                TaskStatus::Completed,
+                SubtaskCompletionPolicy::default()
+            )
+            .is_err()
+        );
+        assert!(
+            task.update_status(
+                None,
+                TaskStatus::Completed,
+                SubtaskCompletionPolicy::AllowOpen
+            )
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_update_tags_normalizes_and_dedups() {
+        let mut task = Task::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "Test".to_string(),
+            None,
+            TaskPriority::Medium,
+            None,
+        )
+        .unwrap();
+
+        task.update_tags(vec![
+            "Backend".to_string(),
+            "q3-release".to_string(),
+            " backend ".to_string(),
+        ])
+        .unwrap();
+
+        assert_eq!(task.tags, vec!["backend", "q3-release"]);
+    }
+
+    #[test]
+    fn test_completed_recurring_task_spawns_next_occurrence() {
+        let due_date = "2026-10-19T09:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let mut task = Task::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "Relatório semanal".to_string(),
+            None,
+            TaskPriority::Medium,
+            Some(due_date),
+        )
+        .unwrap();
+        task.set_recurrence(Some("FREQ=WEEKLY;COUNT=2".parse().unwrap()));
+
+        assert!(task.next_occurrence(Uuid::new_v4()).unwrap().is_none());
+
+        task.update_status(
+            None,
+            TaskStatus::InProgress,
+            SubtaskCompletionPolicy::default(),
+        )
+        .unwrap();
+        task.update_status(
+            None,
+            TaskStatus::Completed,
+            SubtaskCompletionPolicy::default(),
+        )
+        .unwrap();
+        let next = task.next_occurrence(Uuid::new_v4()).unwrap().unwrap();
+
+        assert_eq!(next.status, TaskStatus::Pending);
+        assert_eq!(next.series_id, Some(task.id));
+        assert_eq!(next.workspace_id, task.workspace_id);
+        assert_eq!(next.occurrence, 2);
+        assert_eq!(next.series_start, Some(due_date));
+        assert_eq!(next.due_date, Some(due_date + chrono::Duration::weeks(1)));
+
+        // COUNT=2 atingido: a série termina
+        let mut last = next;
+        last.update_status(
+            None,
+            TaskStatus::InProgress,
+            SubtaskCompletionPolicy::default(),
+        )
+        .unwrap();
+        last.update_status(
+            None,
+            TaskStatus::Completed,
+            SubtaskCompletionPolicy::default(),
+        )
+        .unwrap();
+        assert!(last.next_occurrence(Uuid::new_v4()).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_parent_must_share_workspace() {
+        let new_task = |workspace_id| {
+            Task::new(
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                workspace_id,
+                "Tarefa".to_string(),
+                None,
+                TaskPriority::Medium,
+                None,
+            )
+            .unwrap()
+        };
+        let workspace_id = Uuid::new_v4();
+        let parent = new_task(workspace_id);
+
+        assert!(
+            new_task(workspace_id)
+                .set_parent(&parent, SubtaskCompletionPolicy::default())
+                .is_ok()
+        );
+        assert!(
+            new_task(Uuid::new_v4())
+                .set_parent(&parent, SubtaskCompletionPolicy::default())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_set_project_checks_workspace_and_archive() {
+        let workspace_id = Uuid::new_v4();
+        let mut task = Task::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            workspace_id,
+            "Tarefa".to_string(),
+            None,
+            TaskPriority::Medium,
+            None,
+        )
+        .unwrap();
+        let mut project = Project::new(workspace_id, "Backend", None, 0).unwrap();
+
+        task.set_project(Some(&project)).unwrap();
+        assert_eq!(task.project_id, Some(project.id));
+
+        let other = Project::new(Uuid::new_v4(), "Outro", None, 0).unwrap();
+        assert!(task.set_project(Some(&other)).is_err());
+
+        project.archive();
+        assert!(task.set_project(Some(&project)).is_err());
+
+        task.set_project(None).unwrap();
+        assert_eq!(task.project_id, None);
+    }
+
+    #[test]
+    fn test_move_to_honors_status_transitions() {
+        let mut task = Task::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "Tarefa".to_string(),
+            None,
+            TaskPriority::Medium,
+            None,
+        )
+        .unwrap();
+        let rank = Rank::between(Some(&task.rank), None).unwrap();
+
+        // pending -> completed não é uma transição válida
+        let result = task.move_to(
+            None,
+            TaskStatus::Completed,
+            rank.clone(),
+            SubtaskCompletionPolicy::default(),
+        );
+        assert!(result.is_err());
+        assert_eq!(task.rank, Rank::initial());
+
+        task.move_to(
+            None,
+            TaskStatus::InProgress,
+            rank.clone(),
+            SubtaskCompletionPolicy::default(),
+        )
+        .unwrap();
+        assert_eq!(task.status, TaskStatus::InProgress);
+        assert_eq!(task.rank, rank);
+    }
+
+    #[test]
+    fn test_workflow_replaces_builtin_transitions() {
+        let mut task = Task::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "Tarefa".to_string(),
+            None,
+            TaskPriority::Medium,
+            None,
+        )
+        .unwrap();
+        let states = [
+            ("Backlog", TaskStatus::Pending),
+            ("Fazendo", TaskStatus::InProgress),
+            ("Revisão", TaskStatus::InProgress),
+            ("Feito", TaskStatus::Completed),
+            ("Descartado", TaskStatus::Cancelled),
+        ]
+        .into_iter()
+        .map(|(name, category)| (name.to_string(), category))
+        .collect();
+        let transitions = [
+            ("Backlog", "Fazendo"),
+            ("Fazendo", "Revisão"),
+            ("Revisão", "Feito"),
+        ]
+        .into_iter()
+        .map(|(from, to)| (from.to_string(), to.to_string()))
+        .collect();
+        let workflow = Workflow::new(task.workspace_id, "Revisão", states, transitions).unwrap();
+        let policy = SubtaskCompletionPolicy::default();
+
+        task.enter_workflow(Some(&workflow));
+        assert_eq!(task.state_id, Some(workflow.states[0].id));
+
+        // pending -> cancelled é válido por padrão, mas não neste workflow
+        assert!(
+            task.update_status(Some(&workflow), TaskStatus::Cancelled, policy)
+                .is_err()
+        );
+
+        task.update_status(Some(&workflow), TaskStatus::InProgress, policy)
+            .unwrap();
+        assert_eq!(task.state_id, Some(workflow.states[1].id));
+
+        // Sem o workflow, as transições padrão não servem de atalho
+        assert!(
+            task.update_status(None, TaskStatus::Completed, policy)
+                .is_err()
+        );
+        assert_eq!(task.status, TaskStatus::InProgress);
+
+        // Fazendo -> Feito exige passar pela revisão
+        assert!(
+            task.update_state(&workflow, workflow.states[3].id, policy)
+                .is_err()
+        );
+        task.update_state(&workflow, workflow.states[2].id, policy)
+            .unwrap();
+        assert_eq!(task.status, TaskStatus::InProgress);
+        task.update_state(&workflow, workflow.states[3].id, policy)
+            .unwrap();
+        assert_eq!(task.status, TaskStatus::Completed);
+        assert!(task.completed_at.is_some());
+
+        task.enter_workflow(None);
+        assert_eq!(task.state_id, None);
+    }
+
+    #[test]
+    fn test_reopen_closed_task() {
+        let mut task = Task::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "Tarefa".to_string(),
+            None,
+            TaskPriority::Medium,
+            None,
+        )
+        .unwrap();
+
+        // Apenas status finais podem ser reabertos
+        assert!(
+            task.reopen(
+                None,
+                None,
+                ReopenPolicy::All,
+                SubtaskCompletionPolicy::default()
+            )
+            .is_err()
+        );
+
+        task.update_status(
+            None,
+            TaskStatus::InProgress,
+            SubtaskCompletionPolicy::default(),
+        )
+        .unwrap();
+        task.update_status(
+            None,
+            TaskStatus::Completed,
+            SubtaskCompletionPolicy::default(),
+        )
+        .unwrap();
+        assert!(matches!(
+            task.reopen(
+                None,
+                None,
+                ReopenPolicy::CancelledOnly,
+                SubtaskCompletionPolicy::default()
+            ),
+            Err(DomainError::Forbidden(_))
+        ));
+        assert_eq!(task.status, TaskStatus::Completed);
+
+        task.reopen(
+            None,
+            None,
+            ReopenPolicy::CompletedOnly,
+            SubtaskCompletionPolicy::default(),
+        )
+        .unwrap();
+        assert_eq!(task.status, TaskStatus::Pending);
+        assert!(task.completed_at.is_none());
+    }
+
+    #[test]
+    fn test_reopen_subtask_requires_open_parent() {
+        let new_task = |title: &str| {
+            Task::new(
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                title.to_string(),
+                None,
+                TaskPriority::Medium,
+                None,
+            )
+            .unwrap()
+        };
+        let policy = SubtaskCompletionPolicy::default();
+
+        let mut parent = new_task("Pai");
+        let mut subtask = new_task("Subtarefa");
+        subtask.workspace_id = parent.workspace_id;
+        subtask.set_parent(&parent, policy).unwrap();
+        subtask
+            .update_status(None, TaskStatus::InProgress, policy)
+            .unwrap();
+        subtask
+            .update_status(None, TaskStatus::Completed, policy)
+            .unwrap();
+        parent
+            .update_status(None, TaskStatus::InProgress, policy)
+            .unwrap();
+        parent
+            .update_status(None, TaskStatus::Completed, policy)
+            .unwrap();
+
+        // A tarefa pai é obrigatória e precisa ser a da subtarefa
+        assert!(
+            subtask
+                .reopen(None, None, ReopenPolicy::All, policy)
+                .is_err()
+        );
+        let other = new_task("Outra");
+        assert!(
+            subtask
+                .reopen(None, Some(&other), ReopenPolicy::All, policy)
+                .is_err()
+        );
+
+        // Pai concluído não pode voltar a ter subtarefas em aberto
+        assert!(
+            subtask
+                .reopen(None, Some(&parent), ReopenPolicy::All, policy)
+                .is_err()
+        );
+        assert_eq!(subtask.status, TaskStatus::Completed);
+
+        subtask
+            .reopen(
+                None,
+                Some(&parent),
+                ReopenPolicy::All,
+                SubtaskCompletionPolicy::AllowOpen,
+            )
+            .unwrap();
+        assert_eq!(subtask.status, TaskStatus::Pending);
+
+        // Com o pai reaberto, a subtarefa também pode ser reaberta
+        subtask
+            .update_status(None, TaskStatus::InProgress, policy)
+            .unwrap();
+        subtask
+            .update_status(None, TaskStatus::Completed, policy)
+            .unwrap();
+        parent
+            .reopen(None, None, ReopenPolicy::All, policy)
+            .unwrap();
+        subtask
+            .reopen(None, Some(&parent), ReopenPolicy::All, policy)
+            .unwrap();
+        assert_eq!(subtask.status, TaskStatus::Pending);
+    }
 }
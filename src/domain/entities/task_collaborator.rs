@@ -0,0 +1,27 @@
+use crate::domain::value_objects::CollaboratorRole;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Entidade TaskCollaborator - Usuário com acesso compartilhado a uma tarefa
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskCollaborator {
+    pub task_id: Uuid,
+    pub user_id: Uuid,
+    pub role: CollaboratorRole,
+    pub invited_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TaskCollaborator {
+    /// Cria um novo vínculo de colaboração
+    pub fn new(task_id: Uuid, user_id: Uuid, role: CollaboratorRole, invited_by: Uuid) -> Self {
+        Self {
+            task_id,
+            user_id,
+            role,
+            invited_by: Some(invited_by),
+            created_at: Utc::now(),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::domain::errors::{DomainError, DomainResult};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Entidade TaskDependency - A tarefa `task_id` é bloqueada por `blocked_by_id`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskDependency {
+    pub task_id: Uuid,
+    pub blocked_by_id: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TaskDependency {
+    /// Cria uma nova dependência
+    pub fn new(task_id: Uuid, blocked_by_id: Uuid) -> DomainResult<Self> {
+        if task_id == blocked_by_id {
+            return Err(DomainError::ValidationError(
+                "Uma tarefa não pode bloquear a si mesma".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            task_id,
+            blocked_by_id,
+            created_at: Utc::now(),
+        })
+    }
+}
@@ -0,0 +1,57 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Entidade TotpCredential - Segredo TOTP (RFC 6238) do segundo fator de um usuário
+///
+/// O 2FA só fica ativo após a confirmação com um código válido.
+#[derive(Debug, Clone)]
+pub struct TotpCredential {
+    pub user_id: Uuid,
+    /// Segredo em base32 (formato usado pelos apps autenticadores)
+    pub secret: String,
+    pub confirmed_at: Option<DateTime<Utc>>,
+    /// Último passo de tempo aceito (impede reutilizar um código)
+    pub last_used_step: Option<i64>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TotpCredential {
+    /// Inicia uma inscrição (ainda não confirmada)
+    pub fn new(user_id: Uuid, secret: String) -> Self {
+        Self {
+            user_id,
+            secret,
+            confirmed_at: None,
+            last_used_step: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// Verifica se o 2FA está ativo
+    pub fn is_confirmed(&self) -> bool {
+        self.confirmed_at.is_some()
+    }
+
+    /// Ativa o 2FA
+    pub fn confirm(&mut self) {
+        self.confirmed_at.get_or_insert_with(Utc::now);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_credential_starts_unconfirmed() {
+        let mut credential = TotpCredential::new(Uuid::new_v4(), "JBSWY3DPEHPK3PXP".to_string());
+        assert!(!credential.is_confirmed());
+
+        credential.confirm();
+        let confirmed_at = credential.confirmed_at;
+        credential.confirm();
+
+        assert!(credential.is_confirmed());
+        assert_eq!(credential.confirmed_at, confirmed_at);
+    }
+}
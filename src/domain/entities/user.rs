@@ -1,4 +1,4 @@
-use crate::domain::value_objects::Email;
+use crate::domain::value_objects::{Email, UserRole};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -11,6 +11,13 @@ pub struct User {
     #[serde(skip_serializing)]
     pub password_hash: String,
     pub name: String,
+    pub role: UserRole,
+    /// Versão dos tokens; incrementada no "sair de todos os dispositivos"
+    pub token_version: i32,
+    /// Momento da confirmação do email (`None` enquanto não verificado)
+    pub email_verified_at: Option<DateTime<Utc>>,
+    /// Momento em que um administrador desativou a conta
+    pub disabled_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -24,6 +31,10 @@ impl User {
             email,
             password_hash,
             name,
+            role: UserRole::User,
+            token_version: 0,
+            email_verified_at: None,
+            disabled_at: None,
             created_at: now,
             updated_at: now,
         }
@@ -35,9 +46,58 @@ impl User {
         self.updated_at = Utc::now();
     }
 
+    /// Verifica se o email já foi confirmado
+    pub fn is_email_verified(&self) -> bool {
+        self.email_verified_at.is_some()
+    }
+
+    /// Marca o email como confirmado
+    pub fn verify_email(&mut self) {
+        if self.email_verified_at.is_none() {
+            let now = Utc::now();
+            self.email_verified_at = Some(now);
+            self.updated_at = now;
+        }
+    }
+
+    /// Troca o email por um endereço já confirmado pelo link de verificação
+    pub fn change_email(&mut self, email: Email) {
+        let now = Utc::now();
+        self.email = email;
+        self.email_verified_at = Some(now);
+        self.updated_at = now;
+    }
+
     /// Atualiza o hash da senha
     pub fn update_password(&mut self, password_hash: String) {
         self.password_hash = password_hash;
         self.updated_at = Utc::now();
     }
+
+    /// Verifica se o usuário é administrador
+    pub fn is_admin(&self) -> bool {
+        self.role == UserRole::Admin
+    }
+
+    /// Verifica se a conta foi desativada
+    pub fn is_disabled(&self) -> bool {
+        self.disabled_at.is_some()
+    }
+
+    /// Desativa a conta
+    pub fn disable(&mut self) {
+        if self.disabled_at.is_none() {
+            let now = Utc::now();
+            self.disabled_at = Some(now);
+            self.updated_at = now;
+        }
+    }
+
+    /// Reativa a conta
+    pub fn enable(&mut self) {
+        if self.disabled_at.is_some() {
+            self.disabled_at = None;
+            self.updated_at = Utc::now();
+        }
+    }
 }
@@ -0,0 +1,204 @@
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::events::TaskEventKind;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Tamanho mínimo do segredo usado para assinar os payloads
+pub const MIN_WEBHOOK_SECRET_LENGTH: usize = 16;
+
+/// Tamanho máximo da URL de destino
+const MAX_WEBHOOK_URL_LENGTH: usize = 2048;
+
+/// Entidade Webhook - Assinatura de um usuário para eventos de tarefas
+#[derive(Debug, Clone)]
+pub struct Webhook {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub url: String,
+    /// Segredo compartilhado para a assinatura HMAC-SHA256
+    pub secret: String,
+    pub event_types: Vec<TaskEventKind>,
+    pub active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Webhook {
+    /// Cria uma nova assinatura de webhook
+    pub fn new(
+        id: Uuid,
+        user_id: Uuid,
+        url: String,
+        secret: String,
+        event_types: Vec<TaskEventKind>,
+    ) -> DomainResult<Self> {
+        let now = Utc::now();
+        Ok(Self {
+            id,
+            user_id,
+            url: Self::validate_url(url)?,
+            secret: Self::validate_secret(secret)?,
+            event_types: Self::validate_event_types(event_types)?,
+            active: true,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Altera a URL de destino
+    pub fn update_url(&mut self, url: String) -> DomainResult<()> {
+        self.url = Self::validate_url(url)?;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Substitui o segredo de assinatura
+    pub fn update_secret(&mut self, secret: String) -> DomainResult<()> {
+        self.secret = Self::validate_secret(secret)?;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Substitui os tipos de evento assinados
+    pub fn update_event_types(&mut self, event_types: Vec<TaskEventKind>) -> DomainResult<()> {
+        self.event_types = Self::validate_event_types(event_types)?;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Ativa ou desativa o envio
+    pub fn set_active(&mut self, active: bool) {
+        self.active = active;
+        self.updated_at = Utc::now();
+    }
+
+    /// Verifica se o usuário é o dono do webhook
+    pub fn is_owned_by(&self, user_id: &Uuid) -> bool {
+        &self.user_id == user_id
+    }
+
+    /// Indica se o webhook deve receber eventos do tipo informado
+    pub fn subscribes_to(&self, kind: TaskEventKind) -> bool {
+        self.active && self.event_types.contains(&kind)
+    }
+
+    fn validate_url(url: String) -> DomainResult<String> {
+        let url = url.trim().to_string();
+
+        let rest = url
+            .strip_prefix("https://")
+            .or_else(|| url.strip_prefix("http://"))
+            .ok_or_else(|| {
+                DomainError::ValidationError("URL do webhook deve usar http ou https".to_string())
+            })?;
+
+        let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+        if host.is_empty()
+            || url.len() > MAX_WEBHOOK_URL_LENGTH
+            || url.contains(char::is_whitespace)
+        {
+            return Err(DomainError::ValidationError(
+                "URL do webhook inválida".to_string(),
+            ));
+        }
+
+        Ok(url)
+    }
+
+    fn validate_secret(secret: String) -> DomainResult<String> {
+        if secret.chars().count() < MIN_WEBHOOK_SECRET_LENGTH {
+            return Err(DomainError::ValidationError(format!(
+                "Segredo do webhook deve ter pelo menos {} caracteres",
+                MIN_WEBHOOK_SECRET_LENGTH
+            )));
+        }
+
+        Ok(secret)
+    }
+
+    fn validate_event_types(
+        mut event_types: Vec<TaskEventKind>,
+    ) -> DomainResult<Vec<TaskEventKind>> {
+        let mut unique = Vec::with_capacity(event_types.len());
+        for kind in event_types.drain(..) {
+            if !unique.contains(&kind) {
+                unique.push(kind);
+            }
+        }
+
+        if unique.is_empty() {
+            return Err(DomainError::ValidationError(
+                "Informe ao menos um tipo de evento".to_string(),
+            ));
+        }
+
+        Ok(unique)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn webhook(url: &str) -> DomainResult<Webhook> {
+        Webhook::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            url.to_string(),
+            "0123456789abcdef".to_string(),
+            vec![TaskEventKind::TaskCreated, TaskEventKind::TaskCreated],
+        )
+    }
+
+    #[test]
+    fn test_create_webhook() {
+        let webhook = webhook(" https://hooks.example.com/tasks ").unwrap();
+
+        assert_eq!(webhook.url, "https://hooks.example.com/tasks");
+        assert_eq!(webhook.event_types, vec![TaskEventKind::TaskCreated]);
+        assert!(webhook.subscribes_to(TaskEventKind::TaskCreated));
+        assert!(!webhook.subscribes_to(TaskEventKind::TaskDeleted));
+    }
+
+    #[test]
+    fn test_invalid_url() {
+        assert!(webhook("ftp://example.com").is_err());
+        assert!(webhook("https://").is_err());
+        assert!(webhook("https://exa mple.com").is_err());
+    }
+
+    #[test]
+    fn test_short_secret_and_empty_events() {
+        let user_id = Uuid::new_v4();
+        let url = "http://localhost:9000/hook".to_string();
+
+        assert!(
+            Webhook::new(
+                Uuid::new_v4(),
+                user_id,
+                url.clone(),
+                "short".to_string(),
+                vec![TaskEventKind::TaskUpdated],
+            )
+            .is_err()
+        );
+        assert!(
+            Webhook::new(
+                Uuid::new_v4(),
+                user_id,
+                url,
+                "0123456789abcdef".to_string(),
+                vec![],
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_inactive_webhook_does_not_subscribe() {
+        let mut webhook = webhook("https://example.com").unwrap();
+        webhook.set_active(false);
+
+        assert!(!webhook.subscribes_to(TaskEventKind::TaskCreated));
+    }
+}
@@ -0,0 +1,135 @@
+use crate::domain::events::TaskEventKind;
+use crate::domain::value_objects::{WebhookDeliveryStatus, WebhookRetryPolicy};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Tamanho máximo da mensagem de erro registrada por tentativa
+const MAX_ERROR_LENGTH: usize = 500;
+
+/// Entidade WebhookDelivery - Entrega de um evento para um webhook (fila e log)
+#[derive(Debug, Clone)]
+pub struct WebhookDelivery {
+    pub id: Uuid,
+    pub webhook_id: Uuid,
+    /// Identificador do evento de domínio (idempotência no destino)
+    pub event_id: Uuid,
+    pub event_type: TaskEventKind,
+    /// Corpo JSON enviado
+    pub payload: String,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    pub next_attempt_at: DateTime<Utc>,
+    pub last_status_code: Option<i32>,
+    pub last_error: Option<String>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl WebhookDelivery {
+    /// Enfileira uma nova entrega para envio imediato
+    pub fn new(
+        webhook_id: Uuid,
+        event_id: Uuid,
+        event_type: TaskEventKind,
+        payload: String,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            webhook_id,
+            event_id,
+            event_type,
+            payload,
+            status: WebhookDeliveryStatus::Pending,
+            attempts: 0,
+            next_attempt_at: now,
+            last_status_code: None,
+            last_error: None,
+            delivered_at: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Registra uma tentativa bem-sucedida
+    pub fn record_success(&mut self, status_code: u16) {
+        let now = Utc::now();
+        self.attempts += 1;
+        self.status = WebhookDeliveryStatus::Delivered;
+        self.last_status_code = Some(status_code as i32);
+        self.last_error = None;
+        self.delivered_at = Some(now);
+        self.updated_at = now;
+    }
+
+    /// Registra uma tentativa falha, agendando nova tentativa ou movendo para dead letter
+    pub fn record_failure(
+        &mut self,
+        status_code: Option<u16>,
+        error: &str,
+        policy: &WebhookRetryPolicy,
+    ) {
+        let now = Utc::now();
+        self.attempts += 1;
+        self.last_status_code = status_code.map(|code| code as i32);
+        self.last_error = Some(error.chars().take(MAX_ERROR_LENGTH).collect());
+        self.updated_at = now;
+
+        let attempts = self.attempts.max(0) as u32;
+        if policy.is_exhausted(attempts) {
+            self.status = WebhookDeliveryStatus::DeadLettered;
+        } else {
+            self.next_attempt_at = now + policy.delay_for(attempts);
+        }
+    }
+
+    /// Move a entrega para dead letter sem nova tentativa
+    pub fn dead_letter(&mut self, reason: &str) {
+        self.status = WebhookDeliveryStatus::DeadLettered;
+        self.last_error = Some(reason.chars().take(MAX_ERROR_LENGTH).collect());
+        self.updated_at = Utc::now();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn delivery() -> WebhookDelivery {
+        WebhookDelivery::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            TaskEventKind::TaskCreated,
+            "{}".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_record_success() {
+        let mut delivery = delivery();
+        delivery.record_success(204);
+
+        assert_eq!(delivery.status, WebhookDeliveryStatus::Delivered);
+        assert_eq!(delivery.attempts, 1);
+        assert_eq!(delivery.last_status_code, Some(204));
+        assert!(delivery.delivered_at.is_some());
+    }
+
+    #[test]
+    fn test_record_failure_schedules_retry_then_dead_letters() {
+        let policy = WebhookRetryPolicy::new(2, Duration::seconds(30), Duration::minutes(10));
+        let mut delivery = delivery();
+
+        delivery.record_failure(Some(500), "HTTP 500", &policy);
+        assert_eq!(delivery.status, WebhookDeliveryStatus::Pending);
+        assert!(delivery.next_attempt_at > Utc::now() + Duration::seconds(25));
+
+        delivery.record_failure(None, "connection refused", &policy);
+        assert_eq!(delivery.status, WebhookDeliveryStatus::DeadLettered);
+        assert_eq!(delivery.attempts, 2);
+        assert_eq!(delivery.last_status_code, None);
+        assert_eq!(delivery.last_error.as_deref(), Some("connection refused"));
+    }
+}
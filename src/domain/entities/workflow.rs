@@ -0,0 +1,326 @@
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::value_objects::TaskStatus;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Tamanho máximo do nome de um workflow
+const MAX_NAME_LENGTH: usize = 100;
+
+/// Tamanho máximo do nome de um estado
+const MAX_STATE_NAME_LENGTH: usize = 50;
+
+/// Estado de um workflow, mapeado para uma categoria (`TaskStatus`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkflowState {
+    pub id: Uuid,
+    pub workflow_id: Uuid,
+    pub name: String,
+    pub category: TaskStatus,
+    pub position: i32,
+}
+
+/// Transição permitida entre dois estados de um workflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkflowTransition {
+    pub from_state_id: Uuid,
+    pub to_state_id: Uuid,
+}
+
+/// Entidade Workflow - Máquina de estados configurável das tarefas de um projeto
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Workflow {
+    pub id: Uuid,
+    pub workspace_id: Uuid,
+    pub name: String,
+    /// Estados na ordem de exibição
+    pub states: Vec<WorkflowState>,
+    pub transitions: Vec<WorkflowTransition>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Workflow {
+    /// Cria um workflow a partir dos estados `(nome, categoria)` e das transições `(de, para)` por nome
+    ///
+    /// Cada categoria precisa de ao menos um estado, para que toda tarefa tenha
+    /// um estado correspondente ao entrar no workflow.
+    pub fn new(
+        workspace_id: Uuid,
+        name: &str,
+        states: Vec<(String, TaskStatus)>,
+        transitions: Vec<(String, String)>,
+    ) -> DomainResult<Self> {
+        let id = Uuid::new_v4();
+        let name = validate_name(name, MAX_NAME_LENGTH, "Nome do workflow")?;
+
+        let mut workflow_states: Vec<WorkflowState> = Vec::with_capacity(states.len());
+        for (position, (state_name, category)) in states.into_iter().enumerate() {
+            let state_name = validate_name(&state_name, MAX_STATE_NAME_LENGTH, "Nome do estado")?;
+
+            if workflow_states
+                .iter()
+                .any(|state| state.name.eq_ignore_ascii_case(&state_name))
+            {
+                return Err(DomainError::ValidationError(format!(
+                    "Estado duplicado no workflow: {}",
+                    state_name
+                )));
+            }
+
+            workflow_states.push(WorkflowState {
+                id: Uuid::new_v4(),
+                workflow_id: id,
+                name: state_name,
+                category,
+                position: position as i32,
+            });
+        }
+
+        if let Some(missing) = TaskStatus::ALL
+            .into_iter()
+            .find(|category| !workflow_states.iter().any(|s| s.category == *category))
+        {
+            return Err(DomainError::ValidationError(format!(
+                "O workflow precisa de ao menos um estado na categoria {}",
+                missing
+            )));
+        }
+
+        let find = |state_name: &str| {
+            workflow_states
+                .iter()
+                .find(|state| state.name.eq_ignore_ascii_case(state_name.trim()))
+                .map(|state| state.id)
+                .ok_or_else(|| {
+                    DomainError::ValidationError(format!(
+                        "Transição com estado desconhecido: {}",
+                        state_name
+                    ))
+                })
+        };
+
+        let mut workflow_transitions: Vec<WorkflowTransition> = Vec::new();
+        for (from, to) in transitions {
+            let transition = WorkflowTransition {
+                from_state_id: find(&from)?,
+                to_state_id: find(&to)?,
+            };
+
+            if transition.from_state_id != transition.to_state_id
+                && !workflow_transitions.contains(&transition)
+            {
+                workflow_transitions.push(transition);
+            }
+        }
+
+        let now = Utc::now();
+        Ok(Self {
+            id,
+            workspace_id,
+            name,
+            states: workflow_states,
+            transitions: workflow_transitions,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Busca um estado do workflow
+    pub fn state(&self, state_id: &Uuid) -> Option<&WorkflowState> {
+        self.states.iter().find(|state| &state.id == state_id)
+    }
+
+    /// Primeiro estado (na ordem de exibição) de uma categoria
+    pub fn first_state_of(&self, category: TaskStatus) -> Option<&WorkflowState> {
+        self.states
+            .iter()
+            .filter(|state| state.category == category)
+            .min_by_key(|state| state.position)
+    }
+
+    /// Verifica se a transição é permitida (permanecer no mesmo estado sempre é)
+    pub fn can_transition(&self, from: &Uuid, to: &Uuid) -> bool {
+        from == to
+            || self
+                .transitions
+                .iter()
+                .any(|t| &t.from_state_id == from && &t.to_state_id == to)
+    }
+
+    /// Valida a transição e retorna o estado de destino
+    pub fn transition(&self, from: &Uuid, to: &Uuid) -> DomainResult<&WorkflowState> {
+        let current = self.find_state(from)?;
+        let target = self.find_state(to)?;
+
+        if !self.can_transition(from, to) {
+            return Err(DomainError::ValidationError(format!(
+                "Transição inválida de {} para {}",
+                current.name, target.name
+            )));
+        }
+
+        Ok(target)
+    }
+
+    /// Estado de destino para levar a tarefa a uma categoria
+    ///
+    /// Permanece no estado atual se ele já é da categoria; senão usa o primeiro
+    /// estado da categoria alcançável por uma transição permitida.
+    pub fn target_for(&self, from: &Uuid, category: TaskStatus) -> DomainResult<&WorkflowState> {
+        let current = self.find_state(from)?;
+
+        if current.category == category {
+            return Ok(current);
+        }
+
+        self.states
+            .iter()
+            .filter(|state| state.category == category && self.can_transition(from, &state.id))
+            .min_by_key(|state| state.position)
+            .ok_or_else(|| {
+                DomainError::ValidationError(format!(
+                    "Transição inválida de {} para {}",
+                    current.name, category
+                ))
+            })
+    }
+
+    fn find_state(&self, state_id: &Uuid) -> DomainResult<&WorkflowState> {
+        self.state(state_id).ok_or_else(|| {
+            DomainError::ValidationError("Estado não pertence ao workflow".to_string())
+        })
+    }
+}
+
+/// Valida um nome (sem espaços nas pontas, não vazio e dentro do limite)
+fn validate_name(name: &str, max_length: usize, field: &str) -> DomainResult<String> {
+    let name = name.trim();
+
+    if name.is_empty() {
+        return Err(DomainError::ValidationError(format!(
+            "{} não pode ser vazio",
+            field
+        )));
+    }
+
+    if name.chars().count() > max_length {
+        return Err(DomainError::ValidationError(format!(
+            "{} deve ter no máximo {} caracteres",
+            field, max_length
+        )));
+    }
+
+    Ok(name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn review_workflow() -> Workflow {
+        let states = vec![
+            ("Backlog".to_string(), TaskStatus::Pending),
+            ("Fazendo".to_string(), TaskStatus::InProgress),
+            ("Revisão".to_string(), TaskStatus::InProgress),
+            ("Feito".to_string(), TaskStatus::Completed),
+            ("Descartado".to_string(), TaskStatus::Cancelled),
+        ];
+        let transitions = [
+            ("Backlog", "Fazendo"),
+            ("Fazendo", "Revisão"),
+            ("Revisão", "Fazendo"),
+            ("Revisão", "Feito"),
+            ("Backlog", "Descartado"),
+        ]
+        .into_iter()
+        .map(|(from, to)| (from.to_string(), to.to_string()))
+        .collect();
+
+        Workflow::new(Uuid::new_v4(), "Com revisão", states, transitions).unwrap()
+    }
+
+    fn state_id(workflow: &Workflow, name: &str) -> Uuid {
+        workflow
+            .states
+            .iter()
+            .find(|state| state.name == name)
+            .unwrap()
+            .id
+    }
+
+    #[test]
+    fn test_transitions_follow_the_table() {
+        let workflow = review_workflow();
+        let backlog = state_id(&workflow, "Backlog");
+        let doing = state_id(&workflow, "Fazendo");
+        let review = state_id(&workflow, "Revisão");
+        let done = state_id(&workflow, "Feito");
+
+        assert!(workflow.transition(&backlog, &doing).is_ok());
+        assert!(workflow.transition(&doing, &done).is_err());
+        assert_eq!(workflow.transition(&review, &done).unwrap().id, done);
+        assert!(workflow.transition(&done, &done).is_ok());
+        assert!(workflow.transition(&backlog, &Uuid::new_v4()).is_err());
+    }
+
+    #[test]
+    fn test_target_for_category() {
+        let workflow = review_workflow();
+        let backlog = state_id(&workflow, "Backlog");
+        let review = state_id(&workflow, "Revisão");
+
+        let target = workflow
+            .target_for(&backlog, TaskStatus::InProgress)
+            .unwrap();
+        assert_eq!(target.name, "Fazendo");
+        assert_eq!(
+            workflow
+                .target_for(&review, TaskStatus::InProgress)
+                .unwrap()
+                .id,
+            review
+        );
+        assert!(
+            workflow
+                .target_for(&backlog, TaskStatus::Completed)
+                .is_err()
+        );
+        assert_eq!(
+            workflow.first_state_of(TaskStatus::Pending).unwrap().id,
+            backlog
+        );
+    }
+
+    #[test]
+    fn test_new_validates_states_and_transitions() {
+        let states = |names: &[(&str, TaskStatus)]| {
+            names
+                .iter()
+                .map(|(name, category)| (name.to_string(), *category))
+                .collect::<Vec<_>>()
+        };
+        let all = [
+            ("A fazer", TaskStatus::Pending),
+            ("Fazendo", TaskStatus::InProgress),
+            ("Feito", TaskStatus::Completed),
+            ("Cancelado", TaskStatus::Cancelled),
+        ];
+
+        // Categoria sem estado
+        assert!(Workflow::new(Uuid::new_v4(), "W", states(&all[..3]), vec![]).is_err());
+
+        // Estado duplicado
+        let mut duplicated = states(&all);
+        duplicated.push(("feito".to_string(), TaskStatus::Completed));
+        assert!(Workflow::new(Uuid::new_v4(), "W", duplicated, vec![]).is_err());
+
+        // Transição para estado inexistente
+        let unknown = vec![("A fazer".to_string(), "Arquivado".to_string())];
+        assert!(Workflow::new(Uuid::new_v4(), "W", states(&all), unknown).is_err());
+
+        let workflow = Workflow::new(Uuid::new_v4(), " W ", states(&all), vec![]).unwrap();
+        assert_eq!(workflow.name, "W");
+        assert_eq!(workflow.states[2].position, 2);
+    }
+}
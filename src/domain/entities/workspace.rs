@@ -0,0 +1,135 @@
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::value_objects::WorkspaceRole;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Tamanho máximo do nome de um workspace
+const MAX_NAME_LENGTH: usize = 100;
+
+/// Nome do workspace criado no cadastro de cada usuário
+const PERSONAL_WORKSPACE_NAME: &str = "Pessoal";
+
+/// Entidade Workspace - Espaço de trabalho (tenant) que agrupa tarefas e membros
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Workspace {
+    pub id: Uuid,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Workspace {
+    /// Cria um novo workspace
+    pub fn new(name: &str) -> DomainResult<Self> {
+        let name = name.trim();
+
+        if name.is_empty() {
+            return Err(DomainError::ValidationError(
+                "Nome do workspace não pode ser vazio".to_string(),
+            ));
+        }
+
+        if name.chars().count() > MAX_NAME_LENGTH {
+            return Err(DomainError::ValidationError(format!(
+                "Nome do workspace deve ter no máximo {} caracteres",
+                MAX_NAME_LENGTH
+            )));
+        }
+
+        let now = Utc::now();
+        Ok(Self {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Cria o workspace pessoal de um novo usuário
+    pub fn personal() -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            name: PERSONAL_WORKSPACE_NAME.to_string(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+/// Entidade Membership - Vínculo de um usuário com um workspace
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Membership {
+    pub workspace_id: Uuid,
+    pub user_id: Uuid,
+    pub role: WorkspaceRole,
+    pub invited_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Membership {
+    /// Cria o vínculo do criador do workspace
+    pub fn owner(workspace_id: Uuid, user_id: Uuid) -> Self {
+        Self {
+            workspace_id,
+            user_id,
+            role: WorkspaceRole::Owner,
+            invited_by: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// Cria o vínculo de um membro convidado
+    pub fn invited(
+        workspace_id: Uuid,
+        user_id: Uuid,
+        role: WorkspaceRole,
+        invited_by: Uuid,
+    ) -> DomainResult<Self> {
+        if role == WorkspaceRole::Owner {
+            return Err(DomainError::ValidationError(
+                "Convidados não podem entrar como owner".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            workspace_id,
+            user_id,
+            role,
+            invited_by: Some(invited_by),
+            created_at: Utc::now(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_workspace_validates_name() {
+        let workspace = Workspace::new("  Time de Produto ").unwrap();
+        assert_eq!(workspace.name, "Time de Produto");
+
+        assert!(Workspace::new("   ").is_err());
+        assert!(Workspace::new(&"a".repeat(MAX_NAME_LENGTH + 1)).is_err());
+    }
+
+    #[test]
+    fn test_invited_member_cannot_be_owner() {
+        let workspace_id = Uuid::new_v4();
+        let inviter = Uuid::new_v4();
+
+        let member =
+            Membership::invited(workspace_id, Uuid::new_v4(), WorkspaceRole::Admin, inviter)
+                .unwrap();
+        assert_eq!(member.invited_by, Some(inviter));
+        assert!(member.role.can_invite());
+
+        assert!(
+            Membership::invited(workspace_id, Uuid::new_v4(), WorkspaceRole::Owner, inviter)
+                .is_err()
+        );
+    }
+}
@@ -21,9 +21,15 @@ pub enum DomainError {
     #[error("Operação não autorizada")]
     Unauthorized,
 
+    #[error("Acesso negado: {0}")]
+    Forbidden(String),
+
     #[error("Conflito de dados: {0}")]
     Conflict(String),
 
+    #[error("Muitas tentativas. Tente novamente em {0} segundos")]
+    TooManyAttempts(i64),
+
     #[error("Erro interno: {0}")]
     Internal(String),
 }
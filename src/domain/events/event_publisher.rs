@@ -0,0 +1,9 @@
+use crate::domain::events::TaskEvent;
+
+/// Publicador de eventos de domínio (Port do padrão Hexagonal)
+///
+/// A publicação é "fire-and-forget": não falha se não houver assinantes.
+pub trait EventPublisher: Send + Sync {
+    /// Publica um evento
+    fn publish(&self, event: TaskEvent);
+}
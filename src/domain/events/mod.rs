@@ -0,0 +1,5 @@
+pub mod event_publisher;
+pub mod task_event;
+
+pub use event_publisher::EventPublisher;
+pub use task_event::{TaskEvent, TaskEventKind};
@@ -0,0 +1,98 @@
+use crate::domain::entities::Task;
+use crate::domain::errors::DomainError;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// Tipos de evento de domínio emitidos sobre tarefas
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TaskEventKind {
+    #[serde(rename = "task.created")]
+    TaskCreated,
+    #[serde(rename = "task.updated")]
+    TaskUpdated,
+    #[serde(rename = "task.deleted")]
+    TaskDeleted,
+}
+
+impl fmt::Display for TaskEventKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TaskEventKind::TaskCreated => write!(f, "task.created"),
+            TaskEventKind::TaskUpdated => write!(f, "task.updated"),
+            TaskEventKind::TaskDeleted => write!(f, "task.deleted"),
+        }
+    }
+}
+
+impl FromStr for TaskEventKind {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "task.created" => Ok(TaskEventKind::TaskCreated),
+            "task.updated" => Ok(TaskEventKind::TaskUpdated),
+            "task.deleted" => Ok(TaskEventKind::TaskDeleted),
+            _ => Err(DomainError::ValidationError(format!(
+                "Tipo de evento inválido: {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// Evento de domínio sobre uma tarefa
+#[derive(Debug, Clone)]
+pub struct TaskEvent {
+    pub id: Uuid,
+    pub kind: TaskEventKind,
+    pub task_id: Uuid,
+    /// Dono da tarefa (destinatário das notificações)
+    pub owner_id: Uuid,
+    /// Usuário que executou a ação
+    pub actor_id: Uuid,
+    /// Estado da tarefa após a ação (ausente na deleção)
+    pub task: Option<Task>,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl TaskEvent {
+    fn new(kind: TaskEventKind, task: &Task, actor_id: Uuid, snapshot: Option<Task>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            kind,
+            task_id: task.id,
+            owner_id: task.user_id,
+            actor_id,
+            task: snapshot,
+            occurred_at: Utc::now(),
+        }
+    }
+
+    /// Tarefa criada
+    pub fn created(task: &Task, actor_id: Uuid) -> Self {
+        Self::new(
+            TaskEventKind::TaskCreated,
+            task,
+            actor_id,
+            Some(task.clone()),
+        )
+    }
+
+    /// Tarefa atualizada
+    pub fn updated(task: &Task, actor_id: Uuid) -> Self {
+        Self::new(
+            TaskEventKind::TaskUpdated,
+            task,
+            actor_id,
+            Some(task.clone()),
+        )
+    }
+
+    /// Tarefa deletada
+    pub fn deleted(task: &Task, actor_id: Uuid) -> Self {
+        Self::new(TaskEventKind::TaskDeleted, task, actor_id, None)
+    }
+}
@@ -0,0 +1,28 @@
+use crate::domain::entities::Attachment;
+use crate::domain::errors::DomainResult;
+use crate::domain::value_objects::AttachmentQuota;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// Trait repository para metadados de anexos (Port do padrão Hexagonal)
+#[async_trait]
+pub trait AttachmentRepository: Send + Sync {
+    /// Registra um novo anexo, verificando a cota do usuário na mesma transação
+    async fn create(
+        &self,
+        attachment: &Attachment,
+        quota: &AttachmentQuota,
+    ) -> DomainResult<Attachment>;
+
+    /// Busca um anexo por ID
+    async fn find_by_id(&self, id: &Uuid) -> DomainResult<Option<Attachment>>;
+
+    /// Lista os anexos de uma tarefa
+    async fn list_by_task(&self, task_id: &Uuid) -> DomainResult<Vec<Attachment>>;
+
+    /// Soma o tamanho (em bytes) dos anexos enviados pelo usuário
+    async fn total_size_by_user(&self, user_id: &Uuid) -> DomainResult<i64>;
+
+    /// Remove o registro de um anexo
+    async fn delete(&self, id: &Uuid) -> DomainResult<()>;
+}
@@ -0,0 +1,20 @@
+use crate::domain::errors::DomainResult;
+use async_trait::async_trait;
+use std::pin::Pin;
+use tokio::io::AsyncRead;
+
+/// Leitor assíncrono do conteúdo de um anexo
+pub type AttachmentReader = Pin<Box<dyn AsyncRead + Send>>;
+
+/// Trait para armazenamento do conteúdo dos anexos (Port do padrão Hexagonal)
+#[async_trait]
+pub trait AttachmentStorage: Send + Sync {
+    /// Grava o conteúdo sob a chave informada
+    async fn put(&self, key: &str, content: &[u8]) -> DomainResult<()>;
+
+    /// Abre o conteúdo para leitura em streaming
+    async fn get(&self, key: &str) -> DomainResult<AttachmentReader>;
+
+    /// Remove o conteúdo (não falha se a chave não existir)
+    async fn delete(&self, key: &str) -> DomainResult<()>;
+}
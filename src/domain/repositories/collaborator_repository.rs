@@ -0,0 +1,20 @@
+use crate::domain::entities::TaskCollaborator;
+use crate::domain::errors::DomainResult;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// Trait repository para colaboradores de tarefas (Port do padrão Hexagonal)
+#[async_trait]
+pub trait CollaboratorRepository: Send + Sync {
+    /// Adiciona um colaborador (ou atualiza o papel se já existir)
+    async fn upsert(&self, collaborator: &TaskCollaborator) -> DomainResult<TaskCollaborator>;
+
+    /// Busca o vínculo de um usuário com uma tarefa
+    async fn find(&self, task_id: &Uuid, user_id: &Uuid) -> DomainResult<Option<TaskCollaborator>>;
+
+    /// Lista os colaboradores de uma tarefa
+    async fn list_by_task(&self, task_id: &Uuid) -> DomainResult<Vec<TaskCollaborator>>;
+
+    /// Remove um colaborador de uma tarefa
+    async fn remove(&self, task_id: &Uuid, user_id: &Uuid) -> DomainResult<()>;
+}
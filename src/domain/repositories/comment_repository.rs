@@ -0,0 +1,23 @@
+use crate::domain::entities::Comment;
+use crate::domain::errors::DomainResult;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// Trait repository para comentários de tarefas (Port do padrão Hexagonal)
+#[async_trait]
+pub trait CommentRepository: Send + Sync {
+    /// Cria um novo comentário
+    async fn create(&self, comment: &Comment) -> DomainResult<Comment>;
+
+    /// Busca um comentário por ID
+    async fn find_by_id(&self, id: &Uuid) -> DomainResult<Option<Comment>>;
+
+    /// Lista os comentários de uma tarefa (mais antigos primeiro)
+    async fn list_by_task(&self, task_id: &Uuid) -> DomainResult<Vec<Comment>>;
+
+    /// Atualiza um comentário
+    async fn update(&self, comment: &Comment) -> DomainResult<Comment>;
+
+    /// Deleta um comentário
+    async fn delete(&self, id: &Uuid) -> DomainResult<()>;
+}
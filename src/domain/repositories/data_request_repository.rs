@@ -0,0 +1,38 @@
+use crate::domain::entities::DataRequest;
+use crate::domain::errors::DomainResult;
+use crate::domain::value_objects::DataRequestKind;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Trait repository para a fila de solicitações de dados (Port do padrão Hexagonal)
+#[async_trait]
+pub trait DataRequestRepository: Send + Sync {
+    /// Registra uma nova solicitação
+    async fn create(&self, request: &DataRequest) -> DomainResult<DataRequest>;
+
+    /// Busca uma solicitação por ID
+    async fn find_by_id(&self, id: &Uuid) -> DomainResult<Option<DataRequest>>;
+
+    /// Busca a solicitação mais recente do usuário para o tipo informado
+    async fn find_latest(
+        &self,
+        user_id: &Uuid,
+        kind: DataRequestKind,
+    ) -> DomainResult<Option<DataRequest>>;
+
+    /// Lista as solicitações de um usuário (mais recentes primeiro)
+    async fn list_by_user(&self, user_id: &Uuid) -> DomainResult<Vec<DataRequest>>;
+
+    /// Reserva a próxima solicitação pendente, marcando-a como em processamento
+    ///
+    /// Solicitações em processamento iniciadas antes de `stale_before` (worker
+    /// interrompido) voltam a ser elegíveis.
+    async fn claim_next(&self, stale_before: DateTime<Utc>) -> DomainResult<Option<DataRequest>>;
+
+    /// Lista as exportações concluídas cujo prazo de retenção terminou
+    async fn list_expired_exports(&self, now: DateTime<Utc>) -> DomainResult<Vec<DataRequest>>;
+
+    /// Atualiza a situação de uma solicitação
+    async fn update(&self, request: &DataRequest) -> DomainResult<()>;
+}
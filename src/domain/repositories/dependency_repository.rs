@@ -0,0 +1,32 @@
+use crate::domain::entities::TaskDependency;
+use crate::domain::errors::DomainResult;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// Trait repository para dependências entre tarefas (Port do padrão Hexagonal)
+#[async_trait]
+pub trait DependencyRepository: Send + Sync {
+    /// Adiciona uma dependência, recusando duplicatas e ciclos
+    ///
+    /// A verificação de ciclo e a inserção ocorrem de forma atômica: alterações
+    /// concorrentes no grafo do workspace não podem criar um ciclo.
+    async fn add(&self, dependency: &TaskDependency) -> DomainResult<()>;
+
+    /// Remove uma dependência
+    async fn remove(&self, task_id: &Uuid, blocked_by_id: &Uuid) -> DomainResult<()>;
+
+    /// Lista os IDs das tarefas que bloqueiam a tarefa
+    async fn list_blocker_ids(&self, task_id: &Uuid) -> DomainResult<Vec<Uuid>>;
+
+    /// Lista os IDs das tarefas bloqueadas pela tarefa
+    async fn list_dependent_ids(&self, task_id: &Uuid) -> DomainResult<Vec<Uuid>>;
+
+    /// Lista todas as dependências alcançáveis a partir da tarefa (bloqueadoras, recursivamente)
+    async fn list_upstream(&self, task_id: &Uuid) -> DomainResult<Vec<TaskDependency>>;
+
+    /// Conta as bloqueadoras da tarefa que ainda não foram finalizadas
+    async fn count_unfinished_blockers(&self, task_id: &Uuid) -> DomainResult<i64>;
+
+    /// Lista as dependências ainda bloqueantes das tarefas do usuário
+    async fn list_unfinished_for_user(&self, user_id: &Uuid) -> DomainResult<Vec<TaskDependency>>;
+}
@@ -0,0 +1,26 @@
+use crate::domain::entities::{LoginAttempt, LoginFailures};
+use crate::domain::errors::DomainResult;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::net::IpAddr;
+
+/// Trait repository para tentativas de login (Port do padrão Hexagonal)
+#[async_trait]
+pub trait LoginAttemptRepository: Send + Sync {
+    /// Registra uma tentativa
+    async fn record(&self, attempt: &LoginAttempt) -> DomainResult<()>;
+
+    /// Falhas de um email desde `since`, zeradas pelo último login bem-sucedido
+    async fn failures_by_email(
+        &self,
+        email: &str,
+        since: DateTime<Utc>,
+    ) -> DomainResult<LoginFailures>;
+
+    /// Falhas de um IP desde `since` (em qualquer conta)
+    async fn failures_by_ip(
+        &self,
+        ip_address: &IpAddr,
+        since: DateTime<Utc>,
+    ) -> DomainResult<LoginFailures>;
+}
@@ -0,0 +1,17 @@
+use crate::domain::errors::DomainResult;
+use async_trait::async_trait;
+
+/// Email de texto simples a ser enviado
+#[derive(Debug, Clone)]
+pub struct EmailMessage {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+}
+
+/// Trait para envio de emails (Port do padrão Hexagonal)
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    /// Envia o email
+    async fn send(&self, message: &EmailMessage) -> DomainResult<()>;
+}
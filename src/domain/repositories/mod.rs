@@ -1,5 +1,47 @@
+pub mod attachment_repository;
+pub mod attachment_storage;
+pub mod collaborator_repository;
+pub mod comment_repository;
+pub mod data_request_repository;
+pub mod dependency_repository;
+pub mod email_verification_repository;
+pub mod login_attempt_repository;
+pub mod mailer;
+pub mod password_reset_repository;
+pub mod personal_data_repository;
+pub mod project_repository;
+pub mod revoked_token_repository;
+pub mod session_repository;
+pub mod statistics_repository;
 pub mod task_repository;
+pub mod two_factor_repository;
 pub mod user_repository;
+pub mod webhook_client;
+pub mod webhook_delivery_repository;
+pub mod webhook_repository;
+pub mod workflow_repository;
+pub mod workspace_repository;
 
+pub use attachment_repository::AttachmentRepository;
+pub use attachment_storage::{AttachmentReader, AttachmentStorage};
+pub use collaborator_repository::CollaboratorRepository;
+pub use comment_repository::CommentRepository;
+pub use data_request_repository::DataRequestRepository;
+pub use dependency_repository::DependencyRepository;
+pub use email_verification_repository::EmailVerificationRepository;
+pub use login_attempt_repository::LoginAttemptRepository;
+pub use mailer::{EmailMessage, Mailer};
+pub use password_reset_repository::PasswordResetRepository;
+pub use personal_data_repository::{PersonalData, PersonalDataRepository};
+pub use project_repository::ProjectRepository;
+pub use revoked_token_repository::RevokedTokenRepository;
+pub use session_repository::SessionRepository;
+pub use statistics_repository::{StatisticsRepository, SystemStatistics};
 pub use task_repository::TaskRepository;
-pub use user_repository::UserRepository;
+pub use two_factor_repository::TwoFactorRepository;
+pub use user_repository::{UserFilter, UserRepository};
+pub use webhook_client::WebhookClient;
+pub use webhook_delivery_repository::WebhookDeliveryRepository;
+pub use webhook_repository::WebhookRepository;
+pub use workflow_repository::WorkflowRepository;
+pub use workspace_repository::WorkspaceRepository;
@@ -0,0 +1,25 @@
+use crate::domain::entities::PasswordResetToken;
+use crate::domain::errors::DomainResult;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// Trait repository para tokens de redefinição de senha (Port do padrão Hexagonal)
+#[async_trait]
+pub trait PasswordResetRepository: Send + Sync {
+    /// Cria um novo token
+    async fn create(&self, token: &PasswordResetToken) -> DomainResult<PasswordResetToken>;
+
+    /// Busca um token pelo hash
+    async fn find_by_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> DomainResult<Option<PasswordResetToken>>;
+
+    /// Marca o token como usado
+    ///
+    /// Retorna `false` se ele já havia sido usado (uso concorrente).
+    async fn consume(&self, id: &Uuid) -> DomainResult<bool>;
+
+    /// Invalida todos os tokens ainda não usados do usuário
+    async fn invalidate_for_user(&self, user_id: &Uuid) -> DomainResult<()>;
+}
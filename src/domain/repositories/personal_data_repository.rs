@@ -0,0 +1,37 @@
+use crate::domain::errors::DomainResult;
+use async_trait::async_trait;
+use serde::Serialize;
+use uuid::Uuid;
+
+/// Dados pessoais de um usuário, agrupados por categoria (portabilidade LGPD/GDPR)
+///
+/// Cada seção é um documento JSON montado pelo adaptador de persistência.
+#[derive(Debug, Clone, Serialize)]
+pub struct PersonalData {
+    pub profile: serde_json::Value,
+    pub tasks: serde_json::Value,
+    pub task_history: serde_json::Value,
+    pub comments: serde_json::Value,
+    pub attachments: serde_json::Value,
+    pub tags: serde_json::Value,
+    pub collaborations: serde_json::Value,
+    pub workspaces: serde_json::Value,
+    pub webhooks: serde_json::Value,
+    pub sessions: serde_json::Value,
+    pub login_attempts: serde_json::Value,
+}
+
+/// Trait repository para exportação e eliminação de dados pessoais (Port do padrão Hexagonal)
+#[async_trait]
+pub trait PersonalDataRepository: Send + Sync {
+    /// Reúne todos os dados pessoais do usuário
+    async fn export(&self, user_id: &Uuid) -> DomainResult<PersonalData>;
+
+    /// Anonimiza o usuário e remove seus dados pessoais em uma única transação
+    ///
+    /// Tarefas e histórico permanecem sem conteúdo identificável, preservando as
+    /// estatísticas agregadas. Retorna as chaves dos anexos removidos, cujo
+    /// conteúdo deve ser apagado do armazenamento. Retorna `Conflict` se o
+    /// usuário é o único owner de um workspace com outros membros.
+    async fn erase(&self, user_id: &Uuid) -> DomainResult<Vec<String>>;
+}
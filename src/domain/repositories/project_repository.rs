@@ -0,0 +1,34 @@
+use crate::domain::entities::Project;
+use crate::domain::errors::DomainResult;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// Trait repository para projetos (Port do padrão Hexagonal)
+#[async_trait]
+pub trait ProjectRepository: Send + Sync {
+    /// Cria um novo projeto
+    async fn create(&self, project: &Project) -> DomainResult<Project>;
+
+    /// Busca um projeto por ID
+    async fn find_by_id(&self, id: &Uuid) -> DomainResult<Option<Project>>;
+
+    /// Lista os projetos de um workspace na ordem de exibição
+    async fn list_by_workspace(
+        &self,
+        workspace_id: &Uuid,
+        include_archived: bool,
+    ) -> DomainResult<Vec<Project>>;
+
+    /// Próxima posição livre no fim da lista do workspace
+    async fn next_position(&self, workspace_id: &Uuid) -> DomainResult<i32>;
+
+    /// Atualiza um projeto
+    async fn update(&self, project: &Project) -> DomainResult<Project>;
+
+    /// Salva o workflow do projeto e recoloca cada tarefa no primeiro estado da
+    /// categoria do seu status (ou remove o estado, se o projeto ficou sem workflow)
+    async fn assign_workflow(&self, project: &Project) -> DomainResult<Project>;
+
+    /// Deleta um projeto (as tarefas voltam para a caixa de entrada)
+    async fn delete(&self, id: &Uuid) -> DomainResult<()>;
+}
@@ -0,0 +1,22 @@
+use crate::domain::errors::DomainResult;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Trait repository para access tokens revogados (Port do padrão Hexagonal)
+#[async_trait]
+pub trait RevokedTokenRepository: Send + Sync {
+    /// Revoga um token até a sua expiração (idempotente)
+    async fn revoke(
+        &self,
+        jti: &Uuid,
+        user_id: &Uuid,
+        expires_at: DateTime<Utc>,
+    ) -> DomainResult<()>;
+
+    /// Verifica se um token foi revogado
+    async fn is_revoked(&self, jti: &Uuid) -> DomainResult<bool>;
+
+    /// Remove revogações de tokens já expirados
+    async fn purge_expired(&self) -> DomainResult<u64>;
+}
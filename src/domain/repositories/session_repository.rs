@@ -0,0 +1,28 @@
+use crate::domain::entities::Session;
+use crate::domain::errors::DomainResult;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// Trait repository para sessões/refresh tokens (Port do padrão Hexagonal)
+#[async_trait]
+pub trait SessionRepository: Send + Sync {
+    /// Cria uma nova sessão
+    async fn create(&self, session: &Session) -> DomainResult<Session>;
+
+    /// Busca uma sessão pelo hash do refresh token
+    async fn find_by_token_hash(&self, token_hash: &str) -> DomainResult<Option<Session>>;
+
+    /// Marca o refresh token como trocado
+    ///
+    /// Retorna `false` se ele já havia sido trocado ou revogado (uso concorrente).
+    async fn mark_rotated(&self, id: &Uuid) -> DomainResult<bool>;
+
+    /// Revoga todos os refresh tokens de uma família
+    async fn revoke_family(&self, family_id: &Uuid) -> DomainResult<()>;
+
+    /// Revoga todas as sessões do usuário
+    async fn revoke_all_by_user(&self, user_id: &Uuid) -> DomainResult<()>;
+
+    /// Lista os refresh tokens ativos do usuário (um por família)
+    async fn list_active_by_user(&self, user_id: &Uuid) -> DomainResult<Vec<Session>>;
+}
@@ -0,0 +1,32 @@
+use crate::domain::errors::DomainResult;
+use crate::domain::value_objects::{TaskPriority, TaskStatus};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+/// Estatísticas agregadas de todo o sistema
+#[derive(Debug, Clone, Default)]
+pub struct SystemStatistics {
+    pub total_users: i64,
+    pub admin_users: i64,
+    pub disabled_users: i64,
+    pub erased_users: i64,
+    pub total_tasks: i64,
+    /// Quantidade de tarefas por status (apenas status com tarefas)
+    pub tasks_by_status: Vec<(TaskStatus, i64)>,
+    /// Quantidade de tarefas por prioridade (apenas prioridades com tarefas)
+    pub tasks_by_priority: Vec<(TaskPriority, i64)>,
+    /// Tarefas abertas com prazo vencido
+    pub overdue_tasks: i64,
+    /// Tarefas criadas a partir de `created_since`
+    pub recent_tasks: i64,
+}
+
+/// Trait repository para estatísticas do sistema (Port do padrão Hexagonal)
+#[async_trait]
+pub trait StatisticsRepository: Send + Sync {
+    /// Calcula as estatísticas, contando como recentes as tarefas criadas após `created_since`
+    async fn system_statistics(
+        &self,
+        created_since: DateTime<Utc>,
+    ) -> DomainResult<SystemStatistics>;
+}
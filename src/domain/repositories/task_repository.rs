@@ -1,17 +1,38 @@
 use crate::domain::entities::{Task, TaskHistory};
 use crate::domain::errors::DomainResult;
-use crate::domain::value_objects::{TaskPriority, TaskStatus};
+use crate::domain::value_objects::{HistoryCursor, Rank, TaskPriority, TaskStatus};
 use async_trait::async_trait;
+use serde::Deserialize;
+use std::sync::Arc;
 use uuid::Uuid;
 
 /// Parâmetros de filtro para listagem de tarefas
 #[derive(Debug, Clone)]
 pub struct TaskFilter {
     pub user_id: Uuid,
+    /// Inclui tarefas compartilhadas com o usuário
+    pub include_shared: bool,
     pub status: Option<TaskStatus>,
     pub priority: Option<TaskPriority>,
     pub overdue_only: bool,
     pub search_query: Option<String>,
+    /// Tarefas que possuem pelo menos uma das tags
+    pub tags_any: Option<Vec<String>>,
+    /// Tarefas que possuem todas as tags
+    pub tags_all: Option<Vec<String>>,
+    pub project_id: Option<Uuid>,
+    pub sort: TaskSort,
+}
+
+/// Ordenação da listagem de tarefas
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskSort {
+    /// Mais recentes primeiro
+    #[default]
+    Newest,
+    /// Posição no quadro
+    Rank,
 }
 
 /// Parâmetros de paginação
@@ -27,6 +48,15 @@ impl Pagination {
     }
 }
 
+/// Resultado da deleção de uma tarefa com sua hierarquia
+#[derive(Debug, Clone, Default)]
+pub struct DeletedTaskTree {
+    /// Subtarefas deletadas junto com a tarefa
+    pub subtasks: Vec<Task>,
+    /// Chaves de armazenamento dos anexos removidos
+    pub storage_keys: Vec<String>,
+}
+
 /// Resultado paginado
 #[derive(Debug, Clone)]
 pub struct PaginatedResult<T> {
@@ -40,7 +70,12 @@ pub struct PaginatedResult<T> {
 /// Trait repository para Task (Port do padrão Hexagonal)
 #[async_trait]
 pub trait TaskRepository: Send + Sync {
+    /// Retorna o repositório restrito às tarefas de um workspace
+    fn for_workspace(&self, workspace_id: Uuid) -> Arc<dyn TaskRepository>;
+
     /// Cria uma nova tarefa
+    ///
+    /// Retorna `Conflict` se a ocorrência da série já foi criada.
     async fn create(&self, task: &Task) -> DomainResult<Task>;
 
     /// Busca uma tarefa por ID
@@ -65,6 +100,48 @@ pub trait TaskRepository: Send + Sync {
     /// Lista o histórico de uma tarefa
     async fn get_history(&self, task_id: &Uuid) -> DomainResult<Vec<TaskHistory>>;
 
+    /// Lista, em ordem cronológica, o histórico posterior ao cursor das
+    /// tarefas do usuário (próprias ou compartilhadas)
+    async fn list_history_since(
+        &self,
+        user_id: &Uuid,
+        after: &HistoryCursor,
+        limit: i64,
+    ) -> DomainResult<Vec<TaskHistory>>;
+
+    /// Lista as tarefas em aberto (pendentes ou em andamento) de um usuário
+    async fn list_open(&self, user_id: &Uuid) -> DomainResult<Vec<Task>>;
+
+    /// Lista as subtarefas diretas de uma tarefa
+    async fn list_subtasks(&self, parent_id: &Uuid) -> DomainResult<Vec<Task>>;
+
+    /// Lista todas as subtarefas de uma tarefa, recursivamente
+    async fn list_descendants(&self, id: &Uuid) -> DomainResult<Vec<Task>>;
+
+    /// Deleta uma tarefa junto com sua hierarquia em uma única transação
+    ///
+    /// Trava a tarefa e a cadeia de ancestrais antes de alterar a hierarquia.
+    /// Com `reparent`, as subtarefas diretas passam para o pai da tarefa; senão
+    /// são deletadas em cascata. As subtarefas a deletar devem ser exatamente
+    /// `descendants` (já autorizadas pelo chamador): se a hierarquia mudou, nada
+    /// é deletado. Os anexos são removidos na mesma transação.
+    async fn delete_with_subtasks(
+        &self,
+        id: &Uuid,
+        reparent: bool,
+        descendants: &[Uuid],
+    ) -> DomainResult<DeletedTaskTree>;
+
+    /// Maior posição no quadro do workspace (`None` se não há tarefas)
+    async fn last_rank(&self, workspace_id: &Uuid) -> DomainResult<Option<Rank>>;
+
     /// Conta tarefas por status para um usuário
     async fn count_by_status(&self, user_id: &Uuid, status: TaskStatus) -> DomainResult<i64>;
+
+    /// Conta tarefas por status em um projeto
+    async fn count_by_project_status(
+        &self,
+        project_id: &Uuid,
+        status: TaskStatus,
+    ) -> DomainResult<i64>;
 }
@@ -0,0 +1,32 @@
+use crate::domain::entities::{RecoveryCode, TotpCredential};
+use crate::domain::errors::DomainResult;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// Trait repository para o segundo fator (TOTP e códigos de recuperação) (Port do padrão Hexagonal)
+#[async_trait]
+pub trait TwoFactorRepository: Send + Sync {
+    /// Busca a credencial TOTP do usuário
+    async fn find_by_user(&self, user_id: &Uuid) -> DomainResult<Option<TotpCredential>>;
+
+    /// Cria ou substitui a credencial TOTP do usuário
+    async fn save(&self, credential: &TotpCredential) -> DomainResult<TotpCredential>;
+
+    /// Registra o passo de tempo de um código aceito
+    ///
+    /// Retorna `false` se o passo não é posterior ao último usado (código reutilizado).
+    async fn mark_step_used(&self, user_id: &Uuid, step: i64) -> DomainResult<bool>;
+
+    /// Remove a credencial e os códigos de recuperação (desativa o 2FA)
+    async fn delete(&self, user_id: &Uuid) -> DomainResult<()>;
+
+    /// Substitui todos os códigos de recuperação do usuário
+    async fn replace_recovery_codes(
+        &self,
+        user_id: &Uuid,
+        codes: &[RecoveryCode],
+    ) -> DomainResult<()>;
+
+    /// Consome um código de recuperação ainda não usado
+    async fn consume_recovery_code(&self, user_id: &Uuid, code_hash: &str) -> DomainResult<bool>;
+}
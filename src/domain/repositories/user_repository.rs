@@ -1,9 +1,20 @@
 use crate::domain::entities::User;
 use crate::domain::errors::DomainResult;
-use crate::domain::value_objects::Email;
+use crate::domain::repositories::task_repository::{PaginatedResult, Pagination};
+use crate::domain::value_objects::{Email, UserRole};
 use async_trait::async_trait;
 use uuid::Uuid;
 
+/// Parâmetros de filtro para a busca administrativa de usuários
+#[derive(Debug, Clone, Default)]
+pub struct UserFilter {
+    /// Trecho do email ou do nome
+    pub search_query: Option<String>,
+    pub role: Option<UserRole>,
+    /// Apenas contas desativadas (`true`) ou ativas (`false`)
+    pub disabled: Option<bool>,
+}
+
 /// Trait repository para User (Port do padrão Hexagonal)
 #[async_trait]
 pub trait UserRepository: Send + Sync {
@@ -24,4 +35,20 @@ pub trait UserRepository: Send + Sync {
 
     /// Verifica se um email já existe
     async fn email_exists(&self, email: &Email) -> DomainResult<bool>;
+
+    /// Lista usuários com filtros e paginação (mais recentes primeiro)
+    async fn search(
+        &self,
+        filter: UserFilter,
+        pagination: Pagination,
+    ) -> DomainResult<PaginatedResult<User>>;
+
+    /// Busca a versão atual dos tokens do usuário
+    ///
+    /// Retorna `None` para usuários inexistentes ou desativados, o que faz
+    /// todos os tokens da conta serem rejeitados.
+    async fn find_token_version(&self, id: &Uuid) -> DomainResult<Option<i32>>;
+
+    /// Incrementa a versão dos tokens, invalidando todos os já emitidos
+    async fn increment_token_version(&self, id: &Uuid) -> DomainResult<i32>;
 }
@@ -0,0 +1,18 @@
+use crate::domain::entities::{Webhook, WebhookDelivery};
+use crate::domain::errors::DomainResult;
+use async_trait::async_trait;
+
+/// Trait para envio HTTP de entregas de webhooks (Port do padrão Hexagonal)
+#[async_trait]
+pub trait WebhookClient: Send + Sync {
+    /// Envia o payload assinado e retorna o status HTTP da resposta
+    ///
+    /// Falhas de rede ou timeout retornam erro; respostas não-2xx retornam
+    /// o status normalmente para que o chamador decida sobre novas tentativas.
+    async fn send(&self, webhook: &Webhook, delivery: &WebhookDelivery) -> DomainResult<u16>;
+
+    /// Garante que a URL aponta para um destino permitido (resolvendo o host)
+    ///
+    /// Destinos internos (loopback, redes privadas etc.) retornam `ValidationError`.
+    async fn ensure_allowed_destination(&self, url: &str) -> DomainResult<()>;
+}
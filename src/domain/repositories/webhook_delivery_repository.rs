@@ -0,0 +1,32 @@
+use crate::domain::entities::WebhookDelivery;
+use crate::domain::errors::DomainResult;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Trait repository para a fila de entregas de webhooks (Port do padrão Hexagonal)
+#[async_trait]
+pub trait WebhookDeliveryRepository: Send + Sync {
+    /// Enfileira uma entrega (ignora evento já enfileirado para o mesmo webhook)
+    async fn enqueue(&self, delivery: &WebhookDelivery) -> DomainResult<()>;
+
+    /// Reserva até `limit` entregas pendentes vencidas
+    ///
+    /// As entregas reservadas só voltam a ser elegíveis após `lease_until`,
+    /// evitando envios duplicados entre workers concorrentes.
+    async fn claim_due(
+        &self,
+        limit: i64,
+        lease_until: DateTime<Utc>,
+    ) -> DomainResult<Vec<WebhookDelivery>>;
+
+    /// Atualiza o resultado de uma entrega
+    async fn update(&self, delivery: &WebhookDelivery) -> DomainResult<()>;
+
+    /// Lista as entregas de um webhook (mais recentes primeiro)
+    async fn list_by_webhook(
+        &self,
+        webhook_id: &Uuid,
+        limit: i64,
+    ) -> DomainResult<Vec<WebhookDelivery>>;
+}
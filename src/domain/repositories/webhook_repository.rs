@@ -0,0 +1,31 @@
+use crate::domain::entities::Webhook;
+use crate::domain::errors::DomainResult;
+use crate::domain::events::TaskEventKind;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// Trait repository para webhooks (Port do padrão Hexagonal)
+#[async_trait]
+pub trait WebhookRepository: Send + Sync {
+    /// Cria um novo webhook
+    async fn create(&self, webhook: &Webhook) -> DomainResult<Webhook>;
+
+    /// Busca um webhook por ID
+    async fn find_by_id(&self, id: &Uuid) -> DomainResult<Option<Webhook>>;
+
+    /// Lista os webhooks de um usuário
+    async fn list_by_user(&self, user_id: &Uuid) -> DomainResult<Vec<Webhook>>;
+
+    /// Lista os webhooks ativos do usuário inscritos no tipo de evento
+    async fn list_subscribed(
+        &self,
+        user_id: &Uuid,
+        kind: TaskEventKind,
+    ) -> DomainResult<Vec<Webhook>>;
+
+    /// Atualiza um webhook
+    async fn update(&self, webhook: &Webhook) -> DomainResult<Webhook>;
+
+    /// Deleta um webhook (e suas entregas)
+    async fn delete(&self, id: &Uuid) -> DomainResult<()>;
+}
@@ -0,0 +1,23 @@
+use crate::domain::entities::Workflow;
+use crate::domain::errors::DomainResult;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// Trait repository para workflows (Port do padrão Hexagonal)
+#[async_trait]
+pub trait WorkflowRepository: Send + Sync {
+    /// Cria um workflow com seus estados e transições
+    async fn create(&self, workflow: &Workflow) -> DomainResult<Workflow>;
+
+    /// Busca um workflow por ID
+    async fn find_by_id(&self, id: &Uuid) -> DomainResult<Option<Workflow>>;
+
+    /// Busca o workflow ao qual um estado pertence
+    async fn find_by_state(&self, state_id: &Uuid) -> DomainResult<Option<Workflow>>;
+
+    /// Lista os workflows de um workspace
+    async fn list_by_workspace(&self, workspace_id: &Uuid) -> DomainResult<Vec<Workflow>>;
+
+    /// Deleta um workflow (projetos e tarefas voltam aos status padrão)
+    async fn delete(&self, id: &Uuid) -> DomainResult<()>;
+}
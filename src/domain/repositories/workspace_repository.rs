@@ -0,0 +1,33 @@
+use crate::domain::entities::{Membership, Workspace};
+use crate::domain::errors::DomainResult;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// Trait repository para workspaces e seus membros (Port do padrão Hexagonal)
+#[async_trait]
+pub trait WorkspaceRepository: Send + Sync {
+    /// Cria um workspace junto com o vínculo do seu owner
+    async fn create(&self, workspace: &Workspace, owner: &Membership) -> DomainResult<Workspace>;
+
+    /// Busca um workspace por ID
+    async fn find_by_id(&self, id: &Uuid) -> DomainResult<Option<Workspace>>;
+
+    /// Lista os workspaces do usuário com o papel dele em cada um
+    async fn list_by_user(&self, user_id: &Uuid) -> DomainResult<Vec<(Workspace, Membership)>>;
+
+    /// Busca o workspace padrão do usuário (o vínculo mais antigo)
+    async fn find_default_for_user(&self, user_id: &Uuid) -> DomainResult<Option<Uuid>>;
+
+    /// Busca o vínculo de um usuário com um workspace
+    async fn find_membership(
+        &self,
+        workspace_id: &Uuid,
+        user_id: &Uuid,
+    ) -> DomainResult<Option<Membership>>;
+
+    /// Adiciona um membro (`Conflict` se ele já fizer parte do workspace)
+    async fn add_member(&self, membership: &Membership) -> DomainResult<Membership>;
+
+    /// Lista os membros de um workspace
+    async fn list_members(&self, workspace_id: &Uuid) -> DomainResult<Vec<Membership>>;
+}
@@ -0,0 +1,149 @@
+use crate::domain::entities::TaskDependency;
+use crate::domain::errors::{DomainError, DomainResult};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use uuid::Uuid;
+
+/// Grafo de dependências "bloqueada por" entre tarefas
+#[derive(Debug, Default)]
+pub struct DependencyGraph {
+    /// Tarefa -> tarefas que a bloqueiam
+    blockers: HashMap<Uuid, HashSet<Uuid>>,
+}
+
+impl DependencyGraph {
+    /// Monta o grafo a partir das dependências
+    pub fn new<'a>(dependencies: impl IntoIterator<Item = &'a TaskDependency>) -> Self {
+        let mut blockers: HashMap<Uuid, HashSet<Uuid>> = HashMap::new();
+
+        for dependency in dependencies {
+            blockers
+                .entry(dependency.task_id)
+                .or_default()
+                .insert(dependency.blocked_by_id);
+        }
+
+        Self { blockers }
+    }
+
+    /// Verifica se adicionar "`task_id` bloqueada por `blocked_by_id`" criaria um ciclo
+    ///
+    /// Há ciclo se `task_id` já bloqueia (direta ou indiretamente) `blocked_by_id`.
+    pub fn would_create_cycle(&self, task_id: Uuid, blocked_by_id: Uuid) -> bool {
+        if task_id == blocked_by_id {
+            return true;
+        }
+
+        let mut visited = HashSet::new();
+        let mut stack = vec![blocked_by_id];
+
+        while let Some(current) = stack.pop() {
+            if current == task_id {
+                return true;
+            }
+
+            if !visited.insert(current) {
+                continue;
+            }
+
+            if let Some(blockers) = self.blockers.get(&current) {
+                stack.extend(blockers.iter().copied());
+            }
+        }
+
+        false
+    }
+
+    /// Garante que a nova dependência não cria um ciclo
+    pub fn ensure_acyclic(&self, dependency: &TaskDependency) -> DomainResult<()> {
+        if self.would_create_cycle(dependency.task_id, dependency.blocked_by_id) {
+            return Err(DomainError::Conflict(
+                "A dependência criaria um ciclo entre as tarefas".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Ordena as tarefas de forma que as bloqueadoras venham antes das bloqueadas
+    ///
+    /// Apenas as dependências entre as tarefas informadas são consideradas e,
+    /// entre tarefas independentes, a ordem de entrada é preservada.
+    pub fn topological_order(&self, tasks: &[Uuid]) -> DomainResult<Vec<Uuid>> {
+        let index: HashMap<Uuid, usize> =
+            tasks.iter().enumerate().map(|(i, id)| (*id, i)).collect();
+
+        let mut in_degree = vec![0usize; tasks.len()];
+        let mut dependents: Vec<Vec<usize>> = vec![Vec::new(); tasks.len()];
+
+        for (i, task_id) in tasks.iter().enumerate() {
+            for blocker in self.blockers.get(task_id).into_iter().flatten() {
+                if let Some(&j) = index.get(blocker) {
+                    in_degree[i] += 1;
+                    dependents[j].push(i);
+                }
+            }
+        }
+
+        let mut ready: BTreeSet<usize> = (0..tasks.len()).filter(|&i| in_degree[i] == 0).collect();
+        let mut ordered = Vec::with_capacity(tasks.len());
+
+        while let Some(i) = ready.pop_first() {
+            ordered.push(tasks[i]);
+
+            for &j in &dependents[i] {
+                in_degree[j] -= 1;
+                if in_degree[j] == 0 {
+                    ready.insert(j);
+                }
+            }
+        }
+
+        if ordered.len() != tasks.len() {
+            return Err(DomainError::Conflict(
+                "Ciclo detectado nas dependências das tarefas".to_string(),
+            ));
+        }
+
+        Ok(ordered)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dependency(task_id: Uuid, blocked_by_id: Uuid) -> TaskDependency {
+        TaskDependency::new(task_id, blocked_by_id).unwrap()
+    }
+
+    #[test]
+    fn test_detects_indirect_cycle() {
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        // b bloqueada por a, c bloqueada por b
+        let edges = vec![dependency(b, a), dependency(c, b)];
+        let graph = DependencyGraph::new(&edges);
+
+        // a bloqueada por c fecharia o ciclo a -> b -> c -> a
+        assert!(graph.would_create_cycle(a, c));
+        assert!(!graph.would_create_cycle(c, a));
+    }
+
+    #[test]
+    fn test_topological_order_puts_blockers_first() {
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let edges = vec![dependency(a, c), dependency(b, a)];
+        let graph = DependencyGraph::new(&edges);
+
+        let ordered = graph.topological_order(&[a, b, c]).unwrap();
+        assert_eq!(ordered, vec![c, a, b]);
+    }
+
+    #[test]
+    fn test_topological_order_rejects_cycles() {
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let edges = vec![dependency(a, b), dependency(b, a)];
+        let graph = DependencyGraph::new(&edges);
+
+        assert!(graph.topological_order(&[a, b]).is_err());
+    }
+}
@@ -0,0 +1,7 @@
+pub mod dependency_graph;
+pub mod task_access_policy;
+pub mod task_dependency_policy;
+
+pub use dependency_graph::DependencyGraph;
+pub use task_access_policy::{TaskAccessPolicy, TaskAction};
+pub use task_dependency_policy::TaskDependencyPolicy;
@@ -0,0 +1,119 @@
+use crate::domain::entities::Task;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::CollaboratorRepository;
+use crate::domain::value_objects::CollaboratorRole;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Ações que podem ser realizadas sobre uma tarefa
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskAction {
+    View,
+    Edit,
+    Delete,
+    ManageCollaborators,
+}
+
+impl TaskAction {
+    /// Papel mínimo necessário para executar a ação
+    pub fn required_role(&self) -> CollaboratorRole {
+        match self {
+            TaskAction::View => CollaboratorRole::Viewer,
+            TaskAction::Edit => CollaboratorRole::Editor,
+            TaskAction::Delete | TaskAction::ManageCollaborators => CollaboratorRole::Owner,
+        }
+    }
+
+    /// Verifica se o papel permite executar a ação
+    pub fn is_allowed_for(&self, role: CollaboratorRole) -> bool {
+        role >= self.required_role()
+    }
+}
+
+/// Serviço de domínio que decide o acesso de um usuário a uma tarefa
+pub struct TaskAccessPolicy {
+    collaborator_repository: Arc<dyn CollaboratorRepository>,
+}
+
+impl TaskAccessPolicy {
+    pub fn new(collaborator_repository: Arc<dyn CollaboratorRepository>) -> Self {
+        Self {
+            collaborator_repository,
+        }
+    }
+
+    /// Retorna o papel efetivo do usuário na tarefa (o dono é sempre `Owner`)
+    pub async fn role_for(
+        &self,
+        task: &Task,
+        user_id: &Uuid,
+    ) -> DomainResult<Option<CollaboratorRole>> {
+        if task.is_owned_by(user_id) {
+            return Ok(Some(CollaboratorRole::Owner));
+        }
+
+        Ok(self
+            .collaborator_repository
+            .find(&task.id, user_id)
+            .await?
+            .map(|collaborator| collaborator.role))
+    }
+
+    /// Verifica se o usuário pode ver uma tarefa identificada apenas pelo ID e pelo dono
+    ///
+    /// Útil quando só há o evento da tarefa (ex.: notificações em tempo real).
+    pub async fn can_view(
+        &self,
+        task_id: &Uuid,
+        owner_id: &Uuid,
+        user_id: &Uuid,
+    ) -> DomainResult<bool> {
+        if owner_id == user_id {
+            return Ok(true);
+        }
+
+        Ok(self
+            .collaborator_repository
+            .find(task_id, user_id)
+            .await?
+            .is_some_and(|collaborator| TaskAction::View.is_allowed_for(collaborator.role)))
+    }
+
+    /// Garante que o usuário pode executar a ação, retornando seu papel
+    pub async fn ensure(
+        &self,
+        task: &Task,
+        user_id: &Uuid,
+        action: TaskAction,
+    ) -> DomainResult<CollaboratorRole> {
+        match self.role_for(task, user_id).await? {
+            Some(role) if action.is_allowed_for(role) => Ok(role),
+            _ => Err(DomainError::Unauthorized),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_viewer_can_only_view() {
+        assert!(TaskAction::View.is_allowed_for(CollaboratorRole::Viewer));
+        assert!(!TaskAction::Edit.is_allowed_for(CollaboratorRole::Viewer));
+        assert!(!TaskAction::Delete.is_allowed_for(CollaboratorRole::Viewer));
+    }
+
+    #[test]
+    fn test_editor_cannot_delete_or_share() {
+        assert!(TaskAction::Edit.is_allowed_for(CollaboratorRole::Editor));
+        assert!(!TaskAction::Delete.is_allowed_for(CollaboratorRole::Editor));
+        assert!(!TaskAction::ManageCollaborators.is_allowed_for(CollaboratorRole::Editor));
+    }
+
+    #[test]
+    fn test_owner_can_do_everything() {
+        assert!(TaskAction::Delete.is_allowed_for(CollaboratorRole::Owner));
+        assert!(TaskAction::ManageCollaborators.is_allowed_for(CollaboratorRole::Owner));
+    }
+}
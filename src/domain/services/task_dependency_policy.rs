@@ -0,0 +1,46 @@
+use crate::domain::entities::Task;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::DependencyRepository;
+use crate::domain::value_objects::TaskStatus;
+use std::sync::Arc;
+
+/// Serviço de domínio que aplica as regras de dependência entre tarefas
+pub struct TaskDependencyPolicy {
+    dependency_repository: Arc<dyn DependencyRepository>,
+}
+
+impl TaskDependencyPolicy {
+    pub fn new(dependency_repository: Arc<dyn DependencyRepository>) -> Self {
+        Self {
+            dependency_repository,
+        }
+    }
+
+    /// Garante que a tarefa pode mudar para o novo status
+    ///
+    /// Complementa `TaskStatus::can_transition_to`: uma tarefa só pode entrar em
+    /// andamento quando todas as suas bloqueadoras estiverem finalizadas.
+    pub async fn ensure_can_transition(
+        &self,
+        task: &Task,
+        new_status: TaskStatus,
+    ) -> DomainResult<()> {
+        if new_status != TaskStatus::InProgress || task.status == TaskStatus::InProgress {
+            return Ok(());
+        }
+
+        let unfinished = self
+            .dependency_repository
+            .count_unfinished_blockers(&task.id)
+            .await?;
+
+        if unfinished > 0 {
+            return Err(DomainError::ValidationError(format!(
+                "A tarefa está bloqueada por {} tarefa(s) não finalizada(s)",
+                unfinished
+            )));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+use crate::domain::errors::{DomainError, DomainResult};
+
+/// Limites de anexos: tamanho máximo por arquivo e cota total por usuário
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AttachmentQuota {
+    pub max_file_bytes: u64,
+    pub quota_bytes: u64,
+}
+
+impl AttachmentQuota {
+    pub fn new(max_file_bytes: u64, quota_bytes: u64) -> Self {
+        Self {
+            max_file_bytes,
+            quota_bytes,
+        }
+    }
+
+    /// Garante que um arquivo de `size` bytes cabe no limite por arquivo
+    pub fn ensure_file_size(&self, size: u64) -> DomainResult<()> {
+        if size == 0 {
+            return Err(DomainError::ValidationError(
+                "O arquivo está vazio".to_string(),
+            ));
+        }
+
+        if size > self.max_file_bytes {
+            return Err(DomainError::ValidationError(format!(
+                "O arquivo excede o tamanho máximo de {} bytes",
+                self.max_file_bytes
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Garante que o usuário, já usando `used` bytes, pode enviar mais `size` bytes
+    pub fn ensure_allows(&self, used: u64, size: u64) -> DomainResult<()> {
+        self.ensure_file_size(size)?;
+
+        if used.saturating_add(size) > self.quota_bytes {
+            return Err(DomainError::ValidationError(format!(
+                "Cota de anexos excedida ({} de {} bytes usados)",
+                used, self.quota_bytes
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rejects_empty_and_oversized_files() {
+        let quota = AttachmentQuota::new(10, 100);
+        assert!(quota.ensure_file_size(0).is_err());
+        assert!(quota.ensure_file_size(11).is_err());
+        assert!(quota.ensure_file_size(10).is_ok());
+    }
+
+    #[test]
+    fn test_enforces_user_quota() {
+        let quota = AttachmentQuota::new(10, 100);
+        assert!(quota.ensure_allows(90, 10).is_ok());
+        assert!(quota.ensure_allows(95, 10).is_err());
+    }
+}
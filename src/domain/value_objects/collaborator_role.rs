@@ -0,0 +1,43 @@
+use crate::domain::errors::DomainError;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Papel de um colaborador em uma tarefa compartilhada
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, sqlx::Type,
+)]
+#[sqlx(type_name = "collaborator_role", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum CollaboratorRole {
+    #[default]
+    Viewer,
+    Editor,
+    Owner,
+}
+
+impl FromStr for CollaboratorRole {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "viewer" => Ok(CollaboratorRole::Viewer),
+            "editor" => Ok(CollaboratorRole::Editor),
+            "owner" => Ok(CollaboratorRole::Owner),
+            _ => Err(DomainError::ValidationError(format!(
+                "Papel de colaborador inválido: {}",
+                s
+            ))),
+        }
+    }
+}
+
+impl std::fmt::Display for CollaboratorRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            CollaboratorRole::Viewer => "viewer",
+            CollaboratorRole::Editor => "editor",
+            CollaboratorRole::Owner => "owner",
+        };
+        write!(f, "{}", s)
+    }
+}
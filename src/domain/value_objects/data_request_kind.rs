@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+/// Tipo de solicitação sobre os dados pessoais do usuário (LGPD/GDPR)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "data_request_kind", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum DataRequestKind {
+    /// Exportação (portabilidade) dos dados
+    Export,
+    /// Eliminação da conta com anonimização
+    Erasure,
+}
+
+impl std::fmt::Display for DataRequestKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            DataRequestKind::Export => "export",
+            DataRequestKind::Erasure => "erasure",
+        };
+        write!(f, "{}", s)
+    }
+}
@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+/// Situação de uma solicitação de exportação ou eliminação de dados
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "data_request_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum DataRequestStatus {
+    /// Aguardando o worker
+    #[default]
+    Pending,
+    /// Em processamento
+    Processing,
+    /// Concluída com sucesso
+    Completed,
+    /// Falhou após esgotar as tentativas
+    Failed,
+    /// Arquivo de exportação removido após o prazo de retenção
+    Expired,
+}
+
+impl DataRequestStatus {
+    /// Verifica se a solicitação ainda está em andamento
+    pub fn is_active(&self) -> bool {
+        matches!(
+            self,
+            DataRequestStatus::Pending | DataRequestStatus::Processing
+        )
+    }
+}
+
+impl std::fmt::Display for DataRequestStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            DataRequestStatus::Pending => "pending",
+            DataRequestStatus::Processing => "processing",
+            DataRequestStatus::Completed => "completed",
+            DataRequestStatus::Failed => "failed",
+            DataRequestStatus::Expired => "expired",
+        };
+        write!(f, "{}", s)
+    }
+}
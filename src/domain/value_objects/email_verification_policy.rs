@@ -0,0 +1,75 @@
+use crate::domain::errors::DomainError;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Política aplicada a contas com email ainda não confirmado
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EmailVerificationPolicy {
+    /// Contas não verificadas têm acesso completo
+    #[default]
+    Optional,
+    /// Login (e emissão de tokens no registro) exige email confirmado
+    BlockLogin,
+    /// Login permitido, mas criar tarefas exige email confirmado
+    BlockTaskCreation,
+}
+
+impl EmailVerificationPolicy {
+    /// Indica se a política impede o login de contas não verificadas
+    pub fn blocks_login(&self) -> bool {
+        matches!(self, EmailVerificationPolicy::BlockLogin)
+    }
+
+    /// Indica se a política impede a criação de tarefas por contas não verificadas
+    ///
+    /// Bloquear o login também bloqueia tudo que depende dele.
+    pub fn blocks_task_creation(&self) -> bool {
+        !matches!(self, EmailVerificationPolicy::Optional)
+    }
+}
+
+impl FromStr for EmailVerificationPolicy {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "optional" => Ok(EmailVerificationPolicy::Optional),
+            "block_login" => Ok(EmailVerificationPolicy::BlockLogin),
+            "block_task_creation" => Ok(EmailVerificationPolicy::BlockTaskCreation),
+            _ => Err(DomainError::ValidationError(format!(
+                "Política de verificação de email inválida: {}",
+                s
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_policy() {
+        assert_eq!(
+            "block_login".parse::<EmailVerificationPolicy>().unwrap(),
+            EmailVerificationPolicy::BlockLogin
+        );
+        assert_eq!(
+            "BLOCK_TASK_CREATION"
+                .parse::<EmailVerificationPolicy>()
+                .unwrap(),
+            EmailVerificationPolicy::BlockTaskCreation
+        );
+        assert!("strict".parse::<EmailVerificationPolicy>().is_err());
+    }
+
+    #[test]
+    fn test_policy_restrictions() {
+        assert!(!EmailVerificationPolicy::Optional.blocks_login());
+        assert!(!EmailVerificationPolicy::Optional.blocks_task_creation());
+        assert!(!EmailVerificationPolicy::BlockTaskCreation.blocks_login());
+        assert!(EmailVerificationPolicy::BlockTaskCreation.blocks_task_creation());
+        assert!(EmailVerificationPolicy::BlockLogin.blocks_task_creation());
+    }
+}
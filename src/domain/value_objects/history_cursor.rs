@@ -0,0 +1,92 @@
+use crate::domain::entities::TaskHistory;
+use crate::domain::errors::DomainError;
+use chrono::{DateTime, Utc};
+use std::fmt;
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// Posição no fluxo de histórico: `(changed_at, id)` do último registro entregue
+///
+/// Serializado como `<microssegundos desde epoch>:<id>` para uso como
+/// `Last-Event-ID` em Server-Sent Events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HistoryCursor {
+    pub changed_at: DateTime<Utc>,
+    pub id: Uuid,
+}
+
+impl HistoryCursor {
+    pub fn new(changed_at: DateTime<Utc>, id: Uuid) -> Self {
+        Self { changed_at, id }
+    }
+
+    /// Cursor posicionado no instante atual (apenas registros novos)
+    pub fn now() -> Self {
+        Self::new(Utc::now(), Uuid::nil())
+    }
+}
+
+impl From<&TaskHistory> for HistoryCursor {
+    fn from(history: &TaskHistory) -> Self {
+        Self::new(history.changed_at, history.id)
+    }
+}
+
+impl fmt::Display for HistoryCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.changed_at.timestamp_micros(), self.id)
+    }
+}
+
+impl FromStr for HistoryCursor {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid =
+            || DomainError::ValidationError(format!("Cursor de histórico inválido: {}", s));
+
+        let (micros, id) = s.trim().split_once(':').ok_or_else(invalid)?;
+        let micros: i64 = micros.parse().map_err(|_| invalid())?;
+        let changed_at = DateTime::from_timestamp_micros(micros).ok_or_else(invalid)?;
+        let id = Uuid::parse_str(id).map_err(|_| invalid())?;
+
+        Ok(Self::new(changed_at, id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let history = TaskHistory::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "status".to_string(),
+            Some("pending".to_string()),
+            Some("in_progress".to_string()),
+        );
+
+        let cursor = HistoryCursor::from(&history);
+        let parsed: HistoryCursor = cursor.to_string().parse().unwrap();
+
+        assert_eq!(parsed.id, history.id);
+        assert_eq!(
+            parsed.changed_at.timestamp_micros(),
+            history.changed_at.timestamp_micros()
+        );
+    }
+
+    #[test]
+    fn test_invalid_cursor() {
+        assert!("".parse::<HistoryCursor>().is_err());
+        assert!("abc:def".parse::<HistoryCursor>().is_err());
+        assert!("1700000000000000".parse::<HistoryCursor>().is_err());
+        assert!(
+            "1700000000000000:not-a-uuid"
+                .parse::<HistoryCursor>()
+                .is_err()
+        );
+    }
+}
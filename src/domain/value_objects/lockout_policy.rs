@@ -0,0 +1,86 @@
+use chrono::Duration;
+
+/// Política de proteção contra força bruta no login
+///
+/// Cada falha atrasa a resposta de forma progressiva; ao atingir o limite, a
+/// conta (ou o IP) fica bloqueada por um período que dobra a cada nova falha,
+/// até a duração da janela de contagem.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LockoutPolicy {
+    /// Falhas por conta antes do bloqueio
+    pub max_failures_per_account: u32,
+    /// Falhas por IP (somando todas as contas) antes do bloqueio
+    pub max_failures_per_ip: u32,
+    /// Janela em que as falhas são contadas
+    pub window: Duration,
+    /// Bloqueio ao atingir o limite
+    pub lockout: Duration,
+    /// Atraso da resposta após a primeira falha (zero desativa)
+    pub base_delay: Duration,
+    /// Atraso máximo da resposta
+    pub max_delay: Duration,
+}
+
+impl LockoutPolicy {
+    /// Atraso da resposta após `failures` falhas consecutivas
+    pub fn delay_for(&self, failures: u32) -> Duration {
+        if failures == 0 {
+            return Duration::zero();
+        }
+
+        Self::double(self.base_delay, failures - 1).min(self.max_delay)
+    }
+
+    /// Duração do bloqueio para `failures` falhas, dado o limite `max_failures`
+    pub fn lockout_for(&self, failures: u32, max_failures: u32) -> Option<Duration> {
+        if failures < max_failures {
+            return None;
+        }
+
+        Some(Self::double(self.lockout, failures - max_failures).min(self.window))
+    }
+
+    fn double(duration: Duration, times: u32) -> Duration {
+        duration
+            .checked_mul(2_i32.pow(times.min(30)))
+            .unwrap_or(Duration::MAX)
+    }
+}
+
+impl Default for LockoutPolicy {
+    fn default() -> Self {
+        Self {
+            max_failures_per_account: 5,
+            max_failures_per_ip: 50,
+            window: Duration::hours(1),
+            lockout: Duration::minutes(15),
+            base_delay: Duration::milliseconds(250),
+            max_delay: Duration::seconds(4),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay_grows_progressively() {
+        let policy = LockoutPolicy::default();
+
+        assert_eq!(policy.delay_for(0), Duration::zero());
+        assert_eq!(policy.delay_for(1), Duration::milliseconds(250));
+        assert_eq!(policy.delay_for(3), Duration::seconds(1));
+        assert_eq!(policy.delay_for(40), Duration::seconds(4));
+    }
+
+    #[test]
+    fn test_lockout_doubles_after_limit() {
+        let policy = LockoutPolicy::default();
+
+        assert_eq!(policy.lockout_for(4, 5), None);
+        assert_eq!(policy.lockout_for(5, 5), Some(Duration::minutes(15)));
+        assert_eq!(policy.lockout_for(6, 5), Some(Duration::minutes(30)));
+        assert_eq!(policy.lockout_for(60, 5), Some(Duration::hours(1)));
+    }
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+/// Resultado de uma tentativa de login (registrado para auditoria)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "login_outcome", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum LoginOutcome {
+    /// Sessão iniciada
+    Success,
+    /// Senha correta; aguardando o segundo fator
+    MfaRequired,
+    /// Email ou senha incorretos
+    InvalidCredentials,
+    /// Código do segundo fator incorreto
+    InvalidMfaCode,
+    /// Recusada por bloqueio temporário
+    Locked,
+}
+
+impl LoginOutcome {
+    /// Indica se a tentativa conta como falha para o bloqueio
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            LoginOutcome::InvalidCredentials | LoginOutcome::InvalidMfaCode
+        )
+    }
+}
+
+impl std::fmt::Display for LoginOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            LoginOutcome::Success => "success",
+            LoginOutcome::MfaRequired => "mfa_required",
+            LoginOutcome::InvalidCredentials => "invalid_credentials",
+            LoginOutcome::InvalidMfaCode => "invalid_mfa_code",
+            LoginOutcome::Locked => "locked",
+        };
+        write!(f, "{}", s)
+    }
+}
@@ -1,7 +1,41 @@
+pub mod attachment_quota;
+pub mod collaborator_role;
+pub mod data_request_kind;
+pub mod data_request_status;
 pub mod email;
+pub mod email_verification_policy;
+pub mod history_cursor;
+pub mod lockout_policy;
+pub mod login_outcome;
+pub mod rank;
+pub mod recurrence_rule;
+pub mod reopen_policy;
+pub mod subtask_progress;
 pub mod task_priority;
 pub mod task_status;
+pub mod user_role;
+pub mod webhook_delivery_status;
+pub mod webhook_destination_policy;
+pub mod webhook_retry_policy;
+pub mod workspace_role;
 
+pub use attachment_quota::AttachmentQuota;
+pub use collaborator_role::CollaboratorRole;
+pub use data_request_kind::DataRequestKind;
+pub use data_request_status::DataRequestStatus;
 pub use email::Email;
+pub use email_verification_policy::EmailVerificationPolicy;
+pub use history_cursor::HistoryCursor;
+pub use lockout_policy::LockoutPolicy;
+pub use login_outcome::LoginOutcome;
+pub use rank::Rank;
+pub use recurrence_rule::{RecurrenceFrequency, RecurrenceRule};
+pub use reopen_policy::ReopenPolicy;
+pub use subtask_progress::{SubtaskCompletionPolicy, SubtaskProgress};
 pub use task_priority::TaskPriority;
 pub use task_status::TaskStatus;
+pub use user_role::UserRole;
+pub use webhook_delivery_status::WebhookDeliveryStatus;
+pub use webhook_destination_policy::WebhookDestinationPolicy;
+pub use webhook_retry_policy::WebhookRetryPolicy;
+pub use workspace_role::WorkspaceRole;
@@ -0,0 +1,318 @@
+use crate::domain::errors::{DomainError, DomainResult};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Dígitos das chaves em ordem ASCII (a mesma ordem do `COLLATE "C"` no banco)
+const DIGITS: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Menor parte inteira representável (não pode ser decrementada)
+const SMALLEST_INTEGER: &str = "A00000000000000000000000000";
+
+/// Value Object Rank - posição de uma tarefa no quadro (fractional indexing)
+///
+/// A chave é uma parte inteira de tamanho variável (o primeiro caractere indica
+/// o tamanho: `a`-`z` positivos, `A`-`Z` negativos) seguida de uma fração em
+/// base 62. Chaves são comparadas lexicograficamente e sempre existe uma chave
+/// entre outras duas, então mover uma tarefa reescreve apenas a própria tarefa.
+/// Inserir repetidamente no início ou no fim cresce a chave de forma logarítmica.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Rank(String);
+
+impl Rank {
+    /// Posição inicial (`a0`) de um quadro vazio
+    pub fn initial() -> Self {
+        Self("a0".to_string())
+    }
+
+    /// Gera uma posição entre `before` e `after` (`None` é o início/fim do quadro)
+    pub fn between(before: Option<&Rank>, after: Option<&Rank>) -> DomainResult<Rank> {
+        let key = match (before, after) {
+            (None, None) => return Ok(Self::initial()),
+            (Some(a), Some(b)) if a >= b => {
+                return Err(DomainError::ValidationError(
+                    "A posição anterior deve vir antes da posterior".to_string(),
+                ));
+            }
+            (None, Some(b)) => {
+                let (integer, fraction) = b.split();
+                if integer == SMALLEST_INTEGER {
+                    format!("{}{}", integer, midpoint(b"", Some(fraction.as_bytes())))
+                } else if !fraction.is_empty() {
+                    integer.to_string()
+                } else {
+                    decrement_integer(integer).ok_or_else(exhausted)?
+                }
+            }
+            (Some(a), None) => {
+                let (integer, fraction) = a.split();
+                match increment_integer(integer) {
+                    Some(next) => next,
+                    None => format!("{}{}", integer, midpoint(fraction.as_bytes(), None)),
+                }
+            }
+            (Some(a), Some(b)) => {
+                let (integer_a, fraction_a) = a.split();
+                let (integer_b, fraction_b) = b.split();
+                if integer_a == integer_b {
+                    format!(
+                        "{}{}",
+                        integer_a,
+                        midpoint(fraction_a.as_bytes(), Some(fraction_b.as_bytes()))
+                    )
+                } else {
+                    match increment_integer(integer_a).ok_or_else(exhausted)? {
+                        next if next.as_str() < b.as_str() => next,
+                        _ => format!("{}{}", integer_a, midpoint(fraction_a.as_bytes(), None)),
+                    }
+                }
+            }
+        };
+
+        Ok(Self(key))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Separa a chave em parte inteira e fração
+    fn split(&self) -> (&str, &str) {
+        self.0.split_at(integer_length(self.0.as_bytes()[0]))
+    }
+}
+
+/// Tamanho da parte inteira indicado pelo primeiro caractere
+fn integer_length(head: u8) -> usize {
+    match head {
+        b'a'..=b'z' => (head - b'a') as usize + 2,
+        _ => (b'Z' - head) as usize + 2,
+    }
+}
+
+fn digit_value(digit: u8) -> usize {
+    DIGITS.iter().position(|d| *d == digit).unwrap_or(0)
+}
+
+fn exhausted() -> DomainError {
+    DomainError::Internal("Espaço de posições esgotado".to_string())
+}
+
+/// Fração estritamente entre `a` e `b` (`None` representa 1)
+fn midpoint(a: &[u8], b: Option<&[u8]>) -> String {
+    if let Some(b) = b {
+        // Prefixo comum (com `a` completado por zeros)
+        let common = b
+            .iter()
+            .enumerate()
+            .take_while(|(i, digit)| a.get(*i).copied().unwrap_or(DIGITS[0]) == **digit)
+            .count();
+
+        if common > 0 {
+            let prefix = String::from_utf8_lossy(&b[..common]);
+            let rest = midpoint(a.get(common..).unwrap_or_default(), Some(&b[common..]));
+            return format!("{}{}", prefix, rest);
+        }
+    }
+
+    let digit_a = a.first().map_or(0, |digit| digit_value(*digit));
+    let digit_b = b.map_or(DIGITS.len(), |b| digit_value(b[0]));
+
+    if digit_b - digit_a > 1 {
+        (DIGITS[(digit_a + digit_b).div_ceil(2)] as char).to_string()
+    } else if let Some(b) = b
+        && b.len() > 1
+    {
+        (b[0] as char).to_string()
+    } else {
+        format!(
+            "{}{}",
+            DIGITS[digit_a] as char,
+            midpoint(a.get(1..).unwrap_or_default(), None)
+        )
+    }
+}
+
+/// Próximo inteiro (`None` quando não há maior representável)
+fn increment_integer(integer: &str) -> Option<String> {
+    let (head, digits) = integer.as_bytes().split_first()?;
+    let mut digits = digits.to_vec();
+
+    for digit in digits.iter_mut().rev() {
+        let value = digit_value(*digit) + 1;
+        if value < DIGITS.len() {
+            *digit = DIGITS[value];
+            return Some(to_key(*head, &digits));
+        }
+        *digit = DIGITS[0];
+    }
+
+    match head {
+        b'Z' => Some(to_key(b'a', &[DIGITS[0]])),
+        b'z' => None,
+        _ => {
+            let head = head + 1;
+            if head > b'a' {
+                digits.push(DIGITS[0]);
+            } else {
+                digits.pop();
+            }
+            Some(to_key(head, &digits))
+        }
+    }
+}
+
+/// Inteiro anterior (`None` quando não há menor representável)
+fn decrement_integer(integer: &str) -> Option<String> {
+    let (head, digits) = integer.as_bytes().split_first()?;
+    let mut digits = digits.to_vec();
+    let last = DIGITS[DIGITS.len() - 1];
+
+    for digit in digits.iter_mut().rev() {
+        let value = digit_value(*digit);
+        if value > 0 {
+            *digit = DIGITS[value - 1];
+            return Some(to_key(*head, &digits));
+        }
+        *digit = last;
+    }
+
+    match head {
+        b'a' => Some(to_key(b'Z', &[last])),
+        b'A' => None,
+        _ => {
+            let head = head - 1;
+            if head < b'Z' {
+                digits.push(last);
+            } else {
+                digits.pop();
+            }
+            Some(to_key(head, &digits))
+        }
+    }
+}
+
+fn to_key(head: u8, digits: &[u8]) -> String {
+    let mut key = String::with_capacity(digits.len() + 1);
+    key.push(head as char);
+    key.extend(digits.iter().map(|digit| *digit as char));
+    key
+}
+
+impl fmt::Display for Rank {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Rank {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || DomainError::ValidationError(format!("Posição inválida: {}", s));
+
+        let bytes = s.as_bytes();
+        let head = *bytes.first().ok_or_else(invalid)?;
+        if !head.is_ascii_alphabetic() || !bytes[1..].iter().all(|d| DIGITS.contains(d)) {
+            return Err(invalid());
+        }
+
+        let length = integer_length(head);
+        if bytes.len() < length
+            || s == SMALLEST_INTEGER
+            || (bytes.len() > length && s.ends_with('0'))
+        {
+            return Err(invalid());
+        }
+
+        Ok(Self(s.to_string()))
+    }
+}
+
+impl TryFrom<String> for Rank {
+    type Error = DomainError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<Rank> for String {
+    fn from(rank: Rank) -> Self {
+        rank.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rank(s: &str) -> Rank {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_between_orders_keys() {
+        let cases = [
+            (None, None, "a0"),
+            (Some("a0"), None, "a1"),
+            (Some("az"), None, "b00"),
+            (None, Some("a0"), "Zz"),
+            (None, Some("a0V"), "a0"),
+            (Some("a0"), Some("a1"), "a0V"),
+            (Some("a0V"), Some("a1"), "a0l"),
+            (Some("a0"), Some("a0V"), "a0G"),
+            (Some("a1"), Some("a2"), "a1V"),
+            (Some("a0"), Some("a01"), "a00V"),
+        ];
+
+        for (before, after, expected) in cases {
+            let before = before.map(rank);
+            let after = after.map(rank);
+            let key = Rank::between(before.as_ref(), after.as_ref()).unwrap();
+            assert_eq!(key.as_str(), expected);
+            assert!(before.is_none_or(|before| before < key));
+            assert!(after.is_none_or(|after| key < after));
+        }
+    }
+
+    #[test]
+    fn test_between_rejects_inverted_bounds() {
+        let result = Rank::between(Some(&rank("a1")), Some(&rank("a0")));
+        assert!(result.is_err());
+        assert!(Rank::between(Some(&rank("a1")), Some(&rank("a1"))).is_err());
+    }
+
+    #[test]
+    fn test_repeated_moves_stay_ordered_and_short() {
+        // Sempre no fim: crescimento logarítmico
+        let mut last = Rank::initial();
+        for _ in 0..10_000 {
+            let next = Rank::between(Some(&last), None).unwrap();
+            assert!(last < next);
+            last = next;
+        }
+        assert!(last.as_str().len() <= 4);
+
+        // Sempre logo após o primeiro: a chave continua válida e ordenada
+        let first = Rank::initial();
+        let mut after = Rank::between(Some(&first), None).unwrap();
+        for _ in 0..100 {
+            let key = Rank::between(Some(&first), Some(&after)).unwrap();
+            assert!(first < key && key < after);
+            after = key;
+        }
+    }
+
+    #[test]
+    fn test_parse_validates_keys() {
+        assert!("a0".parse::<Rank>().is_ok());
+        assert!("a0V".parse::<Rank>().is_ok());
+        assert!("".parse::<Rank>().is_err());
+        assert!("a".parse::<Rank>().is_err());
+        assert!("a00".parse::<Rank>().is_err());
+        assert!("a0-".parse::<Rank>().is_err());
+        assert!("0a".parse::<Rank>().is_err());
+    }
+}
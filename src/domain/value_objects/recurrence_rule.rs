@@ -0,0 +1,374 @@
+use crate::domain::errors::{DomainError, DomainResult};
+use chrono::{DateTime, Datelike, Duration, Months, NaiveDate, NaiveDateTime, Utc, Weekday};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Intervalo máximo aceito em uma regra de recorrência
+pub const MAX_RECURRENCE_INTERVAL: u32 = 1000;
+
+/// Frequência de uma regra de recorrência
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecurrenceFrequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl FromStr for RecurrenceFrequency {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "DAILY" => Ok(RecurrenceFrequency::Daily),
+            "WEEKLY" => Ok(RecurrenceFrequency::Weekly),
+            "MONTHLY" => Ok(RecurrenceFrequency::Monthly),
+            "YEARLY" => Ok(RecurrenceFrequency::Yearly),
+            _ => Err(invalid(format!("frequência não suportada: {}", s))),
+        }
+    }
+}
+
+impl fmt::Display for RecurrenceFrequency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecurrenceFrequency::Daily => write!(f, "DAILY"),
+            RecurrenceFrequency::Weekly => write!(f, "WEEKLY"),
+            RecurrenceFrequency::Monthly => write!(f, "MONTHLY"),
+            RecurrenceFrequency::Yearly => write!(f, "YEARLY"),
+        }
+    }
+}
+
+/// Value Object RecurrenceRule - subconjunto da RRULE do iCalendar (RFC 5545)
+///
+/// Suporta `FREQ` (DAILY, WEEKLY, MONTHLY, YEARLY), `INTERVAL`, `BYDAY`
+/// (apenas com WEEKLY), `UNTIL` e `COUNT`. Ex.: `FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,TH`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct RecurrenceRule {
+    pub frequency: RecurrenceFrequency,
+    pub interval: u32,
+    pub by_weekday: Vec<Weekday>,
+    pub until: Option<DateTime<Utc>>,
+    pub count: Option<u32>,
+}
+
+impl RecurrenceRule {
+    /// Calcula a próxima ocorrência após `current`
+    ///
+    /// `occurrence` é a posição (a partir de 1) de `current` na série e `dtstart`
+    /// a data da primeira ocorrência. Retorna `None` quando a série terminou
+    /// (`COUNT` atingido ou data após `UNTIL`). Em MONTHLY/YEARLY a data é
+    /// calculada a partir de `dtstart` (`dtstart + occurrence * INTERVAL`), com o
+    /// dia ajustado ao último dia do mês quando necessário, sem que o ajuste se
+    /// propague para as ocorrências seguintes. Sem `dtstart`, parte de `current`.
+    pub fn next_occurrence(
+        &self,
+        dtstart: Option<DateTime<Utc>>,
+        current: DateTime<Utc>,
+        occurrence: u32,
+    ) -> Option<DateTime<Utc>> {
+        if self.count.is_some_and(|count| occurrence >= count) {
+            return None;
+        }
+
+        let add_months = |months: u32| match dtstart {
+            Some(dtstart) => {
+                dtstart.checked_add_months(Months::new(months.checked_mul(occurrence)?))
+            }
+            None => current.checked_add_months(Months::new(months)),
+        };
+
+        let next = match self.frequency {
+            RecurrenceFrequency::Daily => current + Duration::days(self.interval as i64),
+            RecurrenceFrequency::Weekly if self.by_weekday.is_empty() => {
+                current + Duration::weeks(self.interval as i64)
+            }
+            RecurrenceFrequency::Weekly => self.next_weekday(current)?,
+            RecurrenceFrequency::Monthly => add_months(self.interval)?,
+            RecurrenceFrequency::Yearly => add_months(self.interval.checked_mul(12)?)?,
+        };
+
+        match self.until {
+            Some(until) if next > until => None,
+            _ => Some(next),
+        }
+    }
+
+    /// Próximo dia listado em `BYDAY`, respeitando o intervalo de semanas
+    fn next_weekday(&self, current: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let week_start = |date: DateTime<Utc>| {
+            date.date_naive() - Duration::days(date.weekday().num_days_from_monday() as i64)
+        };
+        let current_week = week_start(current);
+
+        (1..=7 * self.interval as i64)
+            .map(|offset| current + Duration::days(offset))
+            .find(|candidate| {
+                let weeks = (week_start(*candidate) - current_week).num_weeks();
+                self.by_weekday.contains(&candidate.weekday()) && weeks % self.interval as i64 == 0
+            })
+    }
+}
+
+impl FromStr for RecurrenceRule {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let s = s.strip_prefix("RRULE:").unwrap_or(s);
+
+        let mut frequency = None;
+        let mut interval = 1;
+        let mut by_weekday = Vec::new();
+        let mut until = None;
+        let mut count = None;
+
+        for part in s.split(';').filter(|part| !part.is_empty()) {
+            let (key, value) = part
+                .split_once('=')
+                .ok_or_else(|| invalid(format!("parte malformada: {}", part)))?;
+
+            match key.to_uppercase().as_str() {
+                "FREQ" => frequency = Some(value.parse()?),
+                "INTERVAL" => {
+                    interval = value
+                        .parse::<u32>()
+                        .ok()
+                        .filter(|i| (1..=MAX_RECURRENCE_INTERVAL).contains(i))
+                        .ok_or_else(|| invalid(format!("INTERVAL inválido: {}", value)))?;
+                }
+                "BYDAY" => {
+                    by_weekday = value
+                        .split(',')
+                        .map(parse_weekday)
+                        .collect::<DomainResult<Vec<_>>>()?;
+                    by_weekday.sort_by_key(|day| day.num_days_from_monday());
+                    by_weekday.dedup();
+                }
+                "UNTIL" => until = Some(parse_until(value)?),
+                "COUNT" => {
+                    count = Some(
+                        value
+                            .parse::<u32>()
+                            .ok()
+                            .filter(|c| *c > 0)
+                            .ok_or_else(|| invalid(format!("COUNT inválido: {}", value)))?,
+                    );
+                }
+                _ => return Err(invalid(format!("parâmetro não suportado: {}", key))),
+            }
+        }
+
+        let frequency = frequency.ok_or_else(|| invalid("FREQ é obrigatório".to_string()))?;
+
+        if !by_weekday.is_empty() && frequency != RecurrenceFrequency::Weekly {
+            return Err(invalid("BYDAY só é suportado com FREQ=WEEKLY".to_string()));
+        }
+
+        if until.is_some() && count.is_some() {
+            return Err(invalid(
+                "UNTIL e COUNT não podem ser usados juntos".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            frequency,
+            interval,
+            by_weekday,
+            until,
+            count,
+        })
+    }
+}
+
+impl fmt::Display for RecurrenceRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "FREQ={}", self.frequency)?;
+
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+
+        if !self.by_weekday.is_empty() {
+            let days: Vec<&str> = self
+                .by_weekday
+                .iter()
+                .map(|day| weekday_code(*day))
+                .collect();
+            write!(f, ";BYDAY={}", days.join(","))?;
+        }
+
+        if let Some(until) = self.until {
+            write!(f, ";UNTIL={}", until.format("%Y%m%dT%H%M%SZ"))?;
+        }
+
+        if let Some(count) = self.count {
+            write!(f, ";COUNT={}", count)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<String> for RecurrenceRule {
+    type Error = DomainError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<RecurrenceRule> for String {
+    fn from(rule: RecurrenceRule) -> Self {
+        rule.to_string()
+    }
+}
+
+fn invalid(message: String) -> DomainError {
+    DomainError::ValidationError(format!("Regra de recorrência inválida: {}", message))
+}
+
+fn parse_weekday(value: &str) -> DomainResult<Weekday> {
+    match value.trim().to_uppercase().as_str() {
+        "MO" => Ok(Weekday::Mon),
+        "TU" => Ok(Weekday::Tue),
+        "WE" => Ok(Weekday::Wed),
+        "TH" => Ok(Weekday::Thu),
+        "FR" => Ok(Weekday::Fri),
+        "SA" => Ok(Weekday::Sat),
+        "SU" => Ok(Weekday::Sun),
+        _ => Err(invalid(format!("dia da semana inválido: {}", value))),
+    }
+}
+
+fn weekday_code(day: Weekday) -> &'static str {
+    match day {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+/// Aceita `AAAAMMDD` (fim do dia) ou `AAAAMMDDTHHMMSSZ`
+fn parse_until(value: &str) -> DomainResult<DateTime<Utc>> {
+    if let Ok(datetime) = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%SZ") {
+        return Ok(datetime.and_utc());
+    }
+
+    NaiveDate::parse_from_str(value, "%Y%m%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(23, 59, 59))
+        .map(|datetime| datetime.and_utc())
+        .ok_or_else(|| invalid(format!("UNTIL inválido: {}", value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn date(y: i32, m: u32, d: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, 9, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_display_roundtrip() {
+        let rule: RecurrenceRule = "RRULE:FREQ=weekly;INTERVAL=2;BYDAY=TH,MO;COUNT=5"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            rule.to_string(),
+            "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,TH;COUNT=5"
+        );
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        assert!("INTERVAL=2".parse::<RecurrenceRule>().is_err());
+        assert!("FREQ=HOURLY".parse::<RecurrenceRule>().is_err());
+        assert!("FREQ=DAILY;BYDAY=MO".parse::<RecurrenceRule>().is_err());
+        assert!(
+            "FREQ=DAILY;COUNT=2;UNTIL=20261231"
+                .parse::<RecurrenceRule>()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_weekly_by_day_with_interval() {
+        let rule: RecurrenceRule = "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,TH".parse().unwrap();
+
+        // 2026-10-19 é segunda-feira
+        let thursday = rule.next_occurrence(None, date(2026, 10, 19), 1).unwrap();
+        assert_eq!(thursday, date(2026, 10, 22));
+
+        // Depois de quinta, pula uma semana
+        let monday = rule.next_occurrence(None, thursday, 2).unwrap();
+        assert_eq!(monday, date(2026, 11, 2));
+    }
+
+    #[test]
+    fn test_monthly_clamps_to_end_of_month() {
+        let rule: RecurrenceRule = "FREQ=MONTHLY".parse().unwrap();
+        assert_eq!(
+            rule.next_occurrence(None, date(2026, 1, 31), 1),
+            Some(date(2026, 2, 28))
+        );
+    }
+
+    #[test]
+    fn test_monthly_from_jan_31_does_not_carry_clamp() {
+        let rule: RecurrenceRule = "FREQ=MONTHLY".parse().unwrap();
+        let start = Some(date(2026, 1, 31));
+
+        let february = rule.next_occurrence(start, date(2026, 1, 31), 1).unwrap();
+        assert_eq!(february, date(2026, 2, 28));
+
+        let march = rule.next_occurrence(start, february, 2).unwrap();
+        assert_eq!(march, date(2026, 3, 31));
+
+        let april = rule.next_occurrence(start, march, 3).unwrap();
+        assert_eq!(april, date(2026, 4, 30));
+
+        let rule: RecurrenceRule = "FREQ=MONTHLY;INTERVAL=3".parse().unwrap();
+        assert_eq!(
+            rule.next_occurrence(start, date(2026, 4, 30), 2),
+            Some(date(2026, 7, 31))
+        );
+    }
+
+    #[test]
+    fn test_yearly_from_feb_29() {
+        let rule: RecurrenceRule = "FREQ=YEARLY".parse().unwrap();
+        let start = Some(date(2028, 2, 29));
+
+        let next = rule.next_occurrence(start, date(2028, 2, 29), 1).unwrap();
+        assert_eq!(next, date(2029, 2, 28));
+
+        let next = rule.next_occurrence(start, next, 2).unwrap();
+        assert_eq!(next, date(2030, 2, 28));
+
+        assert_eq!(
+            rule.next_occurrence(start, date(2031, 2, 28), 4),
+            Some(date(2032, 2, 29))
+        );
+    }
+
+    #[test]
+    fn test_series_ends_with_count_and_until() {
+        let rule: RecurrenceRule = "FREQ=DAILY;COUNT=2".parse().unwrap();
+        assert!(rule.next_occurrence(None, date(2026, 10, 17), 1).is_some());
+        assert!(rule.next_occurrence(None, date(2026, 10, 18), 2).is_none());
+
+        let rule: RecurrenceRule = "FREQ=YEARLY;UNTIL=20271231".parse().unwrap();
+        assert!(rule.next_occurrence(None, date(2026, 10, 17), 1).is_some());
+        assert!(rule.next_occurrence(None, date(2027, 10, 17), 2).is_none());
+    }
+}
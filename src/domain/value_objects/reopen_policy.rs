@@ -0,0 +1,76 @@
+use crate::domain::errors::DomainError;
+use crate::domain::value_objects::TaskStatus;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Política que define quais status finais podem ser reabertos
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReopenPolicy {
+    /// Tarefas concluídas e canceladas podem ser reabertas
+    #[default]
+    All,
+    /// Apenas tarefas concluídas podem ser reabertas
+    CompletedOnly,
+    /// Apenas tarefas canceladas podem ser reabertas
+    CancelledOnly,
+    /// Nenhuma tarefa pode ser reaberta
+    Disabled,
+}
+
+impl ReopenPolicy {
+    /// Indica se tarefas no status informado podem ser reabertas
+    pub fn allows(&self, status: TaskStatus) -> bool {
+        match self {
+            ReopenPolicy::All => status.is_closed(),
+            ReopenPolicy::CompletedOnly => status == TaskStatus::Completed,
+            ReopenPolicy::CancelledOnly => status == TaskStatus::Cancelled,
+            ReopenPolicy::Disabled => false,
+        }
+    }
+}
+
+impl FromStr for ReopenPolicy {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "all" => Ok(ReopenPolicy::All),
+            "completed_only" => Ok(ReopenPolicy::CompletedOnly),
+            "cancelled_only" => Ok(ReopenPolicy::CancelledOnly),
+            "disabled" => Ok(ReopenPolicy::Disabled),
+            _ => Err(DomainError::ValidationError(format!(
+                "Política de reabertura inválida: {}",
+                s
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_policy() {
+        assert_eq!(
+            "COMPLETED_ONLY".parse::<ReopenPolicy>().unwrap(),
+            ReopenPolicy::CompletedOnly
+        );
+        assert_eq!(
+            "disabled".parse::<ReopenPolicy>().unwrap(),
+            ReopenPolicy::Disabled
+        );
+        assert!("sometimes".parse::<ReopenPolicy>().is_err());
+    }
+
+    #[test]
+    fn test_policy_allows_only_closed_statuses() {
+        assert!(ReopenPolicy::All.allows(TaskStatus::Completed));
+        assert!(ReopenPolicy::All.allows(TaskStatus::Cancelled));
+        assert!(!ReopenPolicy::All.allows(TaskStatus::InProgress));
+        assert!(!ReopenPolicy::CompletedOnly.allows(TaskStatus::Cancelled));
+        assert!(ReopenPolicy::CancelledOnly.allows(TaskStatus::Cancelled));
+        assert!(!ReopenPolicy::Disabled.allows(TaskStatus::Completed));
+    }
+}
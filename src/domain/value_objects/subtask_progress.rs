@@ -0,0 +1,74 @@
+use crate::domain::errors::DomainError;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Resumo do andamento das subtarefas diretas de uma tarefa
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubtaskProgress {
+    /// Total de subtarefas
+    pub total: i64,
+    /// Subtarefas concluídas
+    pub completed: i64,
+    /// Subtarefas pendentes ou em andamento
+    pub open: i64,
+}
+
+impl SubtaskProgress {
+    /// Percentual concluído, ignorando subtarefas canceladas
+    ///
+    /// Retorna `None` quando não há subtarefas a considerar.
+    pub fn percentage(&self) -> Option<u8> {
+        let relevant = self.completed + self.open;
+        if relevant == 0 {
+            return None;
+        }
+
+        Some(((self.completed * 100) / relevant) as u8)
+    }
+}
+
+/// Política para concluir tarefas que possuem subtarefas em aberto
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SubtaskCompletionPolicy {
+    /// Exige que todas as subtarefas estejam concluídas ou canceladas
+    #[default]
+    RequireClosed,
+    /// Permite concluir a tarefa mesmo com subtarefas em aberto
+    AllowOpen,
+}
+
+impl FromStr for SubtaskCompletionPolicy {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "require_closed" => Ok(SubtaskCompletionPolicy::RequireClosed),
+            "allow_open" => Ok(SubtaskCompletionPolicy::AllowOpen),
+            _ => Err(DomainError::ValidationError(format!(
+                "Política de conclusão de subtarefas inválida: {}",
+                s
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percentage_ignores_cancelled() {
+        let progress = SubtaskProgress {
+            total: 5,
+            completed: 2,
+            open: 2,
+        };
+        assert_eq!(progress.percentage(), Some(50));
+    }
+
+    #[test]
+    fn test_percentage_without_subtasks() {
+        assert_eq!(SubtaskProgress::default().percentage(), None);
+    }
+}
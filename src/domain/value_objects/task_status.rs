@@ -14,6 +14,14 @@ pub enum TaskStatus {
 }
 
 impl TaskStatus {
+    /// Todos os status, na ordem do fluxo
+    pub const ALL: [TaskStatus; 4] = [
+        TaskStatus::Pending,
+        TaskStatus::InProgress,
+        TaskStatus::Completed,
+        TaskStatus::Cancelled,
+    ];
+
     /// Verifica se a transição de status é válida
     pub fn can_transition_to(&self, new_status: TaskStatus) -> bool {
         use TaskStatus::*;
@@ -29,6 +37,11 @@ impl TaskStatus {
         }
     }
 
+    /// Verifica se o status é final (concluída ou cancelada)
+    pub fn is_closed(&self) -> bool {
+        matches!(self, TaskStatus::Completed | TaskStatus::Cancelled)
+    }
+
     /// Valida e cria uma nova transição de status
     pub fn transition(&self, new_status: TaskStatus) -> DomainResult<TaskStatus> {
         if self.can_transition_to(new_status) {
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+/// Papel do usuário no sistema (RBAC)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "user_role", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum UserRole {
+    /// Usuário comum
+    #[default]
+    User,
+    /// Administrador: gerencia contas e consulta estatísticas do sistema
+    Admin,
+}
+
+impl std::fmt::Display for UserRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            UserRole::User => "user",
+            UserRole::Admin => "admin",
+        };
+        write!(f, "{}", s)
+    }
+}
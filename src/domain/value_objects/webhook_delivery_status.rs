@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+/// Situação de uma entrega de webhook
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "webhook_delivery_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookDeliveryStatus {
+    /// Aguardando envio (primeira tentativa ou nova tentativa agendada)
+    #[default]
+    Pending,
+    /// Entregue com resposta 2xx
+    Delivered,
+    /// Tentativas esgotadas; não será reenviada
+    DeadLettered,
+}
+
+impl std::fmt::Display for WebhookDeliveryStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            WebhookDeliveryStatus::Pending => "pending",
+            WebhookDeliveryStatus::Delivered => "delivered",
+            WebhookDeliveryStatus::DeadLettered => "dead_lettered",
+        };
+        write!(f, "{}", s)
+    }
+}
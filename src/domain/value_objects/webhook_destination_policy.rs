@@ -0,0 +1,194 @@
+use crate::domain::errors::{DomainError, DomainResult};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+/// Política de destinos de webhooks (proteção contra SSRF)
+///
+/// Bloqueia endereços internos: loopback, redes privadas (RFC 1918),
+/// link-local, CGNAT (100.64.0.0/10), benchmark (198.18.0.0/15), reservados
+/// (240.0.0.0/4), unique-local (fc00::/7), não especificados e multicast,
+/// inclusive quando embutidos em IPv6 (mapeados, compatíveis, NAT64 e 6to4).
+/// Hosts em `allowed_hosts` são liberados (para
+/// testes e desenvolvimento local, ex.: `localhost`).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WebhookDestinationPolicy {
+    allowed_hosts: Vec<String>,
+}
+
+impl WebhookDestinationPolicy {
+    pub fn new(allowed_hosts: Vec<String>) -> Self {
+        Self {
+            allowed_hosts: allowed_hosts
+                .iter()
+                .map(|host| normalize_host(host))
+                .filter(|host| !host.is_empty())
+                .collect(),
+        }
+    }
+
+    /// Indica se o host está na lista de liberados
+    pub fn allows_host(&self, host: &str) -> bool {
+        let host = normalize_host(host);
+        self.allowed_hosts.contains(&host)
+    }
+
+    /// Garante que o endereço resolvido para `host` pode receber entregas
+    pub fn ensure_allowed(&self, host: &str, ip: IpAddr) -> DomainResult<()> {
+        if self.allows_host(host) || !is_internal(ip) {
+            return Ok(());
+        }
+
+        Err(DomainError::ValidationError(format!(
+            "Destino do webhook não permitido: {} resolve para um endereço interno",
+            host
+        )))
+    }
+}
+
+/// Verifica se o endereço pertence a uma faixa interna ou não roteável
+pub fn is_internal(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_internal_v4(ip),
+        IpAddr::V6(ip) => match embedded_ipv4(ip) {
+            Some(embedded) => is_internal_v4(embedded),
+            None => is_internal_v6(ip),
+        },
+    }
+}
+
+fn is_internal_v4(ip: Ipv4Addr) -> bool {
+    let [first, second, ..] = ip.octets();
+
+    ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_multicast()
+        || ip.is_broadcast()
+        // 0.0.0.0/8 ("esta rede")
+        || first == 0
+        // 100.64.0.0/10 (CGNAT)
+        || (first == 100 && second & 0xc0 == 64)
+        // 198.18.0.0/15 (benchmark)
+        || (first == 198 && second & 0xfe == 18)
+        // 240.0.0.0/4 (reservado)
+        || first & 0xf0 == 240
+}
+
+fn is_internal_v6(ip: Ipv6Addr) -> bool {
+    let first_segment = ip.segments()[0];
+
+    ip.is_loopback()
+        || ip.is_unspecified()
+        || ip.is_multicast()
+        // fc00::/7 (unique-local)
+        || first_segment & 0xfe00 == 0xfc00
+        // fe80::/10 (link-local)
+        || first_segment & 0xffc0 == 0xfe80
+}
+
+/// Endereço IPv4 embutido em um IPv6 que leva a ele
+///
+/// Cobre ::ffff:a.b.c.d (mapeado), ::a.b.c.d (compatível, exceto :: e ::1),
+/// 64:ff9b::/96 (NAT64) e 2002::/16 (6to4).
+fn embedded_ipv4(ip: Ipv6Addr) -> Option<Ipv4Addr> {
+    if let Some(mapped) = ip.to_ipv4_mapped() {
+        return Some(mapped);
+    }
+
+    let segments = ip.segments();
+    let octets = ip.octets();
+    let last_four = Ipv4Addr::new(octets[12], octets[13], octets[14], octets[15]);
+    match segments {
+        [0, 0, 0, 0, 0, 0, ..] if !ip.is_loopback() && !ip.is_unspecified() => Some(last_four),
+        [0x64, 0xff9b, 0, 0, 0, 0, ..] => Some(last_four),
+        [0x2002, ..] => Some(Ipv4Addr::new(octets[2], octets[3], octets[4], octets[5])),
+        _ => None,
+    }
+}
+
+/// Normaliza o host (minúsculas, sem colchetes de IPv6 nem ponto final)
+fn normalize_host(host: &str) -> String {
+    host.trim()
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .trim_end_matches('.')
+        .to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rejects_internal_destinations() {
+        let internal = [
+            "127.0.0.1",
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "100.127.255.254",
+            "0.0.0.0",
+            "224.0.0.1",
+            "::1",
+            "::",
+            "fc00::1",
+            "fd12:3456::1",
+            "fe80::1",
+            "ff02::1",
+            "::ffff:127.0.0.1",
+            "::ffff:169.254.169.254",
+            "198.18.0.1",
+            "198.19.255.254",
+            "240.0.0.1",
+            "255.255.255.255",
+            "::127.0.0.1",
+            "::10.0.0.1",
+            "64:ff9b::127.0.0.1",
+            "64:ff9b::a9fe:a9fe",
+            "2002:7f00:1::",
+            "2002:a9fe:a9fe::1",
+        ];
+
+        let policy = WebhookDestinationPolicy::default();
+        for ip in internal {
+            let ip: IpAddr = ip.parse().unwrap();
+            assert!(is_internal(ip), "{} deveria ser interno", ip);
+            assert!(policy.ensure_allowed("hooks.example.com", ip).is_err());
+        }
+    }
+
+    #[test]
+    fn test_accepts_public_destinations() {
+        let policy = WebhookDestinationPolicy::default();
+        for ip in [
+            "93.184.216.34",
+            "100.128.0.1",
+            "8.8.8.8",
+            "198.20.0.1",
+            "2606:4700::1111",
+            "64:ff9b::808:808",
+            "2002:808:808::1",
+        ] {
+            let ip: IpAddr = ip.parse().unwrap();
+            assert!(!is_internal(ip), "{} deveria ser público", ip);
+            assert!(policy.ensure_allowed("hooks.example.com", ip).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_allowlist_releases_host() {
+        let policy =
+            WebhookDestinationPolicy::new(vec![" LOCALHOST ".to_string(), "::1".to_string()]);
+        let loopback: IpAddr = "127.0.0.1".parse().unwrap();
+
+        assert!(policy.ensure_allowed("localhost", loopback).is_ok());
+        assert!(
+            policy
+                .ensure_allowed("[::1]", "::1".parse().unwrap())
+                .is_ok()
+        );
+        assert!(policy.ensure_allowed("127.0.0.1", loopback).is_err());
+    }
+}
@@ -0,0 +1,68 @@
+use chrono::Duration;
+
+/// Política de novas tentativas de entrega de webhooks (backoff exponencial)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WebhookRetryPolicy {
+    /// Total de tentativas antes de mover a entrega para dead letter
+    pub max_attempts: u32,
+    /// Espera após a primeira falha
+    pub base_delay: Duration,
+    /// Espera máxima entre tentativas
+    pub max_delay: Duration,
+}
+
+impl WebhookRetryPolicy {
+    pub fn new(max_attempts: u32, base_delay: Duration, max_delay: Duration) -> Self {
+        Self {
+            max_attempts,
+            base_delay,
+            max_delay,
+        }
+    }
+
+    /// Espera antes da próxima tentativa após `attempts` tentativas falhas
+    pub fn delay_for(&self, attempts: u32) -> Duration {
+        let exponent = attempts.saturating_sub(1).min(30);
+        let delay = self
+            .base_delay
+            .checked_mul(2_i32.pow(exponent))
+            .unwrap_or(self.max_delay);
+
+        delay.min(self.max_delay)
+    }
+
+    /// Indica se as tentativas se esgotaram
+    pub fn is_exhausted(&self, attempts: u32) -> bool {
+        attempts >= self.max_attempts
+    }
+}
+
+impl Default for WebhookRetryPolicy {
+    fn default() -> Self {
+        Self::new(8, Duration::seconds(30), Duration::hours(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay_grows_exponentially_up_to_max() {
+        let policy = WebhookRetryPolicy::new(10, Duration::seconds(10), Duration::minutes(5));
+
+        assert_eq!(policy.delay_for(1), Duration::seconds(10));
+        assert_eq!(policy.delay_for(2), Duration::seconds(20));
+        assert_eq!(policy.delay_for(3), Duration::seconds(40));
+        assert_eq!(policy.delay_for(6), Duration::minutes(5));
+        assert_eq!(policy.delay_for(40), Duration::minutes(5));
+    }
+
+    #[test]
+    fn test_is_exhausted() {
+        let policy = WebhookRetryPolicy::new(3, Duration::seconds(1), Duration::seconds(10));
+
+        assert!(!policy.is_exhausted(2));
+        assert!(policy.is_exhausted(3));
+    }
+}
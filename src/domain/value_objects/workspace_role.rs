@@ -0,0 +1,51 @@
+use crate::domain::errors::DomainError;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Papel de um membro em um workspace
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, sqlx::Type,
+)]
+#[sqlx(type_name = "workspace_role", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum WorkspaceRole {
+    #[default]
+    Member,
+    /// Pode convidar membros
+    Admin,
+    Owner,
+}
+
+impl WorkspaceRole {
+    /// Verifica se o papel permite convidar membros
+    pub fn can_invite(&self) -> bool {
+        *self >= WorkspaceRole::Admin
+    }
+}
+
+impl FromStr for WorkspaceRole {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "member" => Ok(WorkspaceRole::Member),
+            "admin" => Ok(WorkspaceRole::Admin),
+            "owner" => Ok(WorkspaceRole::Owner),
+            _ => Err(DomainError::ValidationError(format!(
+                "Papel de membro inválido: {}",
+                s
+            ))),
+        }
+    }
+}
+
+impl std::fmt::Display for WorkspaceRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            WorkspaceRole::Member => "member",
+            WorkspaceRole::Admin => "admin",
+            WorkspaceRole::Owner => "owner",
+        };
+        write!(f, "{}", s)
+    }
+}
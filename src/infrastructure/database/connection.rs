@@ -9,3 +9,20 @@ pub async fn create_pool(database_url: &str) -> Result<PgPool> {
         .await
         .context("Falha ao conectar ao banco de dados")
 }
+
+/// Verifica se o papel da conexão ignora as políticas de row-level security
+/// (superusuários e papéis com `BYPASSRLS` enxergam todos os workspaces)
+pub async fn bypasses_row_level_security(pool: &PgPool) -> Result<bool> {
+    let bypass = sqlx::query_scalar!(
+        r#"
+        SELECT (rolsuper OR rolbypassrls) as "bypass!"
+        FROM pg_roles
+        WHERE rolname = current_user
+        "#
+    )
+    .fetch_one(pool)
+    .await
+    .context("Falha ao consultar o papel do banco de dados")?;
+
+    Ok(bypass)
+}
@@ -1,3 +1,3 @@
 pub mod connection;
 
-pub use connection::create_pool;
+pub use connection::{bypasses_row_level_security, create_pool};
@@ -0,0 +1,93 @@
+use crate::domain::events::{EventPublisher, TaskEvent};
+use tokio::sync::broadcast;
+
+/// Capacidade padrão do canal de eventos
+pub const DEFAULT_EVENT_BUS_CAPACITY: usize = 1024;
+
+/// Barramento de eventos em memória baseado em `tokio::sync::broadcast`
+///
+/// Cada assinante recebe todos os eventos publicados após a assinatura;
+/// assinantes lentos perdem os eventos mais antigos (`RecvError::Lagged`).
+#[derive(Clone)]
+pub struct InMemoryEventBus {
+    sender: broadcast::Sender<TaskEvent>,
+}
+
+impl InMemoryEventBus {
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+        Self { sender }
+    }
+
+    /// Assina o barramento
+    pub fn subscribe(&self) -> broadcast::Receiver<TaskEvent> {
+        self.sender.subscribe()
+    }
+}
+
+impl Default for InMemoryEventBus {
+    fn default() -> Self {
+        Self::new(DEFAULT_EVENT_BUS_CAPACITY)
+    }
+}
+
+impl EventPublisher for InMemoryEventBus {
+    fn publish(&self, event: TaskEvent) {
+        // Sem assinantes o envio falha, o que não é um erro
+        let _ = self.sender.send(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::Task;
+    use crate::domain::events::TaskEventKind;
+    use crate::domain::value_objects::TaskPriority;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_publish_reaches_every_subscriber() {
+        let bus = InMemoryEventBus::default();
+        let mut first = bus.subscribe();
+        let mut second = bus.subscribe();
+
+        let owner_id = Uuid::new_v4();
+        let task = Task::new(
+            Uuid::new_v4(),
+            owner_id,
+            Uuid::new_v4(),
+            "Task".to_string(),
+            None,
+            TaskPriority::Medium,
+            None,
+        )
+        .unwrap();
+
+        bus.publish(TaskEvent::deleted(&task, owner_id));
+
+        for receiver in [&mut first, &mut second] {
+            let event = receiver.try_recv().unwrap();
+            assert_eq!(event.kind, TaskEventKind::TaskDeleted);
+            assert_eq!(event.owner_id, owner_id);
+            assert!(event.task.is_none());
+        }
+    }
+
+    #[test]
+    fn test_publish_without_subscribers_is_noop() {
+        let bus = InMemoryEventBus::new(4);
+        let task = Task::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "Task".to_string(),
+            None,
+            TaskPriority::Low,
+            None,
+        )
+        .unwrap();
+
+        bus.publish(TaskEvent::created(&task, task.user_id));
+    }
+}
@@ -0,0 +1,83 @@
+use super::message::format_message;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{EmailMessage, Mailer};
+use async_trait::async_trait;
+use chrono::Utc;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+/// Mailer para desenvolvimento local: grava cada email como `.eml` e registra no log
+pub struct LogMailer {
+    outbox_dir: PathBuf,
+    from: String,
+}
+
+impl LogMailer {
+    pub fn new(outbox_dir: impl Into<PathBuf>, from: impl Into<String>) -> Self {
+        Self {
+            outbox_dir: outbox_dir.into(),
+            from: from.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl Mailer for LogMailer {
+    async fn send(&self, message: &EmailMessage) -> DomainResult<()> {
+        let formatted = format_message(&self.from, message)?;
+
+        tokio::fs::create_dir_all(&self.outbox_dir)
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        let path = self.outbox_dir.join(format!(
+            "{}-{}.eml",
+            Utc::now().format("%Y%m%dT%H%M%S"),
+            Uuid::new_v4()
+        ));
+
+        tokio::fs::write(&path, formatted)
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        tracing::info!(
+            "📧 Email para {} gravado em {}\nAssunto: {}\n\n{}",
+            message.to,
+            path.display(),
+            message.subject,
+            message.body
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_send_writes_eml_file() {
+        let dir = std::env::temp_dir().join(format!("todo-outbox-{}", Uuid::new_v4()));
+        let mailer = LogMailer::new(&dir, "no-reply@todo.dev");
+
+        mailer
+            .send(&EmailMessage {
+                to: "ana@example.com".to_string(),
+                subject: "Teste".to_string(),
+                body: "Corpo".to_string(),
+            })
+            .await
+            .unwrap();
+
+        let mut entries = tokio::fs::read_dir(&dir).await.unwrap();
+        let entry = entries.next_entry().await.unwrap().unwrap();
+        let content = tokio::fs::read_to_string(entry.path()).await.unwrap();
+
+        assert!(entry.file_name().to_string_lossy().ends_with(".eml"));
+        assert!(content.contains("To: ana@example.com\r\n"));
+        assert!(content.contains("Subject: Teste\r\n"));
+
+        tokio::fs::remove_dir_all(&dir).await.unwrap();
+    }
+}
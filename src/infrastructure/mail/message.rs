@@ -0,0 +1,110 @@
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::EmailMessage;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use chrono::Utc;
+use uuid::Uuid;
+
+/// Monta o email no formato RFC 5322 (texto UTF-8 com corpo em base64)
+///
+/// Cabeçalhos com quebra de linha são rejeitados para evitar injeção.
+pub fn format_message(from: &str, message: &EmailMessage) -> DomainResult<String> {
+    for header in [from, message.to.as_str(), message.subject.as_str()] {
+        if header.contains(['\r', '\n']) {
+            return Err(DomainError::ValidationError(
+                "Cabeçalho de email não pode conter quebras de linha".to_string(),
+            ));
+        }
+    }
+
+    let domain = envelope_address(from)
+        .rsplit_once('@')
+        .map(|(_, domain)| domain.to_string())
+        .unwrap_or_else(|| "localhost".to_string());
+
+    let mut output = String::new();
+    output.push_str(&format!("From: {}\r\n", from));
+    output.push_str(&format!("To: {}\r\n", message.to));
+    output.push_str(&format!("Subject: {}\r\n", encode_header(&message.subject)));
+    output.push_str(&format!("Date: {}\r\n", Utc::now().to_rfc2822()));
+    output.push_str(&format!("Message-ID: <{}@{}>\r\n", Uuid::new_v4(), domain));
+    output.push_str("MIME-Version: 1.0\r\n");
+    output.push_str("Content-Type: text/plain; charset=UTF-8\r\n");
+    output.push_str("Content-Transfer-Encoding: base64\r\n");
+    output.push_str("\r\n");
+
+    // Linhas de no máximo 76 caracteres (RFC 2045)
+    let encoded = STANDARD.encode(message.body.as_bytes());
+    for line in encoded.as_bytes().chunks(76) {
+        output.push_str(std::str::from_utf8(line).unwrap_or_default());
+        output.push_str("\r\n");
+    }
+
+    Ok(output)
+}
+
+/// Extrai o endereço de `Nome <email@dominio>`, usado no envelope SMTP
+pub fn envelope_address(mailbox: &str) -> &str {
+    match (mailbox.find('<'), mailbox.rfind('>')) {
+        (Some(start), Some(end)) if start < end => mailbox[start + 1..end].trim(),
+        _ => mailbox.trim(),
+    }
+}
+
+/// Codifica cabeçalhos não-ASCII (RFC 2047)
+fn encode_header(value: &str) -> String {
+    if value.is_ascii() {
+        value.to_string()
+    } else {
+        format!("=?UTF-8?B?{}?=", STANDARD.encode(value.as_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(subject: &str) -> EmailMessage {
+        EmailMessage {
+            to: "ana@example.com".to_string(),
+            subject: subject.to_string(),
+            body: "Olá, Ana!".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_format_message_encodes_subject_and_body() {
+        let formatted = format_message(
+            "Todo API <no-reply@todo.dev>",
+            &message("Redefinição de senha"),
+        )
+        .unwrap();
+
+        assert!(formatted.contains("To: ana@example.com\r\n"));
+        assert!(formatted.contains(&format!(
+            "Subject: =?UTF-8?B?{}?=\r\n",
+            STANDARD.encode("Redefinição de senha")
+        )));
+        assert!(formatted.contains("@todo.dev>\r\n"));
+        assert!(formatted.ends_with(&format!("\r\n\r\n{}\r\n", STANDARD.encode("Olá, Ana!"))));
+    }
+
+    #[test]
+    fn test_format_message_rejects_header_injection() {
+        let result = format_message(
+            "no-reply@todo.dev",
+            &message("Oi\r\nBcc: intruso@example.com"),
+        );
+
+        assert!(matches!(result, Err(DomainError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_envelope_address() {
+        assert_eq!(
+            envelope_address("Todo API <no-reply@todo.dev>"),
+            "no-reply@todo.dev"
+        );
+        assert_eq!(envelope_address(" no-reply@todo.dev "), "no-reply@todo.dev");
+    }
+}
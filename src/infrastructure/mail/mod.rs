@@ -0,0 +1,36 @@
+pub mod log_mailer;
+pub mod message;
+pub mod smtp_mailer;
+
+pub use log_mailer::LogMailer;
+pub use smtp_mailer::{SmtpMailer, SmtpSecurity, SmtpSettings};
+
+use crate::domain::errors::DomainError;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Transporte usado para enviar emails
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MailTransport {
+    /// Grava os emails em arquivos `.eml` e no log (desenvolvimento local)
+    #[default]
+    Log,
+    /// Envia os emails por um servidor SMTP
+    Smtp,
+}
+
+impl FromStr for MailTransport {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "log" => Ok(MailTransport::Log),
+            "smtp" => Ok(MailTransport::Smtp),
+            _ => Err(DomainError::ValidationError(format!(
+                "Transporte de email inválido: {}",
+                s
+            ))),
+        }
+    }
+}
@@ -0,0 +1,381 @@
+use super::message::{envelope_address, format_message};
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{EmailMessage, Mailer};
+use anyhow::{Context, Result, bail};
+use async_trait::async_trait;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufStream};
+use tokio::net::TcpStream;
+use tokio_rustls::TlsConnector;
+use tokio_rustls::rustls::ClientConfig;
+use tokio_rustls::rustls::pki_types::ServerName;
+
+/// Segurança da conexão SMTP
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SmtpSecurity {
+    /// Conexão em texto puro promovida a TLS via STARTTLS (porta 587)
+    #[default]
+    Starttls,
+    /// TLS desde o início da conexão (porta 465)
+    Tls,
+    /// Sem criptografia (apenas servidores locais de teste)
+    None,
+}
+
+impl FromStr for SmtpSecurity {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "starttls" => Ok(SmtpSecurity::Starttls),
+            "tls" => Ok(SmtpSecurity::Tls),
+            "none" => Ok(SmtpSecurity::None),
+            _ => Err(DomainError::ValidationError(format!(
+                "Segurança SMTP inválida: {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// Parâmetros de conexão com o servidor SMTP
+#[derive(Debug, Clone)]
+pub struct SmtpSettings {
+    pub host: String,
+    pub port: u16,
+    pub security: SmtpSecurity,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub timeout: Duration,
+}
+
+/// Mailer SMTP: abre uma conexão por email (EHLO, STARTTLS, AUTH PLAIN, MAIL, RCPT, DATA)
+pub struct SmtpMailer {
+    settings: SmtpSettings,
+    from: String,
+    tls: Option<TlsConnector>,
+}
+
+impl SmtpMailer {
+    pub fn new(settings: SmtpSettings, from: impl Into<String>) -> Result<Self> {
+        let tls = match settings.security {
+            SmtpSecurity::None => None,
+            SmtpSecurity::Starttls | SmtpSecurity::Tls => {
+                use rustls_platform_verifier::ConfigVerifierExt;
+
+                let config = ClientConfig::with_platform_verifier()
+                    .context("Falha ao carregar certificados para o SMTP")?;
+                Some(TlsConnector::from(Arc::new(config)))
+            }
+        };
+
+        Ok(Self {
+            settings,
+            from: from.into(),
+            tls,
+        })
+    }
+
+    /// Executa a conversa SMTP completa para um email
+    async fn deliver(&self, recipient: &str, data: &str) -> Result<()> {
+        let address = (self.settings.host.as_str(), self.settings.port);
+        let tcp = TcpStream::connect(address)
+            .await
+            .with_context(|| format!("Falha ao conectar em {}:{}", address.0, address.1))?;
+
+        match self.settings.security {
+            SmtpSecurity::None => {
+                let mut connection = SmtpConnection::new(tcp);
+                connection.greet().await?;
+                self.transaction(&mut connection, recipient, data).await
+            }
+            SmtpSecurity::Tls => {
+                let mut connection = SmtpConnection::new(self.wrap_tls(tcp).await?);
+                connection.greet().await?;
+                self.transaction(&mut connection, recipient, data).await
+            }
+            SmtpSecurity::Starttls => {
+                let mut plain = SmtpConnection::new(tcp);
+                let capabilities = plain.greet().await?;
+                if !capabilities
+                    .lines()
+                    .any(|line| line.eq_ignore_ascii_case("STARTTLS"))
+                {
+                    bail!("Servidor SMTP não oferece STARTTLS");
+                }
+                plain.command("STARTTLS", &[220]).await?;
+
+                let tcp = plain.stream.into_inner();
+                let mut connection = SmtpConnection::new(self.wrap_tls(tcp).await?);
+                connection.ehlo().await?;
+                self.transaction(&mut connection, recipient, data).await
+            }
+        }
+    }
+
+    async fn wrap_tls(&self, tcp: TcpStream) -> Result<tokio_rustls::client::TlsStream<TcpStream>> {
+        let connector = self.tls.as_ref().context("TLS não configurado")?;
+        let server_name = ServerName::try_from(self.settings.host.clone())
+            .context("Host SMTP inválido para TLS")?;
+
+        connector
+            .connect(server_name, tcp)
+            .await
+            .context("Falha no handshake TLS com o servidor SMTP")
+    }
+
+    /// Autentica (se configurado) e envia o email
+    async fn transaction<S: AsyncRead + AsyncWrite + Unpin>(
+        &self,
+        connection: &mut SmtpConnection<S>,
+        recipient: &str,
+        data: &str,
+    ) -> Result<()> {
+        if let (Some(username), Some(password)) = (&self.settings.username, &self.settings.password)
+        {
+            let credentials = STANDARD.encode(format!("\0{}\0{}", username, password));
+            connection
+                .command(&format!("AUTH PLAIN {}", credentials), &[235])
+                .await?;
+        }
+
+        connection
+            .command(
+                &format!("MAIL FROM:<{}>", envelope_address(&self.from)),
+                &[250],
+            )
+            .await?;
+        connection
+            .command(&format!("RCPT TO:<{}>", recipient), &[250, 251])
+            .await?;
+        connection.command("DATA", &[354]).await?;
+
+        // Dot-stuffing: linhas iniciadas por "." recebem um ponto extra
+        let mut payload = String::with_capacity(data.len() + 5);
+        for line in data.split_inclusive("\r\n") {
+            if line.starts_with('.') {
+                payload.push('.');
+            }
+            payload.push_str(line);
+        }
+        payload.push('.');
+        connection.command(&payload, &[250]).await?;
+
+        // Falha no QUIT não invalida um email já aceito
+        let _ = connection.command("QUIT", &[221]).await;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+    async fn send(&self, message: &EmailMessage) -> DomainResult<()> {
+        let recipient = envelope_address(&message.to).to_string();
+        let data = format_message(&self.from, message)?;
+
+        tokio::time::timeout(self.settings.timeout, self.deliver(&recipient, &data))
+            .await
+            .map_err(|_| DomainError::Internal("Tempo esgotado no envio SMTP".to_string()))?
+            .map_err(|e| DomainError::Internal(format!("Falha no envio SMTP: {:#}", e)))
+    }
+}
+
+/// Conexão SMTP sobre qualquer stream (TCP ou TLS)
+struct SmtpConnection<S> {
+    stream: BufStream<S>,
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> SmtpConnection<S> {
+    fn new(stream: S) -> Self {
+        Self {
+            stream: BufStream::new(stream),
+        }
+    }
+
+    /// Lê a saudação do servidor e envia EHLO
+    async fn greet(&mut self) -> Result<String> {
+        self.expect(&[220]).await?;
+        self.ehlo().await
+    }
+
+    /// Envia EHLO e retorna as extensões anunciadas
+    async fn ehlo(&mut self) -> Result<String> {
+        self.command("EHLO localhost", &[250]).await
+    }
+
+    /// Envia um comando e valida o código da resposta
+    async fn command(&mut self, line: &str, expected: &[u16]) -> Result<String> {
+        self.stream.write_all(line.as_bytes()).await?;
+        self.stream.write_all(b"\r\n").await?;
+        self.stream.flush().await?;
+
+        self.expect(expected).await
+    }
+
+    /// Lê uma resposta (possivelmente multilinha) e valida o código
+    async fn expect(&mut self, expected: &[u16]) -> Result<String> {
+        let mut text = String::new();
+
+        loop {
+            let mut line = String::new();
+            if self.stream.read_line(&mut line).await? == 0 {
+                bail!("Conexão SMTP encerrada pelo servidor");
+            }
+
+            let line = line.trim_end();
+            let code: u16 = line
+                .get(..3)
+                .and_then(|code| code.parse().ok())
+                .with_context(|| format!("Resposta SMTP inválida: {}", line))?;
+
+            text.push_str(line.get(4..).unwrap_or_default());
+            text.push('\n');
+
+            // "250-..." continua a resposta; "250 ..." a encerra
+            if line.as_bytes().get(3) != Some(&b'-') {
+                if !expected.contains(&code) {
+                    bail!("Servidor SMTP respondeu {}: {}", code, text.trim_end());
+                }
+                return Ok(text);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::BufReader;
+    use tokio::net::TcpListener;
+
+    /// Sobe um servidor SMTP local que registra os comandos recebidos
+    ///
+    /// `rcpt_reply` permite simular a recusa do destinatário.
+    async fn stand_in(rcpt_reply: &'static str) -> (u16, tokio::task::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let handle = tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let (reader, mut writer) = socket.into_split();
+            let mut reader = BufReader::new(reader);
+            let mut received = Vec::new();
+
+            writer.write_all(b"220 stand-in ESMTP\r\n").await.unwrap();
+
+            let mut line = String::new();
+            while reader.read_line(&mut line).await.unwrap() > 0 {
+                let command = line.trim_end().to_string();
+                line.clear();
+
+                let reply: &[u8] = if command.starts_with("EHLO") {
+                    b"250-stand-in\r\n250 AUTH PLAIN\r\n"
+                } else if command.starts_with("AUTH") {
+                    b"235 ok\r\n"
+                } else if command.starts_with("RCPT") {
+                    rcpt_reply.as_bytes()
+                } else if command == "DATA" {
+                    received.push(command);
+                    writer.write_all(b"354 go ahead\r\n").await.unwrap();
+
+                    let mut data = String::new();
+                    loop {
+                        let mut data_line = String::new();
+                        reader.read_line(&mut data_line).await.unwrap();
+                        if data_line == ".\r\n" {
+                            break;
+                        }
+                        data.push_str(&data_line);
+                    }
+                    received.push(data);
+                    writer.write_all(b"250 queued\r\n").await.unwrap();
+                    continue;
+                } else if command == "QUIT" {
+                    received.push(command);
+                    writer.write_all(b"221 bye\r\n").await.unwrap();
+                    break;
+                } else {
+                    b"250 ok\r\n"
+                };
+
+                received.push(command);
+                writer.write_all(reply).await.unwrap();
+            }
+
+            received
+        });
+
+        (port, handle)
+    }
+
+    fn mailer(port: u16) -> SmtpMailer {
+        SmtpMailer::new(
+            SmtpSettings {
+                host: "127.0.0.1".to_string(),
+                port,
+                security: SmtpSecurity::None,
+                username: Some("user".to_string()),
+                password: Some("secret".to_string()),
+                timeout: Duration::from_secs(5),
+            },
+            "Todo API <no-reply@todo.dev>",
+        )
+        .unwrap()
+    }
+
+    fn message() -> EmailMessage {
+        EmailMessage {
+            to: "ana@example.com".to_string(),
+            subject: "Teste".to_string(),
+            body: "Corpo".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_send_runs_smtp_conversation() {
+        let (port, server) = stand_in("250 ok\r\n").await;
+
+        mailer(port).send(&message()).await.unwrap();
+
+        let received = server.await.unwrap();
+        assert_eq!(received[0], "EHLO localhost");
+        assert_eq!(
+            received[1],
+            format!("AUTH PLAIN {}", STANDARD.encode("\0user\0secret"))
+        );
+        assert_eq!(received[2], "MAIL FROM:<no-reply@todo.dev>");
+        assert_eq!(received[3], "RCPT TO:<ana@example.com>");
+        assert_eq!(received[4], "DATA");
+        assert!(received[5].contains("Subject: Teste\r\n"));
+        assert_eq!(received[6], "QUIT");
+    }
+
+    #[tokio::test]
+    async fn test_send_fails_when_recipient_is_rejected() {
+        let (port, _server) = stand_in("550 no such user\r\n").await;
+
+        let result = mailer(port).send(&message()).await;
+
+        match result {
+            Err(DomainError::Internal(msg)) => assert!(msg.contains("550")),
+            other => panic!("esperava erro SMTP, obteve {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_smtp_security() {
+        assert_eq!(
+            "STARTTLS".parse::<SmtpSecurity>().unwrap(),
+            SmtpSecurity::Starttls
+        );
+        assert_eq!("tls".parse::<SmtpSecurity>().unwrap(), SmtpSecurity::Tls);
+        assert!("ssl".parse::<SmtpSecurity>().is_err());
+    }
+}
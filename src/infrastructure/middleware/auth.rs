@@ -1,13 +1,19 @@
-use crate::infrastructure::security::{Claims, validate_jwt};
+use crate::domain::errors::DomainError;
+use crate::infrastructure::security::{Claims, TokenRevocationService, validate_jwt};
 use axum::{
-    extract::Request,
+    extract::{Request, State},
     http::StatusCode,
     middleware::Next,
     response::{IntoResponse, Response},
 };
+use std::sync::Arc;
 
 /// Middleware de autenticação JWT
-pub async fn auth_middleware(mut req: Request, next: Next) -> Result<Response, AuthError> {
+pub async fn auth_middleware(
+    State(token_revocation): State<Arc<TokenRevocationService>>,
+    mut req: Request,
+    next: Next,
+) -> Result<Response, AuthError> {
     // Extrair token do header Authorization
     let auth_header = req
         .headers()
@@ -23,6 +29,12 @@ pub async fn auth_middleware(mut req: Request, next: Next) -> Result<Response, A
     // Validar token
     let claims = validate_jwt(token).map_err(|_| AuthError::InvalidToken)?;
 
+    // Rejeitar tokens revogados, de versão anterior ("sair de todos") ou de contas desativadas
+    token_revocation
+        .ensure_active(&claims)
+        .await
+        .map_err(AuthError::from)?;
+
     // Inserir claims na request para uso nos handlers
     req.extensions_mut().insert(claims);
 
@@ -35,6 +47,20 @@ pub enum AuthError {
     MissingToken,
     InvalidFormat,
     InvalidToken,
+    RevokedToken,
+    Internal,
+}
+
+impl From<DomainError> for AuthError {
+    fn from(err: DomainError) -> Self {
+        match err {
+            DomainError::Unauthorized => AuthError::RevokedToken,
+            err => {
+                tracing::error!("Erro ao verificar revogação do token: {:?}", err);
+                AuthError::Internal
+            }
+        }
+    }
 }
 
 impl IntoResponse for AuthError {
@@ -43,6 +69,11 @@ impl IntoResponse for AuthError {
             AuthError::MissingToken => (StatusCode::UNAUTHORIZED, "Token não fornecido"),
             AuthError::InvalidFormat => (StatusCode::UNAUTHORIZED, "Formato de token inválido"),
             AuthError::InvalidToken => (StatusCode::UNAUTHORIZED, "Token inválido ou expirado"),
+            AuthError::RevokedToken => (StatusCode::UNAUTHORIZED, "Token revogado"),
+            AuthError::Internal => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Erro interno do servidor",
+            ),
         };
 
         (status, message).into_response()
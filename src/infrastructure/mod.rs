@@ -1,4 +1,8 @@
 pub mod database;
+pub mod events;
+pub mod mail;
 pub mod middleware;
 pub mod repositories;
 pub mod security;
+pub mod storage;
+pub mod webhooks;
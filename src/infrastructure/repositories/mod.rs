@@ -1,5 +1,42 @@
+pub mod postgres_attachment_repository;
+pub mod postgres_collaborator_repository;
+pub mod postgres_comment_repository;
+pub mod postgres_data_request_repository;
+pub mod postgres_dependency_repository;
+pub mod postgres_email_verification_repository;
+pub mod postgres_login_attempt_repository;
+pub mod postgres_password_reset_repository;
+pub mod postgres_personal_data_repository;
+pub mod postgres_project_repository;
+pub mod postgres_revoked_token_repository;
+pub mod postgres_session_repository;
+pub mod postgres_statistics_repository;
 pub mod postgres_task_repository;
+pub mod postgres_two_factor_repository;
 pub mod postgres_user_repository;
+pub mod postgres_webhook_delivery_repository;
+pub mod postgres_webhook_repository;
+pub mod postgres_workflow_repository;
+pub mod postgres_workspace_repository;
+mod row_level_security;
 
+pub use postgres_attachment_repository::PostgresAttachmentRepository;
+pub use postgres_collaborator_repository::PostgresCollaboratorRepository;
+pub use postgres_comment_repository::PostgresCommentRepository;
+pub use postgres_data_request_repository::PostgresDataRequestRepository;
+pub use postgres_dependency_repository::PostgresDependencyRepository;
+pub use postgres_email_verification_repository::PostgresEmailVerificationRepository;
+pub use postgres_login_attempt_repository::PostgresLoginAttemptRepository;
+pub use postgres_password_reset_repository::PostgresPasswordResetRepository;
+pub use postgres_personal_data_repository::PostgresPersonalDataRepository;
+pub use postgres_project_repository::PostgresProjectRepository;
+pub use postgres_revoked_token_repository::PostgresRevokedTokenRepository;
+pub use postgres_session_repository::PostgresSessionRepository;
+pub use postgres_statistics_repository::PostgresStatisticsRepository;
 pub use postgres_task_repository::PostgresTaskRepository;
+pub use postgres_two_factor_repository::PostgresTwoFactorRepository;
 pub use postgres_user_repository::PostgresUserRepository;
+pub use postgres_webhook_delivery_repository::PostgresWebhookDeliveryRepository;
+pub use postgres_webhook_repository::PostgresWebhookRepository;
+pub use postgres_workflow_repository::PostgresWorkflowRepository;
+pub use postgres_workspace_repository::PostgresWorkspaceRepository;
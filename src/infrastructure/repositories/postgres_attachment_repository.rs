@@ -0,0 +1,181 @@
+use crate::domain::entities::Attachment;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::AttachmentRepository;
+use crate::domain::value_objects::AttachmentQuota;
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Implementação PostgreSQL do AttachmentRepository
+pub struct PostgresAttachmentRepository {
+    pool: PgPool,
+}
+
+impl PostgresAttachmentRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AttachmentRepository for PostgresAttachmentRepository {
+    async fn create(
+        &self,
+        attachment: &Attachment,
+        quota: &AttachmentQuota,
+    ) -> DomainResult<Attachment> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        // Travar o usuário serializa uploads concorrentes contra a mesma cota
+        sqlx::query!(
+            r#"
+            SELECT id FROM users WHERE id = $1 FOR NO KEY UPDATE
+            "#,
+            attachment.user_id,
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?
+        .ok_or(DomainError::NotFound("Usuário não encontrado".to_string()))?;
+
+        let used = sqlx::query_scalar!(
+            r#"
+            SELECT COALESCE(SUM(size_bytes), 0)::BIGINT as "total!"
+            FROM attachments
+            WHERE user_id = $1
+            "#,
+            attachment.user_id,
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        quota.ensure_allows(used.max(0) as u64, attachment.size_bytes.max(0) as u64)?;
+
+        let row = sqlx::query_as!(
+            AttachmentRow,
+            r#"
+            INSERT INTO attachments (id, task_id, user_id, file_name, content_type, size_bytes, sha256, storage_key, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING id, task_id, user_id, file_name, content_type, size_bytes, sha256, storage_key, created_at
+            "#,
+            attachment.id,
+            attachment.task_id,
+            attachment.user_id,
+            attachment.file_name,
+            attachment.content_type,
+            attachment.size_bytes,
+            attachment.sha256,
+            attachment.storage_key,
+            attachment.created_at,
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(row.into())
+    }
+
+    async fn find_by_id(&self, id: &Uuid) -> DomainResult<Option<Attachment>> {
+        let row = sqlx::query_as!(
+            AttachmentRow,
+            r#"
+            SELECT id, task_id, user_id, file_name, content_type, size_bytes, sha256, storage_key, created_at
+            FROM attachments
+            WHERE id = $1
+            "#,
+            id,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn list_by_task(&self, task_id: &Uuid) -> DomainResult<Vec<Attachment>> {
+        let rows = sqlx::query_as!(
+            AttachmentRow,
+            r#"
+            SELECT id, task_id, user_id, file_name, content_type, size_bytes, sha256, storage_key, created_at
+            FROM attachments
+            WHERE task_id = $1
+            ORDER BY created_at ASC
+            "#,
+            task_id,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn total_size_by_user(&self, user_id: &Uuid) -> DomainResult<i64> {
+        let result = sqlx::query!(
+            r#"
+            SELECT COALESCE(SUM(size_bytes), 0)::BIGINT as "total!"
+            FROM attachments
+            WHERE user_id = $1
+            "#,
+            user_id,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(result.total)
+    }
+
+    async fn delete(&self, id: &Uuid) -> DomainResult<()> {
+        sqlx::query!(
+            r#"
+            DELETE FROM attachments WHERE id = $1
+            "#,
+            id,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+/// Row struct para mapeamento do banco
+#[derive(Debug)]
+struct AttachmentRow {
+    id: Uuid,
+    task_id: Uuid,
+    user_id: Uuid,
+    file_name: String,
+    content_type: String,
+    size_bytes: i64,
+    sha256: String,
+    storage_key: String,
+    created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<AttachmentRow> for Attachment {
+    fn from(row: AttachmentRow) -> Self {
+        Attachment {
+            id: row.id,
+            task_id: row.task_id,
+            user_id: row.user_id,
+            file_name: row.file_name,
+            content_type: row.content_type,
+            size_bytes: row.size_bytes,
+            sha256: row.sha256,
+            storage_key: row.storage_key,
+            created_at: row.created_at,
+        }
+    }
+}
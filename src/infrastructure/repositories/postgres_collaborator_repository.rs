@@ -0,0 +1,116 @@
+use crate::domain::entities::TaskCollaborator;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::CollaboratorRepository;
+use crate::domain::value_objects::CollaboratorRole;
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Implementação PostgreSQL do CollaboratorRepository
+pub struct PostgresCollaboratorRepository {
+    pool: PgPool,
+}
+
+impl PostgresCollaboratorRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CollaboratorRepository for PostgresCollaboratorRepository {
+    async fn upsert(&self, collaborator: &TaskCollaborator) -> DomainResult<TaskCollaborator> {
+        let row = sqlx::query_as!(
+            TaskCollaboratorRow,
+            r#"
+            INSERT INTO task_collaborators (task_id, user_id, role, invited_by, created_at)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (task_id, user_id) DO UPDATE SET role = EXCLUDED.role
+            RETURNING task_id, user_id, role as "role: CollaboratorRole", invited_by, created_at
+            "#,
+            collaborator.task_id,
+            collaborator.user_id,
+            collaborator.role as CollaboratorRole,
+            collaborator.invited_by,
+            collaborator.created_at,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(row.into())
+    }
+
+    async fn find(&self, task_id: &Uuid, user_id: &Uuid) -> DomainResult<Option<TaskCollaborator>> {
+        let row = sqlx::query_as!(
+            TaskCollaboratorRow,
+            r#"
+            SELECT task_id, user_id, role as "role: CollaboratorRole", invited_by, created_at
+            FROM task_collaborators
+            WHERE task_id = $1 AND user_id = $2
+            "#,
+            task_id,
+            user_id,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn list_by_task(&self, task_id: &Uuid) -> DomainResult<Vec<TaskCollaborator>> {
+        let rows = sqlx::query_as!(
+            TaskCollaboratorRow,
+            r#"
+            SELECT task_id, user_id, role as "role: CollaboratorRole", invited_by, created_at
+            FROM task_collaborators
+            WHERE task_id = $1
+            ORDER BY created_at ASC
+            "#,
+            task_id,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn remove(&self, task_id: &Uuid, user_id: &Uuid) -> DomainResult<()> {
+        sqlx::query!(
+            r#"
+            DELETE FROM task_collaborators WHERE task_id = $1 AND user_id = $2
+            "#,
+            task_id,
+            user_id,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+/// Row struct para mapeamento do banco
+#[derive(Debug)]
+struct TaskCollaboratorRow {
+    task_id: Uuid,
+    user_id: Uuid,
+    role: CollaboratorRole,
+    invited_by: Option<Uuid>,
+    created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<TaskCollaboratorRow> for TaskCollaborator {
+    fn from(row: TaskCollaboratorRow) -> Self {
+        TaskCollaborator {
+            task_id: row.task_id,
+            user_id: row.user_id,
+            role: row.role,
+            invited_by: row.invited_by,
+            created_at: row.created_at,
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use crate::domain::entities::Comment;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::CommentRepository;
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Implementação PostgreSQL do CommentRepository
+pub struct PostgresCommentRepository {
+    pool: PgPool,
+}
+
+impl PostgresCommentRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CommentRepository for PostgresCommentRepository {
+    async fn create(&self, comment: &Comment) -> DomainResult<Comment> {
+        let row = sqlx::query_as!(
+            CommentRow,
+            r#"
+            INSERT INTO comments (id, task_id, user_id, body, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING id, task_id, user_id, body, created_at, updated_at
+            "#,
+            comment.id,
+            comment.task_id,
+            comment.user_id,
+            comment.body,
+            comment.created_at,
+            comment.updated_at,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(row.into())
+    }
+
+    async fn find_by_id(&self, id: &Uuid) -> DomainResult<Option<Comment>> {
+        let row = sqlx::query_as!(
+            CommentRow,
+            r#"
+            SELECT id, task_id, user_id, body, created_at, updated_at
+            FROM comments
+            WHERE id = $1
+            "#,
+            id,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn list_by_task(&self, task_id: &Uuid) -> DomainResult<Vec<Comment>> {
+        let rows = sqlx::query_as!(
+            CommentRow,
+            r#"
+            SELECT id, task_id, user_id, body, created_at, updated_at
+            FROM comments
+            WHERE task_id = $1
+            ORDER BY created_at ASC
+            "#,
+            task_id,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn update(&self, comment: &Comment) -> DomainResult<Comment> {
+        let row = sqlx::query_as!(
+            CommentRow,
+            r#"
+            UPDATE comments
+            SET body = $2, updated_at = $3
+            WHERE id = $1
+            RETURNING id, task_id, user_id, body, created_at, updated_at
+            "#,
+            comment.id,
+            comment.body,
+            comment.updated_at,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        row.map(Into::into).ok_or(DomainError::NotFound(
+            "Comentário não encontrado".to_string(),
+        ))
+    }
+
+    async fn delete(&self, id: &Uuid) -> DomainResult<()> {
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM comments WHERE id = $1
+            "#,
+            id,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::NotFound(
+                "Comentário não encontrado".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Row struct para mapeamento do banco
+#[derive(Debug)]
+struct CommentRow {
+    id: Uuid,
+    task_id: Uuid,
+    user_id: Uuid,
+    body: String,
+    created_at: chrono::DateTime<chrono::Utc>,
+    updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<CommentRow> for Comment {
+    fn from(row: CommentRow) -> Self {
+        Comment {
+            id: row.id,
+            task_id: row.task_id,
+            user_id: row.user_id,
+            body: row.body,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
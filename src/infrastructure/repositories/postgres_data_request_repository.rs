@@ -0,0 +1,228 @@
+use crate::domain::entities::DataRequest;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::DataRequestRepository;
+use crate::domain::value_objects::{DataRequestKind, DataRequestStatus};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Implementação PostgreSQL do DataRequestRepository
+pub struct PostgresDataRequestRepository {
+    pool: PgPool,
+}
+
+impl PostgresDataRequestRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl DataRequestRepository for PostgresDataRequestRepository {
+    async fn create(&self, request: &DataRequest) -> DomainResult<DataRequest> {
+        let row = sqlx::query_as!(
+            DataRequestRow,
+            r#"
+            INSERT INTO data_requests (id, user_id, kind, status, attempts, requested_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING
+                id, user_id,
+                kind as "kind: DataRequestKind",
+                status as "status: DataRequestStatus",
+                storage_key, error, attempts, requested_at, started_at, completed_at, expires_at
+            "#,
+            request.id,
+            request.user_id,
+            request.kind as DataRequestKind,
+            request.status as DataRequestStatus,
+            request.attempts,
+            request.requested_at,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(row.into())
+    }
+
+    async fn find_by_id(&self, id: &Uuid) -> DomainResult<Option<DataRequest>> {
+        let row = sqlx::query_as!(
+            DataRequestRow,
+            r#"
+            SELECT
+                id, user_id,
+                kind as "kind: DataRequestKind",
+                status as "status: DataRequestStatus",
+                storage_key, error, attempts, requested_at, started_at, completed_at, expires_at
+            FROM data_requests
+            WHERE id = $1
+            "#,
+            id,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn find_latest(
+        &self,
+        user_id: &Uuid,
+        kind: DataRequestKind,
+    ) -> DomainResult<Option<DataRequest>> {
+        let row = sqlx::query_as!(
+            DataRequestRow,
+            r#"
+            SELECT
+                id, user_id,
+                kind as "kind: DataRequestKind",
+                status as "status: DataRequestStatus",
+                storage_key, error, attempts, requested_at, started_at, completed_at, expires_at
+            FROM data_requests
+            WHERE user_id = $1 AND kind = $2
+            ORDER BY requested_at DESC
+            LIMIT 1
+            "#,
+            user_id,
+            kind as DataRequestKind,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn list_by_user(&self, user_id: &Uuid) -> DomainResult<Vec<DataRequest>> {
+        let rows = sqlx::query_as!(
+            DataRequestRow,
+            r#"
+            SELECT
+                id, user_id,
+                kind as "kind: DataRequestKind",
+                status as "status: DataRequestStatus",
+                storage_key, error, attempts, requested_at, started_at, completed_at, expires_at
+            FROM data_requests
+            WHERE user_id = $1
+            ORDER BY requested_at DESC
+            "#,
+            user_id,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn claim_next(&self, stale_before: DateTime<Utc>) -> DomainResult<Option<DataRequest>> {
+        let row = sqlx::query_as!(
+            DataRequestRow,
+            r#"
+            UPDATE data_requests
+            SET status = 'processing', attempts = attempts + 1, started_at = NOW()
+            WHERE id IN (
+                SELECT id FROM data_requests
+                WHERE status = 'pending'
+                   OR (status = 'processing' AND started_at < $1)
+                ORDER BY requested_at
+                LIMIT 1
+                FOR UPDATE SKIP LOCKED
+            )
+            RETURNING
+                id, user_id,
+                kind as "kind: DataRequestKind",
+                status as "status: DataRequestStatus",
+                storage_key, error, attempts, requested_at, started_at, completed_at, expires_at
+            "#,
+            stale_before,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn list_expired_exports(&self, now: DateTime<Utc>) -> DomainResult<Vec<DataRequest>> {
+        let rows = sqlx::query_as!(
+            DataRequestRow,
+            r#"
+            SELECT
+                id, user_id,
+                kind as "kind: DataRequestKind",
+                status as "status: DataRequestStatus",
+                storage_key, error, attempts, requested_at, started_at, completed_at, expires_at
+            FROM data_requests
+            WHERE kind = 'export' AND status = 'completed' AND expires_at <= $1
+            ORDER BY expires_at
+            "#,
+            now,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn update(&self, request: &DataRequest) -> DomainResult<()> {
+        sqlx::query!(
+            r#"
+            UPDATE data_requests
+            SET status = $2, storage_key = $3, error = $4, attempts = $5,
+                started_at = $6, completed_at = $7, expires_at = $8
+            WHERE id = $1
+            "#,
+            request.id,
+            request.status as DataRequestStatus,
+            request.storage_key,
+            request.error,
+            request.attempts,
+            request.started_at,
+            request.completed_at,
+            request.expires_at,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+/// Row struct para mapeamento do banco
+#[derive(Debug)]
+struct DataRequestRow {
+    id: Uuid,
+    user_id: Uuid,
+    kind: DataRequestKind,
+    status: DataRequestStatus,
+    storage_key: Option<String>,
+    error: Option<String>,
+    attempts: i32,
+    requested_at: DateTime<Utc>,
+    started_at: Option<DateTime<Utc>>,
+    completed_at: Option<DateTime<Utc>>,
+    expires_at: Option<DateTime<Utc>>,
+}
+
+impl From<DataRequestRow> for DataRequest {
+    fn from(row: DataRequestRow) -> Self {
+        Self {
+            id: row.id,
+            user_id: row.user_id,
+            kind: row.kind,
+            status: row.status,
+            storage_key: row.storage_key,
+            error: row.error,
+            attempts: row.attempts,
+            requested_at: row.requested_at,
+            started_at: row.started_at,
+            completed_at: row.completed_at,
+            expires_at: row.expires_at,
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use crate::domain::entities::TaskDependency;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::DependencyRepository;
+use crate::domain::services::DependencyGraph;
+use crate::infrastructure::repositories::row_level_security::begin_cross_tenant;
+use async_trait::async_trait;
+use sqlx::{PgExecutor, PgPool};
+use uuid::Uuid;
+
+/// Implementação PostgreSQL do DependencyRepository
+pub struct PostgresDependencyRepository {
+    pool: PgPool,
+}
+
+impl PostgresDependencyRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl DependencyRepository for PostgresDependencyRepository {
+    async fn add(&self, dependency: &TaskDependency) -> DomainResult<()> {
+        let mut tx = begin_cross_tenant(&self.pool).await?;
+
+        // Serializar as alterações do grafo do workspace: duas dependências inversas
+        // adicionadas ao mesmo tempo não podem passar ambas pela verificação de ciclo
+        sqlx::query!(
+            r#"
+            SELECT pg_advisory_xact_lock(hashtextextended(workspace_id::text, 0))
+            FROM tasks
+            WHERE id = $1
+            "#,
+            dependency.task_id,
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        let upstream = fetch_upstream(&mut *tx, &dependency.blocked_by_id).await?;
+        DependencyGraph::new(&upstream).ensure_acyclic(dependency)?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO task_dependencies (task_id, blocked_by_id, created_at)
+            VALUES ($1, $2, $3)
+            "#,
+            dependency.task_id,
+            dependency.blocked_by_id,
+            dependency.created_at,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            if let sqlx::Error::Database(db_err) = &e
+                && db_err.constraint() == Some("task_dependencies_pkey")
+            {
+                return DomainError::Conflict("Dependência já existe".to_string());
+            }
+            DomainError::Internal(e.to_string())
+        })?;
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn remove(&self, task_id: &Uuid, blocked_by_id: &Uuid) -> DomainResult<()> {
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM task_dependencies WHERE task_id = $1 AND blocked_by_id = $2
+            "#,
+            task_id,
+            blocked_by_id,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::NotFound(
+                "Dependência não encontrada".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    async fn list_blocker_ids(&self, task_id: &Uuid) -> DomainResult<Vec<Uuid>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT blocked_by_id FROM task_dependencies
+            WHERE task_id = $1
+            ORDER BY created_at ASC
+            "#,
+            task_id,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|row| row.blocked_by_id).collect())
+    }
+
+    async fn list_dependent_ids(&self, task_id: &Uuid) -> DomainResult<Vec<Uuid>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT task_id FROM task_dependencies
+            WHERE blocked_by_id = $1
+            ORDER BY created_at ASC
+            "#,
+            task_id,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|row| row.task_id).collect())
+    }
+
+    async fn list_upstream(&self, task_id: &Uuid) -> DomainResult<Vec<TaskDependency>> {
+        fetch_upstream(&self.pool, task_id).await
+    }
+
+    async fn count_unfinished_blockers(&self, task_id: &Uuid) -> DomainResult<i64> {
+        let mut tx = begin_cross_tenant(&self.pool).await?;
+
+        let result = sqlx::query!(
+            r#"
+            SELECT COUNT(*) as "count!"
+            FROM task_dependencies d
+            INNER JOIN tasks b ON b.id = d.blocked_by_id
+            WHERE d.task_id = $1 AND b.status NOT IN ('completed', 'cancelled')
+            "#,
+            task_id,
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(result.count)
+    }
+
+    async fn list_unfinished_for_user(&self, user_id: &Uuid) -> DomainResult<Vec<TaskDependency>> {
+        let mut tx = begin_cross_tenant(&self.pool).await?;
+
+        let rows = sqlx::query_as!(
+            TaskDependencyRow,
+            r#"
+            SELECT d.task_id, d.blocked_by_id, d.created_at
+            FROM task_dependencies d
+            INNER JOIN tasks t ON t.id = d.task_id
+            INNER JOIN tasks b ON b.id = d.blocked_by_id
+            WHERE t.user_id = $1 AND b.status NOT IN ('completed', 'cancelled')
+            "#,
+            user_id,
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+}
+
+/// Dependências alcançáveis a partir da tarefa (bloqueadoras, recursivamente)
+async fn fetch_upstream(
+    executor: impl PgExecutor<'_>,
+    task_id: &Uuid,
+) -> DomainResult<Vec<TaskDependency>> {
+    let rows = sqlx::query_as!(
+        TaskDependencyRow,
+        r#"
+            WITH RECURSIVE upstream AS (
+                SELECT task_id, blocked_by_id, created_at
+                FROM task_dependencies
+                WHERE task_id = $1
+                UNION
+                SELECT d.task_id, d.blocked_by_id, d.created_at
+                FROM task_dependencies d
+                INNER JOIN upstream u ON d.task_id = u.blocked_by_id
+            )
+            SELECT
+                task_id as "task_id!",
+                blocked_by_id as "blocked_by_id!",
+                created_at as "created_at!"
+            FROM upstream
+            "#,
+        task_id,
+    )
+    .fetch_all(executor)
+    .await
+    .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+    Ok(rows.into_iter().map(Into::into).collect())
+}
+
+/// Row struct para mapeamento do banco
+#[derive(Debug)]
+struct TaskDependencyRow {
+    task_id: Uuid,
+    blocked_by_id: Uuid,
+    created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<TaskDependencyRow> for TaskDependency {
+    fn from(row: TaskDependencyRow) -> Self {
+        TaskDependency {
+            task_id: row.task_id,
+            blocked_by_id: row.blocked_by_id,
+            created_at: row.created_at,
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use crate::domain::entities::EmailVerificationToken;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::EmailVerificationRepository;
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Implementação PostgreSQL do EmailVerificationRepository
+pub struct PostgresEmailVerificationRepository {
+    pool: PgPool,
+}
+
+impl PostgresEmailVerificationRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl EmailVerificationRepository for PostgresEmailVerificationRepository {
+    async fn create(&self, token: &EmailVerificationToken) -> DomainResult<EmailVerificationToken> {
+        let row = sqlx::query_as!(
+            EmailVerificationTokenRow,
+            r#"
+            INSERT INTO email_verification_tokens (id, user_id, token_hash, new_email, expires_at, used_at, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING id, user_id, token_hash, new_email, expires_at, used_at, created_at
+            "#,
+            token.id,
+            token.user_id,
+            token.token_hash,
+            token.new_email,
+            token.expires_at,
+            token.used_at,
+            token.created_at,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(row.into())
+    }
+
+    async fn find_by_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> DomainResult<Option<EmailVerificationToken>> {
+        let row = sqlx::query_as!(
+            EmailVerificationTokenRow,
+            r#"
+            SELECT id, user_id, token_hash, new_email, expires_at, used_at, created_at
+            FROM email_verification_tokens
+            WHERE token_hash = $1
+            "#,
+            token_hash,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn consume(&self, id: &Uuid) -> DomainResult<bool> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE email_verification_tokens
+            SET used_at = NOW()
+            WHERE id = $1 AND used_at IS NULL AND expires_at > NOW()
+            "#,
+            id,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn invalidate_for_user(&self, user_id: &Uuid) -> DomainResult<()> {
+        sqlx::query!(
+            r#"
+            UPDATE email_verification_tokens
+            SET used_at = NOW()
+            WHERE user_id = $1 AND used_at IS NULL
+            "#,
+            user_id,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+/// Row struct para mapeamento do banco
+#[derive(Debug)]
+struct EmailVerificationTokenRow {
+    id: Uuid,
+    user_id: Uuid,
+    token_hash: String,
+    new_email: Option<String>,
+    expires_at: chrono::DateTime<chrono::Utc>,
+    used_at: Option<chrono::DateTime<chrono::Utc>>,
+    created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<EmailVerificationTokenRow> for EmailVerificationToken {
+    fn from(row: EmailVerificationTokenRow) -> Self {
+        EmailVerificationToken {
+            id: row.id,
+            user_id: row.user_id,
+            token_hash: row.token_hash,
+            new_email: row.new_email,
+            expires_at: row.expires_at,
+            used_at: row.used_at,
+            created_at: row.created_at,
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use crate::domain::entities::{LoginAttempt, LoginFailures};
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::LoginAttemptRepository;
+use crate::domain::value_objects::LoginOutcome;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use std::net::IpAddr;
+
+/// Implementação PostgreSQL do LoginAttemptRepository
+pub struct PostgresLoginAttemptRepository {
+    pool: PgPool,
+}
+
+impl PostgresLoginAttemptRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl LoginAttemptRepository for PostgresLoginAttemptRepository {
+    async fn record(&self, attempt: &LoginAttempt) -> DomainResult<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO login_attempts (id, user_id, email, ip_address, user_agent, outcome, created_at)
+            VALUES ($1, $2, $3, $4::text::inet, $5, $6, $7)
+            "#,
+            attempt.id,
+            attempt.user_id,
+            attempt.email,
+            attempt.ip_address.map(|ip| ip.to_string()),
+            attempt.user_agent,
+            attempt.outcome as LoginOutcome,
+            attempt.created_at,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn failures_by_email(
+        &self,
+        email: &str,
+        since: DateTime<Utc>,
+    ) -> DomainResult<LoginFailures> {
+        let row = sqlx::query_as!(
+            LoginFailuresRow,
+            r#"
+            SELECT COUNT(*) as "count!", MAX(created_at) as last_failed_at
+            FROM login_attempts
+            WHERE email = $1
+              AND created_at >= $2
+              AND outcome IN ('invalid_credentials', 'invalid_mfa_code')
+              AND created_at > COALESCE(
+                  (SELECT MAX(created_at) FROM login_attempts WHERE email = $1 AND outcome = 'success'),
+                  '-infinity'
+              )
+            "#,
+            email,
+            since,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(row.into())
+    }
+
+    async fn failures_by_ip(
+        &self,
+        ip_address: &IpAddr,
+        since: DateTime<Utc>,
+    ) -> DomainResult<LoginFailures> {
+        let row = sqlx::query_as!(
+            LoginFailuresRow,
+            r#"
+            SELECT COUNT(*) as "count!", MAX(created_at) as last_failed_at
+            FROM login_attempts
+            WHERE ip_address = $1::text::inet
+              AND created_at >= $2
+              AND outcome IN ('invalid_credentials', 'invalid_mfa_code')
+            "#,
+            ip_address.to_string(),
+            since,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(row.into())
+    }
+}
+
+/// Row struct para mapeamento do banco
+#[derive(Debug)]
+struct LoginFailuresRow {
+    count: i64,
+    last_failed_at: Option<DateTime<Utc>>,
+}
+
+impl From<LoginFailuresRow> for LoginFailures {
+    fn from(row: LoginFailuresRow) -> Self {
+        LoginFailures {
+            count: row.count.try_into().unwrap_or(u32::MAX),
+            last_failed_at: row.last_failed_at,
+        }
+    }
+}
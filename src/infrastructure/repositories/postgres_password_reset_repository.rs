@@ -0,0 +1,118 @@
+use crate::domain::entities::PasswordResetToken;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::PasswordResetRepository;
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Implementação PostgreSQL do PasswordResetRepository
+pub struct PostgresPasswordResetRepository {
+    pool: PgPool,
+}
+
+impl PostgresPasswordResetRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PasswordResetRepository for PostgresPasswordResetRepository {
+    async fn create(&self, token: &PasswordResetToken) -> DomainResult<PasswordResetToken> {
+        let row = sqlx::query_as!(
+            PasswordResetTokenRow,
+            r#"
+            INSERT INTO password_reset_tokens (id, user_id, token_hash, expires_at, used_at, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING id, user_id, token_hash, expires_at, used_at, created_at
+            "#,
+            token.id,
+            token.user_id,
+            token.token_hash,
+            token.expires_at,
+            token.used_at,
+            token.created_at,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(row.into())
+    }
+
+    async fn find_by_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> DomainResult<Option<PasswordResetToken>> {
+        let row = sqlx::query_as!(
+            PasswordResetTokenRow,
+            r#"
+            SELECT id, user_id, token_hash, expires_at, used_at, created_at
+            FROM password_reset_tokens
+            WHERE token_hash = $1
+            "#,
+            token_hash,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn consume(&self, id: &Uuid) -> DomainResult<bool> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE password_reset_tokens
+            SET used_at = NOW()
+            WHERE id = $1 AND used_at IS NULL AND expires_at > NOW()
+            "#,
+            id,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn invalidate_for_user(&self, user_id: &Uuid) -> DomainResult<()> {
+        sqlx::query!(
+            r#"
+            UPDATE password_reset_tokens
+            SET used_at = NOW()
+            WHERE user_id = $1 AND used_at IS NULL
+            "#,
+            user_id,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+/// Row struct para mapeamento do banco
+#[derive(Debug)]
+struct PasswordResetTokenRow {
+    id: Uuid,
+    user_id: Uuid,
+    token_hash: String,
+    expires_at: chrono::DateTime<chrono::Utc>,
+    used_at: Option<chrono::DateTime<chrono::Utc>>,
+    created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<PasswordResetTokenRow> for PasswordResetToken {
+    fn from(row: PasswordResetTokenRow) -> Self {
+        PasswordResetToken {
+            id: row.id,
+            user_id: row.user_id,
+            token_hash: row.token_hash,
+            expires_at: row.expires_at,
+            used_at: row.used_at,
+            created_at: row.created_at,
+        }
+    }
+}
@@ -0,0 +1,470 @@
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{PersonalData, PersonalDataRepository};
+use crate::infrastructure::repositories::row_level_security::begin_cross_tenant;
+use crate::infrastructure::security::{generate_opaque_token, hash_password};
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Nome exibido para contas eliminadas
+const ERASED_USER_NAME: &str = "Usuário removido";
+
+/// Título que substitui o das tarefas de contas eliminadas
+const ERASED_TASK_TITLE: &str = "Tarefa removida";
+
+/// Implementação PostgreSQL do PersonalDataRepository
+pub struct PostgresPersonalDataRepository {
+    pool: PgPool,
+}
+
+impl PostgresPersonalDataRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Converte o JSON agregado pelo banco
+fn parse_section(json: String) -> DomainResult<serde_json::Value> {
+    serde_json::from_str(&json).map_err(|e| DomainError::Internal(e.to_string()))
+}
+
+#[async_trait]
+impl PersonalDataRepository for PostgresPersonalDataRepository {
+    async fn export(&self, user_id: &Uuid) -> DomainResult<PersonalData> {
+        // As tarefas do usuário podem estar em qualquer workspace
+        let mut tx = begin_cross_tenant(&self.pool).await?;
+
+        let profile = sqlx::query_scalar!(
+            r#"
+            SELECT row_to_json(u)::text as "json!"
+            FROM (
+                SELECT id, email, name, email_verified_at, created_at, updated_at
+                FROM users
+                WHERE id = $1
+            ) u
+            "#,
+            user_id,
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?
+        .ok_or(DomainError::NotFound("Usuário não encontrado".to_string()))?;
+
+        let tasks = sqlx::query_scalar!(
+            r#"
+            SELECT COALESCE(json_agg(t ORDER BY t.created_at), '[]')::text as "json!"
+            FROM (
+                SELECT
+                    tasks.id, tasks.workspace_id, tasks.project_id, tasks.parent_id, tasks.title,
+                    tasks.description,
+                    tasks.status, tasks.priority, tasks.due_date, tasks.completed_at,
+                    tasks.recurrence_rule, tasks.series_id, tasks.occurrence,
+                    tasks.created_at, tasks.updated_at,
+                    COALESCE(
+                        ARRAY(
+                            SELECT tags.name FROM task_tags
+                            JOIN tags ON tags.id = task_tags.tag_id
+                            WHERE task_tags.task_id = tasks.id
+                            ORDER BY tags.name
+                        ),
+                        '{}'
+                    ) as tags
+                FROM tasks
+                WHERE tasks.user_id = $1
+            ) t
+            "#,
+            user_id,
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        let task_history = sqlx::query_scalar!(
+            r#"
+            SELECT COALESCE(json_agg(h ORDER BY h.changed_at, h.id), '[]')::text as "json!"
+            FROM (
+                SELECT id, task_id, user_id, field_name, old_value, new_value, changed_at
+                FROM task_history
+                WHERE user_id = $1
+                   OR task_id IN (SELECT id FROM tasks WHERE user_id = $1)
+            ) h
+            "#,
+            user_id,
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        let comments = sqlx::query_scalar!(
+            r#"
+            SELECT COALESCE(json_agg(c ORDER BY c.created_at), '[]')::text as "json!"
+            FROM (
+                SELECT id, task_id, body, created_at, updated_at
+                FROM comments
+                WHERE user_id = $1
+            ) c
+            "#,
+            user_id,
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        let attachments = sqlx::query_scalar!(
+            r#"
+            SELECT COALESCE(json_agg(a ORDER BY a.created_at), '[]')::text as "json!"
+            FROM (
+                SELECT id, task_id, file_name, content_type, size_bytes, sha256, created_at
+                FROM attachments
+                WHERE user_id = $1
+            ) a
+            "#,
+            user_id,
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        let tags = sqlx::query_scalar!(
+            r#"
+            SELECT COALESCE(json_agg(t ORDER BY t.name), '[]')::text as "json!"
+            FROM (
+                SELECT id, name, created_at
+                FROM tags
+                WHERE user_id = $1
+            ) t
+            "#,
+            user_id,
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        let collaborations = sqlx::query_scalar!(
+            r#"
+            SELECT COALESCE(json_agg(c ORDER BY c.created_at), '[]')::text as "json!"
+            FROM (
+                SELECT task_id, role, invited_by, created_at
+                FROM task_collaborators
+                WHERE user_id = $1
+            ) c
+            "#,
+            user_id,
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        let workspaces = sqlx::query_scalar!(
+            r#"
+            SELECT COALESCE(json_agg(w ORDER BY w.created_at), '[]')::text as "json!"
+            FROM (
+                SELECT workspaces.id, workspaces.name, workspace_members.role,
+                       workspace_members.invited_by, workspace_members.created_at
+                FROM workspace_members
+                JOIN workspaces ON workspaces.id = workspace_members.workspace_id
+                WHERE workspace_members.user_id = $1
+            ) w
+            "#,
+            user_id,
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        let webhooks = sqlx::query_scalar!(
+            r#"
+            SELECT COALESCE(json_agg(w ORDER BY w.created_at), '[]')::text as "json!"
+            FROM (
+                SELECT id, url, event_types, active, created_at, updated_at
+                FROM webhooks
+                WHERE user_id = $1
+            ) w
+            "#,
+            user_id,
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        let sessions = sqlx::query_scalar!(
+            r#"
+            SELECT COALESCE(json_agg(s ORDER BY s.started_at), '[]')::text as "json!"
+            FROM (
+                SELECT id, user_agent, ip_address, started_at, last_used_at, expires_at, revoked_at
+                FROM sessions
+                WHERE user_id = $1
+            ) s
+            "#,
+            user_id,
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        let login_attempts = sqlx::query_scalar!(
+            r#"
+            SELECT COALESCE(json_agg(l ORDER BY l.created_at), '[]')::text as "json!"
+            FROM (
+                SELECT email, ip_address, user_agent, outcome, created_at
+                FROM login_attempts
+                WHERE user_id = $1
+            ) l
+            "#,
+            user_id,
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(PersonalData {
+            profile: parse_section(profile)?,
+            tasks: parse_section(tasks)?,
+            task_history: parse_section(task_history)?,
+            comments: parse_section(comments)?,
+            attachments: parse_section(attachments)?,
+            tags: parse_section(tags)?,
+            collaborations: parse_section(collaborations)?,
+            workspaces: parse_section(workspaces)?,
+            webhooks: parse_section(webhooks)?,
+            sessions: parse_section(sessions)?,
+            login_attempts: parse_section(login_attempts)?,
+        })
+    }
+
+    async fn erase(&self, user_id: &Uuid) -> DomainResult<Vec<String>> {
+        // Senha aleatória descartada: a conta não pode mais ser acessada
+        let password_hash = hash_password(&generate_opaque_token())
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        let mut tx = begin_cross_tenant(&self.pool).await?;
+
+        let email = sqlx::query_scalar!(
+            r#"
+            SELECT email FROM users WHERE id = $1 FOR UPDATE
+            "#,
+            user_id,
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?
+        .ok_or(DomainError::NotFound("Usuário não encontrado".to_string()))?;
+
+        // Workspaces compartilhados não podem ficar sem owner
+        let orphaned = sqlx::query_scalar!(
+            r#"
+            SELECT w.name
+            FROM workspace_members m
+            JOIN workspaces w ON w.id = m.workspace_id
+            WHERE m.user_id = $1
+              AND m.role = 'owner'
+              AND EXISTS (
+                  SELECT 1 FROM workspace_members o
+                  WHERE o.workspace_id = m.workspace_id AND o.user_id <> $1
+              )
+              AND NOT EXISTS (
+                  SELECT 1 FROM workspace_members o
+                  WHERE o.workspace_id = m.workspace_id AND o.user_id <> $1 AND o.role = 'owner'
+              )
+            ORDER BY w.name
+            FOR UPDATE OF m
+            "#,
+            user_id,
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        if !orphaned.is_empty() {
+            return Err(DomainError::Conflict(format!(
+                "Transfira a propriedade dos workspaces antes de excluir a conta: {}",
+                orphaned.join(", ")
+            )));
+        }
+
+        // Anexos enviados pelo usuário ou presos às suas tarefas
+        let storage_keys = sqlx::query_scalar!(
+            r#"
+            DELETE FROM attachments
+            WHERE user_id = $1
+               OR task_id IN (SELECT id FROM tasks WHERE user_id = $1)
+            RETURNING storage_key
+            "#,
+            user_id,
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM comments WHERE user_id = $1
+            "#,
+            user_id,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM tags WHERE user_id = $1
+            "#,
+            user_id,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        // Tarefas permanecem (status, prioridade e datas) sem conteúdo identificável
+        sqlx::query!(
+            r#"
+            UPDATE tasks
+            SET title = $2, description = NULL
+            WHERE user_id = $1
+            "#,
+            user_id,
+            ERASED_TASK_TITLE,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        // Histórico mantém apenas as mudanças de status e prioridade
+        sqlx::query!(
+            r#"
+            UPDATE task_history
+            SET old_value = NULL, new_value = NULL
+            WHERE (user_id = $1 OR task_id IN (SELECT id FROM tasks WHERE user_id = $1))
+              AND field_name NOT IN ('status', 'priority')
+            "#,
+            user_id,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM task_collaborators WHERE user_id = $1
+            "#,
+            user_id,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM workspace_members WHERE user_id = $1
+            "#,
+            user_id,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM webhooks WHERE user_id = $1
+            "#,
+            user_id,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM sessions WHERE user_id = $1
+            "#,
+            user_id,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM password_reset_tokens WHERE user_id = $1
+            "#,
+            user_id,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM email_verification_tokens WHERE user_id = $1
+            "#,
+            user_id,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM recovery_codes WHERE user_id = $1
+            "#,
+            user_id,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM user_totp WHERE user_id = $1
+            "#,
+            user_id,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        // Tentativas de login ficam apenas como contagem
+        sqlx::query!(
+            r#"
+            UPDATE login_attempts
+            SET user_id = NULL, email = '', ip_address = NULL, user_agent = NULL
+            WHERE user_id = $1 OR email = $2
+            "#,
+            user_id,
+            email,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        sqlx::query!(
+            r#"
+            UPDATE users
+            SET email = 'erased-' || id::text || '@anonymized.invalid',
+                name = $2,
+                password_hash = $3,
+                email_verified_at = NULL,
+                erased_at = NOW(),
+                updated_at = NOW()
+            WHERE id = $1
+            "#,
+            user_id,
+            ERASED_USER_NAME,
+            password_hash,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(storage_keys)
+    }
+}
@@ -0,0 +1,221 @@
+use crate::domain::entities::Project;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::ProjectRepository;
+use crate::infrastructure::repositories::row_level_security::begin_in_workspace;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Implementação PostgreSQL do ProjectRepository
+pub struct PostgresProjectRepository {
+    pool: PgPool,
+}
+
+impl PostgresProjectRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ProjectRepository for PostgresProjectRepository {
+    async fn create(&self, project: &Project) -> DomainResult<Project> {
+        let row = sqlx::query_as!(
+            ProjectRow,
+            r#"
+            INSERT INTO projects (id, workspace_id, name, color, position, workflow_id, archived_at, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING id, workspace_id, name, color, position, workflow_id, archived_at, created_at, updated_at
+            "#,
+            project.id,
+            project.workspace_id,
+            project.name,
+            project.color,
+            project.position,
+            project.workflow_id,
+            project.archived_at,
+            project.created_at,
+            project.updated_at,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(row.into())
+    }
+
+    async fn find_by_id(&self, id: &Uuid) -> DomainResult<Option<Project>> {
+        let row = sqlx::query_as!(
+            ProjectRow,
+            r#"
+            SELECT id, workspace_id, name, color, position, workflow_id, archived_at, created_at, updated_at
+            FROM projects
+            WHERE id = $1
+            "#,
+            id,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn list_by_workspace(
+        &self,
+        workspace_id: &Uuid,
+        include_archived: bool,
+    ) -> DomainResult<Vec<Project>> {
+        let rows = sqlx::query_as!(
+            ProjectRow,
+            r#"
+            SELECT id, workspace_id, name, color, position, workflow_id, archived_at, created_at, updated_at
+            FROM projects
+            WHERE workspace_id = $1 AND ($2 OR archived_at IS NULL)
+            ORDER BY position ASC, created_at ASC
+            "#,
+            workspace_id,
+            include_archived,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn next_position(&self, workspace_id: &Uuid) -> DomainResult<i32> {
+        let position = sqlx::query_scalar!(
+            r#"
+            SELECT COALESCE(MAX(position) + 1, 0) as "position!"
+            FROM projects
+            WHERE workspace_id = $1
+            "#,
+            workspace_id,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(position)
+    }
+
+    async fn update(&self, project: &Project) -> DomainResult<Project> {
+        let row = sqlx::query_as!(
+            ProjectRow,
+            r#"
+            UPDATE projects
+            SET name = $2, color = $3, position = $4, archived_at = $5, updated_at = $6
+            WHERE id = $1
+            RETURNING id, workspace_id, name, color, position, workflow_id, archived_at, created_at, updated_at
+            "#,
+            project.id,
+            project.name,
+            project.color,
+            project.position,
+            project.archived_at,
+            project.updated_at,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?
+        .ok_or(DomainError::NotFound("Projeto não encontrado".to_string()))?;
+
+        Ok(row.into())
+    }
+
+    async fn assign_workflow(&self, project: &Project) -> DomainResult<Project> {
+        let mut tx = begin_in_workspace(&self.pool, project.workspace_id).await?;
+
+        let row = sqlx::query_as!(
+            ProjectRow,
+            r#"
+            UPDATE projects
+            SET workflow_id = $2, updated_at = $3
+            WHERE id = $1
+            RETURNING id, workspace_id, name, color, position, workflow_id, archived_at, created_at, updated_at
+            "#,
+            project.id,
+            project.workflow_id,
+            project.updated_at,
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?
+        .ok_or(DomainError::NotFound("Projeto não encontrado".to_string()))?;
+
+        sqlx::query!(
+            r#"
+            UPDATE tasks t
+            SET state_id = (
+                SELECT ws.id
+                FROM workflow_states ws
+                WHERE ws.workflow_id = $2 AND ws.category = t.status
+                ORDER BY ws.position ASC
+                LIMIT 1
+            )
+            WHERE t.project_id = $1
+            "#,
+            project.id,
+            project.workflow_id,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(row.into())
+    }
+
+    async fn delete(&self, id: &Uuid) -> DomainResult<()> {
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM projects WHERE id = $1
+            "#,
+            id,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::NotFound("Projeto não encontrado".to_string()));
+        }
+
+        Ok(())
+    }
+}
+
+/// Row struct para mapeamento do banco
+#[derive(Debug)]
+struct ProjectRow {
+    id: Uuid,
+    workspace_id: Uuid,
+    name: String,
+    color: Option<String>,
+    position: i32,
+    workflow_id: Option<Uuid>,
+    archived_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<ProjectRow> for Project {
+    fn from(row: ProjectRow) -> Self {
+        Self {
+            id: row.id,
+            workspace_id: row.workspace_id,
+            name: row.name,
+            color: row.color,
+            position: row.position,
+            workflow_id: row.workflow_id,
+            archived_at: row.archived_at,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
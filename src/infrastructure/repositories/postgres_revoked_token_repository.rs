@@ -0,0 +1,70 @@
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::RevokedTokenRepository;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Implementação PostgreSQL do RevokedTokenRepository
+pub struct PostgresRevokedTokenRepository {
+    pool: PgPool,
+}
+
+impl PostgresRevokedTokenRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl RevokedTokenRepository for PostgresRevokedTokenRepository {
+    async fn revoke(
+        &self,
+        jti: &Uuid,
+        user_id: &Uuid,
+        expires_at: DateTime<Utc>,
+    ) -> DomainResult<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO revoked_tokens (jti, user_id, expires_at)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (jti) DO NOTHING
+            "#,
+            jti,
+            user_id,
+            expires_at,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn is_revoked(&self, jti: &Uuid) -> DomainResult<bool> {
+        let result = sqlx::query!(
+            r#"
+            SELECT EXISTS(SELECT 1 FROM revoked_tokens WHERE jti = $1) as "exists!"
+            "#,
+            jti,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(result.exists)
+    }
+
+    async fn purge_expired(&self) -> DomainResult<u64> {
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM revoked_tokens WHERE expires_at <= NOW()
+            "#,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(result.rows_affected())
+    }
+}
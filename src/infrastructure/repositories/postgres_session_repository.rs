@@ -0,0 +1,179 @@
+use crate::domain::entities::Session;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::SessionRepository;
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Implementação PostgreSQL do SessionRepository
+pub struct PostgresSessionRepository {
+    pool: PgPool,
+}
+
+impl PostgresSessionRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SessionRepository for PostgresSessionRepository {
+    async fn create(&self, session: &Session) -> DomainResult<Session> {
+        let row = sqlx::query_as!(
+            SessionRow,
+            r#"
+            INSERT INTO sessions (
+                id, family_id, user_id, workspace_id, refresh_token_hash, user_agent, ip_address,
+                started_at, last_used_at, expires_at, rotated_at, revoked_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            RETURNING
+                id, family_id, user_id, workspace_id, refresh_token_hash, user_agent, ip_address,
+                started_at, last_used_at, expires_at, rotated_at, revoked_at
+            "#,
+            session.id,
+            session.family_id,
+            session.user_id,
+            session.workspace_id,
+            session.refresh_token_hash,
+            session.user_agent,
+            session.ip_address,
+            session.started_at,
+            session.last_used_at,
+            session.expires_at,
+            session.rotated_at,
+            session.revoked_at,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(row.into())
+    }
+
+    async fn find_by_token_hash(&self, token_hash: &str) -> DomainResult<Option<Session>> {
+        let row = sqlx::query_as!(
+            SessionRow,
+            r#"
+            SELECT
+                id, family_id, user_id, workspace_id, refresh_token_hash, user_agent, ip_address,
+                started_at, last_used_at, expires_at, rotated_at, revoked_at
+            FROM sessions
+            WHERE refresh_token_hash = $1
+            "#,
+            token_hash,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn mark_rotated(&self, id: &Uuid) -> DomainResult<bool> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE sessions
+            SET rotated_at = NOW()
+            WHERE id = $1 AND rotated_at IS NULL AND revoked_at IS NULL
+            "#,
+            id,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn revoke_family(&self, family_id: &Uuid) -> DomainResult<()> {
+        sqlx::query!(
+            r#"
+            UPDATE sessions
+            SET revoked_at = NOW()
+            WHERE family_id = $1 AND revoked_at IS NULL
+            "#,
+            family_id,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn revoke_all_by_user(&self, user_id: &Uuid) -> DomainResult<()> {
+        sqlx::query!(
+            r#"
+            UPDATE sessions
+            SET revoked_at = NOW()
+            WHERE user_id = $1 AND revoked_at IS NULL
+            "#,
+            user_id,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn list_active_by_user(&self, user_id: &Uuid) -> DomainResult<Vec<Session>> {
+        let rows = sqlx::query_as!(
+            SessionRow,
+            r#"
+            SELECT
+                id, family_id, user_id, workspace_id, refresh_token_hash, user_agent, ip_address,
+                started_at, last_used_at, expires_at, rotated_at, revoked_at
+            FROM sessions
+            WHERE user_id = $1
+              AND rotated_at IS NULL
+              AND revoked_at IS NULL
+              AND expires_at > NOW()
+            ORDER BY last_used_at DESC
+            "#,
+            user_id,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+}
+
+/// Row struct para mapeamento do banco
+#[derive(Debug)]
+struct SessionRow {
+    id: Uuid,
+    family_id: Uuid,
+    user_id: Uuid,
+    workspace_id: Option<Uuid>,
+    refresh_token_hash: String,
+    user_agent: Option<String>,
+    ip_address: Option<String>,
+    started_at: chrono::DateTime<chrono::Utc>,
+    last_used_at: chrono::DateTime<chrono::Utc>,
+    expires_at: chrono::DateTime<chrono::Utc>,
+    rotated_at: Option<chrono::DateTime<chrono::Utc>>,
+    revoked_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl From<SessionRow> for Session {
+    fn from(row: SessionRow) -> Self {
+        Session {
+            id: row.id,
+            family_id: row.family_id,
+            user_id: row.user_id,
+            workspace_id: row.workspace_id,
+            refresh_token_hash: row.refresh_token_hash,
+            user_agent: row.user_agent,
+            ip_address: row.ip_address,
+            started_at: row.started_at,
+            last_used_at: row.last_used_at,
+            expires_at: row.expires_at,
+            rotated_at: row.rotated_at,
+            revoked_at: row.revoked_at,
+        }
+    }
+}
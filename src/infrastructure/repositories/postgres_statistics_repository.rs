@@ -0,0 +1,105 @@
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{StatisticsRepository, SystemStatistics};
+use crate::domain::value_objects::{TaskPriority, TaskStatus};
+use crate::infrastructure::repositories::row_level_security::begin_cross_tenant;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+/// Implementação PostgreSQL do StatisticsRepository
+pub struct PostgresStatisticsRepository {
+    pool: PgPool,
+}
+
+impl PostgresStatisticsRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl StatisticsRepository for PostgresStatisticsRepository {
+    async fn system_statistics(
+        &self,
+        created_since: DateTime<Utc>,
+    ) -> DomainResult<SystemStatistics> {
+        let users = sqlx::query!(
+            r#"
+            SELECT
+                COUNT(*) as "total!",
+                COUNT(*) FILTER (WHERE role = 'admin') as "admins!",
+                COUNT(*) FILTER (WHERE disabled_at IS NOT NULL) as "disabled!",
+                COUNT(*) FILTER (WHERE erased_at IS NOT NULL) as "erased!"
+            FROM users
+            "#,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        // Estatísticas do sistema contam tarefas de todos os workspaces
+        let mut tx = begin_cross_tenant(&self.pool).await?;
+
+        let tasks = sqlx::query!(
+            r#"
+            SELECT
+                COUNT(*) as "total!",
+                COUNT(*) FILTER (
+                    WHERE due_date < NOW() AND status IN ('pending', 'in_progress')
+                ) as "overdue!",
+                COUNT(*) FILTER (WHERE created_at >= $1) as "recent!"
+            FROM tasks
+            "#,
+            created_since,
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        let by_status = sqlx::query!(
+            r#"
+            SELECT status as "status: TaskStatus", COUNT(*) as "count!"
+            FROM tasks
+            GROUP BY status
+            ORDER BY status
+            "#,
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        let by_priority = sqlx::query!(
+            r#"
+            SELECT priority as "priority: TaskPriority", COUNT(*) as "count!"
+            FROM tasks
+            GROUP BY priority
+            ORDER BY priority
+            "#,
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(SystemStatistics {
+            total_users: users.total,
+            admin_users: users.admins,
+            disabled_users: users.disabled,
+            erased_users: users.erased,
+            total_tasks: tasks.total,
+            tasks_by_status: by_status
+                .into_iter()
+                .map(|row| (row.status, row.count))
+                .collect(),
+            tasks_by_priority: by_priority
+                .into_iter()
+                .map(|row| (row.priority, row.count))
+                .collect(),
+            overdue_tasks: tasks.overdue,
+            recent_tasks: tasks.recent,
+        })
+    }
+}
@@ -1,72 +1,160 @@
-use crate::domain::entities::{Task, TaskHistory};
+use crate::domain::entities::{Tag, Task, TaskHistory};
 use crate::domain::errors::{DomainError, DomainResult};
-use crate::domain::repositories::{PaginatedResult, Pagination, TaskFilter, TaskRepository};
-use crate::domain::value_objects::{TaskPriority, TaskStatus};
+use crate::domain::repositories::{
+    DeletedTaskTree, PaginatedResult, Pagination, TaskFilter, TaskRepository, TaskSort,
+};
+use crate::domain::value_objects::{
+    HistoryCursor, Rank, SubtaskProgress, TaskPriority, TaskStatus,
+};
+use crate::infrastructure::repositories::row_level_security::{
+    begin_cross_tenant, begin_in_workspace,
+};
 use async_trait::async_trait;
-use sqlx::PgPool;
+use sqlx::{PgPool, Postgres, Transaction};
+use std::collections::HashSet;
+use std::sync::Arc;
 use uuid::Uuid;
 
 /// Implementação PostgreSQL do TaskRepository
+///
+/// Cada operação roda em uma transação: com workspace, `app.workspace_id`
+/// definido restringe as linhas pela política de RLS da tabela `tasks`; o
+/// acesso a todas as tarefas (jobs em background) só existe pelo construtor
+/// `cross_tenant`, que pede o bypass explicitamente com `app.bypass_rls`.
 pub struct PostgresTaskRepository {
     pool: PgPool,
+    workspace_id: Option<Uuid>,
 }
 
 impl PostgresTaskRepository {
-    pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+    /// Repositório restrito às tarefas do workspace
+    pub fn new(pool: PgPool, workspace_id: Uuid) -> Self {
+        Self {
+            pool,
+            workspace_id: Some(workspace_id),
+        }
+    }
+
+    /// Repositório com acesso às tarefas de todos os workspaces
+    ///
+    /// Apenas para jobs que atravessam tenants; requisições usam `for_workspace`.
+    pub fn cross_tenant(pool: PgPool) -> Self {
+        Self {
+            pool,
+            workspace_id: None,
+        }
+    }
+
+    /// Inicia uma transação no escopo do workspace do repositório
+    async fn begin(&self) -> DomainResult<Transaction<'static, Postgres>> {
+        match self.workspace_id {
+            Some(workspace_id) => begin_in_workspace(&self.pool, workspace_id).await,
+            None => begin_cross_tenant(&self.pool).await,
+        }
     }
 }
 
 #[async_trait]
 impl TaskRepository for PostgresTaskRepository {
+    fn for_workspace(&self, workspace_id: Uuid) -> Arc<dyn TaskRepository> {
+        Arc::new(Self::new(self.pool.clone(), workspace_id))
+    }
+
     async fn create(&self, task: &Task) -> DomainResult<Task> {
-        sqlx::query_as!(
-            TaskRow,
+        let mut tx = self.begin().await?;
+
+        sqlx::query!(
             r#"
-            INSERT INTO tasks (id, user_id, title, description, status, priority, due_date, completed_at, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
-            RETURNING 
-                id, user_id, title, description,
-                status as "status: TaskStatus",
-                priority as "priority: TaskPriority",
-                due_date, completed_at, created_at, updated_at
+            INSERT INTO tasks (id, user_id, workspace_id, parent_id, title, description, status, priority, due_date, completed_at, recurrence_rule, series_id, occurrence, created_at, updated_at, project_id, rank, state_id, series_start)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19)
             "#,
             task.id,
             task.user_id,
+            task.workspace_id,
+            task.parent_id,
             task.title,
             task.description,
             task.status as TaskStatus,
             task.priority as TaskPriority,
             task.due_date,
             task.completed_at,
+            task.recurrence.as_ref().map(ToString::to_string),
+            task.series_id,
+            task.occurrence,
             task.created_at,
             task.updated_at,
+            task.project_id,
+            task.rank.as_str(),
+            task.state_id,
+            task.series_start,
         )
-        .fetch_one(&self.pool)
+        .execute(&mut *tx)
         .await
-        .map_err(|e| DomainError::Internal(e.to_string()))?
-        .into()
+        .map_err(|e| {
+            if let sqlx::Error::Database(db_err) = &e
+                && db_err.constraint() == Some("idx_tasks_series_occurrence")
+            {
+                return DomainError::Conflict("Ocorrência da série já existe".to_string());
+            }
+            DomainError::Internal(e.to_string())
+        })?;
+
+        Self::sync_tags(&mut tx, task).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        self.find_by_id(&task.id)
+            .await?
+            .ok_or(DomainError::Internal(
+                "Tarefa não encontrada após criação".to_string(),
+            ))
     }
 
     async fn find_by_id(&self, id: &Uuid) -> DomainResult<Option<Task>> {
+        let mut tx = self.begin().await?;
+
         let row = sqlx::query_as!(
             TaskRow,
             r#"
             SELECT 
-                id, user_id, title, description,
-                status as "status: TaskStatus",
+                id, user_id, workspace_id, project_id, parent_id, title, description,
+                status as "status: TaskStatus", rank, state_id,
                 priority as "priority: TaskPriority",
-                due_date, completed_at, created_at, updated_at
+                due_date, completed_at, recurrence_rule, series_id, occurrence, series_start,
+                COALESCE(
+                    (SELECT array_agg(tg.name ORDER BY tg.name)
+                     FROM task_tags tt JOIN tags tg ON tg.id = tt.tag_id
+                     WHERE tt.task_id = tasks.id),
+                    '{}'
+                ) as "tags!",
+                subtask_total as "subtask_total!",
+                subtask_completed as "subtask_completed!",
+                subtask_open as "subtask_open!",
+                created_at, updated_at
             FROM tasks
+            LEFT JOIN LATERAL (
+                SELECT
+                    COUNT(*) as subtask_total,
+                    COUNT(*) FILTER (WHERE s.status = 'completed') as subtask_completed,
+                    COUNT(*) FILTER (WHERE s.status IN ('pending', 'in_progress')) as subtask_open
+                FROM tasks s
+                WHERE s.parent_id = tasks.id
+            ) st ON TRUE
             WHERE id = $1
             "#,
             id,
         )
-        .fetch_optional(&self.pool)
+        .fetch_optional(&mut *tx)
         .await
         .map_err(|e| DomainError::Internal(e.to_string()))?;
 
-        Ok(row.map(Into::into))
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        row.map(TryInto::try_into).transpose()
     }
 
     async fn list(
@@ -75,44 +163,35 @@ impl TaskRepository for PostgresTaskRepository {
         pagination: Pagination,
     ) -> DomainResult<PaginatedResult<Task>> {
         // Construir query dinamicamente
-        let mut query = String::from(
+        let mut query = format!(
             r#"
             SELECT 
-                id, user_id, title, description,
-                status as "status: TaskStatus",
-                priority as "priority: TaskPriority",
-                due_date, completed_at, created_at, updated_at
+                id, user_id, workspace_id, project_id, parent_id, title, description, status, rank, state_id, priority,
+                due_date, completed_at, recurrence_rule, series_id, occurrence, series_start,
+                COALESCE(
+                    (SELECT array_agg(tg.name ORDER BY tg.name)
+                     FROM task_tags tt JOIN tags tg ON tg.id = tt.tag_id
+                     WHERE tt.task_id = tasks.id),
+                    '{{}}'
+                ) as tags,
+                subtask_total, subtask_completed, subtask_open,
+                created_at, updated_at
             FROM tasks
-            WHERE user_id = $1
+            LEFT JOIN LATERAL (
+                SELECT
+                    COUNT(*) as subtask_total,
+                    COUNT(*) FILTER (WHERE s.status = 'completed') as subtask_completed,
+                    COUNT(*) FILTER (WHERE s.status IN ('pending', 'in_progress')) as subtask_open
+                FROM tasks s
+                WHERE s.parent_id = tasks.id
+            ) st ON TRUE
+            WHERE {}
             "#,
+            Self::scope_condition(&filter)
         );
 
-        let mut param_count = 1;
-        let mut conditions = Vec::new();
-
         // Adicionar filtros
-        if filter.status.is_some() {
-            param_count += 1;
-            conditions.push(format!("status = ${}", param_count));
-        }
-
-        if filter.priority.is_some() {
-            param_count += 1;
-            conditions.push(format!("priority = ${}", param_count));
-        }
-
-        if filter.overdue_only {
-            conditions
-                .push("due_date < NOW() AND status NOT IN ('completed', 'cancelled')".to_string());
-        }
-
-        if filter.search_query.is_some() {
-            param_count += 1;
-            conditions.push(format!(
-                "(title ILIKE ${} OR description ILIKE ${})",
-                param_count, param_count
-            ));
-        }
+        let conditions = Self::filter_conditions(&filter);
 
         if !conditions.is_empty() {
             query.push_str(" AND ");
@@ -120,7 +199,10 @@ impl TaskRepository for PostgresTaskRepository {
         }
 
         // Ordenação
-        query.push_str(" ORDER BY created_at DESC");
+        query.push_str(match filter.sort {
+            TaskSort::Newest => " ORDER BY created_at DESC",
+            TaskSort::Rank => " ORDER BY rank ASC, created_at ASC",
+        });
 
         // Paginação
         let offset = pagination.offset();
@@ -146,18 +228,39 @@ impl TaskRepository for PostgresTaskRepository {
             query_builder = query_builder.bind(search_pattern);
         }
 
+        if let Some(ref tags) = filter.tags_any {
+            query_builder = query_builder.bind(tags.clone());
+        }
+
+        if let Some(ref tags) = filter.tags_all {
+            query_builder = query_builder.bind(tags.clone());
+        }
+
+        if let Some(project_id) = filter.project_id {
+            query_builder = query_builder.bind(project_id);
+        }
+
+        let mut tx = self.begin().await?;
+
         let rows = query_builder
-            .fetch_all(&self.pool)
+            .fetch_all(&mut *tx)
             .await
             .map_err(|e| DomainError::Internal(e.to_string()))?;
 
         // Contar total
-        let total = self.count_total(&filter).await?;
+        let total = Self::count_total(&mut tx, &filter).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
 
         let total_pages = ((total as f64) / (pagination.page_size as f64)).ceil() as u32;
 
         Ok(PaginatedResult {
-            items: rows.into_iter().map(Into::into).collect(),
+            items: rows
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<DomainResult<_>>()?,
             total,
             page: pagination.page,
             page_size: pagination.page_size,
@@ -166,18 +269,16 @@ impl TaskRepository for PostgresTaskRepository {
     }
 
     async fn update(&self, task: &Task) -> DomainResult<Task> {
-        sqlx::query_as!(
-            TaskRow,
+        let mut tx = self.begin().await?;
+
+        sqlx::query!(
             r#"
             UPDATE tasks
             SET title = $2, description = $3, status = $4, priority = $5,
-                due_date = $6, completed_at = $7, updated_at = $8
+                due_date = $6, completed_at = $7, updated_at = $8, parent_id = $9,
+                recurrence_rule = $10, series_id = $11, project_id = $12,
+                rank = $13, state_id = $14, series_start = $15
             WHERE id = $1
-            RETURNING 
-                id, user_id, title, description,
-                status as "status: TaskStatus",
-                priority as "priority: TaskPriority",
-                due_date, completed_at, created_at, updated_at
             "#,
             task.id,
             task.title,
@@ -187,28 +288,52 @@ impl TaskRepository for PostgresTaskRepository {
             task.due_date,
             task.completed_at,
             task.updated_at,
+            task.parent_id,
+            task.recurrence.as_ref().map(ToString::to_string),
+            task.series_id,
+            task.project_id,
+            task.rank.as_str(),
+            task.state_id,
+            task.series_start,
         )
-        .fetch_one(&self.pool)
+        .execute(&mut *tx)
         .await
-        .map_err(|e| DomainError::Internal(e.to_string()))?
-        .into()
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Self::sync_tags(&mut tx, task).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        self.find_by_id(&task.id)
+            .await?
+            .ok_or(DomainError::NotFound("Tarefa não encontrada".to_string()))
     }
 
     async fn delete(&self, id: &Uuid) -> DomainResult<()> {
+        let mut tx = self.begin().await?;
+
         sqlx::query!(
             r#"
             DELETE FROM tasks WHERE id = $1
             "#,
             id,
         )
-        .execute(&self.pool)
+        .execute(&mut *tx)
         .await
         .map_err(|e| DomainError::Internal(e.to_string()))?;
 
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
         Ok(())
     }
 
     async fn add_history(&self, history: &TaskHistory) -> DomainResult<()> {
+        let mut tx = self.begin().await?;
+
         sqlx::query!(
             r#"
             INSERT INTO task_history (id, task_id, user_id, field_name, old_value, new_value, changed_at)
@@ -222,14 +347,20 @@ impl TaskRepository for PostgresTaskRepository {
             history.new_value,
             history.changed_at,
         )
-        .execute(&self.pool)
+        .execute(&mut *tx)
         .await
         .map_err(|e| DomainError::Internal(e.to_string()))?;
 
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
         Ok(())
     }
 
     async fn get_history(&self, task_id: &Uuid) -> DomainResult<Vec<TaskHistory>> {
+        let mut tx = self.begin().await?;
+
         let rows = sqlx::query_as!(
             TaskHistoryRow,
             r#"
@@ -240,14 +371,367 @@ impl TaskRepository for PostgresTaskRepository {
             "#,
             task_id,
         )
-        .fetch_all(&self.pool)
+        .fetch_all(&mut *tx)
         .await
         .map_err(|e| DomainError::Internal(e.to_string()))?;
 
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
         Ok(rows.into_iter().map(Into::into).collect())
     }
 
+    async fn list_history_since(
+        &self,
+        user_id: &Uuid,
+        after: &HistoryCursor,
+        limit: i64,
+    ) -> DomainResult<Vec<TaskHistory>> {
+        let mut tx = self.begin().await?;
+
+        let rows = sqlx::query_as!(
+            TaskHistoryRow,
+            r#"
+            SELECT h.id, h.task_id, h.user_id, h.field_name, h.old_value, h.new_value, h.changed_at
+            FROM task_history h
+            JOIN tasks t ON t.id = h.task_id
+            WHERE (t.user_id = $1 OR EXISTS (
+                    SELECT 1 FROM task_collaborators c
+                    WHERE c.task_id = t.id AND c.user_id = $1))
+              AND (h.changed_at, h.id) > ($2, $3)
+            ORDER BY h.changed_at, h.id
+            LIMIT $4
+            "#,
+            user_id,
+            after.changed_at,
+            after.id,
+            limit,
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn list_open(&self, user_id: &Uuid) -> DomainResult<Vec<Task>> {
+        let mut tx = self.begin().await?;
+
+        let rows = sqlx::query_as!(
+            TaskRow,
+            r#"
+            SELECT 
+                id, user_id, workspace_id, project_id, parent_id, title, description,
+                status as "status: TaskStatus", rank, state_id,
+                priority as "priority: TaskPriority",
+                due_date, completed_at, recurrence_rule, series_id, occurrence, series_start,
+                COALESCE(
+                    (SELECT array_agg(tg.name ORDER BY tg.name)
+                     FROM task_tags tt JOIN tags tg ON tg.id = tt.tag_id
+                     WHERE tt.task_id = tasks.id),
+                    '{}'
+                ) as "tags!",
+                subtask_total as "subtask_total!",
+                subtask_completed as "subtask_completed!",
+                subtask_open as "subtask_open!",
+                created_at, updated_at
+            FROM tasks
+            LEFT JOIN LATERAL (
+                SELECT
+                    COUNT(*) as subtask_total,
+                    COUNT(*) FILTER (WHERE s.status = 'completed') as subtask_completed,
+                    COUNT(*) FILTER (WHERE s.status IN ('pending', 'in_progress')) as subtask_open
+                FROM tasks s
+                WHERE s.parent_id = tasks.id
+            ) st ON TRUE
+            WHERE user_id = $1 AND status IN ('pending', 'in_progress')
+            ORDER BY created_at ASC
+            "#,
+            user_id,
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        rows.into_iter().map(TryInto::try_into).collect()
+    }
+
+    async fn list_subtasks(&self, parent_id: &Uuid) -> DomainResult<Vec<Task>> {
+        let mut tx = self.begin().await?;
+
+        let rows = sqlx::query_as!(
+            TaskRow,
+            r#"
+            SELECT 
+                id, user_id, workspace_id, project_id, parent_id, title, description,
+                status as "status: TaskStatus", rank, state_id,
+                priority as "priority: TaskPriority",
+                due_date, completed_at, recurrence_rule, series_id, occurrence, series_start,
+                COALESCE(
+                    (SELECT array_agg(tg.name ORDER BY tg.name)
+                     FROM task_tags tt JOIN tags tg ON tg.id = tt.tag_id
+                     WHERE tt.task_id = tasks.id),
+                    '{}'
+                ) as "tags!",
+                subtask_total as "subtask_total!",
+                subtask_completed as "subtask_completed!",
+                subtask_open as "subtask_open!",
+                created_at, updated_at
+            FROM tasks
+            LEFT JOIN LATERAL (
+                SELECT
+                    COUNT(*) as subtask_total,
+                    COUNT(*) FILTER (WHERE s.status = 'completed') as subtask_completed,
+                    COUNT(*) FILTER (WHERE s.status IN ('pending', 'in_progress')) as subtask_open
+                FROM tasks s
+                WHERE s.parent_id = tasks.id
+            ) st ON TRUE
+            WHERE parent_id = $1
+            ORDER BY created_at ASC
+            "#,
+            parent_id,
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        rows.into_iter().map(TryInto::try_into).collect()
+    }
+
+    async fn list_descendants(&self, id: &Uuid) -> DomainResult<Vec<Task>> {
+        let mut tx = self.begin().await?;
+
+        let rows = sqlx::query_as!(
+            TaskRow,
+            r#"
+            WITH RECURSIVE subtree AS (
+                SELECT id FROM tasks WHERE parent_id = $1
+                UNION ALL
+                SELECT t.id FROM tasks t INNER JOIN subtree s ON t.parent_id = s.id
+            )
+            SELECT 
+                id, user_id, workspace_id, project_id, parent_id, title, description,
+                status as "status: TaskStatus", rank, state_id,
+                priority as "priority: TaskPriority",
+                due_date, completed_at, recurrence_rule, series_id, occurrence, series_start,
+                COALESCE(
+                    (SELECT array_agg(tg.name ORDER BY tg.name)
+                     FROM task_tags tt JOIN tags tg ON tg.id = tt.tag_id
+                     WHERE tt.task_id = tasks.id),
+                    '{}'
+                ) as "tags!",
+                subtask_total as "subtask_total!",
+                subtask_completed as "subtask_completed!",
+                subtask_open as "subtask_open!",
+                created_at, updated_at
+            FROM tasks
+            LEFT JOIN LATERAL (
+                SELECT
+                    COUNT(*) as subtask_total,
+                    COUNT(*) FILTER (WHERE s.status = 'completed') as subtask_completed,
+                    COUNT(*) FILTER (WHERE s.status IN ('pending', 'in_progress')) as subtask_open
+                FROM tasks s
+                WHERE s.parent_id = tasks.id
+            ) st ON TRUE
+            WHERE id IN (SELECT id FROM subtree)
+            ORDER BY created_at ASC
+            "#,
+            id,
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        rows.into_iter().map(TryInto::try_into).collect()
+    }
+
+    async fn delete_with_subtasks(
+        &self,
+        id: &Uuid,
+        reparent: bool,
+        descendants: &[Uuid],
+    ) -> DomainResult<DeletedTaskTree> {
+        let mut tx = self.begin().await?;
+
+        // Travar a tarefa e seus ancestrais (evita ciclos e órfãos com alterações concorrentes)
+        let locked = sqlx::query_scalar!(
+            r#"
+            WITH RECURSIVE chain AS (
+                SELECT id, parent_id FROM tasks WHERE id = $1
+                UNION ALL
+                SELECT t.id, t.parent_id FROM tasks t INNER JOIN chain c ON t.id = c.parent_id
+            )
+            SELECT id FROM tasks WHERE id IN (SELECT id FROM chain)
+            FOR UPDATE
+            "#,
+            id,
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        if !locked.contains(id) {
+            return Err(DomainError::NotFound("Tarefa não encontrada".to_string()));
+        }
+
+        if reparent {
+            sqlx::query!(
+                r#"
+                UPDATE tasks
+                SET parent_id = (SELECT parent_id FROM tasks WHERE id = $1), updated_at = NOW()
+                WHERE parent_id = $1
+                "#,
+                id,
+            )
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        }
+
+        // Travar as subtarefas restantes para que nenhuma nova seja criada sob elas
+        let remaining = sqlx::query_scalar!(
+            r#"
+            WITH RECURSIVE subtree AS (
+                SELECT id FROM tasks WHERE parent_id = $1
+                UNION ALL
+                SELECT t.id FROM tasks t INNER JOIN subtree s ON t.parent_id = s.id
+            )
+            SELECT id FROM tasks WHERE id IN (SELECT id FROM subtree)
+            FOR UPDATE
+            "#,
+            id,
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        // Apenas as subtarefas autorizadas pelo chamador podem ser deletadas
+        let remaining: HashSet<Uuid> = remaining.into_iter().collect();
+        if remaining != descendants.iter().copied().collect() {
+            return Err(DomainError::Conflict(
+                "As subtarefas da tarefa mudaram durante a deleção; tente novamente".to_string(),
+            ));
+        }
+
+        let rows = sqlx::query_as!(
+            TaskRow,
+            r#"
+            WITH RECURSIVE subtree AS (
+                SELECT id FROM tasks WHERE parent_id = $1
+                UNION ALL
+                SELECT t.id FROM tasks t INNER JOIN subtree s ON t.parent_id = s.id
+            )
+            SELECT 
+                id, user_id, workspace_id, project_id, parent_id, title, description,
+                status as "status: TaskStatus", rank, state_id,
+                priority as "priority: TaskPriority",
+                due_date, completed_at, recurrence_rule, series_id, occurrence, series_start,
+                COALESCE(
+                    (SELECT array_agg(tg.name ORDER BY tg.name)
+                     FROM task_tags tt JOIN tags tg ON tg.id = tt.tag_id
+                     WHERE tt.task_id = tasks.id),
+                    '{}'
+                ) as "tags!",
+                subtask_total as "subtask_total!",
+                subtask_completed as "subtask_completed!",
+                subtask_open as "subtask_open!",
+                created_at, updated_at
+            FROM tasks
+            LEFT JOIN LATERAL (
+                SELECT
+                    COUNT(*) as subtask_total,
+                    COUNT(*) FILTER (WHERE s.status = 'completed') as subtask_completed,
+                    COUNT(*) FILTER (WHERE s.status IN ('pending', 'in_progress')) as subtask_open
+                FROM tasks s
+                WHERE s.parent_id = tasks.id
+            ) st ON TRUE
+            WHERE id IN (SELECT id FROM subtree)
+            ORDER BY created_at ASC
+            "#,
+            id,
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+        let subtasks = rows
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<DomainResult<_>>()?;
+
+        // Anexos removidos aqui para devolver as chaves do conteúdo a apagar
+        let storage_keys = sqlx::query_scalar!(
+            r#"
+            DELETE FROM attachments WHERE task_id = $1 OR task_id = ANY($2)
+            RETURNING storage_key
+            "#,
+            id,
+            descendants,
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        // Histórico e subtarefas são deletados em cascata
+        sqlx::query!(
+            r#"
+            DELETE FROM tasks WHERE id = $1
+            "#,
+            id,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(DeletedTaskTree {
+            subtasks,
+            storage_keys,
+        })
+    }
+
+    async fn last_rank(&self, workspace_id: &Uuid) -> DomainResult<Option<Rank>> {
+        let mut tx = self.begin().await?;
+
+        let rank = sqlx::query_scalar!(
+            r#"
+            SELECT MAX(rank) FROM tasks WHERE workspace_id = $1
+            "#,
+            workspace_id,
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        rank.map(|rank| rank.parse()).transpose()
+    }
+
     async fn count_by_status(&self, user_id: &Uuid, status: TaskStatus) -> DomainResult<i64> {
+        let mut tx = self.begin().await?;
+
         let result = sqlx::query!(
             r#"
             SELECT COUNT(*) as "count!"
@@ -257,78 +741,285 @@ impl TaskRepository for PostgresTaskRepository {
             user_id,
             status as TaskStatus,
         )
-        .fetch_one(&self.pool)
+        .fetch_one(&mut *tx)
         .await
         .map_err(|e| DomainError::Internal(e.to_string()))?;
 
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(result.count)
+    }
+
+    async fn count_by_project_status(
+        &self,
+        project_id: &Uuid,
+        status: TaskStatus,
+    ) -> DomainResult<i64> {
+        let mut tx = self.begin().await?;
+
+        let result = sqlx::query!(
+            r#"
+            SELECT COUNT(*) as "count!"
+            FROM tasks
+            WHERE project_id = $1 AND status = $2
+            "#,
+            project_id,
+            status as TaskStatus,
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
         Ok(result.count)
     }
 }
 
 impl PostgresTaskRepository {
-    /// Helper para contar total de tarefas
-    async fn count_total(&self, filter: &TaskFilter) -> DomainResult<i64> {
-        let mut query = String::from("SELECT COUNT(*) FROM tasks WHERE user_id = $1");
+    /// Condição de escopo do usuário ($1), incluindo tarefas compartilhadas se solicitado
+    fn scope_condition(filter: &TaskFilter) -> &'static str {
+        if filter.include_shared {
+            "(user_id = $1 OR id IN (SELECT task_id FROM task_collaborators WHERE user_id = $1))"
+        } else {
+            "user_id = $1"
+        }
+    }
 
+    /// Monta as condições SQL do filtro (parâmetros a partir de $2)
+    ///
+    /// A ordem dos parâmetros deve ser respeitada ao fazer o bind:
+    /// status, priority, search_query, tags_any, tags_all, project_id.
+    fn filter_conditions(filter: &TaskFilter) -> Vec<String> {
+        let mut param_count = 1;
         let mut conditions = Vec::new();
 
         if filter.status.is_some() {
-            conditions.push("status = $2");
+            param_count += 1;
+            conditions.push(format!("status = ${}", param_count));
         }
 
         if filter.priority.is_some() {
-            conditions.push("priority = $3");
+            param_count += 1;
+            conditions.push(format!("priority = ${}", param_count));
         }
 
         if filter.overdue_only {
-            conditions.push("due_date < NOW() AND status NOT IN ('completed', 'cancelled')");
+            conditions
+                .push("due_date < NOW() AND status NOT IN ('completed', 'cancelled')".to_string());
+        }
+
+        if filter.search_query.is_some() {
+            param_count += 1;
+            conditions.push(format!(
+                "(title ILIKE ${} OR description ILIKE ${})",
+                param_count, param_count
+            ));
+        }
+
+        if filter.tags_any.is_some() {
+            param_count += 1;
+            conditions.push(format!(
+                "id IN (SELECT tt.task_id FROM task_tags tt JOIN tags tg ON tg.id = tt.tag_id \
+                 WHERE tg.name = ANY(${}))",
+                param_count
+            ));
+        }
+
+        if filter.tags_all.is_some() {
+            param_count += 1;
+            conditions.push(format!(
+                "id IN (SELECT tt.task_id FROM task_tags tt JOIN tags tg ON tg.id = tt.tag_id \
+                 WHERE tg.name = ANY(${p}) GROUP BY tt.task_id \
+                 HAVING COUNT(DISTINCT tg.name) = cardinality(${p}))",
+                p = param_count
+            ));
+        }
+
+        if filter.project_id.is_some() {
+            param_count += 1;
+            conditions.push(format!("project_id = ${}", param_count));
         }
 
+        conditions
+    }
+
+    /// Helper para contar total de tarefas
+    async fn count_total(
+        tx: &mut Transaction<'static, Postgres>,
+        filter: &TaskFilter,
+    ) -> DomainResult<i64> {
+        let mut query = format!(
+            "SELECT COUNT(*) FROM tasks WHERE {}",
+            Self::scope_condition(filter)
+        );
+
+        let conditions = Self::filter_conditions(filter);
+
         if !conditions.is_empty() {
             query.push_str(" AND ");
             query.push_str(&conditions.join(" AND "));
         }
 
         // Versão simplificada - em produção use query builder apropriado
-        let count: i64 = sqlx::query_scalar(&query)
-            .bind(filter.user_id)
-            .fetch_one(&self.pool)
+        let mut query_builder = sqlx::query_scalar::<_, i64>(&query).bind(filter.user_id);
+
+        if let Some(status) = filter.status {
+            query_builder = query_builder.bind(status);
+        }
+
+        if let Some(priority) = filter.priority {
+            query_builder = query_builder.bind(priority);
+        }
+
+        if let Some(ref search) = filter.search_query {
+            query_builder = query_builder.bind(format!("%{}%", search));
+        }
+
+        if let Some(ref tags) = filter.tags_any {
+            query_builder = query_builder.bind(tags.clone());
+        }
+
+        if let Some(ref tags) = filter.tags_all {
+            query_builder = query_builder.bind(tags.clone());
+        }
+
+        if let Some(project_id) = filter.project_id {
+            query_builder = query_builder.bind(project_id);
+        }
+
+        let count = query_builder
+            .fetch_one(&mut **tx)
             .await
             .map_err(|e| DomainError::Internal(e.to_string()))?;
 
         Ok(count)
     }
+
+    /// Sincroniza as tags da tarefa (cria as tags inexistentes do usuário)
+    async fn sync_tags(tx: &mut Transaction<'_, Postgres>, task: &Task) -> DomainResult<()> {
+        sqlx::query!(
+            r#"
+            DELETE FROM task_tags WHERE task_id = $1
+            "#,
+            task.id,
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        for name in &task.tags {
+            let tag = Tag::new(Uuid::new_v4(), task.user_id, name)?;
+
+            let tag_id = sqlx::query_scalar!(
+                r#"
+                INSERT INTO tags (id, user_id, name, created_at)
+                VALUES ($1, $2, $3, $4)
+                ON CONFLICT (user_id, name) DO UPDATE SET name = EXCLUDED.name
+                RETURNING id
+                "#,
+                tag.id,
+                tag.user_id,
+                tag.name,
+                tag.created_at,
+            )
+            .fetch_one(&mut **tx)
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+            sqlx::query!(
+                r#"
+                INSERT INTO task_tags (task_id, tag_id)
+                VALUES ($1, $2)
+                ON CONFLICT DO NOTHING
+                "#,
+                task.id,
+                tag_id,
+            )
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        }
+
+        Ok(())
+    }
 }
 
 /// Row structs para mapeamento
-#[derive(Debug)]
+#[derive(Debug, sqlx::FromRow)]
 struct TaskRow {
     id: Uuid,
     user_id: Uuid,
+    workspace_id: Uuid,
+    project_id: Option<Uuid>,
+    parent_id: Option<Uuid>,
     title: String,
     description: Option<String>,
     status: TaskStatus,
+    rank: String,
+    state_id: Option<Uuid>,
     priority: TaskPriority,
     due_date: Option<chrono::DateTime<chrono::Utc>>,
     completed_at: Option<chrono::DateTime<chrono::Utc>>,
+    recurrence_rule: Option<String>,
+    series_id: Option<Uuid>,
+    occurrence: i32,
+    series_start: Option<chrono::DateTime<chrono::Utc>>,
+    tags: Vec<String>,
+    subtask_total: i64,
+    subtask_completed: i64,
+    subtask_open: i64,
     created_at: chrono::DateTime<chrono::Utc>,
     updated_at: chrono::DateTime<chrono::Utc>,
 }
 
-impl From<TaskRow> for Task {
-    fn from(row: TaskRow) -> Self {
-        Task {
+impl TryFrom<TaskRow> for Task {
+    type Error = DomainError;
+
+    fn try_from(row: TaskRow) -> Result<Self, Self::Error> {
+        // Valores inválidos no banco são erros internos, não dados a descartar
+        let rank = row.rank.parse().map_err(|e| {
+            DomainError::Internal(format!("Posição inválida na tarefa {}: {}", row.id, e))
+        })?;
+        let recurrence = row
+            .recurrence_rule
+            .map(|rule| rule.parse())
+            .transpose()
+            .map_err(|e| {
+                DomainError::Internal(format!("Recorrência inválida na tarefa {}: {}", row.id, e))
+            })?;
+
+        Ok(Task {
             id: row.id,
             user_id: row.user_id,
+            workspace_id: row.workspace_id,
+            project_id: row.project_id,
+            parent_id: row.parent_id,
             title: row.title,
             description: row.description,
             status: row.status,
+            rank,
+            state_id: row.state_id,
             priority: row.priority,
             due_date: row.due_date,
             completed_at: row.completed_at,
+            tags: row.tags,
+            subtasks: SubtaskProgress {
+                total: row.subtask_total,
+                completed: row.subtask_completed,
+                open: row.subtask_open,
+            },
+            recurrence,
+            series_id: row.series_id,
+            occurrence: row.occurrence,
+            series_start: row.series_start,
             created_at: row.created_at,
             updated_at: row.updated_at,
-        }
+        })
     }
 }
 
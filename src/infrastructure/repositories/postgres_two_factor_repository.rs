@@ -0,0 +1,194 @@
+use crate::domain::entities::{RecoveryCode, TotpCredential};
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::TwoFactorRepository;
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Implementação PostgreSQL do TwoFactorRepository
+pub struct PostgresTwoFactorRepository {
+    pool: PgPool,
+}
+
+impl PostgresTwoFactorRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TwoFactorRepository for PostgresTwoFactorRepository {
+    async fn find_by_user(&self, user_id: &Uuid) -> DomainResult<Option<TotpCredential>> {
+        let row = sqlx::query_as!(
+            TotpCredentialRow,
+            r#"
+            SELECT user_id, secret, confirmed_at, last_used_step, created_at
+            FROM user_totp
+            WHERE user_id = $1
+            "#,
+            user_id,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn save(&self, credential: &TotpCredential) -> DomainResult<TotpCredential> {
+        let row = sqlx::query_as!(
+            TotpCredentialRow,
+            r#"
+            INSERT INTO user_totp (user_id, secret, confirmed_at, last_used_step, created_at)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (user_id) DO UPDATE
+            SET secret = EXCLUDED.secret,
+                confirmed_at = EXCLUDED.confirmed_at,
+                last_used_step = EXCLUDED.last_used_step,
+                created_at = EXCLUDED.created_at
+            RETURNING user_id, secret, confirmed_at, last_used_step, created_at
+            "#,
+            credential.user_id,
+            credential.secret,
+            credential.confirmed_at,
+            credential.last_used_step,
+            credential.created_at,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(row.into())
+    }
+
+    async fn mark_step_used(&self, user_id: &Uuid, step: i64) -> DomainResult<bool> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE user_totp
+            SET last_used_step = $2
+            WHERE user_id = $1 AND (last_used_step IS NULL OR last_used_step < $2)
+            "#,
+            user_id,
+            step,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn delete(&self, user_id: &Uuid) -> DomainResult<()> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM recovery_codes WHERE user_id = $1
+            "#,
+            user_id,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM user_totp WHERE user_id = $1
+            "#,
+            user_id,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))
+    }
+
+    async fn replace_recovery_codes(
+        &self,
+        user_id: &Uuid,
+        codes: &[RecoveryCode],
+    ) -> DomainResult<()> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM recovery_codes WHERE user_id = $1
+            "#,
+            user_id,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        for code in codes {
+            sqlx::query!(
+                r#"
+                INSERT INTO recovery_codes (id, user_id, code_hash, used_at, created_at)
+                VALUES ($1, $2, $3, $4, $5)
+                "#,
+                code.id,
+                code.user_id,
+                code.code_hash,
+                code.used_at,
+                code.created_at,
+            )
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))
+    }
+
+    async fn consume_recovery_code(&self, user_id: &Uuid, code_hash: &str) -> DomainResult<bool> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE recovery_codes
+            SET used_at = NOW()
+            WHERE user_id = $1 AND code_hash = $2 AND used_at IS NULL
+            "#,
+            user_id,
+            code_hash,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+}
+
+/// Row struct para mapeamento do banco
+#[derive(Debug)]
+struct TotpCredentialRow {
+    user_id: Uuid,
+    secret: String,
+    confirmed_at: Option<chrono::DateTime<chrono::Utc>>,
+    last_used_step: Option<i64>,
+    created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<TotpCredentialRow> for TotpCredential {
+    fn from(row: TotpCredentialRow) -> Self {
+        TotpCredential {
+            user_id: row.user_id,
+            secret: row.secret,
+            confirmed_at: row.confirmed_at,
+            last_used_step: row.last_used_step,
+            created_at: row.created_at,
+        }
+    }
+}
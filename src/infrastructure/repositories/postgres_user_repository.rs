@@ -1,7 +1,8 @@
 use crate::domain::entities::User;
 use crate::domain::errors::{DomainError, DomainResult};
-use crate::domain::repositories::UserRepository;
-use crate::domain::value_objects::Email;
+use crate::domain::repositories::task_repository::{PaginatedResult, Pagination};
+use crate::domain::repositories::{UserFilter, UserRepository};
+use crate::domain::value_objects::{Email, UserRole};
 use async_trait::async_trait;
 use sqlx::PgPool;
 use uuid::Uuid;
@@ -23,14 +24,22 @@ impl UserRepository for PostgresUserRepository {
         sqlx::query_as!(
             UserRow,
             r#"
-            INSERT INTO users (id, email, password_hash, name, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6)
-            RETURNING id, email, password_hash, name, created_at, updated_at
+            INSERT INTO users (
+                id, email, password_hash, name, role, email_verified_at, disabled_at,
+                created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING
+                id, email, password_hash, name, role as "role: UserRole", token_version,
+                email_verified_at, disabled_at, created_at, updated_at
             "#,
             user.id,
             user.email.as_str(),
             user.password_hash,
             user.name,
+            user.role as UserRole,
+            user.email_verified_at,
+            user.disabled_at,
             user.created_at,
             user.updated_at,
         )
@@ -51,7 +60,9 @@ impl UserRepository for PostgresUserRepository {
         let row = sqlx::query_as!(
             UserRow,
             r#"
-            SELECT id, email, password_hash, name, created_at, updated_at
+            SELECT
+                id, email, password_hash, name, role as "role: UserRole", token_version,
+                email_verified_at, disabled_at, created_at, updated_at
             FROM users
             WHERE id = $1
             "#,
@@ -71,7 +82,9 @@ impl UserRepository for PostgresUserRepository {
         let row = sqlx::query_as!(
             UserRow,
             r#"
-            SELECT id, email, password_hash, name, created_at, updated_at
+            SELECT
+                id, email, password_hash, name, role as "role: UserRole", token_version,
+                email_verified_at, disabled_at, created_at, updated_at
             FROM users
             WHERE email = $1
             "#,
@@ -92,14 +105,20 @@ impl UserRepository for PostgresUserRepository {
             UserRow,
             r#"
             UPDATE users
-            SET email = $2, password_hash = $3, name = $4, updated_at = $5
+            SET email = $2, password_hash = $3, name = $4, role = $5, email_verified_at = $6,
+                disabled_at = $7, updated_at = $8
             WHERE id = $1
-            RETURNING id, email, password_hash, name, created_at, updated_at
+            RETURNING
+                id, email, password_hash, name, role as "role: UserRole", token_version,
+                email_verified_at, disabled_at, created_at, updated_at
             "#,
             user.id,
             user.email.as_str(),
             user.password_hash,
             user.name,
+            user.role as UserRole,
+            user.email_verified_at,
+            user.disabled_at,
             user.updated_at,
         )
         .fetch_one(&self.pool)
@@ -135,6 +154,102 @@ impl UserRepository for PostgresUserRepository {
 
         Ok(result.exists)
     }
+
+    async fn search(
+        &self,
+        filter: UserFilter,
+        pagination: Pagination,
+    ) -> DomainResult<PaginatedResult<User>> {
+        let search_pattern = filter
+            .search_query
+            .as_ref()
+            .map(|query| format!("%{}%", query));
+
+        let rows = sqlx::query_as!(
+            UserRow,
+            r#"
+            SELECT
+                id, email, password_hash, name, role as "role: UserRole", token_version,
+                email_verified_at, disabled_at, created_at, updated_at
+            FROM users
+            WHERE ($1::text IS NULL OR email ILIKE $1 OR name ILIKE $1)
+              AND ($2::user_role IS NULL OR role = $2)
+              AND ($3::boolean IS NULL OR (disabled_at IS NOT NULL) = $3)
+            ORDER BY created_at DESC, id
+            LIMIT $4 OFFSET $5
+            "#,
+            search_pattern,
+            filter.role as Option<UserRole>,
+            filter.disabled,
+            pagination.page_size as i64,
+            pagination.offset() as i64,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        let total = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) as "count!"
+            FROM users
+            WHERE ($1::text IS NULL OR email ILIKE $1 OR name ILIKE $1)
+              AND ($2::user_role IS NULL OR role = $2)
+              AND ($3::boolean IS NULL OR (disabled_at IS NOT NULL) = $3)
+            "#,
+            search_pattern,
+            filter.role as Option<UserRole>,
+            filter.disabled,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        let total_pages = ((total as f64) / (pagination.page_size as f64)).ceil() as u32;
+
+        Ok(PaginatedResult {
+            items: rows
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<DomainResult<_>>()?,
+            total,
+            page: pagination.page,
+            page_size: pagination.page_size,
+            total_pages,
+        })
+    }
+
+    async fn find_token_version(&self, id: &Uuid) -> DomainResult<Option<i32>> {
+        // Contas desativadas não têm versão válida: todos os tokens são rejeitados
+        let row = sqlx::query!(
+            r#"
+            SELECT token_version FROM users WHERE id = $1 AND disabled_at IS NULL
+            "#,
+            id,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(row.map(|r| r.token_version))
+    }
+
+    async fn increment_token_version(&self, id: &Uuid) -> DomainResult<i32> {
+        let row = sqlx::query!(
+            r#"
+            UPDATE users
+            SET token_version = token_version + 1, updated_at = NOW()
+            WHERE id = $1
+            RETURNING token_version
+            "#,
+            id,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?
+        .ok_or(DomainError::NotFound("Usuário não encontrado".to_string()))?;
+
+        Ok(row.token_version)
+    }
 }
 
 /// Row struct para mapeamento do banco
@@ -144,6 +259,10 @@ struct UserRow {
     email: String,
     password_hash: String,
     name: String,
+    role: UserRole,
+    token_version: i32,
+    email_verified_at: Option<chrono::DateTime<chrono::Utc>>,
+    disabled_at: Option<chrono::DateTime<chrono::Utc>>,
     created_at: chrono::DateTime<chrono::Utc>,
     updated_at: chrono::DateTime<chrono::Utc>,
 }
@@ -157,6 +276,10 @@ impl TryFrom<UserRow> for User {
             email: Email::new(row.email)?,
             password_hash: row.password_hash,
             name: row.name,
+            role: row.role,
+            token_version: row.token_version,
+            email_verified_at: row.email_verified_at,
+            disabled_at: row.disabled_at,
             created_at: row.created_at,
             updated_at: row.updated_at,
         })
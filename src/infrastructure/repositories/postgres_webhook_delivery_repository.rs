@@ -0,0 +1,175 @@
+use crate::domain::entities::WebhookDelivery;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::WebhookDeliveryRepository;
+use crate::domain::value_objects::WebhookDeliveryStatus;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Implementação PostgreSQL do WebhookDeliveryRepository
+pub struct PostgresWebhookDeliveryRepository {
+    pool: PgPool,
+}
+
+impl PostgresWebhookDeliveryRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WebhookDeliveryRepository for PostgresWebhookDeliveryRepository {
+    async fn enqueue(&self, delivery: &WebhookDelivery) -> DomainResult<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO webhook_deliveries (
+                id, webhook_id, event_id, event_type, payload, status,
+                attempts, next_attempt_at, created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            ON CONFLICT (webhook_id, event_id) DO NOTHING
+            "#,
+            delivery.id,
+            delivery.webhook_id,
+            delivery.event_id,
+            delivery.event_type.to_string(),
+            delivery.payload,
+            delivery.status as WebhookDeliveryStatus,
+            delivery.attempts,
+            delivery.next_attempt_at,
+            delivery.created_at,
+            delivery.updated_at,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn claim_due(
+        &self,
+        limit: i64,
+        lease_until: DateTime<Utc>,
+    ) -> DomainResult<Vec<WebhookDelivery>> {
+        let rows = sqlx::query_as!(
+            WebhookDeliveryRow,
+            r#"
+            UPDATE webhook_deliveries
+            SET next_attempt_at = $2
+            WHERE id IN (
+                SELECT id FROM webhook_deliveries
+                WHERE status = 'pending' AND next_attempt_at <= NOW()
+                ORDER BY next_attempt_at
+                LIMIT $1
+                FOR UPDATE SKIP LOCKED
+            )
+            RETURNING
+                id, webhook_id, event_id, event_type, payload,
+                status as "status: WebhookDeliveryStatus",
+                attempts, next_attempt_at, last_status_code, last_error,
+                delivered_at, created_at, updated_at
+            "#,
+            limit,
+            lease_until,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        rows.into_iter().map(TryInto::try_into).collect()
+    }
+
+    async fn update(&self, delivery: &WebhookDelivery) -> DomainResult<()> {
+        sqlx::query!(
+            r#"
+            UPDATE webhook_deliveries
+            SET status = $2, attempts = $3, next_attempt_at = $4, last_status_code = $5,
+                last_error = $6, delivered_at = $7, updated_at = $8
+            WHERE id = $1
+            "#,
+            delivery.id,
+            delivery.status as WebhookDeliveryStatus,
+            delivery.attempts,
+            delivery.next_attempt_at,
+            delivery.last_status_code,
+            delivery.last_error,
+            delivery.delivered_at,
+            delivery.updated_at,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn list_by_webhook(
+        &self,
+        webhook_id: &Uuid,
+        limit: i64,
+    ) -> DomainResult<Vec<WebhookDelivery>> {
+        let rows = sqlx::query_as!(
+            WebhookDeliveryRow,
+            r#"
+            SELECT
+                id, webhook_id, event_id, event_type, payload,
+                status as "status: WebhookDeliveryStatus",
+                attempts, next_attempt_at, last_status_code, last_error,
+                delivered_at, created_at, updated_at
+            FROM webhook_deliveries
+            WHERE webhook_id = $1
+            ORDER BY created_at DESC
+            LIMIT $2
+            "#,
+            webhook_id,
+            limit,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        rows.into_iter().map(TryInto::try_into).collect()
+    }
+}
+
+/// Row struct para mapeamento do banco
+#[derive(Debug)]
+struct WebhookDeliveryRow {
+    id: Uuid,
+    webhook_id: Uuid,
+    event_id: Uuid,
+    event_type: String,
+    payload: String,
+    status: WebhookDeliveryStatus,
+    attempts: i32,
+    next_attempt_at: DateTime<Utc>,
+    last_status_code: Option<i32>,
+    last_error: Option<String>,
+    delivered_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<WebhookDeliveryRow> for WebhookDelivery {
+    type Error = DomainError;
+
+    fn try_from(row: WebhookDeliveryRow) -> Result<Self, Self::Error> {
+        Ok(WebhookDelivery {
+            id: row.id,
+            webhook_id: row.webhook_id,
+            event_id: row.event_id,
+            event_type: row.event_type.parse()?,
+            payload: row.payload,
+            status: row.status,
+            attempts: row.attempts,
+            next_attempt_at: row.next_attempt_at,
+            last_status_code: row.last_status_code,
+            last_error: row.last_error,
+            delivered_at: row.delivered_at,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
+}
@@ -0,0 +1,185 @@
+use crate::domain::entities::Webhook;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::events::TaskEventKind;
+use crate::domain::repositories::WebhookRepository;
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Implementação PostgreSQL do WebhookRepository
+pub struct PostgresWebhookRepository {
+    pool: PgPool,
+}
+
+impl PostgresWebhookRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WebhookRepository for PostgresWebhookRepository {
+    async fn create(&self, webhook: &Webhook) -> DomainResult<Webhook> {
+        let event_types = event_type_names(&webhook.event_types);
+
+        let row = sqlx::query_as!(
+            WebhookRow,
+            r#"
+            INSERT INTO webhooks (id, user_id, url, secret, event_types, active, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING id, user_id, url, secret, event_types, active, created_at, updated_at
+            "#,
+            webhook.id,
+            webhook.user_id,
+            webhook.url,
+            webhook.secret,
+            &event_types,
+            webhook.active,
+            webhook.created_at,
+            webhook.updated_at,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(row.into())
+    }
+
+    async fn find_by_id(&self, id: &Uuid) -> DomainResult<Option<Webhook>> {
+        let row = sqlx::query_as!(
+            WebhookRow,
+            r#"
+            SELECT id, user_id, url, secret, event_types, active, created_at, updated_at
+            FROM webhooks
+            WHERE id = $1
+            "#,
+            id,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn list_by_user(&self, user_id: &Uuid) -> DomainResult<Vec<Webhook>> {
+        let rows = sqlx::query_as!(
+            WebhookRow,
+            r#"
+            SELECT id, user_id, url, secret, event_types, active, created_at, updated_at
+            FROM webhooks
+            WHERE user_id = $1
+            ORDER BY created_at ASC
+            "#,
+            user_id,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn list_subscribed(
+        &self,
+        user_id: &Uuid,
+        kind: TaskEventKind,
+    ) -> DomainResult<Vec<Webhook>> {
+        let rows = sqlx::query_as!(
+            WebhookRow,
+            r#"
+            SELECT id, user_id, url, secret, event_types, active, created_at, updated_at
+            FROM webhooks
+            WHERE user_id = $1 AND active AND $2 = ANY(event_types)
+            "#,
+            user_id,
+            kind.to_string(),
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn update(&self, webhook: &Webhook) -> DomainResult<Webhook> {
+        let event_types = event_type_names(&webhook.event_types);
+
+        let row = sqlx::query_as!(
+            WebhookRow,
+            r#"
+            UPDATE webhooks
+            SET url = $2, secret = $3, event_types = $4, active = $5, updated_at = $6
+            WHERE id = $1
+            RETURNING id, user_id, url, secret, event_types, active, created_at, updated_at
+            "#,
+            webhook.id,
+            webhook.url,
+            webhook.secret,
+            &event_types,
+            webhook.active,
+            webhook.updated_at,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        row.map(Into::into)
+            .ok_or(DomainError::NotFound("Webhook não encontrado".to_string()))
+    }
+
+    async fn delete(&self, id: &Uuid) -> DomainResult<()> {
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM webhooks WHERE id = $1
+            "#,
+            id,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::NotFound("Webhook não encontrado".to_string()));
+        }
+
+        Ok(())
+    }
+}
+
+fn event_type_names(event_types: &[TaskEventKind]) -> Vec<String> {
+    event_types.iter().map(ToString::to_string).collect()
+}
+
+/// Row struct para mapeamento do banco
+#[derive(Debug)]
+struct WebhookRow {
+    id: Uuid,
+    user_id: Uuid,
+    url: String,
+    secret: String,
+    event_types: Vec<String>,
+    active: bool,
+    created_at: chrono::DateTime<chrono::Utc>,
+    updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<WebhookRow> for Webhook {
+    fn from(row: WebhookRow) -> Self {
+        Webhook {
+            id: row.id,
+            user_id: row.user_id,
+            url: row.url,
+            secret: row.secret,
+            // Tipos desconhecidos (ex.: removidos em versões futuras) são ignorados
+            event_types: row
+                .event_types
+                .iter()
+                .filter_map(|kind| kind.parse().ok())
+                .collect(),
+            active: row.active,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use crate::domain::entities::{Workflow, WorkflowState, WorkflowTransition};
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::WorkflowRepository;
+use crate::domain::value_objects::TaskStatus;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Implementação PostgreSQL do WorkflowRepository
+pub struct PostgresWorkflowRepository {
+    pool: PgPool,
+}
+
+impl PostgresWorkflowRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Carrega estados e transições dos workflows informados
+    async fn load(&self, rows: Vec<WorkflowRow>) -> DomainResult<Vec<Workflow>> {
+        let ids: Vec<Uuid> = rows.iter().map(|row| row.id).collect();
+
+        let states = sqlx::query_as!(
+            WorkflowState,
+            r#"
+            SELECT id, workflow_id, name, category as "category: TaskStatus", position
+            FROM workflow_states
+            WHERE workflow_id = ANY($1)
+            ORDER BY position ASC
+            "#,
+            &ids,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        let transitions = sqlx::query_as!(
+            TransitionRow,
+            r#"
+            SELECT workflow_id, from_state_id, to_state_id
+            FROM workflow_transitions
+            WHERE workflow_id = ANY($1)
+            "#,
+            &ids,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| Workflow {
+                id: row.id,
+                workspace_id: row.workspace_id,
+                name: row.name,
+                states: states
+                    .iter()
+                    .filter(|state| state.workflow_id == row.id)
+                    .cloned()
+                    .collect(),
+                transitions: transitions
+                    .iter()
+                    .filter(|transition| transition.workflow_id == row.id)
+                    .map(|transition| WorkflowTransition {
+                        from_state_id: transition.from_state_id,
+                        to_state_id: transition.to_state_id,
+                    })
+                    .collect(),
+                created_at: row.created_at,
+                updated_at: row.updated_at,
+            })
+            .collect())
+    }
+}
+
+#[async_trait]
+impl WorkflowRepository for PostgresWorkflowRepository {
+    async fn create(&self, workflow: &Workflow) -> DomainResult<Workflow> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO workflows (id, workspace_id, name, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5)
+            "#,
+            workflow.id,
+            workflow.workspace_id,
+            workflow.name,
+            workflow.created_at,
+            workflow.updated_at,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        for state in &workflow.states {
+            sqlx::query!(
+                r#"
+                INSERT INTO workflow_states (id, workflow_id, name, category, position)
+                VALUES ($1, $2, $3, $4, $5)
+                "#,
+                state.id,
+                workflow.id,
+                state.name,
+                state.category as TaskStatus,
+                state.position,
+            )
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        }
+
+        for transition in &workflow.transitions {
+            sqlx::query!(
+                r#"
+                INSERT INTO workflow_transitions (workflow_id, from_state_id, to_state_id)
+                VALUES ($1, $2, $3)
+                "#,
+                workflow.id,
+                transition.from_state_id,
+                transition.to_state_id,
+            )
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(workflow.clone())
+    }
+
+    async fn find_by_id(&self, id: &Uuid) -> DomainResult<Option<Workflow>> {
+        let rows = sqlx::query_as!(
+            WorkflowRow,
+            r#"
+            SELECT id, workspace_id, name, created_at, updated_at
+            FROM workflows
+            WHERE id = $1
+            "#,
+            id,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(self.load(rows).await?.into_iter().next())
+    }
+
+    async fn find_by_state(&self, state_id: &Uuid) -> DomainResult<Option<Workflow>> {
+        let rows = sqlx::query_as!(
+            WorkflowRow,
+            r#"
+            SELECT w.id, w.workspace_id, w.name, w.created_at, w.updated_at
+            FROM workflows w
+            INNER JOIN workflow_states ws ON ws.workflow_id = w.id
+            WHERE ws.id = $1
+            "#,
+            state_id,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(self.load(rows).await?.into_iter().next())
+    }
+
+    async fn list_by_workspace(&self, workspace_id: &Uuid) -> DomainResult<Vec<Workflow>> {
+        let rows = sqlx::query_as!(
+            WorkflowRow,
+            r#"
+            SELECT id, workspace_id, name, created_at, updated_at
+            FROM workflows
+            WHERE workspace_id = $1
+            ORDER BY created_at ASC
+            "#,
+            workspace_id,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        self.load(rows).await
+    }
+
+    async fn delete(&self, id: &Uuid) -> DomainResult<()> {
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM workflows WHERE id = $1
+            "#,
+            id,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::NotFound("Workflow não encontrado".to_string()));
+        }
+
+        Ok(())
+    }
+}
+
+/// Row struct para mapeamento do banco
+#[derive(Debug)]
+struct WorkflowRow {
+    id: Uuid,
+    workspace_id: Uuid,
+    name: String,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+/// Row struct para mapeamento do banco
+#[derive(Debug)]
+struct TransitionRow {
+    workflow_id: Uuid,
+    from_state_id: Uuid,
+    to_state_id: Uuid,
+}
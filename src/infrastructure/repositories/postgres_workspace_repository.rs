@@ -0,0 +1,247 @@
+use crate::domain::entities::{Membership, Workspace};
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::WorkspaceRepository;
+use crate::domain::value_objects::WorkspaceRole;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Implementação PostgreSQL do WorkspaceRepository
+pub struct PostgresWorkspaceRepository {
+    pool: PgPool,
+}
+
+impl PostgresWorkspaceRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WorkspaceRepository for PostgresWorkspaceRepository {
+    async fn create(&self, workspace: &Workspace, owner: &Membership) -> DomainResult<Workspace> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        let row = sqlx::query_as!(
+            WorkspaceRow,
+            r#"
+            INSERT INTO workspaces (id, name, created_at, updated_at)
+            VALUES ($1, $2, $3, $4)
+            RETURNING id, name, created_at, updated_at
+            "#,
+            workspace.id,
+            workspace.name,
+            workspace.created_at,
+            workspace.updated_at,
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO workspace_members (workspace_id, user_id, role, invited_by, created_at)
+            VALUES ($1, $2, $3, $4, $5)
+            "#,
+            owner.workspace_id,
+            owner.user_id,
+            owner.role as WorkspaceRole,
+            owner.invited_by,
+            owner.created_at,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(row.into())
+    }
+
+    async fn find_by_id(&self, id: &Uuid) -> DomainResult<Option<Workspace>> {
+        let row = sqlx::query_as!(
+            WorkspaceRow,
+            r#"
+            SELECT id, name, created_at, updated_at
+            FROM workspaces
+            WHERE id = $1
+            "#,
+            id,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn list_by_user(&self, user_id: &Uuid) -> DomainResult<Vec<(Workspace, Membership)>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                w.id, w.name, w.created_at, w.updated_at,
+                m.user_id, m.role as "role: WorkspaceRole", m.invited_by,
+                m.created_at as joined_at
+            FROM workspace_members m
+            JOIN workspaces w ON w.id = m.workspace_id
+            WHERE m.user_id = $1
+            ORDER BY m.created_at, w.id
+            "#,
+            user_id,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                (
+                    Workspace {
+                        id: row.id,
+                        name: row.name,
+                        created_at: row.created_at,
+                        updated_at: row.updated_at,
+                    },
+                    Membership {
+                        workspace_id: row.id,
+                        user_id: row.user_id,
+                        role: row.role,
+                        invited_by: row.invited_by,
+                        created_at: row.joined_at,
+                    },
+                )
+            })
+            .collect())
+    }
+
+    async fn find_default_for_user(&self, user_id: &Uuid) -> DomainResult<Option<Uuid>> {
+        sqlx::query_scalar!(
+            r#"
+            SELECT workspace_id
+            FROM workspace_members
+            WHERE user_id = $1
+            ORDER BY created_at, workspace_id
+            LIMIT 1
+            "#,
+            user_id,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))
+    }
+
+    async fn find_membership(
+        &self,
+        workspace_id: &Uuid,
+        user_id: &Uuid,
+    ) -> DomainResult<Option<Membership>> {
+        let row = sqlx::query_as!(
+            MembershipRow,
+            r#"
+            SELECT workspace_id, user_id, role as "role: WorkspaceRole", invited_by, created_at
+            FROM workspace_members
+            WHERE workspace_id = $1 AND user_id = $2
+            "#,
+            workspace_id,
+            user_id,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn add_member(&self, membership: &Membership) -> DomainResult<Membership> {
+        let row = sqlx::query_as!(
+            MembershipRow,
+            r#"
+            INSERT INTO workspace_members (workspace_id, user_id, role, invited_by, created_at)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (workspace_id, user_id) DO NOTHING
+            RETURNING workspace_id, user_id, role as "role: WorkspaceRole", invited_by, created_at
+            "#,
+            membership.workspace_id,
+            membership.user_id,
+            membership.role as WorkspaceRole,
+            membership.invited_by,
+            membership.created_at,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?
+        .ok_or(DomainError::Conflict(
+            "Usuário já é membro do workspace".to_string(),
+        ))?;
+
+        Ok(row.into())
+    }
+
+    async fn list_members(&self, workspace_id: &Uuid) -> DomainResult<Vec<Membership>> {
+        let rows = sqlx::query_as!(
+            MembershipRow,
+            r#"
+            SELECT workspace_id, user_id, role as "role: WorkspaceRole", invited_by, created_at
+            FROM workspace_members
+            WHERE workspace_id = $1
+            ORDER BY created_at, user_id
+            "#,
+            workspace_id,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+}
+
+/// Row struct para mapeamento do banco
+#[derive(Debug)]
+struct WorkspaceRow {
+    id: Uuid,
+    name: String,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<WorkspaceRow> for Workspace {
+    fn from(row: WorkspaceRow) -> Self {
+        Self {
+            id: row.id,
+            name: row.name,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+/// Row struct para mapeamento do banco
+#[derive(Debug)]
+struct MembershipRow {
+    workspace_id: Uuid,
+    user_id: Uuid,
+    role: WorkspaceRole,
+    invited_by: Option<Uuid>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<MembershipRow> for Membership {
+    fn from(row: MembershipRow) -> Self {
+        Self {
+            workspace_id: row.workspace_id,
+            user_id: row.user_id,
+            role: row.role,
+            invited_by: row.invited_by,
+            created_at: row.created_at,
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use crate::domain::errors::{DomainError, DomainResult};
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+/// Inicia uma transação restrita às tarefas de um workspace
+///
+/// Define `app.workspace_id` na transação; a política de RLS da tabela
+/// `tasks` esconde e recusa as linhas dos demais workspaces.
+pub(crate) async fn begin_in_workspace(
+    pool: &PgPool,
+    workspace_id: Uuid,
+) -> DomainResult<Transaction<'static, Postgres>> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+    sqlx::query!(
+        r#"
+        SELECT set_config('app.workspace_id', $1, true)
+        "#,
+        workspace_id.to_string(),
+    )
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+    Ok(tx)
+}
+
+/// Inicia uma transação com acesso às tarefas de todos os workspaces
+///
+/// Sem `app.workspace_id` a política de RLS não libera nenhuma tarefa; jobs em
+/// background, estatísticas e exportação/eliminação de dados pedem o acesso
+/// entre workspaces explicitamente com `app.bypass_rls`.
+pub(crate) async fn begin_cross_tenant(
+    pool: &PgPool,
+) -> DomainResult<Transaction<'static, Postgres>> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+    sqlx::query!(
+        r#"
+        SELECT set_config('app.bypass_rls', 'on', true)
+        "#,
+    )
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+    Ok(tx)
+}
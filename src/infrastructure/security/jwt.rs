@@ -1,15 +1,36 @@
-use anyhow::{Context, Result};
-use chrono::{Duration, Utc};
-use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation, decode, encode};
+use crate::domain::value_objects::UserRole;
+use crate::infrastructure::security::JwtKeys;
+use anyhow::{Context, Result, anyhow};
+use chrono::{DateTime, Duration, Utc};
+use jsonwebtoken::jwk::JwkSet;
 use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
 use uuid::Uuid;
 
+/// Chaves instaladas na inicialização da aplicação
+static JWT_KEYS: OnceLock<JwtKeys> = OnceLock::new();
+
 /// Claims do JWT
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Claims {
     pub sub: String, // Subject (user_id)
     pub exp: i64,    // Expiration time
     pub iat: i64,    // Issued at
+    /// Identificador único do token (usado na revogação)
+    #[serde(default)]
+    pub jti: String,
+    /// Versão dos tokens do usuário na emissão
+    #[serde(default)]
+    pub ver: i32,
+    /// Sessão (família de refresh tokens) que originou o token
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sid: Option<String>,
+    /// Papel do usuário na emissão (tokens antigos valem como `user`)
+    #[serde(default)]
+    pub role: UserRole,
+    /// Workspace ativo da sessão
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wid: Option<String>,
 }
 
 impl Claims {
@@ -17,46 +38,176 @@ impl Claims {
     pub fn user_id(&self) -> Result<Uuid> {
         Uuid::parse_str(&self.sub).context("ID de usuário inválido no token")
     }
+
+    /// Extrai o identificador único do token
+    pub fn token_id(&self) -> Option<Uuid> {
+        Uuid::parse_str(&self.jti).ok()
+    }
+
+    /// Momento em que o token expira
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        DateTime::from_timestamp(self.exp, 0).unwrap_or_else(Utc::now)
+    }
+
+    /// Verifica se o token foi emitido na versão atual do usuário
+    pub fn matches_version(&self, token_version: i32) -> bool {
+        self.ver == token_version
+    }
+
+    /// Extrai o ID da sessão, se o token foi emitido para uma
+    pub fn session_id(&self) -> Option<Uuid> {
+        self.sid
+            .as_deref()
+            .and_then(|sid| Uuid::parse_str(sid).ok())
+    }
+
+    /// Verifica se o token foi emitido para um administrador
+    pub fn is_admin(&self) -> bool {
+        self.role == UserRole::Admin
+    }
+
+    /// Extrai o workspace ativo, se o token foi emitido para um
+    pub fn workspace_id(&self) -> Option<Uuid> {
+        self.wid
+            .as_deref()
+            .and_then(|wid| Uuid::parse_str(wid).ok())
+    }
 }
 
-/// Gera um JWT para um usuário
-pub fn generate_jwt(user_id: &Uuid) -> Result<String> {
-    let secret = std::env::var("JWT_SECRET").context("JWT_SECRET não definido")?;
+/// Audiência dos tokens de login pendente de segundo fator
+const MFA_PENDING_AUDIENCE: &str = "mfa_pending";
+
+/// Claims do token de login pendente de segundo fator
+///
+/// O `aud` impede que ele seja aceito como access token.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MfaPendingClaims {
+    pub sub: String,
+    pub exp: i64,
+    pub iat: i64,
+    pub aud: String,
+}
+
+impl MfaPendingClaims {
+    /// Extrai o user_id dos claims
+    pub fn user_id(&self) -> Result<Uuid> {
+        Uuid::parse_str(&self.sub).context("ID de usuário inválido no token")
+    }
+}
+
+/// Instala as chaves usadas para assinar e validar tokens (uma vez, na inicialização)
+pub fn install_jwt_keys(keys: JwtKeys) -> Result<()> {
+    JWT_KEYS
+        .set(keys)
+        .map_err(|_| anyhow!("Chaves JWT já foram instaladas"))
+}
+
+/// Chaves públicas de verificação publicadas no JWKS
+pub fn jwks() -> JwkSet {
+    JWT_KEYS
+        .get()
+        .map(JwtKeys::jwks)
+        .unwrap_or(JwkSet { keys: vec![] })
+}
 
+/// Executa com as chaves instaladas ou, na falta delas (ex.: testes), com
+/// HS256 e o `JWT_SECRET` do ambiente
+fn with_keys<T>(f: impl FnOnce(&JwtKeys) -> Result<T>) -> Result<T> {
+    match JWT_KEYS.get() {
+        Some(keys) => f(keys),
+        None => {
+            let secret = std::env::var("JWT_SECRET").context("JWT_SECRET não definido")?;
+            f(&JwtKeys::hmac(&secret))
+        }
+    }
+}
+
+/// Gera um JWT para um usuário
+pub fn generate_jwt(user_id: &Uuid, token_version: i32) -> Result<String> {
     let expiration_hours = std::env::var("JWT_EXPIRATION_HOURS")
         .unwrap_or_else(|_| "24".to_string())
         .parse::<i64>()
         .unwrap_or(24);
 
+    encode_claims(
+        user_id,
+        token_version,
+        UserRole::User,
+        None,
+        None,
+        Duration::hours(expiration_hours),
+    )
+}
+
+/// Gera um access token de curta duração vinculado a uma sessão
+pub fn generate_session_jwt(
+    user_id: &Uuid,
+    token_version: i32,
+    role: UserRole,
+    session_id: &Uuid,
+    workspace_id: Option<&Uuid>,
+    ttl: Duration,
+) -> Result<String> {
+    encode_claims(
+        user_id,
+        token_version,
+        role,
+        Some(session_id),
+        workspace_id,
+        ttl,
+    )
+}
+
+fn encode_claims(
+    user_id: &Uuid,
+    token_version: i32,
+    role: UserRole,
+    session_id: Option<&Uuid>,
+    workspace_id: Option<&Uuid>,
+    ttl: Duration,
+) -> Result<String> {
     let now = Utc::now();
-    let expiration = now + Duration::hours(expiration_hours);
+    let expiration = now + ttl;
 
     let claims = Claims {
         sub: user_id.to_string(),
         exp: expiration.timestamp(),
         iat: now.timestamp(),
+        jti: Uuid::new_v4().to_string(),
+        ver: token_version,
+        sid: session_id.map(ToString::to_string),
+        role,
+        wid: workspace_id.map(ToString::to_string),
     };
 
-    encode(
-        &Header::default(),
-        &claims,
-        &EncodingKey::from_secret(secret.as_bytes()),
-    )
-    .context("Falha ao gerar JWT")
+    with_keys(|keys| keys.encode(&claims))
 }
 
-/// Valida um JWT e retorna os claims
-pub fn validate_jwt(token: &str) -> Result<Claims> {
-    let secret = std::env::var("JWT_SECRET").context("JWT_SECRET não definido")?;
+/// Gera o token de login pendente, trocado pelos tokens da sessão junto com o código 2FA
+pub fn generate_mfa_pending_jwt(user_id: &Uuid, ttl: Duration) -> Result<String> {
+    let now = Utc::now();
 
-    let token_data = decode::<Claims>(
-        token,
-        &DecodingKey::from_secret(secret.as_bytes()),
-        &Validation::default(),
-    )
-    .context("Token inválido ou expirado")?;
+    let claims = MfaPendingClaims {
+        sub: user_id.to_string(),
+        exp: (now + ttl).timestamp(),
+        iat: now.timestamp(),
+        aud: MFA_PENDING_AUDIENCE.to_string(),
+    };
 
-    Ok(token_data.claims)
+    with_keys(|keys| keys.encode(&claims))
+}
+
+/// Valida um token de login pendente de segundo fator
+pub fn validate_mfa_pending_jwt(token: &str) -> Result<MfaPendingClaims> {
+    with_keys(|keys| keys.decode_for_audience(token, MFA_PENDING_AUDIENCE))
+}
+
+/// Valida um JWT e retorna os claims
+///
+/// Verifica apenas assinatura e expiração; revogação (`jti`) e versão (`ver`)
+/// dependem do banco e são checadas pelo `auth_middleware`.
+pub fn validate_jwt(token: &str) -> Result<Claims> {
+    with_keys(|keys| keys.decode(token))
 }
 
 #[cfg(test)]
@@ -69,13 +220,76 @@ mod tests {
         std::env::set_var("JWT_EXPIRATION_HOURS", "1");
 
         let user_id = Uuid::new_v4();
-        let token = generate_jwt(&user_id).unwrap();
+        let token = generate_jwt(&user_id, 0).unwrap();
 
         let claims = validate_jwt(&token).unwrap();
         assert_eq!(claims.sub, user_id.to_string());
         assert_eq!(claims.user_id().unwrap(), user_id);
     }
 
+    #[test]
+    fn test_jwt_carries_jti_and_version() {
+        std::env::set_var("JWT_SECRET", "test-secret-key");
+
+        let user_id = Uuid::new_v4();
+        let first = validate_jwt(&generate_jwt(&user_id, 3).unwrap()).unwrap();
+        let second = validate_jwt(&generate_jwt(&user_id, 3).unwrap()).unwrap();
+
+        assert!(first.token_id().is_some());
+        assert_ne!(first.jti, second.jti);
+        assert!(first.matches_version(3));
+        assert!(!first.matches_version(4));
+    }
+
+    #[test]
+    fn test_session_jwt_carries_session_id() {
+        std::env::set_var("JWT_SECRET", "test-secret-key");
+
+        let user_id = Uuid::new_v4();
+        let session_id = Uuid::new_v4();
+        let workspace_id = Uuid::new_v4();
+        let token = generate_session_jwt(
+            &user_id,
+            0,
+            UserRole::Admin,
+            &session_id,
+            Some(&workspace_id),
+            Duration::minutes(15),
+        )
+        .unwrap();
+
+        let claims = validate_jwt(&token).unwrap();
+        assert_eq!(claims.user_id().unwrap(), user_id);
+        assert_eq!(claims.session_id(), Some(session_id));
+        assert_eq!(claims.workspace_id(), Some(workspace_id));
+        assert!(claims.is_admin());
+        assert!(claims.exp - claims.iat <= 15 * 60);
+
+        // Tokens sem sessão (legado) não carregam privilégios
+        assert!(
+            !validate_jwt(&generate_jwt(&user_id, 0).unwrap())
+                .unwrap()
+                .is_admin()
+        );
+    }
+
+    #[test]
+    fn test_mfa_pending_token_is_not_an_access_token() {
+        std::env::set_var("JWT_SECRET", "test-secret-key");
+
+        let user_id = Uuid::new_v4();
+        let token = generate_mfa_pending_jwt(&user_id, Duration::minutes(5)).unwrap();
+
+        assert_eq!(
+            validate_mfa_pending_jwt(&token).unwrap().user_id().unwrap(),
+            user_id
+        );
+        assert!(validate_jwt(&token).is_err());
+
+        let access_token = generate_jwt(&user_id, 0).unwrap();
+        assert!(validate_mfa_pending_jwt(&access_token).is_err());
+    }
+
     #[test]
     fn test_invalid_token() {
         std::env::set_var("JWT_SECRET", "test-secret-key");
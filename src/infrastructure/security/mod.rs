@@ -1,5 +1,21 @@
 pub mod jwt;
+pub mod jwt_keys;
+pub mod opaque_token;
 pub mod password;
+pub mod token_revocation;
+pub mod totp;
+pub mod trusted_proxies;
 
-pub use jwt::{Claims, generate_jwt, validate_jwt};
-pub use password::{hash_password, verify_password};
+pub use jwt::{
+    Claims, MfaPendingClaims, generate_jwt, generate_mfa_pending_jwt, generate_session_jwt,
+    install_jwt_keys, jwks, validate_jwt, validate_mfa_pending_jwt,
+};
+pub use jwt_keys::JwtKeys;
+pub use opaque_token::{generate_opaque_token, hash_opaque_token};
+pub use password::{dummy_verify_password, hash_password, verify_password};
+pub use token_revocation::TokenRevocationService;
+pub use totp::{
+    generate_recovery_codes, generate_totp_secret, normalize_recovery_code, totp_code, totp_uri,
+    verify_totp,
+};
+pub use trusted_proxies::TrustedProxies;
@@ -0,0 +1,34 @@
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+/// Gera um token opaco (refresh, redefinição de senha...), hexadecimal
+pub fn generate_opaque_token() -> String {
+    format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple())
+}
+
+/// Hash SHA-256 do token opaco (apenas o hash é persistido)
+pub fn hash_opaque_token(token: &str) -> String {
+    format!("{:x}", Sha256::digest(token.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_opaque_token() {
+        let token = generate_opaque_token();
+
+        assert_eq!(token.len(), 64);
+        assert_ne!(token, generate_opaque_token());
+    }
+
+    #[test]
+    fn test_hash_opaque_token() {
+        let token = generate_opaque_token();
+
+        assert_eq!(hash_opaque_token(&token), hash_opaque_token(&token));
+        assert_ne!(hash_opaque_token(&token), token);
+        assert_eq!(hash_opaque_token(&token).len(), 64);
+    }
+}
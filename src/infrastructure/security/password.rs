@@ -1,5 +1,10 @@
 use anyhow::{Context, Result};
 use bcrypt::{DEFAULT_COST, hash, verify};
+use std::sync::LazyLock;
+
+/// Hash de referência usado quando o usuário não existe (mesmo custo dos reais)
+static DUMMY_HASH: LazyLock<String> =
+    LazyLock::new(|| hash_password("senha-de-referencia").unwrap_or_default());
 
 /// Faz hash de uma senha usando bcrypt
 pub fn hash_password(password: &str) -> Result<String> {
@@ -11,6 +16,13 @@ pub fn verify_password(password: &str, hash: &str) -> Result<bool> {
     verify(password, hash).context("Falha ao verificar senha")
 }
 
+/// Executa uma verificação bcrypt descartável
+///
+/// Equaliza o tempo de resposta do login entre emails cadastrados e desconhecidos.
+pub fn dummy_verify_password(password: &str) {
+    let _ = verify(password, &DUMMY_HASH);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
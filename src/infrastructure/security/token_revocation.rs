@@ -0,0 +1,262 @@
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{RevokedTokenRepository, UserRepository};
+use crate::infrastructure::security::Claims;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+/// Quantidade de entradas a partir da qual o cache descarta as vencidas
+const CACHE_PRUNE_THRESHOLD: usize = 10_000;
+
+/// Serviço de revogação de access tokens
+///
+/// Combina a lista de `jti` revogados e a versão de tokens de cada usuário,
+/// ambas persistidas no banco, com um cache em memória para não consultar o
+/// banco a cada requisição. Revogações feitas nesta instância valem
+/// imediatamente; as feitas em outras instâncias em até `cache_ttl`.
+pub struct TokenRevocationService {
+    revoked_token_repository: Arc<dyn RevokedTokenRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    cache_ttl: Duration,
+    cache: RwLock<RevocationCache>,
+}
+
+#[derive(Default)]
+struct RevocationCache {
+    /// Tokens sabidamente revogados (até expirarem)
+    revoked: HashMap<Uuid, DateTime<Utc>>,
+    /// Tokens consultados e não revogados
+    active: HashMap<Uuid, Instant>,
+    /// Versão de tokens de cada usuário
+    versions: HashMap<Uuid, (i32, Instant)>,
+}
+
+impl TokenRevocationService {
+    pub fn new(
+        revoked_token_repository: Arc<dyn RevokedTokenRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        cache_ttl: Duration,
+    ) -> Self {
+        Self {
+            revoked_token_repository,
+            user_repository,
+            cache_ttl,
+            cache: RwLock::new(RevocationCache::default()),
+        }
+    }
+
+    /// Garante que o token não foi revogado nem emitido em versão anterior
+    pub async fn ensure_active(&self, claims: &Claims) -> DomainResult<()> {
+        let user_id = claims.user_id().map_err(|_| DomainError::Unauthorized)?;
+        let jti = claims.token_id().ok_or(DomainError::Unauthorized)?;
+
+        if !claims.matches_version(self.token_version(&user_id).await?) {
+            return Err(DomainError::Unauthorized);
+        }
+
+        if self.is_revoked(&jti, claims.expires_at()).await? {
+            return Err(DomainError::Unauthorized);
+        }
+
+        Ok(())
+    }
+
+    /// Revoga um token até a sua expiração
+    pub async fn revoke(&self, claims: &Claims) -> DomainResult<()> {
+        let user_id = claims.user_id().map_err(|_| DomainError::Unauthorized)?;
+        let jti = claims.token_id().ok_or(DomainError::Unauthorized)?;
+
+        self.revoked_token_repository
+            .revoke(&jti, &user_id, claims.expires_at())
+            .await?;
+
+        let mut cache = self.cache.write().unwrap();
+        cache.active.remove(&jti);
+        cache.revoked.insert(jti, claims.expires_at());
+
+        Ok(())
+    }
+
+    /// Invalida todos os tokens já emitidos para o usuário
+    pub async fn revoke_all(&self, user_id: &Uuid) -> DomainResult<i32> {
+        let version = self
+            .user_repository
+            .increment_token_version(user_id)
+            .await?;
+
+        self.cache
+            .write()
+            .unwrap()
+            .versions
+            .insert(*user_id, (version, Instant::now()));
+
+        Ok(version)
+    }
+
+    /// Versão atual dos tokens do usuário
+    pub async fn token_version(&self, user_id: &Uuid) -> DomainResult<i32> {
+        if let Some((version, fetched_at)) = self.cache.read().unwrap().versions.get(user_id)
+            && fetched_at.elapsed() < self.cache_ttl
+        {
+            return Ok(*version);
+        }
+
+        let version = self
+            .user_repository
+            .find_token_version(user_id)
+            .await?
+            .ok_or(DomainError::Unauthorized)?;
+
+        let mut cache = self.cache.write().unwrap();
+        cache.prune(self.cache_ttl);
+        cache.versions.insert(*user_id, (version, Instant::now()));
+
+        Ok(version)
+    }
+
+    /// Remove do banco e do cache as revogações de tokens já expirados
+    pub async fn purge_expired(&self) -> DomainResult<u64> {
+        let purged = self.revoked_token_repository.purge_expired().await?;
+
+        let now = Utc::now();
+        self.cache
+            .write()
+            .unwrap()
+            .revoked
+            .retain(|_, expires_at| *expires_at > now);
+
+        Ok(purged)
+    }
+
+    async fn is_revoked(&self, jti: &Uuid, expires_at: DateTime<Utc>) -> DomainResult<bool> {
+        {
+            let cache = self.cache.read().unwrap();
+            if cache.revoked.contains_key(jti) {
+                return Ok(true);
+            }
+            if let Some(checked_at) = cache.active.get(jti)
+                && checked_at.elapsed() < self.cache_ttl
+            {
+                return Ok(false);
+            }
+        }
+
+        let revoked = self.revoked_token_repository.is_revoked(jti).await?;
+
+        let mut cache = self.cache.write().unwrap();
+        cache.prune(self.cache_ttl);
+        if revoked {
+            cache.active.remove(jti);
+            cache.revoked.insert(*jti, expires_at);
+        } else {
+            cache.active.insert(*jti, Instant::now());
+        }
+
+        Ok(revoked)
+    }
+}
+
+impl RevocationCache {
+    /// Descarta entradas vencidas quando o cache cresce demais
+    fn prune(&mut self, ttl: Duration) {
+        if self.active.len() + self.versions.len() < CACHE_PRUNE_THRESHOLD {
+            return;
+        }
+
+        self.active
+            .retain(|_, checked_at| checked_at.elapsed() < ttl);
+        self.versions
+            .retain(|_, (_, fetched_at)| fetched_at.elapsed() < ttl);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::User;
+    use crate::domain::value_objects::Email;
+    use crate::infrastructure::security::{generate_jwt, validate_jwt};
+    use crate::test_support::{MockRevokedTokenRepository, MockUserRepository};
+    use std::sync::atomic::Ordering;
+
+    fn users() -> (Uuid, Arc<MockUserRepository>) {
+        let user = User::new(
+            Uuid::new_v4(),
+            Email::new("ana@example.com").unwrap(),
+            "hash".to_string(),
+            "Ana".to_string(),
+        );
+
+        (user.id, Arc::new(MockUserRepository::new(vec![user])))
+    }
+
+    fn claims(user_id: &Uuid, token_version: i32) -> Claims {
+        unsafe { std::env::set_var("JWT_SECRET", "test-secret-key") };
+        validate_jwt(&generate_jwt(user_id, token_version).unwrap()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_revoked_token_is_rejected() {
+        let revoked = Arc::new(MockRevokedTokenRepository::default());
+        let (user_id, users) = users();
+        let service = TokenRevocationService::new(revoked.clone(), users, Duration::from_secs(30));
+        let token = claims(&user_id, 0);
+        let other = claims(&user_id, 0);
+
+        assert!(service.ensure_active(&token).await.is_ok());
+
+        service.revoke(&token).await.unwrap();
+
+        assert!(matches!(
+            service.ensure_active(&token).await,
+            Err(DomainError::Unauthorized)
+        ));
+        assert!(service.ensure_active(&other).await.is_ok());
+        assert!(
+            revoked
+                .revoked
+                .lock()
+                .unwrap()
+                .contains_key(&token.token_id().unwrap())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_revoke_all_invalidates_previous_versions() {
+        let (user_id, users) = users();
+        let service = TokenRevocationService::new(
+            Arc::new(MockRevokedTokenRepository::default()),
+            users,
+            Duration::from_secs(30),
+        );
+        let old = claims(&user_id, 0);
+        assert!(service.ensure_active(&old).await.is_ok());
+
+        let version = service.revoke_all(&user_id).await.unwrap();
+
+        assert_eq!(version, 1);
+        assert!(matches!(
+            service.ensure_active(&old).await,
+            Err(DomainError::Unauthorized)
+        ));
+        assert!(service.ensure_active(&claims(&user_id, 1)).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_lookups_are_cached() {
+        let revoked = Arc::new(MockRevokedTokenRepository::default());
+        let (user_id, users) = users();
+        let service =
+            TokenRevocationService::new(revoked.clone(), users.clone(), Duration::from_secs(30));
+        let token = claims(&user_id, 0);
+
+        for _ in 0..3 {
+            service.ensure_active(&token).await.unwrap();
+        }
+
+        assert_eq!(revoked.lookups.load(Ordering::SeqCst), 1);
+        assert_eq!(users.token_version_lookups.load(Ordering::SeqCst), 1);
+    }
+}
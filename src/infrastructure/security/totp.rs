@@ -0,0 +1,196 @@
+use data_encoding::BASE32_NOPAD;
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use sha1::Sha1;
+
+/// Duração de cada passo de tempo em segundos
+pub const TOTP_PERIOD: i64 = 30;
+
+/// Quantidade de dígitos dos códigos
+pub const TOTP_DIGITS: u32 = 6;
+
+/// Passos aceitos antes e depois do atual (tolerância a relógios dessincronizados)
+const TOTP_SKEW: i64 = 1;
+
+/// Gera um segredo TOTP aleatório de 160 bits em base32
+pub fn generate_totp_secret() -> String {
+    let mut secret = [0u8; 20];
+    rand::rng().fill_bytes(&mut secret);
+    BASE32_NOPAD.encode(&secret)
+}
+
+/// Monta a URI `otpauth://` usada para gerar o QR code nos apps autenticadores
+pub fn totp_uri(issuer: &str, account: &str, secret: &str) -> String {
+    format!(
+        "otpauth://totp/{}:{}?secret={}&issuer={}&algorithm=SHA1&digits={}&period={}",
+        percent_encode(issuer),
+        percent_encode(account),
+        secret,
+        percent_encode(issuer),
+        TOTP_DIGITS,
+        TOTP_PERIOD
+    )
+}
+
+/// Verifica um código TOTP no instante `unix_time`
+///
+/// Retorna o passo de tempo correspondente ao código, usado para impedir
+/// que o mesmo código seja aceito duas vezes.
+pub fn verify_totp(secret: &str, code: &str, unix_time: i64) -> Option<i64> {
+    let code = code.trim();
+    if code.len() != TOTP_DIGITS as usize || !code.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let key = decode_secret(secret)?;
+    let current = unix_time.div_euclid(TOTP_PERIOD);
+
+    (current - TOTP_SKEW..=current + TOTP_SKEW).find(|step| format_code(&key, *step) == code)
+}
+
+/// Calcula o código TOTP de um instante (`None` se o segredo for inválido)
+pub fn totp_code(secret: &str, unix_time: i64) -> Option<String> {
+    let key = decode_secret(secret)?;
+    Some(format_code(&key, unix_time.div_euclid(TOTP_PERIOD)))
+}
+
+/// Gera códigos de recuperação no formato `xxxx-xxxx-xxxx-xxxx` (80 bits cada)
+pub fn generate_recovery_codes(count: usize) -> Vec<String> {
+    (0..count)
+        .map(|_| {
+            let mut bytes = [0u8; 10];
+            rand::rng().fill_bytes(&mut bytes);
+            let encoded = BASE32_NOPAD.encode(&bytes).to_lowercase();
+
+            encoded
+                .as_bytes()
+                .chunks(4)
+                .map(|chunk| std::str::from_utf8(chunk).unwrap_or_default())
+                .collect::<Vec<_>>()
+                .join("-")
+        })
+        .collect()
+}
+
+/// Normaliza um código de recuperação digitado (sem hífens/espaços, minúsculo)
+pub fn normalize_recovery_code(code: &str) -> String {
+    code.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+fn decode_secret(secret: &str) -> Option<Vec<u8>> {
+    BASE32_NOPAD
+        .decode(secret.trim_end_matches('=').as_bytes())
+        .ok()
+}
+
+fn format_code(key: &[u8], step: i64) -> String {
+    format!(
+        "{:0width$}",
+        hotp(key, step as u64),
+        width = TOTP_DIGITS as usize
+    )
+}
+
+/// HOTP (RFC 4226) com HMAC-SHA1
+fn hotp(key: &[u8], counter: u64) -> u32 {
+    let mut mac =
+        Hmac::<Sha1>::new_from_slice(key).expect("HMAC aceita chaves de qualquer tamanho");
+    mac.update(&counter.to_be_bytes());
+    let digest = mac.finalize().into_bytes();
+
+    // Truncamento dinâmico
+    let offset = (digest[19] & 0x0f) as usize;
+    let binary = u32::from_be_bytes([
+        digest[offset] & 0x7f,
+        digest[offset + 1],
+        digest[offset + 2],
+        digest[offset + 3],
+    ]);
+
+    binary % 10u32.pow(TOTP_DIGITS)
+}
+
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Segredo dos vetores de teste da RFC 6238 ("12345678901234567890")
+    fn rfc_secret() -> String {
+        BASE32_NOPAD.encode(b"12345678901234567890")
+    }
+
+    #[test]
+    fn test_rfc6238_vectors() {
+        // Vetores SHA1 da RFC 6238 truncados para 6 dígitos
+        assert_eq!(verify_totp(&rfc_secret(), "287082", 59), Some(1));
+        assert_eq!(
+            verify_totp(&rfc_secret(), "081804", 1111111109),
+            Some(37037036)
+        );
+        assert_eq!(
+            verify_totp(&rfc_secret(), "005924", 1234567890),
+            Some(41152263)
+        );
+    }
+
+    #[test]
+    fn test_verify_accepts_adjacent_step_only() {
+        // Código do passo 1 (t=30..59) ainda vale em t=89, mas não em t=90
+        assert_eq!(verify_totp(&rfc_secret(), "287082", 89), Some(1));
+        assert_eq!(verify_totp(&rfc_secret(), "287082", 90), None);
+        assert_eq!(verify_totp(&rfc_secret(), "28708", 59), None);
+        assert_eq!(verify_totp(&rfc_secret(), "abcdef", 59), None);
+    }
+
+    #[test]
+    fn test_generated_secret_roundtrip() {
+        let secret = generate_totp_secret();
+        let key = BASE32_NOPAD.decode(secret.as_bytes()).unwrap();
+        let now = 1_700_000_000;
+        let code = totp_code(&secret, now).unwrap();
+
+        assert_eq!(key.len(), 20);
+        assert_eq!(verify_totp(&secret, &code, now), Some(now / TOTP_PERIOD));
+    }
+
+    #[test]
+    fn test_totp_uri() {
+        let uri = totp_uri("Todo API", "ana@example.com", "JBSWY3DPEHPK3PXP");
+
+        assert_eq!(
+            uri,
+            "otpauth://totp/Todo%20API:ana%40example.com?secret=JBSWY3DPEHPK3PXP\
+             &issuer=Todo%20API&algorithm=SHA1&digits=6&period=30"
+        );
+    }
+
+    #[test]
+    fn test_recovery_codes_are_unique_and_normalizable() {
+        let codes = generate_recovery_codes(10);
+        let mut unique = codes.clone();
+        unique.sort();
+        unique.dedup();
+
+        assert_eq!(unique.len(), 10);
+        assert_eq!(codes[0].len(), 19);
+        assert_eq!(
+            normalize_recovery_code(&codes[0].to_uppercase()),
+            codes[0].replace('-', "")
+        );
+    }
+}
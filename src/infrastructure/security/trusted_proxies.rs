@@ -0,0 +1,168 @@
+use anyhow::{Context, Result, bail};
+use serde::Deserialize;
+use std::net::IpAddr;
+
+/// Proxies reversos confiáveis (IPs ou faixas CIDR)
+///
+/// O `X-Forwarded-For` só é considerado quando a conexão vem de um desses
+/// proxies; o IP do cliente é o salto mais à direita que não é confiável.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "Vec<String>")]
+pub struct TrustedProxies {
+    ranges: Vec<(IpAddr, u8)>,
+}
+
+impl TrustedProxies {
+    /// Lê a lista de proxies (ex.: `10.0.0.0/8`, `127.0.0.1`, `fd00::/8`)
+    pub fn parse<S: AsRef<str>>(entries: &[S]) -> Result<Self> {
+        let ranges = entries
+            .iter()
+            .map(|entry| parse_range(entry.as_ref().trim()))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self { ranges })
+    }
+
+    /// Indica se o endereço pertence a um proxy confiável
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        let ip = canonical(ip);
+        self.ranges
+            .iter()
+            .any(|(network, prefix)| in_range(ip, *network, *prefix))
+    }
+
+    /// Determina o IP do cliente a partir do socket e do `X-Forwarded-For`
+    ///
+    /// Percorre os saltos da direita para a esquerda enquanto forem proxies
+    /// confiáveis; o primeiro salto não confiável é o cliente. Se a conexão não
+    /// vem de um proxy confiável, o cabeçalho é ignorado.
+    pub fn client_ip(&self, peer: IpAddr, forwarded_for: Option<&str>) -> IpAddr {
+        let mut client = canonical(peer);
+
+        if !self.contains(client) {
+            return client;
+        }
+
+        let hops = forwarded_for.unwrap_or_default().rsplit(',').map(str::trim);
+        for hop in hops {
+            let Ok(ip) = hop.parse::<IpAddr>() else {
+                break;
+            };
+
+            client = canonical(ip);
+            if !self.contains(client) {
+                break;
+            }
+        }
+
+        client
+    }
+}
+
+impl TryFrom<Vec<String>> for TrustedProxies {
+    type Error = anyhow::Error;
+
+    fn try_from(entries: Vec<String>) -> Result<Self> {
+        Self::parse(&entries)
+    }
+}
+
+/// Converte IPv4 mapeado em IPv6 (`::ffff:a.b.c.d`) para IPv4
+fn canonical(ip: IpAddr) -> IpAddr {
+    match ip {
+        IpAddr::V6(v6) => v6.to_ipv4_mapped().map(IpAddr::V4).unwrap_or(ip),
+        ip => ip,
+    }
+}
+
+fn parse_range(entry: &str) -> Result<(IpAddr, u8)> {
+    let (address, prefix) = match entry.split_once('/') {
+        Some((address, prefix)) => (address, Some(prefix)),
+        None => (entry, None),
+    };
+
+    let network: IpAddr = address
+        .parse()
+        .with_context(|| format!("Proxy confiável inválido: {}", entry))?;
+    let max_prefix = if network.is_ipv4() { 32 } else { 128 };
+    let prefix = match prefix {
+        Some(prefix) => prefix
+            .parse::<u8>()
+            .with_context(|| format!("Prefixo CIDR inválido: {}", entry))?,
+        None => max_prefix,
+    };
+
+    if prefix > max_prefix {
+        bail!("Prefixo CIDR inválido: {}", entry);
+    }
+
+    Ok((canonical(network), prefix))
+}
+
+fn in_range(ip: IpAddr, network: IpAddr, prefix: u8) -> bool {
+    match (ip, network) {
+        (IpAddr::V4(ip), IpAddr::V4(network)) => {
+            let mask = u32::MAX.checked_shl(32 - prefix as u32).unwrap_or(0);
+            u32::from(ip) & mask == u32::from(network) & mask
+        }
+        (IpAddr::V6(ip), IpAddr::V6(network)) => {
+            let mask = u128::MAX.checked_shl(128 - prefix as u32).unwrap_or(0);
+            u128::from(ip) & mask == u128::from(network) & mask
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ip(value: &str) -> IpAddr {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_ranges() {
+        let proxies = TrustedProxies::parse(&["10.0.0.0/8", "127.0.0.1", "fd00::/8"]).unwrap();
+
+        assert!(proxies.contains(ip("10.20.30.40")));
+        assert!(proxies.contains(ip("127.0.0.1")));
+        assert!(proxies.contains(ip("::ffff:10.0.0.1")));
+        assert!(proxies.contains(ip("fd12::1")));
+        assert!(!proxies.contains(ip("127.0.0.2")));
+        assert!(!proxies.contains(ip("11.0.0.1")));
+
+        assert!(TrustedProxies::parse(&["10.0.0.0/33"]).is_err());
+        assert!(TrustedProxies::parse(&["proxy.local"]).is_err());
+    }
+
+    #[test]
+    fn test_ignores_forwarded_for_from_untrusted_peer() {
+        let proxies = TrustedProxies::parse(&["10.0.0.1"]).unwrap();
+
+        assert_eq!(
+            proxies.client_ip(ip("203.0.113.9"), Some("1.2.3.4")),
+            ip("203.0.113.9")
+        );
+        assert_eq!(
+            TrustedProxies::default().client_ip(ip("10.0.0.1"), Some("1.2.3.4")),
+            ip("10.0.0.1")
+        );
+    }
+
+    #[test]
+    fn test_takes_right_most_untrusted_hop() {
+        let proxies = TrustedProxies::parse(&["10.0.0.0/8"]).unwrap();
+
+        // O cliente forja o primeiro salto; o proxy acrescenta o IP real
+        assert_eq!(
+            proxies.client_ip(ip("10.0.0.1"), Some("6.6.6.6, 198.51.100.7, 10.0.0.2")),
+            ip("198.51.100.7")
+        );
+        assert_eq!(proxies.client_ip(ip("10.0.0.1"), None), ip("10.0.0.1"));
+        assert_eq!(
+            proxies.client_ip(ip("10.0.0.1"), Some("lixo, 10.0.0.3")),
+            ip("10.0.0.3")
+        );
+    }
+}
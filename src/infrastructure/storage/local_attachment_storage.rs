@@ -0,0 +1,109 @@
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{AttachmentReader, AttachmentStorage};
+use async_trait::async_trait;
+use std::io::ErrorKind;
+use std::path::{Component, Path, PathBuf};
+
+/// Armazenamento de anexos no sistema de arquivos local
+pub struct LocalAttachmentStorage {
+    root: PathBuf,
+}
+
+impl LocalAttachmentStorage {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Resolve a chave para um caminho dentro do diretório raiz
+    fn path_for(&self, key: &str) -> DomainResult<PathBuf> {
+        let relative = Path::new(key);
+        let is_safe = relative
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)));
+
+        if key.is_empty() || !is_safe {
+            return Err(DomainError::Internal(format!(
+                "Chave de armazenamento inválida: {}",
+                key
+            )));
+        }
+
+        Ok(self.root.join(relative))
+    }
+}
+
+#[async_trait]
+impl AttachmentStorage for LocalAttachmentStorage {
+    async fn put(&self, key: &str, content: &[u8]) -> DomainResult<()> {
+        let path = self.path_for(key)?;
+
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| DomainError::Internal(e.to_string()))?;
+        }
+
+        tokio::fs::write(&path, content)
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))
+    }
+
+    async fn get(&self, key: &str) -> DomainResult<AttachmentReader> {
+        let path = self.path_for(key)?;
+
+        match tokio::fs::File::open(&path).await {
+            Ok(file) => Ok(Box::pin(file)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Err(DomainError::NotFound(
+                "Conteúdo do anexo não encontrado".to_string(),
+            )),
+            Err(e) => Err(DomainError::Internal(e.to_string())),
+        }
+    }
+
+    async fn delete(&self, key: &str) -> DomainResult<()> {
+        let path = self.path_for(key)?;
+
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(DomainError::Internal(e.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::AsyncReadExt;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_put_get_delete() {
+        let root = std::env::temp_dir().join(format!("todo-api-attachments-{}", Uuid::new_v4()));
+        let storage = LocalAttachmentStorage::new(&root);
+
+        storage.put("user/file", b"conteudo").await.unwrap();
+
+        let mut content = Vec::new();
+        storage
+            .get("user/file")
+            .await
+            .unwrap()
+            .read_to_end(&mut content)
+            .await
+            .unwrap();
+        assert_eq!(content, b"conteudo");
+
+        storage.delete("user/file").await.unwrap();
+        assert!(storage.get("user/file").await.is_err());
+
+        tokio::fs::remove_dir_all(&root).await.ok();
+    }
+
+    #[tokio::test]
+    async fn test_rejects_path_traversal() {
+        let storage = LocalAttachmentStorage::new(std::env::temp_dir());
+        assert!(storage.put("../escape", b"x").await.is_err());
+        assert!(storage.get("/etc/passwd").await.is_err());
+    }
+}
@@ -0,0 +1,88 @@
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::{AttachmentReader, AttachmentStorage};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::sync::RwLock;
+
+/// Armazenamento de anexos em memória (útil para testes)
+#[derive(Default)]
+pub struct InMemoryAttachmentStorage {
+    files: RwLock<HashMap<String, Vec<u8>>>,
+}
+
+impl InMemoryAttachmentStorage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Verifica se existe conteúdo sob a chave
+    pub fn contains(&self, key: &str) -> bool {
+        self.files
+            .read()
+            .map(|files| files.contains_key(key))
+            .unwrap_or(false)
+    }
+}
+
+#[async_trait]
+impl AttachmentStorage for InMemoryAttachmentStorage {
+    async fn put(&self, key: &str, content: &[u8]) -> DomainResult<()> {
+        self.files
+            .write()
+            .map_err(|e| DomainError::Internal(e.to_string()))?
+            .insert(key.to_string(), content.to_vec());
+
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> DomainResult<AttachmentReader> {
+        let content = self
+            .files
+            .read()
+            .map_err(|e| DomainError::Internal(e.to_string()))?
+            .get(key)
+            .cloned()
+            .ok_or(DomainError::NotFound(
+                "Conteúdo do anexo não encontrado".to_string(),
+            ))?;
+
+        Ok(Box::pin(Cursor::new(content)))
+    }
+
+    async fn delete(&self, key: &str) -> DomainResult<()> {
+        self.files
+            .write()
+            .map_err(|e| DomainError::Internal(e.to_string()))?
+            .remove(key);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::AsyncReadExt;
+
+    #[tokio::test]
+    async fn test_put_get_delete() {
+        let storage = InMemoryAttachmentStorage::new();
+        storage.put("user/file", b"conteudo").await.unwrap();
+        assert!(storage.contains("user/file"));
+
+        let mut content = Vec::new();
+        storage
+            .get("user/file")
+            .await
+            .unwrap()
+            .read_to_end(&mut content)
+            .await
+            .unwrap();
+        assert_eq!(content, b"conteudo");
+
+        storage.delete("user/file").await.unwrap();
+        assert!(!storage.contains("user/file"));
+        assert!(storage.get("user/file").await.is_err());
+    }
+}
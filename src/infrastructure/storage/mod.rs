@@ -0,0 +1,5 @@
+pub mod local_attachment_storage;
+pub mod memory_attachment_storage;
+
+pub use local_attachment_storage::LocalAttachmentStorage;
+pub use memory_attachment_storage::InMemoryAttachmentStorage;
@@ -0,0 +1,305 @@
+use super::signature::{
+    DELIVERY_HEADER, EVENT_HEADER, SIGNATURE_HEADER, TIMESTAMP_HEADER, sign_payload,
+};
+use crate::domain::entities::{Webhook, WebhookDelivery};
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::WebhookClient;
+use crate::domain::value_objects::WebhookDestinationPolicy;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::Utc;
+use reqwest::Url;
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Cliente HTTP de webhooks: envia o payload via POST com assinatura HMAC
+///
+/// Destinos internos são bloqueados pela `WebhookDestinationPolicy` no momento
+/// da conexão (o resolvedor descarta endereços internos, evitando DNS
+/// rebinding), e redirecionamentos não são seguidos.
+pub struct HttpWebhookClient {
+    client: reqwest::Client,
+    policy: Arc<WebhookDestinationPolicy>,
+}
+
+impl HttpWebhookClient {
+    pub fn new(timeout: Duration, policy: WebhookDestinationPolicy) -> Result<Self> {
+        let policy = Arc::new(policy);
+
+        let client = reqwest::Client::builder()
+            .timeout(timeout)
+            .user_agent(concat!("todo-api-webhooks/", env!("CARGO_PKG_VERSION")))
+            .redirect(reqwest::redirect::Policy::none())
+            // Um proxy resolveria o host por conta própria, sem passar pela política
+            .no_proxy()
+            .dns_resolver(GuardedResolver {
+                policy: policy.clone(),
+            })
+            .build()
+            .context("Falha ao criar cliente HTTP de webhooks")?;
+
+        Ok(Self { client, policy })
+    }
+
+    /// Valida a URL e, se o host é um IP literal (que não passa pelo resolvedor), o endereço
+    fn parse_url(&self, url: &str) -> DomainResult<(Url, String)> {
+        let url = Url::parse(url)
+            .map_err(|_| DomainError::ValidationError("URL do webhook inválida".to_string()))?;
+        let host = url
+            .host_str()
+            .ok_or_else(|| DomainError::ValidationError("URL do webhook inválida".to_string()))?
+            .to_string();
+
+        if let Ok(ip) = host
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .parse::<IpAddr>()
+        {
+            self.policy.ensure_allowed(&host, ip)?;
+        }
+
+        Ok((url, host))
+    }
+}
+
+#[async_trait]
+impl WebhookClient for HttpWebhookClient {
+    async fn send(&self, webhook: &Webhook, delivery: &WebhookDelivery) -> DomainResult<u16> {
+        let (url, _) = self.parse_url(&webhook.url)?;
+        let timestamp = Utc::now().timestamp();
+        let signature = sign_payload(&webhook.secret, timestamp, &delivery.payload);
+
+        let response = self
+            .client
+            .post(url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(SIGNATURE_HEADER, signature)
+            .header(TIMESTAMP_HEADER, timestamp.to_string())
+            .header(EVENT_HEADER, delivery.event_type.to_string())
+            .header(DELIVERY_HEADER, delivery.id.to_string())
+            .body(delivery.payload.clone())
+            .send()
+            .await
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        Ok(response.status().as_u16())
+    }
+
+    async fn ensure_allowed_destination(&self, url: &str) -> DomainResult<()> {
+        let (url, host) = self.parse_url(url)?;
+
+        if self.policy.allows_host(&host) {
+            return Ok(());
+        }
+
+        let port = url.port_or_known_default().unwrap_or(80);
+        let addrs = tokio::net::lookup_host((host.as_str(), port))
+            .await
+            .map_err(|_| {
+                DomainError::ValidationError(format!(
+                    "Não foi possível resolver o host do webhook: {}",
+                    host
+                ))
+            })?;
+
+        for addr in addrs {
+            self.policy.ensure_allowed(&host, addr.ip())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Resolvedor DNS que descarta endereços bloqueados pela política de destinos
+struct GuardedResolver {
+    policy: Arc<WebhookDestinationPolicy>,
+}
+
+impl Resolve for GuardedResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let policy = self.policy.clone();
+
+        Box::pin(async move {
+            let host = name.as_str().to_string();
+            let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host.as_str(), 0))
+                .await?
+                .filter(|addr| policy.ensure_allowed(&host, addr.ip()).is_ok())
+                .collect();
+
+            if addrs.is_empty() {
+                return Err(format!("Destino do webhook não permitido: {}", host).into());
+            }
+
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::events::TaskEventKind;
+    use crate::infrastructure::webhooks::verify_signature;
+    use axum::{Router, http::HeaderMap, http::StatusCode, routing::post};
+    use std::sync::{Arc, Mutex};
+    use uuid::Uuid;
+
+    type Captured = Arc<Mutex<Option<(HeaderMap, String)>>>;
+
+    /// Sobe um servidor HTTP local que responde com `status` e captura a requisição
+    async fn stand_in(status: StatusCode) -> (String, Captured) {
+        let captured: Captured = Arc::new(Mutex::new(None));
+        let sink = captured.clone();
+
+        let app = Router::new().route(
+            "/hook",
+            post(move |headers: HeaderMap, body: String| async move {
+                *sink.lock().unwrap() = Some((headers, body));
+                status
+            }),
+        );
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        (format!("http://{}/hook", addr), captured)
+    }
+
+    /// Cliente com o endereço do servidor local liberado
+    fn client() -> HttpWebhookClient {
+        let policy = WebhookDestinationPolicy::new(vec!["127.0.0.1".to_string()]);
+        HttpWebhookClient::new(Duration::from_secs(5), policy).unwrap()
+    }
+
+    fn fixtures(url: String) -> (Webhook, WebhookDelivery) {
+        let webhook = Webhook::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            url,
+            "0123456789abcdef".to_string(),
+            vec![TaskEventKind::TaskCreated],
+        )
+        .unwrap();
+        let delivery = WebhookDelivery::new(
+            webhook.id,
+            Uuid::new_v4(),
+            TaskEventKind::TaskCreated,
+            r#"{"type":"task.created"}"#.to_string(),
+        );
+
+        (webhook, delivery)
+    }
+
+    #[tokio::test]
+    async fn test_send_signs_payload() {
+        let (url, captured) = stand_in(StatusCode::NO_CONTENT).await;
+        let (webhook, delivery) = fixtures(url);
+        let client = client();
+
+        let status = client.send(&webhook, &delivery).await.unwrap();
+        assert_eq!(status, 204);
+
+        let (headers, body) = captured.lock().unwrap().take().unwrap();
+        let header = |name: &str| headers.get(name).unwrap().to_str().unwrap().to_string();
+
+        assert_eq!(body, delivery.payload);
+        assert_eq!(header(EVENT_HEADER), "task.created");
+        assert_eq!(header(DELIVERY_HEADER), delivery.id.to_string());
+        assert!(verify_signature(
+            &webhook.secret,
+            header(TIMESTAMP_HEADER).parse().unwrap(),
+            &body,
+            &header(SIGNATURE_HEADER),
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_send_returns_error_status() {
+        let (url, _) = stand_in(StatusCode::INTERNAL_SERVER_ERROR).await;
+        let (webhook, delivery) = fixtures(url);
+        let client = client();
+
+        assert_eq!(client.send(&webhook, &delivery).await.unwrap(), 500);
+    }
+
+    #[tokio::test]
+    async fn test_send_fails_when_unreachable() {
+        // Porta reservada e liberada em seguida: conexão recusada
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        drop(listener);
+
+        let (webhook, delivery) = fixtures(format!("http://{}/hook", addr));
+        let client = client();
+
+        assert!(client.send(&webhook, &delivery).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_does_not_follow_redirects() {
+        let app = Router::new().route(
+            "/hook",
+            post(|| async { (StatusCode::FOUND, [("location", "http://169.254.169.254/")]) }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let (webhook, delivery) = fixtures(format!("http://{}/hook", addr));
+
+        assert_eq!(client().send(&webhook, &delivery).await.unwrap(), 302);
+    }
+
+    #[tokio::test]
+    async fn test_rejects_internal_destinations() {
+        let client =
+            HttpWebhookClient::new(Duration::from_secs(5), WebhookDestinationPolicy::default())
+                .unwrap();
+
+        for url in [
+            "http://127.0.0.1:9000/hook",
+            "http://localhost/hook",
+            "http://10.0.0.5/hook",
+            "http://169.254.169.254/latest/meta-data",
+            "http://100.64.0.1/hook",
+            "http://[::1]/hook",
+            "http://[fd00::1]/hook",
+            "http://0.0.0.0/hook",
+        ] {
+            assert!(
+                matches!(
+                    client.ensure_allowed_destination(url).await,
+                    Err(DomainError::ValidationError(_))
+                ),
+                "{} deveria ser rejeitada",
+                url
+            );
+
+            // Também na entrega (o resolvedor descarta os endereços internos)
+            let (webhook, delivery) = fixtures(url.to_string());
+            assert!(client.send(&webhook, &delivery).await.is_err());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_allowlisted_host_is_accepted() {
+        let policy = WebhookDestinationPolicy::new(vec!["localhost".to_string()]);
+        let client = HttpWebhookClient::new(Duration::from_secs(5), policy).unwrap();
+
+        assert!(
+            client
+                .ensure_allowed_destination("http://localhost:9000/hook")
+                .await
+                .is_ok()
+        );
+        assert!(
+            client
+                .ensure_allowed_destination("http://127.0.0.1:9000/hook")
+                .await
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,5 @@
+pub mod http_webhook_client;
+pub mod signature;
+
+pub use http_webhook_client::HttpWebhookClient;
+pub use signature::{sign_payload, verify_signature};
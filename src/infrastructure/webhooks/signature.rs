@@ -0,0 +1,96 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Header com a assinatura `sha256=<hex>` do payload
+pub const SIGNATURE_HEADER: &str = "X-Webhook-Signature";
+
+/// Header com o timestamp (segundos desde epoch) incluído na assinatura
+pub const TIMESTAMP_HEADER: &str = "X-Webhook-Timestamp";
+
+/// Header com o tipo do evento (ex: `task.created`)
+pub const EVENT_HEADER: &str = "X-Webhook-Event";
+
+/// Header com o ID da entrega (repetido em novas tentativas)
+pub const DELIVERY_HEADER: &str = "X-Webhook-Delivery";
+
+/// Assina `"{timestamp}.{body}"` com HMAC-SHA256 usando o segredo do webhook
+pub fn sign_payload(secret: &str, timestamp: i64, body: &str) -> String {
+    let digest = new_mac(secret, timestamp, body).finalize().into_bytes();
+    format!("sha256={}", hex::encode(digest))
+}
+
+/// Verifica uma assinatura em tempo constante
+pub fn verify_signature(secret: &str, timestamp: i64, body: &str, signature: &str) -> bool {
+    let Some(Ok(expected)) = signature.strip_prefix("sha256=").map(hex::decode) else {
+        return false;
+    };
+
+    new_mac(secret, timestamp, body)
+        .verify_slice(&expected)
+        .is_ok()
+}
+
+fn new_mac(secret: &str, timestamp: i64, body: &str) -> HmacSha256 {
+    // HMAC aceita chaves de qualquer tamanho
+    let mut mac =
+        HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC aceita qualquer chave");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body.as_bytes());
+    mac
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_and_verify() {
+        let signature = sign_payload(
+            "0123456789abcdef",
+            1_700_000_000,
+            r#"{"type":"task.created"}"#,
+        );
+
+        assert!(signature.starts_with("sha256="));
+        assert_eq!(signature.len(), "sha256=".len() + 64);
+        assert!(verify_signature(
+            "0123456789abcdef",
+            1_700_000_000,
+            r#"{"type":"task.created"}"#,
+            &signature
+        ));
+    }
+
+    #[test]
+    fn test_verify_rejects_tampering() {
+        let signature = sign_payload("0123456789abcdef", 1_700_000_000, "{}");
+
+        assert!(!verify_signature(
+            "0123456789abcdeX",
+            1_700_000_000,
+            "{}",
+            &signature
+        ));
+        assert!(!verify_signature(
+            "0123456789abcdef",
+            1_700_000_001,
+            "{}",
+            &signature
+        ));
+        assert!(!verify_signature(
+            "0123456789abcdef",
+            1_700_000_000,
+            "{ }",
+            &signature
+        ));
+        assert!(!verify_signature(
+            "0123456789abcdef",
+            1_700_000_000,
+            "{}",
+            "sha256=zz"
+        ));
+    }
+}
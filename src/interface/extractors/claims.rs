@@ -33,13 +33,82 @@ where
     }
 }
 
+/// Extractor para obter usuário autenticado com papel de administrador
+#[derive(Debug, Clone)]
+pub struct AdminUser {
+    pub user_id: Uuid,
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for AdminUser
+where
+    S: Send + Sync,
+{
+    type Rejection = AuthError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let claims = parts
+            .extensions
+            .get::<Claims>()
+            .ok_or(AuthError::Unauthorized)?;
+
+        let user_id = claims.user_id().map_err(|_| AuthError::Unauthorized)?;
+
+        if !claims.is_admin() {
+            return Err(AuthError::Forbidden);
+        }
+
+        Ok(AdminUser { user_id })
+    }
+}
+
+/// Extractor para obter usuário autenticado e o workspace ativo do token
+#[derive(Debug, Clone)]
+pub struct WorkspaceMember {
+    pub user_id: Uuid,
+    pub workspace_id: Uuid,
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for WorkspaceMember
+where
+    S: Send + Sync,
+{
+    type Rejection = AuthError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let claims = parts
+            .extensions
+            .get::<Claims>()
+            .ok_or(AuthError::Unauthorized)?;
+
+        let user_id = claims.user_id().map_err(|_| AuthError::Unauthorized)?;
+        let workspace_id = claims.workspace_id().ok_or(AuthError::NoWorkspace)?;
+
+        Ok(WorkspaceMember {
+            user_id,
+            workspace_id,
+        })
+    }
+}
+
 #[derive(Debug)]
 pub enum AuthError {
     Unauthorized,
+    Forbidden,
+    NoWorkspace,
 }
 
 impl IntoResponse for AuthError {
     fn into_response(self) -> Response {
-        (StatusCode::UNAUTHORIZED, "Não autorizado").into_response()
+        match self {
+            AuthError::Unauthorized => (StatusCode::UNAUTHORIZED, "Não autorizado").into_response(),
+            AuthError::Forbidden => {
+                (StatusCode::FORBIDDEN, "Acesso restrito a administradores").into_response()
+            }
+            AuthError::NoWorkspace => {
+                (StatusCode::FORBIDDEN, "Nenhum workspace ativo").into_response()
+            }
+        }
     }
 }
@@ -0,0 +1,98 @@
+use crate::application::dtos::ClientInfoDto;
+use crate::infrastructure::security::TrustedProxies;
+use axum::{
+    async_trait,
+    extract::{ConnectInfo, FromRequestParts},
+    http::{header, request::Parts},
+};
+use std::convert::Infallible;
+use std::net::SocketAddr;
+
+/// Tamanho máximo do User-Agent armazenado na sessão
+const MAX_USER_AGENT_LEN: usize = 255;
+
+/// Extractor com o dispositivo (User-Agent) e o IP de quem faz a requisição
+///
+/// O `X-Forwarded-For` só é considerado quando a conexão vem de um proxy
+/// confiável (`TrustedProxies` nas extensões da requisição).
+#[derive(Debug, Clone)]
+pub struct ClientInfo(pub ClientInfoDto);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for ClientInfo
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let user_agent = parts
+            .headers
+            .get(header::USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.chars().take(MAX_USER_AGENT_LEN).collect());
+
+        let forwarded_for = parts
+            .headers
+            .get("x-forwarded-for")
+            .and_then(|value| value.to_str().ok());
+
+        let ip_address =
+            parts
+                .extensions
+                .get::<ConnectInfo<SocketAddr>>()
+                .map(|ConnectInfo(addr)| {
+                    parts
+                        .extensions
+                        .get::<TrustedProxies>()
+                        .map(|proxies| proxies.client_ip(addr.ip(), forwarded_for))
+                        .unwrap_or(addr.ip())
+                });
+
+        Ok(ClientInfo(ClientInfoDto {
+            user_agent,
+            ip_address,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::Request;
+    use std::net::IpAddr;
+
+    async fn client_ip(peer: &str, forwarded_for: &str) -> Option<IpAddr> {
+        let (mut parts, _) = Request::builder()
+            .header("x-forwarded-for", forwarded_for)
+            .body(())
+            .unwrap()
+            .into_parts();
+        parts
+            .extensions
+            .insert(ConnectInfo(peer.parse::<SocketAddr>().unwrap()));
+        parts
+            .extensions
+            .insert(TrustedProxies::parse(&["10.0.0.0/8"]).unwrap());
+
+        let ClientInfo(client) = ClientInfo::from_request_parts(&mut parts, &())
+            .await
+            .unwrap();
+        client.ip_address
+    }
+
+    #[tokio::test]
+    async fn test_ip_comes_from_trusted_proxies_only() {
+        // Conexão direta: o cabeçalho forjado não troca o IP usado no bloqueio de login
+        assert_eq!(
+            client_ip("203.0.113.9:4000", "1.2.3.4").await,
+            Some("203.0.113.9".parse().unwrap())
+        );
+
+        // Atrás do proxy confiável, vale o salto acrescentado por ele
+        assert_eq!(
+            client_ip("10.0.0.1:4000", "1.2.3.4, 198.51.100.7").await,
+            Some("198.51.100.7".parse().unwrap())
+        );
+    }
+}
@@ -1,3 +1,5 @@
 pub mod claims;
+pub mod client_info;
 
-pub use claims::AuthenticatedUser;
+pub use claims::{AdminUser, AuthenticatedUser, WorkspaceMember};
+pub use client_info::ClientInfo;
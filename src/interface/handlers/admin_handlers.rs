@@ -0,0 +1,112 @@
+use crate::Config;
+use crate::application::dtos::{
+    AdminUserFilterDto, AdminUserResponseDto, PaginatedResponseDto, SystemStatisticsDto,
+};
+use crate::application::services::PasswordResetService;
+use crate::application::use_cases::{
+    AdminResetPasswordUseCase, DisableUserUseCase, EnableUserUseCase, GetSystemStatisticsUseCase,
+    ListUsersUseCase,
+};
+use crate::domain::repositories::{
+    Mailer, PasswordResetRepository, SessionRepository, StatisticsRepository, UserRepository,
+};
+use crate::infrastructure::security::TokenRevocationService;
+use crate::interface::extractors::AdminUser;
+use crate::interface::handlers::auth_handlers::AppError;
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// State compartilhado para handlers de administração
+#[derive(Clone)]
+pub struct AdminState {
+    pub config: Config,
+    pub user_repository: Arc<dyn UserRepository>,
+    pub session_repository: Arc<dyn SessionRepository>,
+    pub token_revocation: Arc<TokenRevocationService>,
+    pub password_reset_repository: Arc<dyn PasswordResetRepository>,
+    pub statistics_repository: Arc<dyn StatisticsRepository>,
+    pub mailer: Arc<dyn Mailer>,
+}
+
+/// Handler: Listar e buscar usuários
+pub async fn list_users_handler(
+    State(state): State<AdminState>,
+    _admin: AdminUser,
+    Query(filter): Query<AdminUserFilterDto>,
+) -> Result<Json<PaginatedResponseDto<AdminUserResponseDto>>, AppError> {
+    // Executar caso de uso
+    let use_case = ListUsersUseCase::new(state.user_repository);
+    let response = use_case.execute(filter).await?;
+
+    Ok(Json(response))
+}
+
+/// Handler: Desativar uma conta
+pub async fn disable_user_handler(
+    State(state): State<AdminState>,
+    AdminUser { user_id: admin_id }: AdminUser,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<AdminUserResponseDto>, AppError> {
+    // Executar caso de uso
+    let use_case = DisableUserUseCase::new(
+        state.user_repository,
+        state.session_repository,
+        state.token_revocation,
+    );
+    let response = use_case.execute(admin_id, user_id).await?;
+
+    Ok(Json(response))
+}
+
+/// Handler: Reativar uma conta
+pub async fn enable_user_handler(
+    State(state): State<AdminState>,
+    _admin: AdminUser,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<AdminUserResponseDto>, AppError> {
+    // Executar caso de uso
+    let use_case = EnableUserUseCase::new(state.user_repository);
+    let response = use_case.execute(user_id).await?;
+
+    Ok(Json(response))
+}
+
+/// Handler: Forçar a redefinição de senha (o link é enviado ao usuário)
+pub async fn admin_reset_password_handler(
+    State(state): State<AdminState>,
+    _admin: AdminUser,
+    Path(user_id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    // Executar caso de uso
+    let use_case = AdminResetPasswordUseCase::new(
+        state.user_repository,
+        state.session_repository,
+        state.token_revocation,
+        PasswordResetService::new(
+            state.password_reset_repository,
+            state.mailer,
+            state.config.password_reset_ttl(),
+            state.config.app_base_url,
+        ),
+    );
+    use_case.execute(user_id).await?;
+
+    Ok(StatusCode::ACCEPTED)
+}
+
+/// Handler: Estatísticas de todo o sistema
+pub async fn system_statistics_handler(
+    State(state): State<AdminState>,
+    _admin: AdminUser,
+) -> Result<Json<SystemStatisticsDto>, AppError> {
+    // Executar caso de uso
+    let use_case = GetSystemStatisticsUseCase::new(state.statistics_repository);
+    let response = use_case.execute().await?;
+
+    Ok(Json(response))
+}
@@ -0,0 +1,189 @@
+use crate::application::dtos::{AttachmentResponseDto, UploadAttachmentDto};
+use crate::application::use_cases::{
+    DeleteAttachmentUseCase, DownloadAttachmentUseCase, ListAttachmentsUseCase,
+    UploadAttachmentUseCase,
+};
+use crate::domain::errors::DomainError;
+use crate::interface::extractors::WorkspaceMember;
+use crate::interface::handlers::auth_handlers::AppError;
+use crate::interface::handlers::task_handlers::TaskState;
+use axum::{
+    Json,
+    body::Body,
+    extract::{Multipart, Path, State},
+    http::{HeaderValue, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use tokio_util::io::ReaderStream;
+use uuid::Uuid;
+
+/// Nome do campo multipart que carrega o arquivo
+const FILE_FIELD: &str = "file";
+
+/// Handler: Listar anexos de uma tarefa
+pub async fn list_attachments_handler(
+    State(state): State<TaskState>,
+    WorkspaceMember {
+        user_id,
+        workspace_id,
+    }: WorkspaceMember,
+    Path(task_id): Path<Uuid>,
+) -> Result<Json<Vec<AttachmentResponseDto>>, AppError> {
+    // Executar caso de uso
+    let use_case = ListAttachmentsUseCase::new(
+        state.tasks(workspace_id),
+        state.collaborator_repository,
+        state.attachment_repository,
+    );
+    let response = use_case.execute(task_id, user_id).await?;
+
+    Ok(Json(response))
+}
+
+/// Handler: Enviar anexo (multipart/form-data, campo `file`)
+pub async fn upload_attachment_handler(
+    State(state): State<TaskState>,
+    WorkspaceMember {
+        user_id,
+        workspace_id,
+    }: WorkspaceMember,
+    Path(task_id): Path<Uuid>,
+    mut multipart: Multipart,
+) -> Result<(StatusCode, Json<AttachmentResponseDto>), AppError> {
+    let quota = state.config.attachment_quota();
+    let invalid =
+        |e: axum::extract::multipart::MultipartError| DomainError::ValidationError(e.body_text());
+
+    // Ler o campo do arquivo, interrompendo se exceder o tamanho máximo
+    let mut upload = None;
+    while let Some(mut field) = multipart.next_field().await.map_err(invalid)? {
+        if field.name() != Some(FILE_FIELD) {
+            continue;
+        }
+
+        let file_name = field.file_name().unwrap_or_default().to_string();
+        let content_type = field.content_type().map(str::to_string);
+        let mut content = Vec::new();
+
+        while let Some(chunk) = field.chunk().await.map_err(invalid)? {
+            content.extend_from_slice(&chunk);
+            quota.ensure_file_size(content.len() as u64)?;
+        }
+
+        upload = Some(UploadAttachmentDto {
+            file_name,
+            content_type,
+            content,
+        });
+        break;
+    }
+
+    let dto = upload.ok_or(DomainError::ValidationError(format!(
+        "Campo '{}' não encontrado no formulário",
+        FILE_FIELD
+    )))?;
+
+    // Executar caso de uso
+    let use_case = UploadAttachmentUseCase::new(
+        state.tasks(workspace_id),
+        state.collaborator_repository,
+        state.attachment_repository,
+        state.attachment_storage,
+        quota,
+    );
+    let response = use_case.execute(task_id, user_id, dto).await?;
+
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// Handler: Baixar anexo (conteúdo enviado em streaming)
+pub async fn download_attachment_handler(
+    State(state): State<TaskState>,
+    WorkspaceMember {
+        user_id,
+        workspace_id,
+    }: WorkspaceMember,
+    Path((task_id, attachment_id)): Path<(Uuid, Uuid)>,
+) -> Result<Response, AppError> {
+    // Executar caso de uso
+    let use_case = DownloadAttachmentUseCase::new(
+        state.tasks(workspace_id),
+        state.collaborator_repository,
+        state.attachment_repository,
+        state.attachment_storage,
+    );
+    let (attachment, reader) = use_case.execute(task_id, attachment_id, user_id).await?;
+
+    let content_type = HeaderValue::from_str(attachment.served_content_type())
+        .unwrap_or(HeaderValue::from_static("application/octet-stream"));
+    let disposition = HeaderValue::from_str(&content_disposition(&attachment.file_name))
+        .unwrap_or(HeaderValue::from_static("attachment"));
+
+    let headers = [
+        (header::CONTENT_TYPE, content_type),
+        (
+            header::CONTENT_LENGTH,
+            HeaderValue::from(attachment.size_bytes),
+        ),
+        (header::CONTENT_DISPOSITION, disposition),
+        (
+            header::X_CONTENT_TYPE_OPTIONS,
+            HeaderValue::from_static("nosniff"),
+        ),
+        (
+            header::ETAG,
+            HeaderValue::from_str(&format!("\"{}\"", attachment.sha256))
+                .unwrap_or(HeaderValue::from_static("\"\"")),
+        ),
+    ];
+
+    Ok((headers, Body::from_stream(ReaderStream::new(reader))).into_response())
+}
+
+/// Handler: Deletar anexo
+pub async fn delete_attachment_handler(
+    State(state): State<TaskState>,
+    WorkspaceMember {
+        user_id,
+        workspace_id,
+    }: WorkspaceMember,
+    Path((task_id, attachment_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, AppError> {
+    // Executar caso de uso
+    let use_case = DeleteAttachmentUseCase::new(
+        state.tasks(workspace_id),
+        state.collaborator_repository,
+        state.attachment_repository,
+        state.attachment_storage,
+    );
+    use_case.execute(task_id, attachment_id, user_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Monta o Content-Disposition com nome ASCII e versão UTF-8 (RFC 6266)
+fn content_disposition(file_name: &str) -> String {
+    let ascii_name: String = file_name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_graphic() || c == ' ' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    let encoded: String = file_name
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'.' | b'-' | b'_' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect();
+
+    format!(
+        "attachment; filename=\"{}\"; filename*=UTF-8''{}",
+        ascii_name.replace('\\', "_"),
+        encoded
+    )
+}
@@ -1,51 +1,355 @@
-use crate::application::dtos::{AuthResponseDto, LoginDto, RegisterUserDto};
-use crate::application::use_cases::{LoginUseCase, RegisterUseCase};
-use crate::domain::repositories::UserRepository;
+use crate::Config;
+use crate::application::dtos::{
+    AuthResponseDto, ForgotPasswordDto, LoginDto, LoginResponseDto, RecoveryCodesResponseDto,
+    RefreshTokenDto, RegisterUserDto, ResendVerificationDto, ResetPasswordDto, SessionResponseDto,
+    TokenResponseDto, TotpCodeDto, TotpEnrollmentResponseDto, UserResponseDto, VerifyEmailQueryDto,
+    VerifyMfaDto,
+};
+use crate::application::services::{
+    EmailVerificationService, LoginProtectionService, PasswordResetQueue, SessionService,
+    TwoFactorService,
+};
+use crate::application::use_cases::{
+    ConfirmTotpUseCase, DisableTotpUseCase, EnrollTotpUseCase, ForgotPasswordUseCase,
+    ListSessionsUseCase, LoginUseCase, LogoutAllUseCase, LogoutUseCase, RefreshTokenUseCase,
+    RegisterUseCase, ResendVerificationUseCase, ResetPasswordUseCase, RevokeSessionUseCase,
+    VerifyEmailUseCase, VerifyMfaLoginUseCase,
+};
+use crate::domain::repositories::{
+    AttachmentStorage, DataRequestRepository, EmailVerificationRepository, LoginAttemptRepository,
+    Mailer, PasswordResetRepository, SessionRepository, TwoFactorRepository, UserRepository,
+    WorkspaceRepository,
+};
+use crate::infrastructure::security::{Claims, TokenRevocationService, validate_jwt};
+use crate::interface::extractors::{AuthenticatedUser, ClientInfo};
 use axum::{
-    Json,
-    extract::State,
-    http::StatusCode,
+    Extension, Json,
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode, header},
     response::{IntoResponse, Response},
 };
 use std::sync::Arc;
+use uuid::Uuid;
 use validator::Validate;
 
 /// State compartilhado para handlers de autenticação
 #[derive(Clone)]
 pub struct AuthState {
+    pub config: Config,
     pub user_repository: Arc<dyn UserRepository>,
+    pub workspace_repository: Arc<dyn WorkspaceRepository>,
+    pub session_repository: Arc<dyn SessionRepository>,
+    pub token_revocation: Arc<TokenRevocationService>,
+    pub password_reset_repository: Arc<dyn PasswordResetRepository>,
+    /// Fila de envios de links de redefinição de senha
+    pub password_reset_queue: PasswordResetQueue,
+    pub email_verification_repository: Arc<dyn EmailVerificationRepository>,
+    pub two_factor_repository: Arc<dyn TwoFactorRepository>,
+    pub login_attempt_repository: Arc<dyn LoginAttemptRepository>,
+    pub data_request_repository: Arc<dyn DataRequestRepository>,
+    /// Armazenamento dos arquivos de exportação de dados
+    pub attachment_storage: Arc<dyn AttachmentStorage>,
+    pub mailer: Arc<dyn Mailer>,
+}
+
+impl AuthState {
+    pub(crate) fn session_service(&self) -> SessionService {
+        SessionService::new(
+            self.user_repository.clone(),
+            self.workspace_repository.clone(),
+            self.session_repository.clone(),
+            self.token_revocation.clone(),
+            self.config.session_ttl(),
+        )
+    }
+
+    pub(crate) fn email_verification_service(&self) -> EmailVerificationService {
+        EmailVerificationService::new(
+            self.email_verification_repository.clone(),
+            self.mailer.clone(),
+            self.config.email_verification_ttl(),
+            self.config.app_base_url.clone(),
+        )
+    }
+
+    fn two_factor_service(&self) -> TwoFactorService {
+        TwoFactorService::new(self.two_factor_repository.clone())
+    }
+
+    fn login_protection_service(&self) -> LoginProtectionService {
+        LoginProtectionService::new(
+            self.login_attempt_repository.clone(),
+            self.config.lockout_policy(),
+        )
+    }
 }
 
 /// Handler: Registrar novo usuário
 pub async fn register_handler(
     State(state): State<AuthState>,
+    ClientInfo(client): ClientInfo,
     Json(dto): Json<RegisterUserDto>,
 ) -> Result<Json<AuthResponseDto>, AppError> {
     // Validar DTO
     dto.validate()?;
 
     // Executar caso de uso
-    let use_case = RegisterUseCase::new(state.user_repository);
-    let response = use_case.execute(dto).await?;
+    let use_case = RegisterUseCase::new(
+        state.user_repository.clone(),
+        state.workspace_repository.clone(),
+        state.session_service(),
+        state.email_verification_service(),
+        state.config.email_verification_policy,
+    );
+    let response = use_case.execute(dto, client).await?;
 
     Ok(Json(response))
 }
 
 /// Handler: Login de usuário
+///
+/// Com o 2FA ativo, responde com um `mfa_token` a ser trocado em `/auth/login/2fa`.
+/// Contas ou IPs bloqueados por excesso de falhas recebem 429 com `Retry-After`.
 pub async fn login_handler(
     State(state): State<AuthState>,
+    ClientInfo(client): ClientInfo,
     Json(dto): Json<LoginDto>,
+) -> Result<Json<LoginResponseDto>, AppError> {
+    // Validar DTO
+    dto.validate()?;
+
+    // Executar caso de uso
+    let use_case = LoginUseCase::new(
+        state.user_repository.clone(),
+        state.two_factor_repository.clone(),
+        state.session_service(),
+        state.login_protection_service(),
+        state.config.email_verification_policy,
+    );
+    let response = use_case.execute(dto, client).await?;
+
+    Ok(Json(response))
+}
+
+/// Handler: Concluir login com o código do segundo fator
+pub async fn verify_mfa_login_handler(
+    State(state): State<AuthState>,
+    ClientInfo(client): ClientInfo,
+    Json(dto): Json<VerifyMfaDto>,
 ) -> Result<Json<AuthResponseDto>, AppError> {
     // Validar DTO
     dto.validate()?;
 
     // Executar caso de uso
-    let use_case = LoginUseCase::new(state.user_repository);
-    let response = use_case.execute(dto).await?;
+    let use_case = VerifyMfaLoginUseCase::new(
+        state.user_repository.clone(),
+        state.two_factor_service(),
+        state.session_service(),
+        state.login_protection_service(),
+    );
+    let response = use_case.execute(dto, client).await?;
+
+    Ok(Json(response))
+}
+
+/// Handler: Renovar access token com o refresh token
+pub async fn refresh_handler(
+    State(state): State<AuthState>,
+    ClientInfo(client): ClientInfo,
+    Json(dto): Json<RefreshTokenDto>,
+) -> Result<Json<TokenResponseDto>, AppError> {
+    // Validar DTO
+    dto.validate()?;
+
+    // Executar caso de uso
+    let use_case = RefreshTokenUseCase::new(state.session_service());
+    let response = use_case.execute(dto, client).await?;
+
+    Ok(Json(response))
+}
+
+/// Handler: Logout (encerra a sessão do refresh token)
+///
+/// Rota pública: o access token é opcional e, se enviado, é revogado junto.
+pub async fn logout_handler(
+    State(state): State<AuthState>,
+    headers: HeaderMap,
+    Json(dto): Json<RefreshTokenDto>,
+) -> Result<StatusCode, AppError> {
+    // Validar DTO
+    dto.validate()?;
+
+    let access_token = headers
+        .get("Authorization")
+        .and_then(|h| h.to_str().ok())
+        .and_then(|h| h.strip_prefix("Bearer "))
+        .and_then(|token| validate_jwt(token).ok());
+
+    // Executar caso de uso
+    let use_case = LogoutUseCase::new(state.session_repository, state.token_revocation);
+    use_case.execute(dto, access_token).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Handler: Sair de todos os dispositivos
+pub async fn logout_all_handler(
+    State(state): State<AuthState>,
+    AuthenticatedUser { user_id }: AuthenticatedUser,
+) -> Result<StatusCode, AppError> {
+    let use_case = LogoutAllUseCase::new(state.session_repository, state.token_revocation);
+    use_case.execute(user_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Handler: Listar sessões ativas do usuário
+pub async fn list_sessions_handler(
+    State(state): State<AuthState>,
+    AuthenticatedUser { user_id }: AuthenticatedUser,
+    Extension(claims): Extension<Claims>,
+) -> Result<Json<Vec<SessionResponseDto>>, AppError> {
+    let use_case = ListSessionsUseCase::new(state.session_repository);
+    let response = use_case.execute(user_id, claims.session_id()).await?;
+
+    Ok(Json(response))
+}
+
+/// Handler: Revogar uma sessão do usuário
+pub async fn revoke_session_handler(
+    State(state): State<AuthState>,
+    AuthenticatedUser { user_id }: AuthenticatedUser,
+    Path(session_id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    let use_case = RevokeSessionUseCase::new(state.session_repository);
+    use_case.execute(user_id, session_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Handler: Confirmar email pelo link enviado
+pub async fn verify_email_handler(
+    State(state): State<AuthState>,
+    Query(query): Query<VerifyEmailQueryDto>,
+) -> Result<Json<UserResponseDto>, AppError> {
+    // Validar DTO
+    query.validate()?;
+
+    // Executar caso de uso
+    let use_case =
+        VerifyEmailUseCase::new(state.user_repository, state.email_verification_repository);
+    let response = use_case.execute(&query.token).await?;
+
+    Ok(Json(response))
+}
+
+/// Handler: Reenviar email de verificação
+///
+/// Responde 202 mesmo para emails não cadastrados ou já verificados.
+pub async fn resend_verification_handler(
+    State(state): State<AuthState>,
+    Json(dto): Json<ResendVerificationDto>,
+) -> Result<StatusCode, AppError> {
+    // Validar DTO
+    dto.validate()?;
+
+    // Executar caso de uso
+    let use_case = ResendVerificationUseCase::new(
+        state.user_repository.clone(),
+        state.email_verification_service(),
+    );
+    use_case.execute(dto).await?;
+
+    Ok(StatusCode::ACCEPTED)
+}
+
+/// Handler: Solicitar redefinição de senha
+///
+/// Responde 202 mesmo para emails não cadastrados.
+pub async fn forgot_password_handler(
+    State(state): State<AuthState>,
+    Json(dto): Json<ForgotPasswordDto>,
+) -> Result<StatusCode, AppError> {
+    // Validar DTO
+    dto.validate()?;
+
+    // Executar caso de uso
+    let use_case = ForgotPasswordUseCase::new(state.user_repository, state.password_reset_queue);
+    use_case.execute(dto).await?;
+
+    Ok(StatusCode::ACCEPTED)
+}
+
+/// Handler: Redefinir senha com o token recebido por email
+pub async fn reset_password_handler(
+    State(state): State<AuthState>,
+    Json(dto): Json<ResetPasswordDto>,
+) -> Result<StatusCode, AppError> {
+    // Validar DTO
+    dto.validate()?;
+
+    // Executar caso de uso
+    let use_case = ResetPasswordUseCase::new(
+        state.user_repository,
+        state.password_reset_repository,
+        state.session_repository,
+        state.token_revocation,
+    );
+    use_case.execute(dto).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Handler: Iniciar inscrição no 2FA (segredo + URI para QR code)
+pub async fn enroll_totp_handler(
+    State(state): State<AuthState>,
+    AuthenticatedUser { user_id }: AuthenticatedUser,
+) -> Result<Json<TotpEnrollmentResponseDto>, AppError> {
+    let use_case = EnrollTotpUseCase::new(
+        state.user_repository,
+        state.two_factor_repository,
+        state.config.totp_issuer,
+    );
+    let response = use_case.execute(user_id).await?;
 
     Ok(Json(response))
 }
 
+/// Handler: Confirmar inscrição no 2FA e obter os códigos de recuperação
+pub async fn confirm_totp_handler(
+    State(state): State<AuthState>,
+    AuthenticatedUser { user_id }: AuthenticatedUser,
+    Json(dto): Json<TotpCodeDto>,
+) -> Result<Json<RecoveryCodesResponseDto>, AppError> {
+    // Validar DTO
+    dto.validate()?;
+
+    // Executar caso de uso
+    let use_case = ConfirmTotpUseCase::new(state.two_factor_repository);
+    let response = use_case.execute(user_id, dto).await?;
+
+    Ok(Json(response))
+}
+
+/// Handler: Desativar o 2FA
+pub async fn disable_totp_handler(
+    State(state): State<AuthState>,
+    AuthenticatedUser { user_id }: AuthenticatedUser,
+    Json(dto): Json<TotpCodeDto>,
+) -> Result<StatusCode, AppError> {
+    // Validar DTO
+    dto.validate()?;
+
+    // Executar caso de uso
+    let use_case = DisableTotpUseCase::new(
+        state.two_factor_repository.clone(),
+        state.two_factor_service(),
+    );
+    use_case.execute(user_id, dto).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
 /// Erro genérico da aplicação
 #[derive(Debug)]
 pub enum AppError {
@@ -69,6 +373,16 @@ impl IntoResponse for AppError {
     fn into_response(self) -> Response {
         use crate::domain::errors::DomainError;
 
+        // Bloqueio temporário: informa quando tentar novamente
+        if let AppError::Domain(err @ DomainError::TooManyAttempts(retry_after)) = &self {
+            return (
+                StatusCode::TOO_MANY_REQUESTS,
+                [(header::RETRY_AFTER, retry_after.to_string())],
+                err.to_string(),
+            )
+                .into_response();
+        }
+
         let (status, message) = match self {
             AppError::Validation(err) => (
                 StatusCode::BAD_REQUEST,
@@ -78,6 +392,7 @@ impl IntoResponse for AppError {
             AppError::Domain(DomainError::Unauthorized) => {
                 (StatusCode::UNAUTHORIZED, "Não autorizado".to_string())
             }
+            AppError::Domain(DomainError::Forbidden(msg)) => (StatusCode::FORBIDDEN, msg),
             AppError::Domain(DomainError::Conflict(msg)) => (StatusCode::CONFLICT, msg),
             AppError::Domain(DomainError::ValidationError(msg)) => (StatusCode::BAD_REQUEST, msg),
             AppError::Domain(err) => {
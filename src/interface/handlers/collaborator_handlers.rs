@@ -0,0 +1,79 @@
+use crate::application::dtos::{CollaboratorResponseDto, InviteCollaboratorDto};
+use crate::application::use_cases::{
+    InviteCollaboratorUseCase, ListCollaboratorsUseCase, RevokeCollaboratorUseCase,
+};
+use crate::interface::extractors::WorkspaceMember;
+use crate::interface::handlers::auth_handlers::AppError;
+use crate::interface::handlers::task_handlers::TaskState;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+/// Handler: Listar colaboradores de uma tarefa
+pub async fn list_collaborators_handler(
+    State(state): State<TaskState>,
+    WorkspaceMember {
+        user_id,
+        workspace_id,
+    }: WorkspaceMember,
+    Path(task_id): Path<Uuid>,
+) -> Result<Json<Vec<CollaboratorResponseDto>>, AppError> {
+    // Executar caso de uso
+    let use_case = ListCollaboratorsUseCase::new(
+        state.tasks(workspace_id),
+        state.collaborator_repository,
+        state.user_repository,
+    );
+    let response = use_case.execute(task_id, user_id).await?;
+
+    Ok(Json(response))
+}
+
+/// Handler: Convidar colaborador
+pub async fn invite_collaborator_handler(
+    State(state): State<TaskState>,
+    WorkspaceMember {
+        user_id,
+        workspace_id,
+    }: WorkspaceMember,
+    Path(task_id): Path<Uuid>,
+    Json(dto): Json<InviteCollaboratorDto>,
+) -> Result<(StatusCode, Json<CollaboratorResponseDto>), AppError> {
+    // Validar DTO
+    dto.validate()?;
+
+    // Executar caso de uso
+    let use_case = InviteCollaboratorUseCase::new(
+        state.tasks(workspace_id),
+        state.collaborator_repository,
+        state.user_repository,
+        state.workspace_repository,
+    );
+    let response = use_case.execute(task_id, user_id, dto).await?;
+
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// Handler: Revogar colaborador
+pub async fn revoke_collaborator_handler(
+    State(state): State<TaskState>,
+    WorkspaceMember {
+        user_id,
+        workspace_id,
+    }: WorkspaceMember,
+    Path((task_id, collaborator_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, AppError> {
+    // Executar caso de uso
+    let use_case = RevokeCollaboratorUseCase::new(
+        state.tasks(workspace_id),
+        state.collaborator_repository,
+        state.user_repository,
+    );
+    use_case.execute(task_id, user_id, collaborator_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,102 @@
+use crate::application::dtos::{CommentResponseDto, CreateCommentDto, UpdateCommentDto};
+use crate::application::use_cases::{
+    CreateCommentUseCase, DeleteCommentUseCase, ListCommentsUseCase, UpdateCommentUseCase,
+};
+use crate::interface::extractors::WorkspaceMember;
+use crate::interface::handlers::auth_handlers::AppError;
+use crate::interface::handlers::task_handlers::TaskState;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+/// Handler: Listar comentários de uma tarefa
+pub async fn list_comments_handler(
+    State(state): State<TaskState>,
+    WorkspaceMember {
+        user_id,
+        workspace_id,
+    }: WorkspaceMember,
+    Path(task_id): Path<Uuid>,
+) -> Result<Json<Vec<CommentResponseDto>>, AppError> {
+    // Executar caso de uso
+    let use_case = ListCommentsUseCase::new(
+        state.tasks(workspace_id),
+        state.collaborator_repository,
+        state.comment_repository,
+    );
+    let response = use_case.execute(task_id, user_id).await?;
+
+    Ok(Json(response))
+}
+
+/// Handler: Comentar em uma tarefa
+pub async fn create_comment_handler(
+    State(state): State<TaskState>,
+    WorkspaceMember {
+        user_id,
+        workspace_id,
+    }: WorkspaceMember,
+    Path(task_id): Path<Uuid>,
+    Json(dto): Json<CreateCommentDto>,
+) -> Result<(StatusCode, Json<CommentResponseDto>), AppError> {
+    // Validar DTO
+    dto.validate()?;
+
+    // Executar caso de uso
+    let use_case = CreateCommentUseCase::new(
+        state.tasks(workspace_id),
+        state.collaborator_repository,
+        state.comment_repository,
+    );
+    let response = use_case.execute(task_id, user_id, dto).await?;
+
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// Handler: Editar comentário
+pub async fn update_comment_handler(
+    State(state): State<TaskState>,
+    WorkspaceMember {
+        user_id,
+        workspace_id,
+    }: WorkspaceMember,
+    Path((task_id, comment_id)): Path<(Uuid, Uuid)>,
+    Json(dto): Json<UpdateCommentDto>,
+) -> Result<Json<CommentResponseDto>, AppError> {
+    // Validar DTO
+    dto.validate()?;
+
+    // Executar caso de uso
+    let use_case = UpdateCommentUseCase::new(
+        state.tasks(workspace_id),
+        state.collaborator_repository,
+        state.comment_repository,
+    );
+    let response = use_case.execute(task_id, comment_id, user_id, dto).await?;
+
+    Ok(Json(response))
+}
+
+/// Handler: Deletar comentário
+pub async fn delete_comment_handler(
+    State(state): State<TaskState>,
+    WorkspaceMember {
+        user_id,
+        workspace_id,
+    }: WorkspaceMember,
+    Path((task_id, comment_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, AppError> {
+    // Executar caso de uso
+    let use_case = DeleteCommentUseCase::new(
+        state.tasks(workspace_id),
+        state.collaborator_repository,
+        state.comment_repository,
+    );
+    use_case.execute(task_id, comment_id, user_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,92 @@
+use crate::application::dtos::{
+    AddDependencyDto, DependencyResponseDto, NextTaskResponseDto, TaskDependenciesResponseDto,
+};
+use crate::application::use_cases::{
+    AddDependencyUseCase, GetDependenciesUseCase, ListNextTasksUseCase, RemoveDependencyUseCase,
+};
+use crate::interface::extractors::WorkspaceMember;
+use crate::interface::handlers::auth_handlers::AppError;
+use crate::interface::handlers::task_handlers::TaskState;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use uuid::Uuid;
+
+/// Handler: Listar dependências de uma tarefa
+pub async fn get_dependencies_handler(
+    State(state): State<TaskState>,
+    WorkspaceMember {
+        user_id,
+        workspace_id,
+    }: WorkspaceMember,
+    Path(task_id): Path<Uuid>,
+) -> Result<Json<TaskDependenciesResponseDto>, AppError> {
+    // Executar caso de uso
+    let use_case = GetDependenciesUseCase::new(
+        state.tasks(workspace_id),
+        state.collaborator_repository,
+        state.dependency_repository,
+    );
+    let response = use_case.execute(task_id, user_id).await?;
+
+    Ok(Json(response))
+}
+
+/// Handler: Adicionar dependência
+pub async fn add_dependency_handler(
+    State(state): State<TaskState>,
+    WorkspaceMember {
+        user_id,
+        workspace_id,
+    }: WorkspaceMember,
+    Path(task_id): Path<Uuid>,
+    Json(dto): Json<AddDependencyDto>,
+) -> Result<(StatusCode, Json<DependencyResponseDto>), AppError> {
+    // Executar caso de uso
+    let use_case = AddDependencyUseCase::new(
+        state.tasks(workspace_id),
+        state.collaborator_repository,
+        state.dependency_repository,
+    );
+    let response = use_case.execute(task_id, user_id, dto).await?;
+
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// Handler: Remover dependência
+pub async fn remove_dependency_handler(
+    State(state): State<TaskState>,
+    WorkspaceMember {
+        user_id,
+        workspace_id,
+    }: WorkspaceMember,
+    Path((task_id, blocked_by_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, AppError> {
+    // Executar caso de uso
+    let use_case = RemoveDependencyUseCase::new(
+        state.tasks(workspace_id),
+        state.collaborator_repository,
+        state.dependency_repository,
+    );
+    use_case.execute(task_id, user_id, blocked_by_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Handler: Listar "o que fazer a seguir"
+pub async fn list_next_tasks_handler(
+    State(state): State<TaskState>,
+    WorkspaceMember {
+        user_id,
+        workspace_id,
+    }: WorkspaceMember,
+) -> Result<Json<Vec<NextTaskResponseDto>>, AppError> {
+    // Executar caso de uso
+    let use_case =
+        ListNextTasksUseCase::new(state.tasks(workspace_id), state.dependency_repository);
+    let response = use_case.execute(user_id).await?;
+
+    Ok(Json(response))
+}
@@ -0,0 +1,18 @@
+use crate::infrastructure::security::jwks;
+use axum::{
+    Json,
+    http::header,
+    response::{IntoResponse, Response},
+};
+
+/// Handler: Chaves públicas de verificação de JWT (JWKS)
+///
+/// Permite que outros serviços validem os tokens sem compartilhar segredo.
+/// Com HS256 a lista é vazia.
+pub async fn jwks_handler() -> Response {
+    (
+        [(header::CACHE_CONTROL, "public, max-age=300")],
+        Json(jwks()),
+    )
+        .into_response()
+}
@@ -1,5 +1,31 @@
+pub mod admin_handlers;
+pub mod attachment_handlers;
 pub mod auth_handlers;
+pub mod collaborator_handlers;
+pub mod comment_handlers;
+pub mod dependency_handlers;
+pub mod jwks_handlers;
+pub mod project_handlers;
+pub mod sse_handlers;
 pub mod task_handlers;
+pub mod user_handlers;
+pub mod webhook_handlers;
+pub mod workflow_handlers;
+pub mod workspace_handlers;
+pub mod ws_handlers;
 
+pub use admin_handlers::*;
+pub use attachment_handlers::*;
 pub use auth_handlers::*;
+pub use collaborator_handlers::*;
+pub use comment_handlers::*;
+pub use dependency_handlers::*;
+pub use jwks_handlers::*;
+pub use project_handlers::*;
+pub use sse_handlers::*;
 pub use task_handlers::*;
+pub use user_handlers::*;
+pub use webhook_handlers::*;
+pub use workflow_handlers::*;
+pub use workspace_handlers::*;
+pub use ws_handlers::*;
@@ -0,0 +1,96 @@
+use crate::application::dtos::{
+    CreateProjectDto, ProjectFilterDto, ProjectResponseDto, UpdateProjectDto,
+};
+use crate::application::use_cases::{
+    CreateProjectUseCase, DeleteProjectUseCase, GetProjectUseCase, ListProjectsUseCase,
+    UpdateProjectUseCase,
+};
+use crate::interface::extractors::WorkspaceMember;
+use crate::interface::handlers::TaskState;
+use crate::interface::handlers::auth_handlers::AppError;
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+/// Handler: Criar projeto no workspace ativo
+pub async fn create_project_handler(
+    State(state): State<TaskState>,
+    WorkspaceMember { workspace_id, .. }: WorkspaceMember,
+    Json(dto): Json<CreateProjectDto>,
+) -> Result<(StatusCode, Json<ProjectResponseDto>), AppError> {
+    // Validar DTO
+    dto.validate()?;
+
+    // Executar caso de uso
+    let use_case =
+        CreateProjectUseCase::new(state.project_repository.clone(), state.tasks(workspace_id));
+    let response = use_case.execute(workspace_id, dto).await?;
+
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// Handler: Listar projetos do workspace ativo
+pub async fn list_projects_handler(
+    State(state): State<TaskState>,
+    WorkspaceMember { workspace_id, .. }: WorkspaceMember,
+    Query(filter): Query<ProjectFilterDto>,
+) -> Result<Json<Vec<ProjectResponseDto>>, AppError> {
+    // Executar caso de uso
+    let use_case =
+        ListProjectsUseCase::new(state.project_repository.clone(), state.tasks(workspace_id));
+    let response = use_case.execute(workspace_id, filter).await?;
+
+    Ok(Json(response))
+}
+
+/// Handler: Obter projeto
+pub async fn get_project_handler(
+    State(state): State<TaskState>,
+    WorkspaceMember { workspace_id, .. }: WorkspaceMember,
+    Path(project_id): Path<Uuid>,
+) -> Result<Json<ProjectResponseDto>, AppError> {
+    // Executar caso de uso
+    let use_case =
+        GetProjectUseCase::new(state.project_repository.clone(), state.tasks(workspace_id));
+    let response = use_case.execute(project_id, workspace_id).await?;
+
+    Ok(Json(response))
+}
+
+/// Handler: Atualizar projeto
+pub async fn update_project_handler(
+    State(state): State<TaskState>,
+    WorkspaceMember { workspace_id, .. }: WorkspaceMember,
+    Path(project_id): Path<Uuid>,
+    Json(dto): Json<UpdateProjectDto>,
+) -> Result<Json<ProjectResponseDto>, AppError> {
+    // Validar DTO
+    dto.validate()?;
+
+    // Executar caso de uso
+    let use_case = UpdateProjectUseCase::new(
+        state.project_repository.clone(),
+        state.tasks(workspace_id),
+        state.workflow_repository,
+    );
+    let response = use_case.execute(project_id, workspace_id, dto).await?;
+
+    Ok(Json(response))
+}
+
+/// Handler: Deletar projeto
+pub async fn delete_project_handler(
+    State(state): State<TaskState>,
+    WorkspaceMember { workspace_id, .. }: WorkspaceMember,
+    Path(project_id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    // Executar caso de uso
+    let use_case = DeleteProjectUseCase::new(state.project_repository);
+    use_case.execute(project_id, workspace_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
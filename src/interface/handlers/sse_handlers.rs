@@ -0,0 +1,80 @@
+use crate::application::use_cases::ListHistorySinceUseCase;
+use crate::domain::errors::DomainError;
+use crate::domain::value_objects::HistoryCursor;
+use crate::interface::extractors::WorkspaceMember;
+use crate::interface::handlers::TaskState;
+use crate::interface::handlers::auth_handlers::AppError;
+use axum::{
+    extract::State,
+    http::HeaderMap,
+    response::sse::{Event, KeepAlive, Sse},
+};
+use futures_util::stream::{self, Stream};
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// Intervalo entre consultas de histórico novo
+const HISTORY_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Intervalo entre heartbeats enviados em conexões ociosas
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Handler: Stream (SSE) do histórico das tarefas do usuário
+///
+/// Cada registro de `TaskHistory` é enviado como evento `task_history`; o `id`
+/// do evento é o cursor usado para retomar a conexão via `Last-Event-ID`.
+pub async fn task_events_handler(
+    State(state): State<TaskState>,
+    WorkspaceMember {
+        user_id,
+        workspace_id,
+    }: WorkspaceMember,
+    headers: HeaderMap,
+) -> Result<Sse<impl Stream<Item = Result<Event, axum::Error>>>, AppError> {
+    // Retomar a partir do último evento recebido, se informado
+    let cursor = match headers.get("Last-Event-ID") {
+        Some(value) => value
+            .to_str()
+            .map_err(|_| DomainError::ValidationError("Last-Event-ID inválido".to_string()))?
+            .parse::<HistoryCursor>()?,
+        None => HistoryCursor::now(),
+    };
+
+    let use_case = ListHistorySinceUseCase::new(state.tasks(workspace_id));
+
+    let events = stream::unfold(
+        (use_case, cursor, VecDeque::new()),
+        move |(use_case, mut cursor, mut pending)| async move {
+            loop {
+                if let Some(history) = pending.pop_front() {
+                    let event = Event::default()
+                        .id(HistoryCursor::from(&history).to_string())
+                        .event("task_history")
+                        .json_data(&history);
+
+                    return Some((event, (use_case, cursor, pending)));
+                }
+
+                match use_case.execute(user_id, &cursor).await {
+                    Ok(batch) => match batch.last() {
+                        Some(last) => {
+                            cursor = HistoryCursor::from(last);
+                            pending.extend(batch);
+                        }
+                        None => tokio::time::sleep(HISTORY_POLL_INTERVAL).await,
+                    },
+                    Err(e) => {
+                        tracing::warn!("Falha ao consultar histórico para SSE: {}", e);
+                        tokio::time::sleep(HISTORY_POLL_INTERVAL).await;
+                    }
+                }
+            }
+        },
+    );
+
+    Ok(Sse::new(events).keep_alive(
+        KeepAlive::new()
+            .interval(HEARTBEAT_INTERVAL)
+            .text("heartbeat"),
+    ))
+}
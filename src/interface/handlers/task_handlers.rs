@@ -1,13 +1,21 @@
+use crate::Config;
 use crate::application::dtos::{
-    CreateTaskDto, PaginatedResponseDto, TaskFilterDto, TaskHistoryResponseDto, TaskResponseDto,
+    BoardQueryDto, BoardResponseDto, CreateTaskDto, DeleteTaskQueryDto, MoveTaskDto,
+    PaginatedResponseDto, ReopenTaskDto, TaskFilterDto, TaskHistoryResponseDto, TaskResponseDto,
     UpdateTaskDto,
 };
 use crate::application::services::TaskService;
 use crate::application::use_cases::{
-    CreateTaskUseCase, DeleteTaskUseCase, GetTaskUseCase, ListTasksUseCase, UpdateTaskUseCase,
+    CreateTaskUseCase, DeleteTaskUseCase, GetBoardUseCase, GetTaskUseCase, ListSubtasksUseCase,
+    ListTasksUseCase, MoveTaskUseCase, ReopenTaskUseCase, UpdateTaskUseCase,
 };
-use crate::domain::repositories::TaskRepository;
-use crate::interface::extractors::AuthenticatedUser;
+use crate::domain::repositories::{
+    AttachmentRepository, AttachmentStorage, CollaboratorRepository, CommentRepository,
+    DependencyRepository, ProjectRepository, TaskRepository, UserRepository, WorkflowRepository,
+    WorkspaceRepository,
+};
+use crate::infrastructure::events::InMemoryEventBus;
+use crate::interface::extractors::WorkspaceMember;
 use crate::interface::handlers::auth_handlers::AppError;
 use axum::{
     Json,
@@ -21,21 +29,52 @@ use validator::Validate;
 /// State compartilhado para handlers de tarefas
 #[derive(Clone)]
 pub struct TaskState {
-    pub task_repository: Arc<dyn TaskRepository>,
+    pub config: Config,
+    /// Tarefas de todos os workspaces: os handlers usam sempre `tasks(workspace_id)`
+    pub cross_tenant_tasks: Arc<dyn TaskRepository>,
+    pub collaborator_repository: Arc<dyn CollaboratorRepository>,
+    pub comment_repository: Arc<dyn CommentRepository>,
+    pub attachment_repository: Arc<dyn AttachmentRepository>,
+    pub attachment_storage: Arc<dyn AttachmentStorage>,
+    pub dependency_repository: Arc<dyn DependencyRepository>,
+    pub user_repository: Arc<dyn UserRepository>,
+    pub workspace_repository: Arc<dyn WorkspaceRepository>,
+    pub project_repository: Arc<dyn ProjectRepository>,
+    pub workflow_repository: Arc<dyn WorkflowRepository>,
+    pub event_bus: Arc<InMemoryEventBus>,
+}
+
+impl TaskState {
+    /// Repositório de tarefas restrito ao workspace ativo
+    pub(crate) fn tasks(&self, workspace_id: Uuid) -> Arc<dyn TaskRepository> {
+        self.cross_tenant_tasks.for_workspace(workspace_id)
+    }
 }
 
 /// Handler: Criar nova tarefa
 pub async fn create_task_handler(
     State(state): State<TaskState>,
-    AuthenticatedUser { user_id }: AuthenticatedUser,
+    WorkspaceMember {
+        user_id,
+        workspace_id,
+    }: WorkspaceMember,
     Json(dto): Json<CreateTaskDto>,
 ) -> Result<(StatusCode, Json<TaskResponseDto>), AppError> {
     // Validar DTO
     dto.validate()?;
 
     // Executar caso de uso
-    let use_case = CreateTaskUseCase::new(state.task_repository);
-    let response = use_case.execute(user_id, dto).await?;
+    let use_case = CreateTaskUseCase::new(
+        state.tasks(workspace_id),
+        state.collaborator_repository,
+        state.event_bus,
+        state.user_repository,
+        state.config.email_verification_policy,
+        state.project_repository,
+        state.workflow_repository,
+        state.config.subtask_completion_policy,
+    );
+    let response = use_case.execute(user_id, workspace_id, dto).await?;
 
     Ok((StatusCode::CREATED, Json(response)))
 }
@@ -43,11 +82,14 @@ pub async fn create_task_handler(
 /// Handler: Listar tarefas com filtros
 pub async fn list_tasks_handler(
     State(state): State<TaskState>,
-    AuthenticatedUser { user_id }: AuthenticatedUser,
+    WorkspaceMember {
+        user_id,
+        workspace_id,
+    }: WorkspaceMember,
     Query(filter): Query<TaskFilterDto>,
 ) -> Result<Json<PaginatedResponseDto<TaskResponseDto>>, AppError> {
     // Executar caso de uso
-    let use_case = ListTasksUseCase::new(state.task_repository);
+    let use_case = ListTasksUseCase::new(state.tasks(workspace_id));
     let response = use_case.execute(user_id, filter).await?;
 
     Ok(Json(response))
@@ -56,11 +98,14 @@ pub async fn list_tasks_handler(
 /// Handler: Obter tarefa específica
 pub async fn get_task_handler(
     State(state): State<TaskState>,
-    AuthenticatedUser { user_id }: AuthenticatedUser,
+    WorkspaceMember {
+        user_id,
+        workspace_id,
+    }: WorkspaceMember,
     Path(task_id): Path<Uuid>,
 ) -> Result<Json<TaskResponseDto>, AppError> {
     // Executar caso de uso
-    let use_case = GetTaskUseCase::new(state.task_repository);
+    let use_case = GetTaskUseCase::new(state.tasks(workspace_id), state.collaborator_repository);
     let response = use_case.execute(task_id, user_id).await?;
 
     Ok(Json(response))
@@ -69,7 +114,10 @@ pub async fn get_task_handler(
 /// Handler: Atualizar tarefa
 pub async fn update_task_handler(
     State(state): State<TaskState>,
-    AuthenticatedUser { user_id }: AuthenticatedUser,
+    WorkspaceMember {
+        user_id,
+        workspace_id,
+    }: WorkspaceMember,
     Path(task_id): Path<Uuid>,
     Json(dto): Json<UpdateTaskDto>,
 ) -> Result<Json<TaskResponseDto>, AppError> {
@@ -77,33 +125,139 @@ pub async fn update_task_handler(
     dto.validate()?;
 
     // Executar caso de uso
-    let use_case = UpdateTaskUseCase::new(state.task_repository);
+    let use_case = UpdateTaskUseCase::new(
+        state.tasks(workspace_id),
+        state.collaborator_repository,
+        state.dependency_repository,
+        state.config.subtask_completion_policy,
+        state.event_bus,
+        state.project_repository,
+        state.workflow_repository,
+    );
+    let response = use_case.execute(task_id, user_id, dto).await?;
+
+    Ok(Json(response))
+}
+
+/// Handler: Mover tarefa no quadro (status e posição)
+pub async fn move_task_handler(
+    State(state): State<TaskState>,
+    WorkspaceMember {
+        user_id,
+        workspace_id,
+    }: WorkspaceMember,
+    Path(task_id): Path<Uuid>,
+    Json(dto): Json<MoveTaskDto>,
+) -> Result<Json<TaskResponseDto>, AppError> {
+    // Executar caso de uso
+    let use_case = MoveTaskUseCase::new(
+        state.tasks(workspace_id),
+        state.collaborator_repository,
+        state.dependency_repository,
+        state.config.subtask_completion_policy,
+        state.event_bus,
+        state.workflow_repository,
+    );
+    let response = use_case.execute(task_id, user_id, dto).await?;
+
+    Ok(Json(response))
+}
+
+/// Handler: Reabrir tarefa concluída ou cancelada
+pub async fn reopen_task_handler(
+    State(state): State<TaskState>,
+    WorkspaceMember {
+        user_id,
+        workspace_id,
+    }: WorkspaceMember,
+    Path(task_id): Path<Uuid>,
+    Json(dto): Json<ReopenTaskDto>,
+) -> Result<Json<TaskResponseDto>, AppError> {
+    // Validar DTO
+    dto.validate()?;
+
+    // Executar caso de uso
+    let use_case = ReopenTaskUseCase::new(
+        state.tasks(workspace_id),
+        state.collaborator_repository,
+        state.workflow_repository,
+        state.config.reopen_policy,
+        state.config.subtask_completion_policy,
+        state.event_bus,
+    );
     let response = use_case.execute(task_id, user_id, dto).await?;
 
     Ok(Json(response))
 }
 
+/// Handler: Quadro kanban do workspace ativo
+pub async fn get_board_handler(
+    State(state): State<TaskState>,
+    WorkspaceMember {
+        user_id,
+        workspace_id,
+    }: WorkspaceMember,
+    Query(query): Query<BoardQueryDto>,
+) -> Result<Json<BoardResponseDto>, AppError> {
+    // Executar caso de uso
+    let use_case = GetBoardUseCase::new(state.tasks(workspace_id));
+    let response = use_case.execute(user_id, query).await?;
+
+    Ok(Json(response))
+}
+
 /// Handler: Deletar tarefa
 pub async fn delete_task_handler(
     State(state): State<TaskState>,
-    AuthenticatedUser { user_id }: AuthenticatedUser,
+    WorkspaceMember {
+        user_id,
+        workspace_id,
+    }: WorkspaceMember,
     Path(task_id): Path<Uuid>,
+    Query(params): Query<DeleteTaskQueryDto>,
 ) -> Result<StatusCode, AppError> {
     // Executar caso de uso
-    let use_case = DeleteTaskUseCase::new(state.task_repository);
-    use_case.execute(task_id, user_id).await?;
+    let use_case = DeleteTaskUseCase::new(
+        state.tasks(workspace_id),
+        state.collaborator_repository,
+        state.attachment_storage,
+        state.event_bus,
+    );
+    use_case
+        .execute(task_id, user_id, params.subtasks.unwrap_or_default())
+        .await?;
 
     Ok(StatusCode::NO_CONTENT)
 }
 
+/// Handler: Listar subtarefas de uma tarefa
+pub async fn list_subtasks_handler(
+    State(state): State<TaskState>,
+    WorkspaceMember {
+        user_id,
+        workspace_id,
+    }: WorkspaceMember,
+    Path(task_id): Path<Uuid>,
+) -> Result<Json<Vec<TaskResponseDto>>, AppError> {
+    // Executar caso de uso
+    let use_case =
+        ListSubtasksUseCase::new(state.tasks(workspace_id), state.collaborator_repository);
+    let response = use_case.execute(task_id, user_id).await?;
+
+    Ok(Json(response))
+}
+
 /// Handler: Obter histórico de tarefa
 pub async fn get_task_history_handler(
     State(state): State<TaskState>,
-    AuthenticatedUser { user_id }: AuthenticatedUser,
+    WorkspaceMember {
+        user_id,
+        workspace_id,
+    }: WorkspaceMember,
     Path(task_id): Path<Uuid>,
 ) -> Result<Json<Vec<TaskHistoryResponseDto>>, AppError> {
     // Executar serviço
-    let service = TaskService::new(state.task_repository);
+    let service = TaskService::new(state.tasks(workspace_id), state.collaborator_repository);
     let response = service.get_task_history(task_id, user_id).await?;
 
     Ok(Json(response))
@@ -0,0 +1,180 @@
+use crate::application::dtos::{
+    ChangeEmailDto, ChangePasswordDto, DataRequestResponseDto, DeleteAccountDto, TokenResponseDto,
+    UpdateProfileDto, UserResponseDto,
+};
+use crate::application::use_cases::{
+    ChangeEmailUseCase, ChangePasswordUseCase, DeleteAccountUseCase, DownloadDataExportUseCase,
+    GetDataExportUseCase, GetDataRequestUseCase, GetProfileUseCase, RequestDataExportUseCase,
+    UpdateProfileUseCase,
+};
+use crate::interface::extractors::{AuthenticatedUser, ClientInfo};
+use crate::interface::handlers::auth_handlers::{AppError, AuthState};
+use axum::{
+    Json,
+    body::Body,
+    extract::{Path, State},
+    http::{HeaderValue, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use tokio_util::io::ReaderStream;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Handler: Obter o perfil do usuário autenticado
+pub async fn get_profile_handler(
+    State(state): State<AuthState>,
+    AuthenticatedUser { user_id }: AuthenticatedUser,
+) -> Result<Json<UserResponseDto>, AppError> {
+    // Executar caso de uso
+    let use_case = GetProfileUseCase::new(state.user_repository);
+    let response = use_case.execute(user_id).await?;
+
+    Ok(Json(response))
+}
+
+/// Handler: Atualizar o perfil do usuário autenticado
+pub async fn update_profile_handler(
+    State(state): State<AuthState>,
+    AuthenticatedUser { user_id }: AuthenticatedUser,
+    Json(dto): Json<UpdateProfileDto>,
+) -> Result<Json<UserResponseDto>, AppError> {
+    // Validar DTO
+    dto.validate()?;
+
+    // Executar caso de uso
+    let use_case = UpdateProfileUseCase::new(state.user_repository);
+    let response = use_case.execute(user_id, dto).await?;
+
+    Ok(Json(response))
+}
+
+/// Handler: Trocar a senha
+///
+/// Encerra as demais sessões e retorna um novo par de tokens.
+pub async fn change_password_handler(
+    State(state): State<AuthState>,
+    AuthenticatedUser { user_id }: AuthenticatedUser,
+    ClientInfo(client): ClientInfo,
+    Json(dto): Json<ChangePasswordDto>,
+) -> Result<Json<TokenResponseDto>, AppError> {
+    // Validar DTO
+    dto.validate()?;
+
+    // Executar caso de uso
+    let use_case = ChangePasswordUseCase::new(
+        state.user_repository.clone(),
+        state.session_repository.clone(),
+        state.token_revocation.clone(),
+        state.session_service(),
+    );
+    let response = use_case.execute(user_id, dto, client).await?;
+
+    Ok(Json(response))
+}
+
+/// Handler: Solicitar a troca de email (confirmada pelo link enviado ao novo endereço)
+pub async fn change_email_handler(
+    State(state): State<AuthState>,
+    AuthenticatedUser { user_id }: AuthenticatedUser,
+    Json(dto): Json<ChangeEmailDto>,
+) -> Result<StatusCode, AppError> {
+    // Validar DTO
+    dto.validate()?;
+
+    // Executar caso de uso
+    let use_case = ChangeEmailUseCase::new(
+        state.user_repository.clone(),
+        state.email_verification_service(),
+    );
+    use_case.execute(user_id, dto).await?;
+
+    Ok(StatusCode::ACCEPTED)
+}
+
+/// Handler: Excluir a conta
+///
+/// A eliminação é processada em background; o andamento pode ser consultado
+/// em `/users/me/data-requests/:id`.
+pub async fn delete_account_handler(
+    State(state): State<AuthState>,
+    AuthenticatedUser { user_id }: AuthenticatedUser,
+    Json(dto): Json<DeleteAccountDto>,
+) -> Result<(StatusCode, Json<DataRequestResponseDto>), AppError> {
+    // Validar DTO
+    dto.validate()?;
+
+    // Executar caso de uso
+    let use_case = DeleteAccountUseCase::new(
+        state.user_repository,
+        state.data_request_repository,
+        state.workspace_repository,
+        state.token_revocation,
+    );
+    let response = use_case.execute(user_id, dto).await?;
+
+    Ok((StatusCode::ACCEPTED, Json(response)))
+}
+
+/// Handler: Solicitar a exportação dos dados pessoais
+pub async fn request_data_export_handler(
+    State(state): State<AuthState>,
+    AuthenticatedUser { user_id }: AuthenticatedUser,
+) -> Result<(StatusCode, Json<DataRequestResponseDto>), AppError> {
+    // Executar caso de uso
+    let use_case = RequestDataExportUseCase::new(state.data_request_repository);
+    let response = use_case.execute(user_id).await?;
+
+    Ok((StatusCode::ACCEPTED, Json(response)))
+}
+
+/// Handler: Situação da exportação de dados mais recente
+pub async fn get_data_export_handler(
+    State(state): State<AuthState>,
+    AuthenticatedUser { user_id }: AuthenticatedUser,
+) -> Result<Json<DataRequestResponseDto>, AppError> {
+    // Executar caso de uso
+    let use_case = GetDataExportUseCase::new(state.data_request_repository);
+    let response = use_case.execute(user_id).await?;
+
+    Ok(Json(response))
+}
+
+/// Handler: Baixar o arquivo da exportação de dados (JSON em streaming)
+pub async fn download_data_export_handler(
+    State(state): State<AuthState>,
+    AuthenticatedUser { user_id }: AuthenticatedUser,
+) -> Result<Response, AppError> {
+    // Executar caso de uso
+    let use_case =
+        DownloadDataExportUseCase::new(state.data_request_repository, state.attachment_storage);
+    let (request, reader) = use_case.execute(user_id).await?;
+
+    let disposition = HeaderValue::from_str(&format!(
+        "attachment; filename=\"dados-{}.json\"",
+        request.id
+    ))
+    .unwrap_or(HeaderValue::from_static("attachment"));
+
+    let headers = [
+        (
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        ),
+        (header::CONTENT_DISPOSITION, disposition),
+    ];
+
+    Ok((headers, Body::from_stream(ReaderStream::new(reader))).into_response())
+}
+
+/// Handler: Situação de uma solicitação de exportação ou eliminação
+pub async fn get_data_request_handler(
+    State(state): State<AuthState>,
+    AuthenticatedUser { user_id }: AuthenticatedUser,
+    Path(request_id): Path<Uuid>,
+) -> Result<Json<DataRequestResponseDto>, AppError> {
+    // Executar caso de uso
+    let use_case = GetDataRequestUseCase::new(state.data_request_repository);
+    let response = use_case.execute(user_id, request_id).await?;
+
+    Ok(Json(response))
+}
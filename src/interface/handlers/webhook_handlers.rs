@@ -0,0 +1,113 @@
+use crate::application::dtos::{
+    CreateWebhookDto, UpdateWebhookDto, WebhookDeliveryQueryDto, WebhookDeliveryResponseDto,
+    WebhookResponseDto,
+};
+use crate::application::use_cases::{
+    CreateWebhookUseCase, DeleteWebhookUseCase, GetWebhookUseCase, ListWebhookDeliveriesUseCase,
+    ListWebhooksUseCase, UpdateWebhookUseCase,
+};
+use crate::domain::repositories::{WebhookClient, WebhookDeliveryRepository, WebhookRepository};
+use crate::interface::extractors::AuthenticatedUser;
+use crate::interface::handlers::auth_handlers::AppError;
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+use validator::Validate;
+
+/// State compartilhado para handlers de webhooks
+#[derive(Clone)]
+pub struct WebhookState {
+    pub webhook_repository: Arc<dyn WebhookRepository>,
+    pub delivery_repository: Arc<dyn WebhookDeliveryRepository>,
+    pub webhook_client: Arc<dyn WebhookClient>,
+}
+
+/// Handler: Cadastrar webhook
+pub async fn create_webhook_handler(
+    State(state): State<WebhookState>,
+    AuthenticatedUser { user_id }: AuthenticatedUser,
+    Json(dto): Json<CreateWebhookDto>,
+) -> Result<(StatusCode, Json<WebhookResponseDto>), AppError> {
+    // Validar DTO
+    dto.validate()?;
+
+    // Executar caso de uso
+    let use_case = CreateWebhookUseCase::new(state.webhook_repository, state.webhook_client);
+    let response = use_case.execute(user_id, dto).await?;
+
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// Handler: Listar webhooks do usuário
+pub async fn list_webhooks_handler(
+    State(state): State<WebhookState>,
+    AuthenticatedUser { user_id }: AuthenticatedUser,
+) -> Result<Json<Vec<WebhookResponseDto>>, AppError> {
+    // Executar caso de uso
+    let use_case = ListWebhooksUseCase::new(state.webhook_repository);
+    let response = use_case.execute(user_id).await?;
+
+    Ok(Json(response))
+}
+
+/// Handler: Obter webhook
+pub async fn get_webhook_handler(
+    State(state): State<WebhookState>,
+    AuthenticatedUser { user_id }: AuthenticatedUser,
+    Path(webhook_id): Path<Uuid>,
+) -> Result<Json<WebhookResponseDto>, AppError> {
+    // Executar caso de uso
+    let use_case = GetWebhookUseCase::new(state.webhook_repository);
+    let response = use_case.execute(webhook_id, user_id).await?;
+
+    Ok(Json(response))
+}
+
+/// Handler: Atualizar webhook
+pub async fn update_webhook_handler(
+    State(state): State<WebhookState>,
+    AuthenticatedUser { user_id }: AuthenticatedUser,
+    Path(webhook_id): Path<Uuid>,
+    Json(dto): Json<UpdateWebhookDto>,
+) -> Result<Json<WebhookResponseDto>, AppError> {
+    // Validar DTO
+    dto.validate()?;
+
+    // Executar caso de uso
+    let use_case = UpdateWebhookUseCase::new(state.webhook_repository, state.webhook_client);
+    let response = use_case.execute(webhook_id, user_id, dto).await?;
+
+    Ok(Json(response))
+}
+
+/// Handler: Deletar webhook
+pub async fn delete_webhook_handler(
+    State(state): State<WebhookState>,
+    AuthenticatedUser { user_id }: AuthenticatedUser,
+    Path(webhook_id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    // Executar caso de uso
+    let use_case = DeleteWebhookUseCase::new(state.webhook_repository);
+    use_case.execute(webhook_id, user_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Handler: Listar log de entregas de um webhook
+pub async fn list_webhook_deliveries_handler(
+    State(state): State<WebhookState>,
+    AuthenticatedUser { user_id }: AuthenticatedUser,
+    Path(webhook_id): Path<Uuid>,
+    Query(query): Query<WebhookDeliveryQueryDto>,
+) -> Result<Json<Vec<WebhookDeliveryResponseDto>>, AppError> {
+    // Executar caso de uso
+    let use_case =
+        ListWebhookDeliveriesUseCase::new(state.webhook_repository, state.delivery_repository);
+    let response = use_case.execute(webhook_id, user_id, query).await?;
+
+    Ok(Json(response))
+}
@@ -0,0 +1,68 @@
+use crate::application::dtos::{CreateWorkflowDto, WorkflowResponseDto};
+use crate::application::use_cases::{
+    CreateWorkflowUseCase, DeleteWorkflowUseCase, GetWorkflowUseCase, ListWorkflowsUseCase,
+};
+use crate::interface::extractors::WorkspaceMember;
+use crate::interface::handlers::TaskState;
+use crate::interface::handlers::auth_handlers::AppError;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+/// Handler: Criar workflow no workspace ativo
+pub async fn create_workflow_handler(
+    State(state): State<TaskState>,
+    WorkspaceMember { workspace_id, .. }: WorkspaceMember,
+    Json(dto): Json<CreateWorkflowDto>,
+) -> Result<(StatusCode, Json<WorkflowResponseDto>), AppError> {
+    // Validar DTO
+    dto.validate()?;
+
+    // Executar caso de uso
+    let use_case = CreateWorkflowUseCase::new(state.workflow_repository);
+    let response = use_case.execute(workspace_id, dto).await?;
+
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// Handler: Listar workflows do workspace ativo
+pub async fn list_workflows_handler(
+    State(state): State<TaskState>,
+    WorkspaceMember { workspace_id, .. }: WorkspaceMember,
+) -> Result<Json<Vec<WorkflowResponseDto>>, AppError> {
+    // Executar caso de uso
+    let use_case = ListWorkflowsUseCase::new(state.workflow_repository);
+    let response = use_case.execute(workspace_id).await?;
+
+    Ok(Json(response))
+}
+
+/// Handler: Obter workflow
+pub async fn get_workflow_handler(
+    State(state): State<TaskState>,
+    WorkspaceMember { workspace_id, .. }: WorkspaceMember,
+    Path(workflow_id): Path<Uuid>,
+) -> Result<Json<WorkflowResponseDto>, AppError> {
+    // Executar caso de uso
+    let use_case = GetWorkflowUseCase::new(state.workflow_repository);
+    let response = use_case.execute(workflow_id, workspace_id).await?;
+
+    Ok(Json(response))
+}
+
+/// Handler: Deletar workflow
+pub async fn delete_workflow_handler(
+    State(state): State<TaskState>,
+    WorkspaceMember { workspace_id, .. }: WorkspaceMember,
+    Path(workflow_id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    // Executar caso de uso
+    let use_case = DeleteWorkflowUseCase::new(state.workflow_repository);
+    use_case.execute(workflow_id, workspace_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,98 @@
+use crate::application::dtos::{
+    CreateWorkspaceDto, InviteMemberDto, MemberResponseDto, RefreshTokenDto, TokenResponseDto,
+    WorkspaceResponseDto,
+};
+use crate::application::use_cases::{
+    CreateWorkspaceUseCase, InviteMemberUseCase, ListMembersUseCase, ListWorkspacesUseCase,
+    SwitchWorkspaceUseCase,
+};
+use crate::infrastructure::security::Claims;
+use crate::interface::extractors::{AuthenticatedUser, ClientInfo};
+use crate::interface::handlers::auth_handlers::{AppError, AuthState};
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+/// Handler: Criar workspace
+pub async fn create_workspace_handler(
+    State(state): State<AuthState>,
+    AuthenticatedUser { user_id }: AuthenticatedUser,
+    Json(dto): Json<CreateWorkspaceDto>,
+) -> Result<(StatusCode, Json<WorkspaceResponseDto>), AppError> {
+    // Validar DTO
+    dto.validate()?;
+
+    // Executar caso de uso
+    let use_case = CreateWorkspaceUseCase::new(state.workspace_repository);
+    let response = use_case.execute(user_id, dto).await?;
+
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// Handler: Listar workspaces do usuário
+pub async fn list_workspaces_handler(
+    State(state): State<AuthState>,
+    AuthenticatedUser { user_id }: AuthenticatedUser,
+) -> Result<Json<Vec<WorkspaceResponseDto>>, AppError> {
+    let use_case = ListWorkspacesUseCase::new(state.workspace_repository);
+    let response = use_case.execute(user_id).await?;
+
+    Ok(Json(response))
+}
+
+/// Handler: Listar membros de um workspace
+pub async fn list_members_handler(
+    State(state): State<AuthState>,
+    AuthenticatedUser { user_id }: AuthenticatedUser,
+    Path(workspace_id): Path<Uuid>,
+) -> Result<Json<Vec<MemberResponseDto>>, AppError> {
+    let use_case = ListMembersUseCase::new(state.workspace_repository, state.user_repository);
+    let response = use_case.execute(workspace_id, user_id).await?;
+
+    Ok(Json(response))
+}
+
+/// Handler: Convidar membro para um workspace
+pub async fn invite_member_handler(
+    State(state): State<AuthState>,
+    AuthenticatedUser { user_id }: AuthenticatedUser,
+    Path(workspace_id): Path<Uuid>,
+    Json(dto): Json<InviteMemberDto>,
+) -> Result<(StatusCode, Json<MemberResponseDto>), AppError> {
+    // Validar DTO
+    dto.validate()?;
+
+    // Executar caso de uso
+    let use_case = InviteMemberUseCase::new(state.workspace_repository, state.user_repository);
+    let response = use_case.execute(workspace_id, user_id, dto).await?;
+
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// Handler: Trocar o workspace ativo
+///
+/// Recebe o refresh token da sessão e devolve um novo par de tokens no workspace escolhido.
+pub async fn switch_workspace_handler(
+    State(state): State<AuthState>,
+    Extension(claims): Extension<Claims>,
+    ClientInfo(client): ClientInfo,
+    Path(workspace_id): Path<Uuid>,
+    Json(dto): Json<RefreshTokenDto>,
+) -> Result<Json<TokenResponseDto>, AppError> {
+    // Validar DTO
+    dto.validate()?;
+
+    // Executar caso de uso
+    let use_case = SwitchWorkspaceUseCase::new(
+        state.workspace_repository.clone(),
+        state.session_service(),
+        state.token_revocation,
+    );
+    let response = use_case.execute(workspace_id, claims, dto, client).await?;
+
+    Ok(Json(response))
+}
@@ -0,0 +1,160 @@
+use crate::application::dtos::TaskEventMessageDto;
+use crate::domain::events::TaskEventKind;
+use crate::domain::repositories::CollaboratorRepository;
+use crate::domain::services::TaskAccessPolicy;
+use crate::infrastructure::events::InMemoryEventBus;
+use crate::infrastructure::middleware::auth::AuthError;
+use crate::infrastructure::security::{Claims, TokenRevocationService, validate_jwt};
+use axum::{
+    extract::{
+        Query, State,
+        ws::{CloseFrame, Message, WebSocket, WebSocketUpgrade, close_code},
+    },
+    http::HeaderMap,
+    response::Response,
+};
+use chrono::Utc;
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast::error::RecvError;
+use uuid::Uuid;
+
+/// Intervalo entre as verificações de revogação do token de uma conexão aberta
+const REVOCATION_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// State compartilhado para o handler de WebSocket
+#[derive(Clone)]
+pub struct WsState {
+    pub event_bus: Arc<InMemoryEventBus>,
+    pub token_revocation: Arc<TokenRevocationService>,
+    pub collaborator_repository: Arc<dyn CollaboratorRepository>,
+}
+
+/// Parâmetros de conexão do WebSocket
+///
+/// Navegadores não permitem headers customizados no handshake, por isso o
+/// token também pode ser enviado na query string.
+#[derive(Debug, Deserialize)]
+pub struct WsQuery {
+    pub token: Option<String>,
+}
+
+/// Handler: Conectar ao canal de notificações em tempo real
+pub async fn ws_handler(
+    State(state): State<WsState>,
+    Query(query): Query<WsQuery>,
+    headers: HeaderMap,
+    ws: WebSocketUpgrade,
+) -> Result<Response, AuthError> {
+    // Autenticar com o mesmo JWT usado pelo auth_middleware
+    let token = match headers.get("Authorization") {
+        Some(header) => header
+            .to_str()
+            .ok()
+            .and_then(|h| h.strip_prefix("Bearer "))
+            .ok_or(AuthError::InvalidFormat)?
+            .to_string(),
+        None => query.token.ok_or(AuthError::MissingToken)?,
+    };
+
+    let claims = validate_jwt(&token).map_err(|_| AuthError::InvalidToken)?;
+    state.token_revocation.ensure_active(&claims).await?;
+    let user_id = claims.user_id().map_err(|_| AuthError::InvalidToken)?;
+
+    Ok(ws.on_upgrade(move |socket| handle_socket(socket, state, claims, user_id)))
+}
+
+/// Encaminha os eventos das tarefas visíveis ao usuário até o cliente desconectar
+///
+/// A conexão é encerrada quando o token expira ou é revogado (logout, "sair de
+/// todos", redefinição de senha).
+async fn handle_socket(mut socket: WebSocket, state: WsState, claims: Claims, user_id: Uuid) {
+    let mut events = state.event_bus.subscribe();
+    let access_policy = TaskAccessPolicy::new(state.collaborator_repository);
+
+    let expires_in = (claims.expires_at() - Utc::now())
+        .to_std()
+        .unwrap_or_default();
+    let expiration = tokio::time::sleep(expires_in);
+    tokio::pin!(expiration);
+
+    let mut revocation_check = tokio::time::interval(REVOCATION_CHECK_INTERVAL);
+    revocation_check.tick().await;
+
+    // Tarefas já entregues a este usuário, para encaminhar também a deleção
+    // (os colaboradores são removidos junto com a tarefa)
+    let mut visible_tasks: HashSet<Uuid> = HashSet::new();
+
+    loop {
+        tokio::select! {
+            event = events.recv() => match event {
+                Ok(event) => {
+                    let can_view = match event.kind {
+                        TaskEventKind::TaskDeleted => {
+                            event.owner_id == user_id || visible_tasks.remove(&event.task_id)
+                        }
+                        _ => match access_policy
+                            .can_view(&event.task_id, &event.owner_id, &user_id)
+                            .await
+                        {
+                            Ok(can_view) => can_view,
+                            Err(e) => {
+                                tracing::warn!("Falha ao verificar acesso ao evento {}: {}", event.id, e);
+                                false
+                            }
+                        },
+                    };
+
+                    if !can_view {
+                        visible_tasks.remove(&event.task_id);
+                        continue;
+                    }
+
+                    if event.kind != TaskEventKind::TaskDeleted {
+                        visible_tasks.insert(event.task_id);
+                    }
+
+                    let message = TaskEventMessageDto::from(event);
+                    let Ok(payload) = serde_json::to_string(&message) else {
+                        continue;
+                    };
+
+                    if socket.send(Message::Text(payload.into())).await.is_err() {
+                        break;
+                    }
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    tracing::warn!("Sessão WebSocket de {} perdeu {} eventos", user_id, skipped);
+                }
+                Err(RecvError::Closed) => break,
+            },
+            incoming = socket.recv() => match incoming {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                // Ping/Pong são respondidos automaticamente; demais mensagens são ignoradas
+                Some(Ok(_)) => {}
+            },
+            _ = &mut expiration => {
+                close(&mut socket, "Token expirado").await;
+                break;
+            }
+            _ = revocation_check.tick() => {
+                if state.token_revocation.ensure_active(&claims).await.is_err() {
+                    close(&mut socket, "Token revogado").await;
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// Encerra a conexão informando o motivo (código 1008, violação de política)
+async fn close(socket: &mut WebSocket, reason: &'static str) {
+    let frame = CloseFrame {
+        code: close_code::POLICY,
+        reason: reason.into(),
+    };
+
+    socket.send(Message::Close(Some(frame))).await.ok();
+}
@@ -0,0 +1,22 @@
+use crate::interface::handlers::{
+    AdminState, admin_reset_password_handler, disable_user_handler, enable_user_handler,
+    list_users_handler, system_statistics_handler,
+};
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+/// Rotas de administração (protegidas; o extractor `AdminUser` exige o papel `admin`)
+pub fn routes(state: AdminState) -> Router {
+    Router::new()
+        .route("/admin/users", get(list_users_handler))
+        .route("/admin/users/:id/disable", post(disable_user_handler))
+        .route("/admin/users/:id/enable", post(enable_user_handler))
+        .route(
+            "/admin/users/:id/password-reset",
+            post(admin_reset_password_handler),
+        )
+        .route("/admin/stats", get(system_statistics_handler))
+        .with_state(state)
+}
@@ -1,10 +1,24 @@
-use crate::interface::handlers::{AuthState, login_handler, register_handler};
-use axum::{Router, routing::post};
+use crate::interface::handlers::{
+    AuthState, forgot_password_handler, login_handler, logout_handler, refresh_handler,
+    register_handler, resend_verification_handler, reset_password_handler, verify_email_handler,
+    verify_mfa_login_handler,
+};
+use axum::{
+    Router,
+    routing::{get, post},
+};
 
 /// Rotas de autenticação
 pub fn routes(state: AuthState) -> Router {
     Router::new()
         .route("/auth/register", post(register_handler))
         .route("/auth/login", post(login_handler))
+        .route("/auth/login/2fa", post(verify_mfa_login_handler))
+        .route("/auth/refresh", post(refresh_handler))
+        .route("/auth/logout", post(logout_handler))
+        .route("/auth/verify", get(verify_email_handler))
+        .route("/auth/verify/resend", post(resend_verification_handler))
+        .route("/auth/password/forgot", post(forgot_password_handler))
+        .route("/auth/password/reset", post(reset_password_handler))
         .with_state(state)
 }
@@ -1,30 +1,120 @@
+pub mod admin_routes;
 pub mod auth_routes;
+pub mod project_routes;
+pub mod session_routes;
 pub mod task_routes;
+pub mod two_factor_routes;
+pub mod user_routes;
+pub mod webhook_routes;
+pub mod workflow_routes;
+pub mod workspace_routes;
 
 use crate::Config;
-use crate::domain::repositories::{TaskRepository, UserRepository};
+use crate::application::services::PasswordResetQueue;
+use crate::domain::repositories::{
+    AttachmentRepository, AttachmentStorage, CollaboratorRepository, CommentRepository,
+    DataRequestRepository, DependencyRepository, EmailVerificationRepository,
+    LoginAttemptRepository, Mailer, PasswordResetRepository, ProjectRepository, SessionRepository,
+    StatisticsRepository, TaskRepository, TwoFactorRepository, UserRepository, WebhookClient,
+    WebhookDeliveryRepository, WebhookRepository, WorkflowRepository, WorkspaceRepository,
+};
+use crate::infrastructure::events::InMemoryEventBus;
 use crate::infrastructure::middleware::{auth_middleware, logging_middleware};
-use crate::interface::handlers::{AuthState, TaskState};
+use crate::infrastructure::security::TokenRevocationService;
+use crate::interface::handlers::{
+    AdminState, AuthState, TaskState, WebhookState, WsState, jwks_handler, ws_handler,
+};
 use axum::{
-    Router, middleware,
+    Extension, Router, middleware,
     routing::{get, post},
 };
 use std::sync::Arc;
 use tower_http::cors::{Any, CorsLayer};
 
+/// Repositórios e serviços de infraestrutura usados pelas rotas
+#[derive(Clone)]
+pub struct AppRepositories {
+    pub user_repository: Arc<dyn UserRepository>,
+    pub session_repository: Arc<dyn SessionRepository>,
+    pub password_reset_repository: Arc<dyn PasswordResetRepository>,
+    pub password_reset_queue: PasswordResetQueue,
+    pub email_verification_repository: Arc<dyn EmailVerificationRepository>,
+    pub two_factor_repository: Arc<dyn TwoFactorRepository>,
+    pub login_attempt_repository: Arc<dyn LoginAttemptRepository>,
+    pub data_request_repository: Arc<dyn DataRequestRepository>,
+    pub statistics_repository: Arc<dyn StatisticsRepository>,
+    pub workspace_repository: Arc<dyn WorkspaceRepository>,
+    pub project_repository: Arc<dyn ProjectRepository>,
+    pub workflow_repository: Arc<dyn WorkflowRepository>,
+    /// Tarefas de todos os workspaces (base de `TaskState::tasks`)
+    pub cross_tenant_tasks: Arc<dyn TaskRepository>,
+    pub collaborator_repository: Arc<dyn CollaboratorRepository>,
+    pub dependency_repository: Arc<dyn DependencyRepository>,
+    pub comment_repository: Arc<dyn CommentRepository>,
+    pub attachment_repository: Arc<dyn AttachmentRepository>,
+    pub attachment_storage: Arc<dyn AttachmentStorage>,
+    pub webhook_repository: Arc<dyn WebhookRepository>,
+    pub webhook_delivery_repository: Arc<dyn WebhookDeliveryRepository>,
+    pub webhook_client: Arc<dyn WebhookClient>,
+    pub event_bus: Arc<InMemoryEventBus>,
+    pub token_revocation: Arc<TokenRevocationService>,
+    pub mailer: Arc<dyn Mailer>,
+}
+
 /// Cria todas as rotas da aplicação
-pub fn create_routes(
-    _config: Config,
-    user_repository: Arc<dyn UserRepository>,
-    task_repository: Arc<dyn TaskRepository>,
-) -> Router {
+pub fn create_routes(config: Config, repositories: AppRepositories) -> Router {
     // States
+    let admin_state = AdminState {
+        config: config.clone(),
+        user_repository: repositories.user_repository.clone(),
+        session_repository: repositories.session_repository.clone(),
+        token_revocation: repositories.token_revocation.clone(),
+        password_reset_repository: repositories.password_reset_repository.clone(),
+        statistics_repository: repositories.statistics_repository,
+        mailer: repositories.mailer.clone(),
+    };
+
     let auth_state = AuthState {
-        user_repository: user_repository.clone(),
+        config: config.clone(),
+        user_repository: repositories.user_repository.clone(),
+        workspace_repository: repositories.workspace_repository.clone(),
+        session_repository: repositories.session_repository,
+        token_revocation: repositories.token_revocation.clone(),
+        password_reset_repository: repositories.password_reset_repository,
+        password_reset_queue: repositories.password_reset_queue,
+        email_verification_repository: repositories.email_verification_repository,
+        two_factor_repository: repositories.two_factor_repository,
+        login_attempt_repository: repositories.login_attempt_repository,
+        data_request_repository: repositories.data_request_repository,
+        attachment_storage: repositories.attachment_storage.clone(),
+        mailer: repositories.mailer,
     };
 
     let task_state = TaskState {
-        task_repository: task_repository.clone(),
+        config: config.clone(),
+        cross_tenant_tasks: repositories.cross_tenant_tasks,
+        collaborator_repository: repositories.collaborator_repository.clone(),
+        dependency_repository: repositories.dependency_repository,
+        comment_repository: repositories.comment_repository,
+        attachment_repository: repositories.attachment_repository,
+        attachment_storage: repositories.attachment_storage,
+        user_repository: repositories.user_repository,
+        workspace_repository: repositories.workspace_repository,
+        project_repository: repositories.project_repository,
+        workflow_repository: repositories.workflow_repository,
+        event_bus: repositories.event_bus.clone(),
+    };
+
+    let webhook_state = WebhookState {
+        webhook_repository: repositories.webhook_repository,
+        delivery_repository: repositories.webhook_delivery_repository,
+        webhook_client: repositories.webhook_client,
+    };
+
+    let ws_state = WsState {
+        event_bus: repositories.event_bus,
+        token_revocation: repositories.token_revocation.clone(),
+        collaborator_repository: repositories.collaborator_repository,
     };
 
     // CORS
@@ -34,23 +124,43 @@ pub fn create_routes(
         .allow_headers(Any);
 
     // Rotas públicas
-    let public_routes = Router::new().merge(auth_routes::routes(auth_state));
+    let public_routes = Router::new()
+        .merge(auth_routes::routes(auth_state.clone()))
+        // WebSocket autentica pelo token na query ou no header
+        .route("/ws", get(ws_handler).with_state(ws_state));
 
     // Rotas protegidas (requerem autenticação)
     let protected_routes = Router::new()
+        .merge(session_routes::routes(auth_state.clone()))
+        .merge(two_factor_routes::routes(auth_state.clone()))
+        .merge(user_routes::routes(auth_state.clone()))
+        .merge(workspace_routes::routes(auth_state))
+        .merge(project_routes::routes(task_state.clone()))
+        .merge(workflow_routes::routes(task_state.clone()))
         .merge(task_routes::routes(task_state))
-        .layer(middleware::from_fn(auth_middleware));
+        .merge(webhook_routes::routes(webhook_state))
+        .merge(admin_routes::routes(admin_state))
+        .layer(middleware::from_fn_with_state(
+            repositories.token_revocation,
+            auth_middleware,
+        ));
 
     // Health check
     let health_routes = Router::new().route("/health", get(health_check));
 
+    // Chaves públicas para verificação de tokens por outros serviços
+    let well_known_routes = Router::new().route("/.well-known/jwks.json", get(jwks_handler));
+
     // Combinar todas as rotas
     Router::new()
         .nest("/api", public_routes)
         .nest("/api", protected_routes)
         .merge(health_routes)
+        .merge(well_known_routes)
         .layer(cors)
         .layer(middleware::from_fn(logging_middleware))
+        // Proxies confiáveis para o extractor `ClientInfo`
+        .layer(Extension(config.trusted_proxies))
 }
 
 /// Handler de health check
@@ -0,0 +1,19 @@
+use crate::interface::handlers::{
+    TaskState, create_project_handler, delete_project_handler, get_project_handler,
+    list_projects_handler, update_project_handler,
+};
+use axum::{
+    Router,
+    routing::{delete, get, post, put},
+};
+
+/// Rotas de projetos do workspace ativo (todas protegidas por autenticação)
+pub fn routes(state: TaskState) -> Router {
+    Router::new()
+        .route("/projects", post(create_project_handler))
+        .route("/projects", get(list_projects_handler))
+        .route("/projects/:id", get(get_project_handler))
+        .route("/projects/:id", put(update_project_handler))
+        .route("/projects/:id", delete(delete_project_handler))
+        .with_state(state)
+}
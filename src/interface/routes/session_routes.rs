@@ -0,0 +1,16 @@
+use crate::interface::handlers::{
+    AuthState, list_sessions_handler, logout_all_handler, revoke_session_handler,
+};
+use axum::{
+    Router,
+    routing::{delete, get, post},
+};
+
+/// Rotas de sessões do usuário (todas protegidas por autenticação)
+pub fn routes(state: AuthState) -> Router {
+    Router::new()
+        .route("/auth/sessions", get(list_sessions_handler))
+        .route("/auth/sessions/:id", delete(revoke_session_handler))
+        .route("/auth/logout-all", post(logout_all_handler))
+        .with_state(state)
+}
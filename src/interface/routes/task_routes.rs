@@ -1,20 +1,76 @@
 use crate::interface::handlers::{
-    TaskState, create_task_handler, delete_task_handler, get_task_handler,
-    get_task_history_handler, list_tasks_handler, update_task_handler,
+    TaskState, add_dependency_handler, create_comment_handler, create_task_handler,
+    delete_attachment_handler, delete_comment_handler, delete_task_handler,
+    download_attachment_handler, get_board_handler, get_dependencies_handler, get_task_handler,
+    get_task_history_handler, invite_collaborator_handler, list_attachments_handler,
+    list_collaborators_handler, list_comments_handler, list_next_tasks_handler,
+    list_subtasks_handler, list_tasks_handler, move_task_handler, remove_dependency_handler,
+    reopen_task_handler, revoke_collaborator_handler, task_events_handler, update_comment_handler,
+    update_task_handler, upload_attachment_handler,
 };
 use axum::{
     Router,
+    extract::DefaultBodyLimit,
     routing::{delete, get, post, put},
 };
 
+/// Folga sobre o tamanho máximo do anexo para os cabeçalhos do multipart
+const MULTIPART_OVERHEAD_BYTES: usize = 64 * 1024;
+
 /// Rotas de tarefas (todas protegidas por autenticação)
 pub fn routes(state: TaskState) -> Router {
+    let upload_limit = state.config.attachment_max_bytes as usize + MULTIPART_OVERHEAD_BYTES;
+
     Router::new()
         .route("/tasks", post(create_task_handler))
         .route("/tasks", get(list_tasks_handler))
+        .route("/tasks/next", get(list_next_tasks_handler))
+        .route("/tasks/events", get(task_events_handler))
+        .route("/board", get(get_board_handler))
         .route("/tasks/:id", get(get_task_handler))
         .route("/tasks/:id", put(update_task_handler))
         .route("/tasks/:id", delete(delete_task_handler))
+        .route("/tasks/:id/move", post(move_task_handler))
+        .route("/tasks/:id/reopen", post(reopen_task_handler))
         .route("/tasks/:id/history", get(get_task_history_handler))
+        .route("/tasks/:id/subtasks", get(list_subtasks_handler))
+        .route("/tasks/:id/collaborators", get(list_collaborators_handler))
+        .route(
+            "/tasks/:id/collaborators",
+            post(invite_collaborator_handler),
+        )
+        .route(
+            "/tasks/:id/collaborators/:user_id",
+            delete(revoke_collaborator_handler),
+        )
+        .route("/tasks/:id/dependencies", get(get_dependencies_handler))
+        .route("/tasks/:id/dependencies", post(add_dependency_handler))
+        .route(
+            "/tasks/:id/dependencies/:blocked_by_id",
+            delete(remove_dependency_handler),
+        )
+        .route("/tasks/:id/comments", get(list_comments_handler))
+        .route("/tasks/:id/comments", post(create_comment_handler))
+        .route(
+            "/tasks/:id/comments/:comment_id",
+            put(update_comment_handler),
+        )
+        .route(
+            "/tasks/:id/comments/:comment_id",
+            delete(delete_comment_handler),
+        )
+        .route("/tasks/:id/attachments", get(list_attachments_handler))
+        .route(
+            "/tasks/:id/attachments",
+            post(upload_attachment_handler).layer(DefaultBodyLimit::max(upload_limit)),
+        )
+        .route(
+            "/tasks/:id/attachments/:attachment_id",
+            get(download_attachment_handler),
+        )
+        .route(
+            "/tasks/:id/attachments/:attachment_id",
+            delete(delete_attachment_handler),
+        )
         .with_state(state)
 }
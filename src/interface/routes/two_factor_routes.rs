@@ -0,0 +1,13 @@
+use crate::interface::handlers::{
+    AuthState, confirm_totp_handler, disable_totp_handler, enroll_totp_handler,
+};
+use axum::{Router, routing::post};
+
+/// Rotas de autenticação em dois fatores (todas protegidas por autenticação)
+pub fn routes(state: AuthState) -> Router {
+    Router::new()
+        .route("/auth/2fa/enroll", post(enroll_totp_handler))
+        .route("/auth/2fa/confirm", post(confirm_totp_handler))
+        .route("/auth/2fa/disable", post(disable_totp_handler))
+        .with_state(state)
+}
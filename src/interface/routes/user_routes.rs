@@ -0,0 +1,32 @@
+use crate::interface::handlers::{
+    AuthState, change_email_handler, change_password_handler, delete_account_handler,
+    download_data_export_handler, get_data_export_handler, get_data_request_handler,
+    get_profile_handler, request_data_export_handler, update_profile_handler,
+};
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+/// Rotas do perfil do usuário (todas protegidas por autenticação)
+pub fn routes(state: AuthState) -> Router {
+    Router::new()
+        .route(
+            "/users/me",
+            get(get_profile_handler)
+                .patch(update_profile_handler)
+                .delete(delete_account_handler),
+        )
+        .route("/users/me/password", post(change_password_handler))
+        .route("/users/me/email", post(change_email_handler))
+        .route(
+            "/users/me/export",
+            get(get_data_export_handler).post(request_data_export_handler),
+        )
+        .route(
+            "/users/me/export/download",
+            get(download_data_export_handler),
+        )
+        .route("/users/me/data-requests/:id", get(get_data_request_handler))
+        .with_state(state)
+}
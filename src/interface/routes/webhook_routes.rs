@@ -0,0 +1,23 @@
+use crate::interface::handlers::{
+    WebhookState, create_webhook_handler, delete_webhook_handler, get_webhook_handler,
+    list_webhook_deliveries_handler, list_webhooks_handler, update_webhook_handler,
+};
+use axum::{
+    Router,
+    routing::{delete, get, post, put},
+};
+
+/// Rotas de webhooks (todas protegidas por autenticação)
+pub fn routes(state: WebhookState) -> Router {
+    Router::new()
+        .route("/webhooks", post(create_webhook_handler))
+        .route("/webhooks", get(list_webhooks_handler))
+        .route("/webhooks/:id", get(get_webhook_handler))
+        .route("/webhooks/:id", put(update_webhook_handler))
+        .route("/webhooks/:id", delete(delete_webhook_handler))
+        .route(
+            "/webhooks/:id/deliveries",
+            get(list_webhook_deliveries_handler),
+        )
+        .with_state(state)
+}
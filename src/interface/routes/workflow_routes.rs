@@ -0,0 +1,18 @@
+use crate::interface::handlers::{
+    TaskState, create_workflow_handler, delete_workflow_handler, get_workflow_handler,
+    list_workflows_handler,
+};
+use axum::{
+    Router,
+    routing::{delete, get, post},
+};
+
+/// Rotas de workflows do workspace ativo (todas protegidas por autenticação)
+pub fn routes(state: TaskState) -> Router {
+    Router::new()
+        .route("/workflows", post(create_workflow_handler))
+        .route("/workflows", get(list_workflows_handler))
+        .route("/workflows/:id", get(get_workflow_handler))
+        .route("/workflows/:id", delete(delete_workflow_handler))
+        .with_state(state)
+}
@@ -0,0 +1,23 @@
+use crate::interface::handlers::{
+    AuthState, create_workspace_handler, invite_member_handler, list_members_handler,
+    list_workspaces_handler, switch_workspace_handler,
+};
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+/// Rotas de workspaces (todas protegidas por autenticação)
+pub fn routes(state: AuthState) -> Router {
+    Router::new()
+        .route(
+            "/workspaces",
+            get(list_workspaces_handler).post(create_workspace_handler),
+        )
+        .route(
+            "/workspaces/:id/members",
+            get(list_members_handler).post(invite_member_handler),
+        )
+        .route("/workspaces/:id/switch", post(switch_workspace_handler))
+        .with_state(state)
+}
@@ -0,0 +1,24 @@
+use crate::application::use_cases::ProcessDataRequestsUseCase;
+use std::time::Duration;
+
+/// Intervalo entre varreduras da fila de solicitações de dados
+const PROCESS_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Inicia o worker de exportação e eliminação de dados pessoais
+pub fn spawn_data_request_worker(process: ProcessDataRequestsUseCase) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(PROCESS_INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            match process.execute().await {
+                Ok(0) => {}
+                Ok(processed) => {
+                    tracing::debug!("{} solicitações de dados processadas", processed)
+                }
+                Err(e) => tracing::error!("❌ Erro ao processar solicitações de dados: {}", e),
+            }
+        }
+    });
+}
@@ -0,0 +1,9 @@
+pub mod data_request_worker;
+pub mod password_reset_worker;
+pub mod token_purge_worker;
+pub mod webhook_worker;
+
+pub use data_request_worker::spawn_data_request_worker;
+pub use password_reset_worker::spawn_password_reset_worker;
+pub use token_purge_worker::spawn_token_purge_worker;
+pub use webhook_worker::spawn_webhook_workers;
@@ -0,0 +1,15 @@
+use crate::application::services::{PasswordResetQueue, PasswordResetService};
+
+/// Envios de redefinição de senha aguardando o worker
+const QUEUE_CAPACITY: usize = 256;
+
+/// Inicia o worker que envia os links de redefinição de senha
+///
+/// Retorna a fila (limitada) usada pelas requisições para agendar os envios.
+pub fn spawn_password_reset_worker(password_reset: PasswordResetService) -> PasswordResetQueue {
+    let (queue, mut jobs) = PasswordResetQueue::new(password_reset, QUEUE_CAPACITY);
+
+    tokio::spawn(async move { while jobs.process_next().await {} });
+
+    queue
+}
@@ -0,0 +1,25 @@
+use crate::infrastructure::security::TokenRevocationService;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Intervalo entre limpezas das revogações expiradas
+const PURGE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Inicia o worker que remove revogações de tokens já expirados
+pub fn spawn_token_purge_worker(token_revocation: Arc<TokenRevocationService>) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(PURGE_INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            match token_revocation.purge_expired().await {
+                Ok(0) => {}
+                Ok(purged) => {
+                    tracing::debug!("{} revogações de tokens expiradas removidas", purged)
+                }
+                Err(e) => tracing::error!("❌ Erro ao limpar revogações de tokens: {}", e),
+            }
+        }
+    });
+}
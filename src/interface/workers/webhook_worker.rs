@@ -0,0 +1,57 @@
+use crate::application::use_cases::{
+    DispatchWebhookDeliveriesUseCase, EnqueueWebhookDeliveriesUseCase,
+};
+use crate::infrastructure::events::InMemoryEventBus;
+use std::time::Duration;
+use tokio::sync::broadcast::error::RecvError;
+
+/// Intervalo entre varreduras da fila de entregas
+const DISPATCH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Inicia os workers de webhooks em background
+///
+/// - o listener converte eventos do barramento em entregas persistidas;
+/// - o dispatcher envia as entregas vencidas, aplicando backoff e dead letter.
+pub fn spawn_webhook_workers(
+    event_bus: &InMemoryEventBus,
+    enqueue: EnqueueWebhookDeliveriesUseCase,
+    dispatch: DispatchWebhookDeliveriesUseCase,
+) {
+    let mut events = event_bus.subscribe();
+
+    tokio::spawn(async move {
+        loop {
+            match events.recv().await {
+                Ok(event) => {
+                    if let Err(e) = enqueue.execute(event).await {
+                        tracing::error!("❌ Erro ao enfileirar webhooks: {}", e);
+                    }
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    tracing::warn!("Listener de webhooks perdeu {} eventos", skipped);
+                }
+                Err(RecvError::Closed) => break,
+            }
+        }
+    });
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(DISPATCH_INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            // Esvazia a fila antes de aguardar o próximo ciclo
+            loop {
+                match dispatch.execute().await {
+                    Ok(0) => break,
+                    Ok(_) => continue,
+                    Err(e) => {
+                        tracing::error!("❌ Erro ao enviar webhooks: {}", e);
+                        break;
+                    }
+                }
+            }
+        }
+    });
+}
@@ -3,3 +3,7 @@ pub mod config;
 pub mod domain;
 pub mod infrastructure;
 pub mod interface;
+
+/// Repositórios em memória compartilhados pelos testes unitários
+#[cfg(test)]
+pub mod test_support;
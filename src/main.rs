@@ -1,9 +1,17 @@
 use anyhow::Result;
+use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Duration;
 use todo_api::{
     Config,
-    infrastructure::{self, repositories},
-    interface::routes,
+    application::services::PasswordResetService,
+    application::use_cases::{
+        DispatchWebhookDeliveriesUseCase, EnqueueWebhookDeliveriesUseCase,
+        ProcessDataRequestsUseCase,
+    },
+    domain::repositories::Mailer,
+    infrastructure::{self, events, mail, repositories, security, storage, webhooks},
+    interface::{routes, workers},
 };
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
@@ -24,6 +32,15 @@ async fn main() -> Result<()> {
     let config = Config::from_env()?;
     tracing::info!("✅ Configuração carregada");
 
+    // Chaves de assinatura/verificação de JWT
+    let jwt_keys = config.jwt_keys()?;
+    tracing::info!(
+        "🔑 Chaves JWT carregadas ({:?}, kid: {})",
+        jwt_keys.algorithm(),
+        jwt_keys.signing_kid().unwrap_or("-")
+    );
+    security::install_jwt_keys(jwt_keys)?;
+
     // Configurar pool de conexões do banco de dados
     tracing::info!("📦 Conectando ao banco de dados...");
     let db_pool = infrastructure::database::create_pool(&config.database_url).await?;
@@ -40,14 +57,172 @@ async fn main() -> Result<()> {
         })?;
     tracing::info!("✅ Migrations executadas com sucesso");
 
+    // O isolamento entre workspaces depende de RLS, ignorado por superusuários
+    if infrastructure::database::bypasses_row_level_security(&db_pool).await? {
+        tracing::warn!(
+            "⚠️  O papel do banco ignora row-level security; use um papel sem SUPERUSER/BYPASSRLS para isolar os workspaces"
+        );
+    }
+
     // Criar repositórios (implementações concretas)
     let user_repository = Arc::new(repositories::PostgresUserRepository::new(db_pool.clone()));
-    let task_repository = Arc::new(repositories::PostgresTaskRepository::new(db_pool.clone()));
+    let session_repository = Arc::new(repositories::PostgresSessionRepository::new(
+        db_pool.clone(),
+    ));
+    let revoked_token_repository = Arc::new(repositories::PostgresRevokedTokenRepository::new(
+        db_pool.clone(),
+    ));
+    let password_reset_repository = Arc::new(repositories::PostgresPasswordResetRepository::new(
+        db_pool.clone(),
+    ));
+    let email_verification_repository = Arc::new(
+        repositories::PostgresEmailVerificationRepository::new(db_pool.clone()),
+    );
+    let two_factor_repository = Arc::new(repositories::PostgresTwoFactorRepository::new(
+        db_pool.clone(),
+    ));
+    let login_attempt_repository = Arc::new(repositories::PostgresLoginAttemptRepository::new(
+        db_pool.clone(),
+    ));
+    let data_request_repository = Arc::new(repositories::PostgresDataRequestRepository::new(
+        db_pool.clone(),
+    ));
+    let personal_data_repository = Arc::new(repositories::PostgresPersonalDataRepository::new(
+        db_pool.clone(),
+    ));
+    let statistics_repository = Arc::new(repositories::PostgresStatisticsRepository::new(
+        db_pool.clone(),
+    ));
+    let workspace_repository = Arc::new(repositories::PostgresWorkspaceRepository::new(
+        db_pool.clone(),
+    ));
+    let project_repository = Arc::new(repositories::PostgresProjectRepository::new(
+        db_pool.clone(),
+    ));
+    let workflow_repository = Arc::new(repositories::PostgresWorkflowRepository::new(
+        db_pool.clone(),
+    ));
+    // Base dos repositórios por workspace: os handlers usam `for_workspace`
+    let cross_tenant_tasks = Arc::new(repositories::PostgresTaskRepository::cross_tenant(
+        db_pool.clone(),
+    ));
+    let collaborator_repository = Arc::new(repositories::PostgresCollaboratorRepository::new(
+        db_pool.clone(),
+    ));
+    let dependency_repository = Arc::new(repositories::PostgresDependencyRepository::new(
+        db_pool.clone(),
+    ));
+    let comment_repository = Arc::new(repositories::PostgresCommentRepository::new(
+        db_pool.clone(),
+    ));
+    let attachment_repository = Arc::new(repositories::PostgresAttachmentRepository::new(
+        db_pool.clone(),
+    ));
+    let attachment_storage = Arc::new(storage::LocalAttachmentStorage::new(
+        &config.attachment_storage_dir,
+    ));
+    let webhook_repository = Arc::new(repositories::PostgresWebhookRepository::new(
+        db_pool.clone(),
+    ));
+    let webhook_delivery_repository = Arc::new(
+        repositories::PostgresWebhookDeliveryRepository::new(db_pool.clone()),
+    );
 
     tracing::info!("✅ Repositórios inicializados");
 
+    // Revogação de access tokens (jti + versão por usuário, com cache em memória)
+    let token_revocation = Arc::new(security::TokenRevocationService::new(
+        revoked_token_repository,
+        user_repository.clone(),
+        Duration::from_secs(config.token_revocation_cache_secs),
+    ));
+    workers::spawn_token_purge_worker(token_revocation.clone());
+
+    // Envio de emails (arquivos .eml locais ou SMTP)
+    let mailer: Arc<dyn Mailer> = match config.mail_transport {
+        mail::MailTransport::Log => Arc::new(mail::LogMailer::new(
+            &config.mail_outbox_dir,
+            &config.mail_from,
+        )),
+        mail::MailTransport::Smtp => Arc::new(mail::SmtpMailer::new(
+            config.smtp_settings(),
+            &config.mail_from,
+        )?),
+    };
+    tracing::info!("✅ Mailer inicializado ({:?})", config.mail_transport);
+
+    // Barramento de eventos de domínio (notificações em tempo real)
+    let event_bus = Arc::new(events::InMemoryEventBus::default());
+
+    // Workers de webhooks (fila persistente com novas tentativas)
+    let webhook_client = Arc::new(webhooks::HttpWebhookClient::new(
+        Duration::from_secs(config.webhook_timeout_secs),
+        config.webhook_destination_policy(),
+    )?);
+    workers::spawn_webhook_workers(
+        &event_bus,
+        EnqueueWebhookDeliveriesUseCase::new(
+            webhook_repository.clone(),
+            webhook_delivery_repository.clone(),
+        ),
+        DispatchWebhookDeliveriesUseCase::new(
+            webhook_repository.clone(),
+            webhook_delivery_repository.clone(),
+            webhook_client.clone(),
+            config.webhook_retry_policy(),
+        ),
+    );
+    tracing::info!("✅ Workers de webhooks iniciados");
+
+    // Worker de envio dos links de redefinição de senha (fila limitada)
+    let password_reset_queue = workers::spawn_password_reset_worker(PasswordResetService::new(
+        password_reset_repository.clone(),
+        mailer.clone(),
+        config.password_reset_ttl(),
+        config.app_base_url.clone(),
+    ));
+    tracing::info!("✅ Worker de redefinição de senha iniciado");
+
+    // Worker de exportação e eliminação de dados pessoais (LGPD/GDPR)
+    workers::spawn_data_request_worker(ProcessDataRequestsUseCase::new(
+        data_request_repository.clone(),
+        personal_data_repository,
+        attachment_storage.clone(),
+        token_revocation.clone(),
+        config.data_export_ttl(),
+    ));
+    tracing::info!("✅ Worker de solicitações de dados iniciado");
+
     // Criar aplicação com todas as rotas
-    let app = routes::create_routes(config.clone(), user_repository, task_repository);
+    let app = routes::create_routes(
+        config.clone(),
+        routes::AppRepositories {
+            user_repository,
+            session_repository,
+            password_reset_repository,
+            password_reset_queue,
+            email_verification_repository,
+            two_factor_repository,
+            login_attempt_repository,
+            data_request_repository,
+            statistics_repository,
+            workspace_repository,
+            project_repository,
+            workflow_repository,
+            cross_tenant_tasks,
+            collaborator_repository,
+            dependency_repository,
+            comment_repository,
+            attachment_repository,
+            attachment_storage,
+            webhook_repository,
+            webhook_delivery_repository,
+            webhook_client,
+            event_bus,
+            token_revocation,
+            mailer,
+        },
+    );
 
     // Configurar endereço do servidor
     let addr = format!("{}:{}", config.server_host, config.server_port);
@@ -60,18 +235,103 @@ async fn main() -> Result<()> {
     tracing::info!("📚 Endpoints disponíveis:");
     tracing::info!("   POST   /api/auth/register      - Registrar usuário");
     tracing::info!("   POST   /api/auth/login         - Login");
+    tracing::info!("   POST   /api/auth/login/2fa     - Concluir login com código 2FA");
+    tracing::info!("   POST   /api/auth/refresh       - Renovar access token");
+    tracing::info!("   POST   /api/auth/logout        - Encerrar sessão");
+    tracing::info!("   GET    /api/auth/verify        - Confirmar email");
+    tracing::info!("   POST   /api/auth/verify/resend - Reenviar email de verificação");
+    tracing::info!("   POST   /api/auth/password/forgot - Solicitar redefinição de senha");
+    tracing::info!("   POST   /api/auth/password/reset  - Redefinir senha");
+    tracing::info!("   GET    /api/auth/sessions      - Listar sessões ativas");
+    tracing::info!("   DELETE /api/auth/sessions/:id  - Revogar sessão");
+    tracing::info!("   POST   /api/auth/logout-all    - Sair de todos os dispositivos");
+    tracing::info!("   POST   /api/auth/2fa/enroll    - Iniciar inscrição no 2FA");
+    tracing::info!(
+        "   POST   /api/auth/2fa/confirm   - Ativar 2FA (retorna códigos de recuperação)"
+    );
+    tracing::info!("   POST   /api/auth/2fa/disable   - Desativar 2FA");
+    tracing::info!("   GET    /api/users/me           - Obter perfil");
+    tracing::info!("   PATCH  /api/users/me           - Atualizar perfil");
+    tracing::info!(
+        "   DELETE /api/users/me           - Excluir conta (anonimização em background)"
+    );
+    tracing::info!("   POST   /api/users/me/password  - Trocar senha");
+    tracing::info!("   POST   /api/users/me/email     - Trocar email (com confirmação)");
+    tracing::info!("   POST   /api/users/me/export    - Solicitar exportação dos dados");
+    tracing::info!("   GET    /api/users/me/export    - Situação da exportação");
+    tracing::info!("   GET    /api/users/me/export/download  - Baixar exportação (JSON)");
+    tracing::info!("   GET    /api/users/me/data-requests/:id - Situação de exportação/eliminação");
+    tracing::info!("   GET    /api/workspaces         - Listar workspaces");
+    tracing::info!("   POST   /api/workspaces         - Criar workspace");
+    tracing::info!("   GET    /api/workspaces/:id/members - Listar membros");
+    tracing::info!("   POST   /api/workspaces/:id/members - Convidar membro");
+    tracing::info!("   POST   /api/workspaces/:id/switch  - Trocar workspace ativo");
+    tracing::info!("   GET    /api/projects           - Listar projetos");
+    tracing::info!("   POST   /api/projects           - Criar projeto");
+    tracing::info!("   GET    /api/projects/:id       - Obter projeto (com contagem por status)");
+    tracing::info!("   PUT    /api/projects/:id       - Atualizar/arquivar projeto");
+    tracing::info!("   DELETE /api/projects/:id       - Deletar projeto");
+    tracing::info!("   GET    /api/workflows          - Listar workflows");
+    tracing::info!("   POST   /api/workflows          - Criar workflow (estados e transições)");
+    tracing::info!("   GET    /api/workflows/:id      - Obter workflow");
+    tracing::info!("   DELETE /api/workflows/:id      - Deletar workflow");
     tracing::info!("   POST   /api/tasks              - Criar tarefa");
     tracing::info!("   GET    /api/tasks              - Listar tarefas");
+    tracing::info!("   GET    /api/tasks/next         - O que fazer a seguir");
+    tracing::info!("   GET    /api/tasks/events       - Stream (SSE) do histórico");
     tracing::info!("   GET    /api/tasks/:id          - Obter tarefa");
     tracing::info!("   PUT    /api/tasks/:id          - Atualizar tarefa");
     tracing::info!("   DELETE /api/tasks/:id          - Deletar tarefa");
+    tracing::info!("   POST   /api/tasks/:id/move     - Mover tarefa no quadro");
+    tracing::info!("   POST   /api/tasks/:id/reopen   - Reabrir tarefa concluída/cancelada");
+    tracing::info!("   GET    /api/board              - Quadro kanban por status");
     tracing::info!("   GET    /api/tasks/:id/history  - Histórico da tarefa");
+    tracing::info!("   GET    /api/tasks/:id/subtasks - Subtarefas da tarefa");
+    tracing::info!("   GET    /api/tasks/:id/collaborators          - Listar colaboradores");
+    tracing::info!("   POST   /api/tasks/:id/collaborators          - Convidar colaborador");
+    tracing::info!("   DELETE /api/tasks/:id/collaborators/:user_id - Revogar colaborador");
+    tracing::info!("   GET    /api/tasks/:id/dependencies                - Listar dependências");
+    tracing::info!("   POST   /api/tasks/:id/dependencies                - Adicionar dependência");
+    tracing::info!("   DELETE /api/tasks/:id/dependencies/:blocked_by_id - Remover dependência");
+    tracing::info!("   GET    /api/tasks/:id/comments             - Listar comentários");
+    tracing::info!("   POST   /api/tasks/:id/comments             - Comentar");
+    tracing::info!("   PUT    /api/tasks/:id/comments/:comment_id - Editar comentário");
+    tracing::info!("   DELETE /api/tasks/:id/comments/:comment_id - Deletar comentário");
+    tracing::info!("   GET    /api/tasks/:id/attachments                - Listar anexos");
+    tracing::info!("   POST   /api/tasks/:id/attachments                - Enviar anexo");
+    tracing::info!("   GET    /api/tasks/:id/attachments/:attachment_id - Baixar anexo");
+    tracing::info!("   DELETE /api/tasks/:id/attachments/:attachment_id - Deletar anexo");
+    tracing::info!("   GET    /api/webhooks                     - Listar webhooks");
+    tracing::info!("   POST   /api/webhooks                     - Cadastrar webhook");
+    tracing::info!("   GET    /api/webhooks/:id                 - Obter webhook");
+    tracing::info!("   PUT    /api/webhooks/:id                 - Atualizar webhook");
+    tracing::info!("   DELETE /api/webhooks/:id                 - Deletar webhook");
+    tracing::info!("   GET    /api/webhooks/:id/deliveries      - Log de entregas");
+    tracing::info!(
+        "   GET    /api/admin/users                     - Listar/buscar usuários (admin)"
+    );
+    tracing::info!("   POST   /api/admin/users/:id/disable         - Desativar conta (admin)");
+    tracing::info!("   POST   /api/admin/users/:id/enable          - Reativar conta (admin)");
+    tracing::info!(
+        "   POST   /api/admin/users/:id/password-reset  - Forçar redefinição de senha (admin)"
+    );
+    tracing::info!(
+        "   GET    /api/admin/stats                     - Estatísticas do sistema (admin)"
+    );
+    tracing::info!("   GET    /api/ws                 - Notificações em tempo real (WebSocket)");
+    tracing::info!("   GET    /.well-known/jwks.json  - Chaves públicas (JWKS)");
     tracing::info!("   GET    /health                 - Health check");
     tracing::info!("");
     tracing::info!("✨ Servidor pronto para receber requisições!");
 
     // Iniciar servidor
-    axum::serve(listener, app).await.map_err(|e| {
+    // ConnectInfo expõe o IP do cliente para o registro de sessões
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await
+    .map_err(|e| {
         tracing::error!("❌ Erro no servidor: {}", e);
         e
     })?;
@@ -0,0 +1,233 @@
+use crate::domain::entities::{Session, User};
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::repositories::task_repository::{PaginatedResult, Pagination};
+use crate::domain::repositories::{
+    RevokedTokenRepository, SessionRepository, UserFilter, UserRepository,
+};
+use crate::domain::value_objects::Email;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use uuid::Uuid;
+
+/// Mock repository de usuários em memória
+#[derive(Default)]
+pub struct MockUserRepository {
+    pub users: Mutex<Vec<User>>,
+    /// Quantidade de consultas à versão dos tokens
+    pub token_version_lookups: AtomicUsize,
+}
+
+impl MockUserRepository {
+    pub fn new(users: Vec<User>) -> Self {
+        Self {
+            users: Mutex::new(users),
+            ..Self::default()
+        }
+    }
+
+    /// Estado atual de um usuário
+    pub fn get(&self, id: &Uuid) -> Option<User> {
+        let users = self.users.lock().unwrap();
+        users.iter().find(|user| &user.id == id).cloned()
+    }
+}
+
+#[async_trait]
+impl UserRepository for MockUserRepository {
+    async fn create(&self, user: &User) -> DomainResult<User> {
+        let mut users = self.users.lock().unwrap();
+        if users.iter().any(|u| u.email == user.email) {
+            return Err(DomainError::Conflict("Email já existe".to_string()));
+        }
+        users.push(user.clone());
+        Ok(user.clone())
+    }
+
+    async fn find_by_id(&self, id: &Uuid) -> DomainResult<Option<User>> {
+        Ok(self.get(id))
+    }
+
+    async fn find_by_email(&self, email: &Email) -> DomainResult<Option<User>> {
+        let users = self.users.lock().unwrap();
+        Ok(users.iter().find(|user| &user.email == email).cloned())
+    }
+
+    async fn update(&self, user: &User) -> DomainResult<User> {
+        let mut users = self.users.lock().unwrap();
+        let stored = users
+            .iter_mut()
+            .find(|u| u.id == user.id)
+            .ok_or(DomainError::NotFound("Usuário não encontrado".to_string()))?;
+
+        // A versão dos tokens só muda por increment_token_version
+        let token_version = stored.token_version;
+        *stored = user.clone();
+        stored.token_version = token_version;
+        Ok(stored.clone())
+    }
+
+    async fn delete(&self, id: &Uuid) -> DomainResult<()> {
+        self.users.lock().unwrap().retain(|user| &user.id != id);
+        Ok(())
+    }
+
+    async fn email_exists(&self, email: &Email) -> DomainResult<bool> {
+        let users = self.users.lock().unwrap();
+        Ok(users.iter().any(|user| &user.email == email))
+    }
+
+    async fn search(
+        &self,
+        filter: UserFilter,
+        pagination: Pagination,
+    ) -> DomainResult<PaginatedResult<User>> {
+        let query = filter.search_query.map(|query| query.to_lowercase());
+        let mut matches: Vec<User> = self
+            .users
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|user| {
+                query.as_ref().is_none_or(|query| {
+                    user.email.as_str().to_lowercase().contains(query)
+                        || user.name.to_lowercase().contains(query)
+                })
+            })
+            .filter(|user| filter.role.is_none_or(|role| user.role == role))
+            .filter(|user| filter.disabled.is_none_or(|d| user.is_disabled() == d))
+            .cloned()
+            .collect();
+        matches.sort_by(|a, b| b.created_at.cmp(&a.created_at).then(a.id.cmp(&b.id)));
+
+        let total = matches.len() as i64;
+        let items = matches
+            .into_iter()
+            .skip(pagination.offset() as usize)
+            .take(pagination.page_size as usize)
+            .collect();
+
+        Ok(PaginatedResult {
+            items,
+            total,
+            page: pagination.page,
+            page_size: pagination.page_size,
+            total_pages: (total as u32).div_ceil(pagination.page_size),
+        })
+    }
+
+    async fn find_token_version(&self, id: &Uuid) -> DomainResult<Option<i32>> {
+        self.token_version_lookups.fetch_add(1, Ordering::SeqCst);
+        Ok(self
+            .get(id)
+            .filter(|user| !user.is_disabled())
+            .map(|user| user.token_version))
+    }
+
+    async fn increment_token_version(&self, id: &Uuid) -> DomainResult<i32> {
+        let mut users = self.users.lock().unwrap();
+        let user = users
+            .iter_mut()
+            .find(|user| &user.id == id)
+            .ok_or(DomainError::NotFound("Usuário não encontrado".to_string()))?;
+
+        user.token_version += 1;
+        Ok(user.token_version)
+    }
+}
+
+/// Mock repository de sessões em memória
+#[derive(Default)]
+pub struct MockSessionRepository {
+    pub sessions: Mutex<Vec<Session>>,
+}
+
+#[async_trait]
+impl SessionRepository for MockSessionRepository {
+    async fn create(&self, session: &Session) -> DomainResult<Session> {
+        self.sessions.lock().unwrap().push(session.clone());
+        Ok(session.clone())
+    }
+
+    async fn find_by_token_hash(&self, token_hash: &str) -> DomainResult<Option<Session>> {
+        let sessions = self.sessions.lock().unwrap();
+        Ok(sessions
+            .iter()
+            .find(|s| s.refresh_token_hash == token_hash)
+            .cloned())
+    }
+
+    async fn mark_rotated(&self, id: &Uuid) -> DomainResult<bool> {
+        let mut sessions = self.sessions.lock().unwrap();
+        let Some(session) = sessions.iter_mut().find(|s| &s.id == id) else {
+            return Ok(false);
+        };
+        if session.rotated_at.is_some() || session.revoked_at.is_some() {
+            return Ok(false);
+        }
+        session.rotated_at = Some(Utc::now());
+        Ok(true)
+    }
+
+    async fn revoke_family(&self, family_id: &Uuid) -> DomainResult<()> {
+        let mut sessions = self.sessions.lock().unwrap();
+        for session in sessions.iter_mut().filter(|s| &s.family_id == family_id) {
+            session.revoked_at.get_or_insert_with(Utc::now);
+        }
+        Ok(())
+    }
+
+    async fn revoke_all_by_user(&self, user_id: &Uuid) -> DomainResult<()> {
+        let mut sessions = self.sessions.lock().unwrap();
+        for session in sessions.iter_mut().filter(|s| &s.user_id == user_id) {
+            session.revoked_at.get_or_insert_with(Utc::now);
+        }
+        Ok(())
+    }
+
+    async fn list_active_by_user(&self, user_id: &Uuid) -> DomainResult<Vec<Session>> {
+        let sessions = self.sessions.lock().unwrap();
+        Ok(sessions
+            .iter()
+            .filter(|s| &s.user_id == user_id && s.is_active())
+            .cloned()
+            .collect())
+    }
+}
+
+/// Mock repository de revogações em memória
+#[derive(Default)]
+pub struct MockRevokedTokenRepository {
+    /// Expiração de cada `jti` revogado
+    pub revoked: Mutex<HashMap<Uuid, DateTime<Utc>>>,
+    /// Quantidade de consultas a `is_revoked`
+    pub lookups: AtomicUsize,
+}
+
+#[async_trait]
+impl RevokedTokenRepository for MockRevokedTokenRepository {
+    async fn revoke(
+        &self,
+        jti: &Uuid,
+        _user_id: &Uuid,
+        expires_at: DateTime<Utc>,
+    ) -> DomainResult<()> {
+        self.revoked.lock().unwrap().insert(*jti, expires_at);
+        Ok(())
+    }
+
+    async fn is_revoked(&self, jti: &Uuid) -> DomainResult<bool> {
+        self.lookups.fetch_add(1, Ordering::SeqCst);
+        Ok(self.revoked.lock().unwrap().contains_key(jti))
+    }
+
+    async fn purge_expired(&self) -> DomainResult<u64> {
+        let mut revoked = self.revoked.lock().unwrap();
+        let before = revoked.len();
+        let now = Utc::now();
+        revoked.retain(|_, expires_at| *expires_at > now);
+        Ok((before - revoked.len()) as u64)
+    }
+}
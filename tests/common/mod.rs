@@ -74,5 +74,5 @@ pub fn generate_test_token(user_id: &Uuid) -> String {
     std::env::set_var("JWT_SECRET", "test-secret-key");
     std::env::set_var("JWT_EXPIRATION_HOURS", "24");
 
-    generate_jwt(user_id).expect("Falha ao gerar token de teste")
+    generate_jwt(user_id, 0).expect("Falha ao gerar token de teste")
 }